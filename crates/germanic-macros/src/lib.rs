@@ -75,7 +75,7 @@ use syn::{DeriveInput, parse_macro_input};
 ///
 /// | Attribute | Type | Description |
 /// |----------|------|-------------|
-/// | `schema_id` | String | Unique schema ID (e.g. `"de.gesundheit.praxis.v1"`) |
+/// | `schema_id` | String | Unique schema ID in `namespace.domain.name.vN` format (e.g. `"de.gesundheit.praxis.v1"`); malformed IDs are a compile error, the `vN` suffix becomes `schema_version()` |
 /// | `flatbuffer` | String | Path to FlatBuffer type (e.g. `"de::praxis::Praxis"`) |
 ///
 /// ## Field-level Attributes
@@ -84,6 +84,27 @@ use syn::{DeriveInput, parse_macro_input};
 /// |----------|------|-------------|
 /// | `required` | Flag | Field must not be `None`/empty |
 /// | `default` | Value | Default value if not specified |
+/// | `min_len` / `max_len` | usize | Length limits (string characters / array elements) |
+/// | `min_items` / `max_items` | usize | Element count limits for `Vec` fields |
+/// | `pattern` | String | Anchored regex the whole string must match |
+/// | `min` / `max` | Number | Numeric range (inclusive) |
+/// | `validate_with` | Path | Custom validator `fn(&T) -> Result<(), String>` |
+/// | `rename` | String | Schema field name when it differs from the Rust name |
+/// | `skip` | Flag | Internal field, excluded from the schema |
+/// | `enumeration` | Flag | Field type is a GermanicSchema enum |
+/// | `flatten` | Flag | Merge a nested struct's fields into the parent schema (pairs with `#[serde(flatten)]`) |
+///
+/// ## Enums
+///
+/// Fieldless enums get `variants()`, `as_str()`, `ordinal()`,
+/// `from_name()` and a `Default` from the `#[germanic(default)]`-marked
+/// variant (or the first).
+///
+/// ## Builder
+///
+/// Every schema struct gets a fluent `{Struct}Builder` via
+/// `Struct::builder()`: one setter per field, unset fields keep their
+/// defaults, and `build()` validates before handing out the value.
 ///
 /// ## Generated Traits
 ///
@@ -34,11 +34,15 @@
 //! - `SchemaMetadata` → schema_id(), schema_version()
 //! - `Validate` → validate()
 //! - `Default` → default()
+//! - `GermanicSerialize` → to_bytes() (only with `flatbuffer = "..."`)
+//! - inherent `schema_definition()` → dynamic [`SchemaDefinition`] mirror
+//! - inherent `json_schema()` → JSON Schema Draft 7 export
+//! - inherent `builder()` → fluent `{Struct}Builder`, validating on `build()`
 
-use darling::{FromDeriveInput, FromField, ast::Data, util::Flag};
+use darling::{FromDeriveInput, FromField, FromVariant, ast::Data, util::Flag};
 use proc_macro::TokenStream;
 use proc_macro2::TokenStream as TokenStream2;
-use quote::quote;
+use quote::{format_ident, quote};
 use syn::{DeriveInput, Ident, Type};
 
 // ============================================================================
@@ -61,9 +65,11 @@ pub struct SchemaOptions {
     data: Data<(), FieldOptions>,
     /// Unique schema ID (required)
     schema_id: String,
-    /// Path to FlatBuffer type (optional, for later)
+    /// Path to the generated FlatBuffer table type (optional).
+    ///
+    /// When set, the macro also implements `GermanicSerialize` by
+    /// generating the FlatBufferBuilder code from the struct fields.
     #[darling(default)]
-    #[allow(dead_code)]
     flatbuffer: Option<String>,
 }
 
@@ -75,20 +81,238 @@ pub struct SchemaOptions {
 ///
 /// #[germanic(default = "DE")]
 /// pub land: String,
+///
+/// #[germanic(min_len = 5, max_len = 5, pattern = "[0-9]{5}")]
+/// pub plz: String,
 /// ```
 #[derive(Debug, FromField)]
-#[darling(attributes(germanic))]
+#[darling(attributes(germanic), forward_attrs(doc))]
 pub struct FieldOptions {
     /// Field name
     ident: Option<Ident>,
     /// Field type
     ty: Type,
+    /// Forwarded attributes (doc comments become field descriptions)
+    attrs: Vec<syn::Attribute>,
     /// Required field flag
     #[darling(default)]
     required: Flag,
     /// Default value as string (e.g. "DE", "true", "false")
     #[darling(default)]
     default: Option<String>,
+    /// Minimum length (string characters / array elements)
+    #[darling(default)]
+    min_len: Option<usize>,
+    /// Maximum length (string characters / array elements)
+    #[darling(default)]
+    max_len: Option<usize>,
+    /// Minimum element count (Vec fields only). Clearer than `min_len`
+    /// on collections: `required` alone only checks non-empty.
+    #[darling(default)]
+    min_items: Option<usize>,
+    /// Maximum element count (Vec fields only)
+    #[darling(default)]
+    max_items: Option<usize>,
+    /// Regex the whole string value must match (anchored)
+    #[darling(default)]
+    pattern: Option<String>,
+    /// Minimum numeric value (inclusive)
+    #[darling(default)]
+    min: Option<SignedNumber>,
+    /// Maximum numeric value (inclusive)
+    #[darling(default)]
+    max: Option<SignedNumber>,
+    /// Custom validator: `fn(&FieldType) -> Result<(), String>`.
+    ///
+    /// Runs with the other constraints; the error message is reported
+    /// as a constraint violation on this field. Rust-only — it has no
+    /// mirror in the dynamic schema definition.
+    #[darling(default)]
+    validate_with: Option<syn::Path>,
+    /// Schema field name when it differs from the Rust field name.
+    ///
+    /// Error paths, `schema_definition()` keys and the FlatBuffer args
+    /// all use this name, so diagnostics match the published schema
+    /// instead of Rust naming conventions.
+    #[darling(default)]
+    rename: Option<String>,
+    /// Internal field (cache, computed value): not validated, not
+    /// serialized, not part of the generated schema definition. The
+    /// Default impl initializes it via `Default::default()`.
+    #[darling(default)]
+    skip: Flag,
+    /// Field type is a `#[derive(GermanicSchema)]` enum.
+    ///
+    /// Enums serialize as strings and map to the dynamic `enum` type
+    /// with the variant names as allowed values. Without this flag the
+    /// macro would treat the type as a nested schema struct.
+    #[darling(default)]
+    enumeration: Flag,
+    /// Merge the nested struct's fields into the parent schema.
+    ///
+    /// Pairs with `#[serde(flatten)]`: the Rust side keeps the
+    /// composition, the schema stays flat. Error paths and
+    /// `schema_definition()` use the nested field names directly,
+    /// without a `{field}.` prefix. Not available together with
+    /// `flatbuffer` serialization — the parent derive cannot see the
+    /// nested struct's fields.
+    #[darling(default)]
+    flatten: Flag,
+}
+
+/// Options at enum level.
+///
+/// ```rust,ignore
+/// #[derive(GermanicSchema)]
+/// pub enum Kassenart {
+///     #[germanic(default)]
+///     Gesetzlich,
+///     Privat,
+/// }
+/// ```
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(germanic), supports(enum_unit))]
+pub struct EnumOptions {
+    /// Enum name
+    ident: Ident,
+    /// Generics
+    generics: syn::Generics,
+    /// Enum variants
+    data: Data<VariantOptions, darling::util::Ignored>,
+}
+
+/// Options at variant level.
+#[derive(Debug, FromVariant)]
+#[darling(attributes(germanic))]
+pub struct VariantOptions {
+    /// Variant name
+    ident: Ident,
+    /// Default variant flag (at most one; otherwise the first variant)
+    #[darling(default)]
+    default: Flag,
+    /// Schema name when it differs from the variant name
+    #[darling(default)]
+    rename: Option<String>,
+}
+
+/// The name a field carries in the schema: `rename` if set, otherwise
+/// the Rust field name.
+fn schema_field_name(field: &FieldOptions, ident: &Ident) -> String {
+    match &field.rename {
+        Some(renamed) => renamed.clone(),
+        None => ident.to_string(),
+    }
+}
+
+/// Collects the `///` doc comment of a field into one description
+/// string, so the rustdoc text reaches schema consumers too.
+fn doc_description(attrs: &[syn::Attribute]) -> Option<String> {
+    let lines: Vec<String> = attrs
+        .iter()
+        .filter_map(|attr| {
+            if !attr.path().is_ident("doc") {
+                return None;
+            }
+            match &attr.meta {
+                syn::Meta::NameValue(name_value) => match &name_value.value {
+                    syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(text),
+                        ..
+                    }) => Some(text.value().trim().to_string()),
+                    _ => None,
+                },
+                _ => None,
+            }
+        })
+        .collect();
+
+    if lines.is_empty() {
+        None
+    } else {
+        Some(lines.join("\n"))
+    }
+}
+
+/// Validates the `schema_id` format and extracts the schema version
+/// from the `vN` suffix.
+///
+/// Expected format: `{namespace}.{domain}.{name}.v{version}` — dot
+/// separated lowercase segments, the last one being `v` followed by the
+/// version number (1-255). Example: `de.gesundheit.praxis.v1`.
+///
+/// A malformed ID is a compile error, not a runtime surprise: the ID is
+/// written to every .grm header and drives registry lookup, so a typo
+/// would otherwise only show up in production data.
+fn parse_schema_version(schema_id: &str) -> Result<u8, darling::Error> {
+    let format_error = || {
+        darling::Error::custom(format!(
+            "invalid schema_id \"{schema_id}\": expected \"namespace.domain.name.vN\" \
+             (e.g. \"de.gesundheit.praxis.v1\")"
+        ))
+    };
+
+    let segments: Vec<&str> = schema_id.split('.').collect();
+
+    // At least namespace + name before the version suffix
+    if segments.len() < 3 {
+        return Err(format_error());
+    }
+
+    let (version_segment, name_segments) = segments.split_last().expect("len checked above");
+    for segment in name_segments {
+        let valid = !segment.is_empty()
+            && segment
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+        if !valid {
+            return Err(format_error());
+        }
+    }
+
+    let Some(version) = version_segment.strip_prefix('v') else {
+        return Err(format_error());
+    };
+    if version.is_empty() || !version.chars().all(|c| c.is_ascii_digit()) {
+        return Err(format_error());
+    }
+    match version.parse::<u8>() {
+        Ok(version) if version >= 1 => Ok(version),
+        _ => Err(darling::Error::custom(format!(
+            "invalid schema_id \"{schema_id}\": version must be between 1 and 255"
+        ))),
+    }
+}
+
+/// Numeric attribute value that also accepts negative literals.
+///
+/// darling's own `f64` rejects the unary minus in `min = -90.0` —
+/// geo coordinates need it.
+#[derive(Debug, Clone, Copy)]
+struct SignedNumber(f64);
+
+impl darling::FromMeta for SignedNumber {
+    fn from_expr(expr: &syn::Expr) -> darling::Result<Self> {
+        match expr {
+            syn::Expr::Unary(syn::ExprUnary {
+                op: syn::UnOp::Neg(_),
+                expr,
+                ..
+            }) => Self::from_expr(expr).map(|n| SignedNumber(-n.0)),
+            syn::Expr::Lit(lit) => Self::from_value(&lit.lit),
+            _ => Err(darling::Error::unexpected_expr_type(expr)),
+        }
+    }
+
+    fn from_value(value: &syn::Lit) -> darling::Result<Self> {
+        match value {
+            // Integer literals are fine too: `min = 0`
+            syn::Lit::Int(lit) => lit
+                .base10_parse::<f64>()
+                .map(SignedNumber)
+                .map_err(darling::Error::from),
+            _ => <f64 as darling::FromMeta>::from_value(value).map(SignedNumber),
+        }
+    }
 }
 
 // ============================================================================
@@ -102,6 +326,11 @@ pub struct FieldOptions {
 /// 2. `Validate` – Required field validation
 /// 3. `Default` – Default values for all fields
 pub fn implement_germanic_schema(input: DeriveInput) -> Result<TokenStream, darling::Error> {
+    // Fieldless enums get their own, much smaller expansion
+    if matches!(&input.data, syn::Data::Enum(_)) {
+        return implement_germanic_enum(input);
+    }
+
     // Parse attributes with darling
     let options = SchemaOptions::from_derive_input(&input)?;
 
@@ -109,6 +338,7 @@ pub fn implement_germanic_schema(input: DeriveInput) -> Result<TokenStream, darl
     let struct_name = &options.ident;
     let (impl_generics, ty_generics, where_clause) = options.generics.split_for_impl();
     let schema_id = &options.schema_id;
+    let schema_version = parse_schema_version(schema_id)?;
 
     // Extract fields
     let fields = match &options.data {
@@ -122,7 +352,27 @@ pub fn implement_germanic_schema(input: DeriveInput) -> Result<TokenStream, darl
 
     // Generate code for the three traits
     let validations = generate_validations(&fields.fields);
+    let constraint_checks = generate_constraint_checks(&fields.fields)?;
     let default_fields = generate_default_fields(&fields.fields);
+    let schema_definition = generate_schema_definition(
+        struct_name,
+        &options.generics,
+        schema_id,
+        schema_version,
+        &fields.fields,
+    )?;
+    let builder = generate_builder(struct_name, &options.generics, &fields.fields);
+
+    // Optional fourth trait: GermanicSerialize from the flatbuffer attribute
+    let serialize_impl = match &options.flatbuffer {
+        Some(path) => generate_serialize(
+            struct_name,
+            &options.generics,
+            path,
+            &fields.fields,
+        )?,
+        None => TokenStream2::new(),
+    };
 
     // Combine everything
     let expanded = quote! {
@@ -138,7 +388,7 @@ pub fn implement_germanic_schema(input: DeriveInput) -> Result<TokenStream, darl
             }
 
             fn schema_version(&self) -> u8 {
-                1
+                #schema_version
             }
         }
 
@@ -148,11 +398,11 @@ pub fn implement_germanic_schema(input: DeriveInput) -> Result<TokenStream, darl
             fn validate(&self) -> ::std::result::Result<(), ::germanic::error::ValidationError> {
                 let mut errors = Vec::new();
                 #validations
-                if errors.is_empty() {
-                    Ok(())
-                } else {
-                    Err(::germanic::error::ValidationError::RequiredFieldsMissing(errors))
+                if !errors.is_empty() {
+                    return Err(::germanic::error::ValidationError::RequiredFieldsMissing(errors));
                 }
+                #constraint_checks
+                Ok(())
             }
         }
 
@@ -165,6 +415,128 @@ pub fn implement_germanic_schema(input: DeriveInput) -> Result<TokenStream, darl
                 }
             }
         }
+
+        #schema_definition
+
+        #builder
+
+        #serialize_impl
+    };
+
+    Ok(expanded.into())
+}
+
+// ============================================================================
+// ENUM SUPPORT
+// ============================================================================
+
+/// Expansion for fieldless enums.
+///
+/// Generates:
+/// - `variants()` – all schema names, in declaration order
+/// - `as_str()` / `from_name()` – string serialization and validation
+/// - `ordinal()` – position in declaration order
+/// - `Default` – the `#[germanic(default)]`-marked variant (or the first)
+/// - `Validate` – trivially Ok: a fieldless enum value is always valid
+fn implement_germanic_enum(input: DeriveInput) -> Result<TokenStream, darling::Error> {
+    let options = EnumOptions::from_derive_input(&input)?;
+    let enum_name = &options.ident;
+    let (impl_generics, ty_generics, where_clause) = options.generics.split_for_impl();
+
+    let variants = match &options.data {
+        Data::Enum(variants) => variants,
+        _ => {
+            return Err(darling::Error::custom(
+                "GermanicSchema on enums requires unit variants",
+            ));
+        }
+    };
+    if variants.is_empty() {
+        return Err(darling::Error::custom(
+            "GermanicSchema enums need at least one variant",
+        ));
+    }
+    if variants.len() > 256 {
+        return Err(darling::Error::custom(
+            "GermanicSchema enums support at most 256 variants (ordinal is u8)",
+        ));
+    }
+
+    let idents: Vec<&Ident> = variants.iter().map(|v| &v.ident).collect();
+    let names: Vec<String> = variants
+        .iter()
+        .map(|v| match &v.rename {
+            Some(renamed) => renamed.clone(),
+            None => v.ident.to_string(),
+        })
+        .collect();
+    let ordinals: Vec<u8> = (0..variants.len()).map(|i| i as u8).collect();
+
+    // Default variant: the marked one, or the first
+    let marked: Vec<&VariantOptions> = variants
+        .iter()
+        .filter(|v| v.default.is_present())
+        .collect();
+    if marked.len() > 1 {
+        return Err(darling::Error::custom(
+            "only one variant may be marked #[germanic(default)]",
+        ));
+    }
+    let default_ident = marked
+        .first()
+        .map(|v| &v.ident)
+        .unwrap_or(&variants[0].ident);
+
+    let expanded = quote! {
+        // ════════════════════════════════════════════════════════════════════
+        // GENERATED CODE - DO NOT EDIT MANUALLY
+        // ════════════════════════════════════════════════════════════════════
+
+        impl #impl_generics #enum_name #ty_generics #where_clause {
+            /// All schema names, in declaration order.
+            pub fn variants() -> &'static [&'static str] {
+                &[#(#names),*]
+            }
+
+            /// The schema string for this variant.
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    #(Self::#idents => #names,)*
+                }
+            }
+
+            /// Position in declaration order (ordinal serialization).
+            pub fn ordinal(&self) -> u8 {
+                match self {
+                    #(Self::#idents => #ordinals,)*
+                }
+            }
+
+            /// Parses a schema string back into a variant.
+            pub fn from_name(name: &str) -> Option<Self> {
+                match name {
+                    #(#names => Some(Self::#idents),)*
+                    _ => None,
+                }
+            }
+        }
+
+        impl #impl_generics ::std::default::Default for #enum_name #ty_generics
+        #where_clause
+        {
+            fn default() -> Self {
+                Self::#default_ident
+            }
+        }
+
+        impl #impl_generics ::germanic::schema::Validate for #enum_name #ty_generics
+        #where_clause
+        {
+            fn validate(&self) -> ::std::result::Result<(), ::germanic::error::ValidationError> {
+                // A fieldless enum value is always one of the allowed variants
+                Ok(())
+            }
+        }
     };
 
     Ok(expanded.into())
@@ -179,6 +551,8 @@ pub fn implement_germanic_schema(input: DeriveInput) -> Result<TokenStream, darl
 /// Logic:
 /// - required String/Vec/Option → check for empty/None
 /// - Nested Structs (Other) → call validate() recursively
+/// - Option<NestedStruct> → validate recursively when Some
+/// - Vec<NestedStruct> → validate each element with indexed paths
 fn generate_validations(fields: &[FieldOptions]) -> TokenStream2 {
     let mut validations = Vec::new();
 
@@ -186,7 +560,10 @@ fn generate_validations(fields: &[FieldOptions]) -> TokenStream2 {
         let Some(field_name) = field.ident.as_ref() else {
             continue;
         };
-        let field_name_str = field_name.to_string();
+        if field.skip.is_present() {
+            continue;
+        }
+        let field_name_str = schema_field_name(field, field_name);
         let ty = type_category(&field.ty);
 
         // 1. Required validation for primitive types
@@ -207,8 +584,8 @@ fn generate_validations(fields: &[FieldOptions]) -> TokenStream2 {
                         errors.push(#field_name_str.to_string());
                     }
                 }),
-                // Bool always has a value
-                TypeCategory::Bool => None,
+                // Bool and numbers always have a value
+                TypeCategory::Bool | TypeCategory::Int | TypeCategory::Float => None,
                 // Nested Structs are handled separately
                 TypeCategory::Other => None,
             };
@@ -220,14 +597,83 @@ fn generate_validations(fields: &[FieldOptions]) -> TokenStream2 {
 
         // 2. Recursive validation for Nested Structs
         //    (independent of required - the nested struct has its own required fields)
+        // Add prefix for better error messages
+        let nested_error_handling = quote! {
+            match nested_error {
+                ::germanic::error::ValidationError::RequiredFieldsMissing(nested_fields) => {
+                    for f in nested_fields {
+                        errors.push(format!("{}.{}", #field_name_str, f));
+                    }
+                }
+                ::germanic::error::ValidationError::ConstraintViolation { field, message } => {
+                    return Err(::germanic::error::ValidationError::ConstraintViolation {
+                        field: format!("{}.{}", #field_name_str, field),
+                        message,
+                    });
+                }
+                other => return Err(other),
+            }
+        };
+
+        // Enum fields carry no nested state to descend into
+        if field.enumeration.is_present() {
+            continue;
+        }
+
         if ty == TypeCategory::Other {
+            validations.push(if field.flatten.is_present() {
+                // Flattened struct: errors keep the flat field names
+                quote! {
+                    if let Err(nested_error) = self.#field_name.validate() {
+                        match nested_error {
+                            ::germanic::error::ValidationError::RequiredFieldsMissing(
+                                nested_fields,
+                            ) => errors.extend(nested_fields),
+                            other => return Err(other),
+                        }
+                    }
+                }
+            } else {
+                quote! {
+                    // Recursive validation of nested struct
+                    if let Err(nested_error) = self.#field_name.validate() {
+                        #nested_error_handling
+                    }
+                }
+            });
+        }
+
+        // 3. Recursive validation inside Option<NestedStruct>
+        //    (only when present — an absent optional schema is fine)
+        if ty == TypeCategory::Option && option_inner_category(&field.ty) == TypeCategory::Other {
+            validations.push(quote! {
+                if let Some(nested) = self.#field_name.as_ref() {
+                    if let Err(nested_error) = nested.validate() {
+                        #nested_error_handling
+                    }
+                }
+            });
+        }
+
+        // 4. Per-element validation for Vec<NestedStruct>
+        //    (indexed paths: "standorte[2].plz")
+        if ty == TypeCategory::Vec && vec_inner_category(&field.ty) == TypeCategory::Other {
             validations.push(quote! {
-                // Recursive validation of nested struct
-                if let Err(nested_error) = self.#field_name.validate() {
-                    // Add prefix for better error messages
-                    if let ::germanic::error::ValidationError::RequiredFieldsMissing(nested_fields) = nested_error {
-                        for f in nested_fields {
-                            errors.push(format!("{}.{}", #field_name_str, f));
+                for (index, nested) in self.#field_name.iter().enumerate() {
+                    if let Err(nested_error) = nested.validate() {
+                        match nested_error {
+                            ::germanic::error::ValidationError::RequiredFieldsMissing(nested_fields) => {
+                                for f in nested_fields {
+                                    errors.push(format!("{}[{}].{}", #field_name_str, index, f));
+                                }
+                            }
+                            ::germanic::error::ValidationError::ConstraintViolation { field, message } => {
+                                return Err(::germanic::error::ValidationError::ConstraintViolation {
+                                    field: format!("{}[{}].{}", #field_name_str, index, field),
+                                    message,
+                                });
+                            }
+                            other => return Err(other),
                         }
                     }
                 }
@@ -238,6 +684,261 @@ fn generate_validations(fields: &[FieldOptions]) -> TokenStream2 {
     quote! { #(#validations)* }
 }
 
+// ============================================================================
+// CODE GENERATION: CONSTRAINTS
+// ============================================================================
+
+/// Generates the constraint checks (`min_len`, `max_len`, `min_items`,
+/// `max_items`, `pattern`, `min`, `max`, `validate_with`) that run
+/// after the required check passed.
+///
+/// Messages mirror the dynamic validator word for word, so static and
+/// dynamic mode report identical diagnostics. Attributes on types they
+/// cannot apply to (e.g. `min` on a `String`) are rejected at compile
+/// time.
+fn generate_constraint_checks(fields: &[FieldOptions]) -> Result<TokenStream2, darling::Error> {
+    let mut checks = Vec::new();
+
+    for field in fields {
+        let Some(field_name) = field.ident.as_ref() else {
+            continue;
+        };
+        if field.skip.is_present() {
+            continue;
+        }
+        let field_name_str = schema_field_name(field, field_name);
+        let ty = &field.ty;
+        // Only for error messages — classification runs on the AST
+        let ty_string = quote!(#ty).to_string().replace(' ', "");
+        let category = type_category(ty);
+        let is_option = category == TypeCategory::Option;
+
+        // ── Numeric range: min / max ────────────────────────────────────
+        if field.min.is_some() || field.max.is_some() {
+            let scalar_category = if is_option {
+                option_inner_category(ty)
+            } else {
+                category
+            };
+            if !matches!(scalar_category, TypeCategory::Int | TypeCategory::Float) {
+                return Err(darling::Error::custom(format!(
+                    "field `{field_name_str}`: min/max only apply to numeric fields, not `{ty_string}`"
+                )));
+            }
+
+            // f64 needs no cast — a redundant `as f64` would trip clippy
+            let scalar_ty = if is_option {
+                option_inner_type(ty).expect("Option category checked above")
+            } else {
+                ty.clone()
+            };
+            let is_f64 = last_path_segment(&scalar_ty).is_some_and(|segment| segment.ident == "f64");
+            let as_f64 = if is_f64 {
+                quote! { value }
+            } else {
+                quote! { value as f64 }
+            };
+
+            let mut range_checks = Vec::new();
+            if let Some(SignedNumber(min)) = field.min {
+                range_checks.push(quote! {
+                    if value < #min {
+                        constraint_errors.push((
+                            #field_name_str.to_string(),
+                            format!("value {} is below minimum {}", value, #min),
+                        ));
+                    }
+                });
+            }
+            if let Some(SignedNumber(max)) = field.max {
+                range_checks.push(quote! {
+                    if value > #max {
+                        constraint_errors.push((
+                            #field_name_str.to_string(),
+                            format!("value {} exceeds maximum {}", value, #max),
+                        ));
+                    }
+                });
+            }
+
+            let body = quote! {
+                let value = #as_f64;
+                #(#range_checks)*
+            };
+            checks.push(if is_option {
+                quote! {
+                    if let Some(value) = self.#field_name {
+                        #body
+                    }
+                }
+            } else {
+                quote! {
+                    {
+                        let value = self.#field_name;
+                        #body
+                    }
+                }
+            });
+        }
+
+        // ── String shape: min_len / max_len / pattern ───────────────────
+        let is_string = category == TypeCategory::String;
+        let is_optional_string = is_option && option_inner_category(ty) == TypeCategory::String;
+        let is_vec = category == TypeCategory::Vec;
+
+        // ── Element count: min_items / max_items ────────────────────────
+        if (field.min_items.is_some() || field.max_items.is_some()) && !is_vec {
+            return Err(darling::Error::custom(format!(
+                "field `{field_name_str}`: min_items/max_items only apply to vectors, not `{ty_string}`"
+            )));
+        }
+
+        if is_vec {
+            // min_len/max_len on a Vec count elements too; min_items is
+            // the clearer spelling and wins when both are given
+            let min_count = field.min_items.or(field.min_len);
+            let max_count = field.max_items.or(field.max_len);
+            if min_count.is_some() || max_count.is_some() {
+                // Arrays: element count, like the dynamic validator
+                let mut len_checks = Vec::new();
+                if let Some(min_count) = min_count {
+                    len_checks.push(quote! {
+                        if count < #min_count {
+                            constraint_errors.push((
+                                #field_name_str.to_string(),
+                                format!("array has {} elements, minimum is {}", count, #min_count),
+                            ));
+                        }
+                    });
+                }
+                if let Some(max_count) = max_count {
+                    len_checks.push(quote! {
+                        if count > #max_count {
+                            constraint_errors.push((
+                                #field_name_str.to_string(),
+                                format!("array has {} elements, maximum is {}", count, #max_count),
+                            ));
+                        }
+                    });
+                }
+                checks.push(quote! {
+                    {
+                        let count = self.#field_name.len();
+                        #(#len_checks)*
+                    }
+                });
+            }
+        } else if field.min_len.is_some() || field.max_len.is_some() {
+            if is_string || is_optional_string {
+                // Strings: character count, not byte count
+                let mut len_checks = Vec::new();
+                if let Some(min_len) = field.min_len {
+                    len_checks.push(quote! {
+                        if len < #min_len {
+                            constraint_errors.push((
+                                #field_name_str.to_string(),
+                                format!("length {} is below minimum length {}", len, #min_len),
+                            ));
+                        }
+                    });
+                }
+                if let Some(max_len) = field.max_len {
+                    len_checks.push(quote! {
+                        if len > #max_len {
+                            constraint_errors.push((
+                                #field_name_str.to_string(),
+                                format!("length {} exceeds maximum length {}", len, #max_len),
+                            ));
+                        }
+                    });
+                }
+                let body = quote! {
+                    let len = value.chars().count();
+                    #(#len_checks)*
+                };
+                checks.push(if is_optional_string {
+                    quote! {
+                        if let Some(value) = self.#field_name.as_deref() {
+                            #body
+                        }
+                    }
+                } else {
+                    quote! {
+                        {
+                            let value = self.#field_name.as_str();
+                            #body
+                        }
+                    }
+                });
+            } else {
+                return Err(darling::Error::custom(format!(
+                    "field `{field_name_str}`: min_len/max_len only apply to strings and vectors, not `{ty_string}`"
+                )));
+            }
+        }
+
+        if let Some(pattern) = &field.pattern {
+            if !is_string && !is_optional_string {
+                return Err(darling::Error::custom(format!(
+                    "field `{field_name_str}`: pattern only applies to string fields, not `{ty_string}`"
+                )));
+            }
+            let check = quote! {
+                // Anchored: the whole value must match the pattern
+                match ::germanic::regex_lite::Regex::new(concat!("^(?:", #pattern, ")$")) {
+                    Err(_) => constraint_errors.push((
+                        #field_name_str.to_string(),
+                        format!("invalid pattern \"{}\"", #pattern),
+                    )),
+                    Ok(re) => {
+                        if !re.is_match(value) {
+                            constraint_errors.push((
+                                #field_name_str.to_string(),
+                                format!("value \"{}\" does not match pattern \"{}\"", value, #pattern),
+                            ));
+                        }
+                    }
+                }
+            };
+            checks.push(if is_optional_string {
+                quote! {
+                    if let Some(value) = self.#field_name.as_deref() {
+                        #check
+                    }
+                }
+            } else {
+                quote! {
+                    {
+                        let value = self.#field_name.as_str();
+                        #check
+                    }
+                }
+            });
+        }
+
+        // ── Custom validator: validate_with ─────────────────────────────
+        if let Some(validator) = &field.validate_with {
+            checks.push(quote! {
+                if let Err(message) = #validator(&self.#field_name) {
+                    constraint_errors.push((#field_name_str.to_string(), message));
+                }
+            });
+        }
+    }
+
+    if checks.is_empty() {
+        return Ok(TokenStream2::new());
+    }
+
+    Ok(quote! {
+        let mut constraint_errors: Vec<(String, String)> = Vec::new();
+        #(#checks)*
+        if let Some((field, message)) = constraint_errors.into_iter().next() {
+            return Err(::germanic::error::ValidationError::ConstraintViolation { field, message });
+        }
+    })
+}
+
 // ============================================================================
 // CODE GENERATION: DEFAULT
 // ============================================================================
@@ -262,6 +963,27 @@ fn generate_default_fields(fields: &[FieldOptions]) -> TokenStream2 {
 /// 1. If `#[germanic(default = "...")]` is set → parse and use
 /// 2. Otherwise → type-specific default
 fn generate_default_value(field: &FieldOptions) -> TokenStream2 {
+    // Skipped fields keep whatever Default their type provides
+    if field.skip.is_present() {
+        return quote! { Default::default() };
+    }
+
+    // Enum fields: a default attribute names a variant
+    if field.enumeration.is_present() {
+        let ty = &field.ty;
+        return match (&field.default, type_category(ty)) {
+            (Some(value), TypeCategory::Option) => match option_inner_type(ty) {
+                // from_name already returns Option<Enum>
+                Some(inner_ty) => quote! { #inner_ty::from_name(#value) },
+                None => quote! { None },
+            },
+            (Some(value), _) => quote! { <#ty>::from_name(#value).unwrap_or_default() },
+            (None, TypeCategory::Option) => quote! { None },
+            (None, TypeCategory::Vec) => quote! { Vec::new() },
+            (None, _) => quote! { Default::default() },
+        };
+    }
+
     let ty = type_category(&field.ty);
 
     match (&field.default, ty) {
@@ -276,10 +998,24 @@ fn generate_default_value(field: &FieldOptions) -> TokenStream2 {
             quote! { #bool_value }
         }
 
-        // Explicit default for Option: #[germanic(default = "value")]
-        (Some(value), TypeCategory::Option) => {
-            quote! { Some(#value.to_string()) }
+        // Explicit default for numbers: #[germanic(default = "42")]
+        (Some(value), TypeCategory::Int) => {
+            numeric_literal(value, false).unwrap_or_else(|| quote! { Default::default() })
         }
+        (Some(value), TypeCategory::Float) => {
+            numeric_literal(value, true).unwrap_or_else(|| quote! { Default::default() })
+        }
+
+        // Explicit default for Option: the literal depends on the inner type
+        (Some(value), TypeCategory::Option) => match option_inner_category(&field.ty) {
+            TypeCategory::Int => numeric_literal(value, false)
+                .map(|lit| quote! { Some(#lit) })
+                .unwrap_or_else(|| quote! { None }),
+            TypeCategory::Float => numeric_literal(value, true)
+                .map(|lit| quote! { Some(#lit) })
+                .unwrap_or_else(|| quote! { None }),
+            _ => quote! { Some(#value.to_string()) },
+        },
 
         // Explicit default for Vec: not supported, use empty
         (Some(_), TypeCategory::Vec) => {
@@ -294,12 +1030,688 @@ fn generate_default_value(field: &FieldOptions) -> TokenStream2 {
         // No explicit default → type-specific defaults
         (None, TypeCategory::String) => quote! { String::new() },
         (None, TypeCategory::Bool) => quote! { false },
+        (None, TypeCategory::Int) => quote! { 0 },
+        (None, TypeCategory::Float) => quote! { 0.0 },
         (None, TypeCategory::Option) => quote! { None },
         (None, TypeCategory::Vec) => quote! { Vec::new() },
         (None, TypeCategory::Other) => quote! { Default::default() },
     }
 }
 
+/// The category of the type inside an `Option<...>` field.
+fn option_inner_category(ty: &Type) -> TypeCategory {
+    inner_category(ty, "Option")
+}
+
+/// The type inside an `Option<...>` field.
+fn option_inner_type(ty: &Type) -> Option<Type> {
+    generic_inner_type(ty, "Option").cloned()
+}
+
+/// The category of the element type of a `Vec<...>` field.
+fn vec_inner_category(ty: &Type) -> TypeCategory {
+    inner_category(ty, "Vec")
+}
+
+/// The category of the type inside a single-parameter wrapper.
+fn inner_category(ty: &Type, wrapper: &str) -> TypeCategory {
+    match generic_inner_type(ty, wrapper) {
+        Some(inner) => type_category(inner),
+        None => TypeCategory::Other,
+    }
+}
+
+/// Unsuffixed numeric literal tokens for a default value string, so the
+/// literal coerces to whatever width the field uses (i32, u8, f32, ...).
+///
+/// Returns `None` if the string is not a number of the requested kind.
+fn numeric_literal(value: &str, float: bool) -> Option<TokenStream2> {
+    let (negative, digits) = match value.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, value),
+    };
+
+    let lit = if float {
+        digits.parse::<f64>().ok()?;
+        let digits = if digits.contains('.') {
+            digits.to_string()
+        } else {
+            format!("{digits}.0")
+        };
+        let lit = syn::LitFloat::new(&digits, proc_macro2::Span::call_site());
+        quote! { #lit }
+    } else {
+        digits.parse::<u64>().ok()?;
+        let lit = syn::LitInt::new(digits, proc_macro2::Span::call_site());
+        quote! { #lit }
+    };
+
+    Some(if negative { quote! { - #lit } } else { lit })
+}
+
+// ============================================================================
+// CODE GENERATION: BUILDER
+// ============================================================================
+
+/// Generates a fluent `{Struct}Builder` with one setter per field.
+///
+/// The builder starts from `Default::default()` and validates on
+/// `build()`, so callers get a `Result` instead of constructing an
+/// invalid schema with `..Default::default()` and hoping for the best.
+/// String fields take `impl Into<String>`, `Option` fields take the
+/// inner type; `skip` fields stay internal and get no setter.
+fn generate_builder(
+    struct_name: &Ident,
+    generics: &syn::Generics,
+    fields: &[FieldOptions],
+) -> TokenStream2 {
+    let builder_name = format_ident!("{struct_name}Builder");
+    let mut setters = Vec::new();
+
+    for field in fields {
+        let Some(field_name) = field.ident.as_ref() else {
+            continue;
+        };
+        if field.skip.is_present() {
+            continue;
+        }
+        let ty = &field.ty;
+        let doc = format!("Sets `{field_name}`.");
+
+        let setter = if type_category(ty) == TypeCategory::String {
+            quote! {
+                #[doc = #doc]
+                pub fn #field_name(mut self, value: impl ::std::convert::Into<String>) -> Self {
+                    self.inner.#field_name = value.into();
+                    self
+                }
+            }
+        } else if option_inner_category(ty) == TypeCategory::String {
+            quote! {
+                #[doc = #doc]
+                pub fn #field_name(mut self, value: impl ::std::convert::Into<String>) -> Self {
+                    self.inner.#field_name = Some(value.into());
+                    self
+                }
+            }
+        } else if let Some(inner_ty) = option_inner_type(ty) {
+            quote! {
+                #[doc = #doc]
+                pub fn #field_name(mut self, value: #inner_ty) -> Self {
+                    self.inner.#field_name = Some(value);
+                    self
+                }
+            }
+        } else {
+            quote! {
+                #[doc = #doc]
+                pub fn #field_name(mut self, value: #ty) -> Self {
+                    self.inner.#field_name = value;
+                    self
+                }
+            }
+        };
+        setters.push(setter);
+    }
+
+    let builder_doc = format!(
+        "Fluent builder for [`{struct_name}`], created via [`{struct_name}::builder()`]."
+    );
+    let build_doc = format!(
+        "Validates the assembled values and returns the finished [`{struct_name}`]."
+    );
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    quote! {
+        #[doc = #builder_doc]
+        ///
+        /// Unset fields keep their `Default` values; `build()` runs the
+        /// same validation as `validate()`.
+        pub struct #builder_name #ty_generics #where_clause {
+            inner: #struct_name #ty_generics,
+        }
+
+        impl #impl_generics #struct_name #ty_generics #where_clause {
+            /// Starts a builder with all fields at their defaults.
+            pub fn builder() -> #builder_name #ty_generics {
+                #builder_name {
+                    inner: <Self as ::std::default::Default>::default(),
+                }
+            }
+        }
+
+        impl #impl_generics #builder_name #ty_generics #where_clause {
+            #(#setters)*
+
+            #[doc = #build_doc]
+            pub fn build(
+                self,
+            ) -> ::std::result::Result<#struct_name #ty_generics, ::germanic::error::ValidationError>
+            {
+                ::germanic::schema::Validate::validate(&self.inner)?;
+                Ok(self.inner)
+            }
+        }
+    }
+}
+
+// ============================================================================
+// CODE GENERATION: SCHEMA DEFINITION
+// ============================================================================
+
+/// Generates `fn schema_definition() -> SchemaDefinition` from the
+/// struct fields, so static structs and the dynamic pipeline share one
+/// source of truth (field order, types, required flags, defaults).
+///
+/// Nested schema fields pull in their own generated definition at
+/// runtime, mirroring how `germanic_create_fb` delegates serialization.
+///
+/// Also emits `fn json_schema()`, which exports that definition as a
+/// JSON Schema Draft 7 document through the dynamic exporter.
+fn generate_schema_definition(
+    struct_name: &Ident,
+    generics: &syn::Generics,
+    schema_id: &str,
+    schema_version: u8,
+    fields: &[FieldOptions],
+) -> Result<TokenStream2, darling::Error> {
+    let mut inserts = Vec::new();
+
+    for field in fields {
+        let Some(field_name) = field.ident.as_ref() else {
+            continue;
+        };
+        if field.skip.is_present() {
+            continue;
+        }
+        let field_name_str = schema_field_name(field, field_name);
+        let ty = &field.ty;
+
+        // Doc comments become the field description
+        let description_assignment = match doc_description(&field.attrs) {
+            Some(description) => quote! {
+                field.description = Some(#description.to_string());
+            },
+            None => TokenStream2::new(),
+        };
+
+        // Flattened structs: their fields merge into the parent table
+        if field.flatten.is_present() {
+            if field.enumeration.is_present() || field.rename.is_some() {
+                return Err(darling::Error::custom(format!(
+                    "field `{field_name_str}`: flatten cannot be combined with enumeration or rename"
+                )));
+            }
+            if type_category(ty) != TypeCategory::Other {
+                let ty_string = quote!(#ty).to_string().replace(' ', "");
+                return Err(darling::Error::custom(format!(
+                    "field `{field_name_str}`: flatten only applies to nested schema structs, not `{ty_string}`"
+                )));
+            }
+            inserts.push(quote! {
+                for (name, nested_field) in #ty::schema_definition().fields {
+                    schema.fields.insert(name, nested_field);
+                }
+            });
+            continue;
+        }
+
+        // Enum fields: dynamic `enum` type, allowed values from the enum
+        if field.enumeration.is_present() {
+            if type_category(ty) == TypeCategory::Vec {
+                return Err(darling::Error::custom(format!(
+                    "field `{field_name_str}`: enum vectors have no dynamic schema type"
+                )));
+            }
+            let (inner_ty, required) = match option_inner_type(ty) {
+                Some(inner) => (inner, field.required.is_present()),
+                // A bare enum field always has a value
+                None => (field.ty.clone(), true),
+            };
+            let required_assignment = if required {
+                quote! { field.required = true; }
+            } else {
+                TokenStream2::new()
+            };
+            let default_assignment = match &field.default {
+                Some(value) => quote! {
+                    field.default =
+                        Some(::germanic::serde_json::Value::String(#value.to_string()));
+                },
+                None => TokenStream2::new(),
+            };
+            inserts.push(quote! {
+                {
+                    let mut field = ::germanic::dynamic::schema_def::FieldDefinition::new(
+                        ::germanic::dynamic::schema_def::FieldType::Enum,
+                    );
+                    #description_assignment
+                    #required_assignment
+                    #default_assignment
+                    field.values = Some(
+                        #inner_ty::variants().iter().map(|v| v.to_string()).collect(),
+                    );
+                    schema.fields.insert(#field_name_str.to_string(), field);
+                }
+            });
+            continue;
+        }
+
+        let (field_type, nested, forced_required) = dynamic_field_type(ty).ok_or_else(|| {
+            let ty_string = quote!(#ty).to_string().replace(' ', "");
+            darling::Error::custom(format!(
+                "field `{field_name_str}`: no dynamic schema type for `{ty_string}`"
+            ))
+        })?;
+
+        let required = forced_required || field.required.is_present();
+        let required_assignment = if required {
+            quote! { field.required = true; }
+        } else {
+            TokenStream2::new()
+        };
+
+        // Defaults follow the same rules as the generated Default impl
+        let category = match type_category(&field.ty) {
+            TypeCategory::Option => option_inner_category(&field.ty),
+            other => other,
+        };
+        let default_assignment = match (&field.default, category) {
+            (Some(value), TypeCategory::Bool) => {
+                let bool_value: bool = value.parse().unwrap_or(false);
+                quote! {
+                    field.default = Some(::germanic::serde_json::Value::Bool(#bool_value));
+                }
+            }
+            (Some(value), TypeCategory::Int) => match value.parse::<i64>() {
+                Ok(int_value) => quote! {
+                    field.default = Some(::germanic::serde_json::Value::from(#int_value));
+                },
+                Err(_) => TokenStream2::new(),
+            },
+            (Some(value), TypeCategory::Float) => match value.parse::<f64>() {
+                Ok(float_value) => quote! {
+                    field.default = Some(::germanic::serde_json::Value::from(#float_value));
+                },
+                Err(_) => TokenStream2::new(),
+            },
+            (Some(value), TypeCategory::String) => quote! {
+                field.default = Some(::germanic::serde_json::Value::String(#value.to_string()));
+            },
+            _ => TokenStream2::new(),
+        };
+
+        // Constraints carry over 1:1 into the dynamic definition
+        let mut constraint_assignments = Vec::new();
+        if let Some(SignedNumber(min)) = field.min {
+            constraint_assignments.push(quote! { field.min = Some(#min); });
+        }
+        if let Some(SignedNumber(max)) = field.max {
+            constraint_assignments.push(quote! { field.max = Some(#max); });
+        }
+        // min_items/max_items share the length slots: the dynamic
+        // definition stores element counts there for array fields
+        if let Some(min_len) = field.min_items.or(field.min_len) {
+            constraint_assignments.push(quote! { field.min_length = Some(#min_len); });
+        }
+        if let Some(max_len) = field.max_items.or(field.max_len) {
+            constraint_assignments.push(quote! { field.max_length = Some(#max_len); });
+        }
+        if let Some(pattern) = &field.pattern {
+            constraint_assignments.push(quote! { field.pattern = Some(#pattern.to_string()); });
+        }
+
+        // Table / TableArray: nested fields come from the nested struct
+        let fields_assignment = match nested {
+            Some(inner_ty) => quote! {
+                field.fields = Some(#inner_ty::schema_definition().fields);
+            },
+            None => TokenStream2::new(),
+        };
+
+        inserts.push(quote! {
+            {
+                let mut field =
+                    ::germanic::dynamic::schema_def::FieldDefinition::new(#field_type);
+                #description_assignment
+                #required_assignment
+                #default_assignment
+                #(#constraint_assignments)*
+                #fields_assignment
+                schema.fields.insert(#field_name_str.to_string(), field);
+            }
+        });
+    }
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #struct_name #ty_generics #where_clause {
+            /// The dynamic-mode schema definition of this struct.
+            ///
+            /// Generated by `#[derive(GermanicSchema)]` — field order,
+            /// types, required flags and defaults mirror the struct, so
+            /// static and dynamic modes share one source of truth.
+            pub fn schema_definition() -> ::germanic::dynamic::schema_def::SchemaDefinition {
+                let mut schema = ::germanic::dynamic::schema_def::SchemaDefinition {
+                    schema_id: #schema_id.to_string(),
+                    version: #schema_version,
+                    description: None,
+                    limits: None,
+                    strict: false,
+                    fields: ::std::default::Default::default(),
+                };
+                #(#inserts)*
+                schema
+            }
+
+            /// This struct's schema as a JSON Schema Draft 7 document.
+            ///
+            /// Delegates to the dynamic exporter via [`Self::schema_definition`],
+            /// so plugins and editors can validate their JSON export before
+            /// ever calling GERMANIC.
+            pub fn json_schema() -> ::germanic::serde_json::Value {
+                ::germanic::dynamic::json_schema::export_json_schema(
+                    &Self::schema_definition(),
+                )
+            }
+        }
+    })
+}
+
+/// Maps a Rust field type onto its dynamic [`FieldType`] tokens.
+///
+/// Returns `(type tokens, nested type, forced required)` — plain
+/// nested structs are required by construction (the field cannot be
+/// absent), everything else takes the flag from the attribute.
+fn dynamic_field_type(ty: &Type) -> Option<(TokenStream2, Option<Type>, bool)> {
+    fn scalar_tokens(category: TypeCategory) -> Option<TokenStream2> {
+        match category {
+            TypeCategory::Bool => Some(quote! { ::germanic::dynamic::schema_def::FieldType::Bool }),
+            TypeCategory::Int => Some(quote! { ::germanic::dynamic::schema_def::FieldType::Int }),
+            TypeCategory::Float => {
+                Some(quote! { ::germanic::dynamic::schema_def::FieldType::Float })
+            }
+            _ => None,
+        }
+    }
+
+    if let Some(inner) = generic_inner_type(ty, "Option") {
+        return match type_category(inner) {
+            TypeCategory::String => Some((
+                quote! { ::germanic::dynamic::schema_def::FieldType::String },
+                None,
+                false,
+            )),
+            category if scalar_tokens(category).is_some() => {
+                Some((scalar_tokens(category)?, None, false))
+            }
+            _ => Some((
+                quote! { ::germanic::dynamic::schema_def::FieldType::Table },
+                Some(inner.clone()),
+                false,
+            )),
+        };
+    }
+
+    if let Some(inner) = generic_inner_type(ty, "Vec") {
+        return match type_category(inner) {
+            TypeCategory::String => Some((
+                quote! { ::germanic::dynamic::schema_def::FieldType::StringArray },
+                None,
+                false,
+            )),
+            TypeCategory::Bool => Some((
+                quote! { ::germanic::dynamic::schema_def::FieldType::BoolArray },
+                None,
+                false,
+            )),
+            TypeCategory::Int => Some((
+                quote! { ::germanic::dynamic::schema_def::FieldType::IntArray },
+                None,
+                false,
+            )),
+            // No float array type exists in the dynamic type system
+            TypeCategory::Float => None,
+            _ => Some((
+                quote! { ::germanic::dynamic::schema_def::FieldType::TableArray },
+                Some(inner.clone()),
+                false,
+            )),
+        };
+    }
+
+    match type_category(ty) {
+        TypeCategory::String => Some((
+            quote! { ::germanic::dynamic::schema_def::FieldType::String },
+            None,
+            false,
+        )),
+        category if scalar_tokens(category).is_some() => {
+            Some((scalar_tokens(category)?, None, false))
+        }
+        _ => Some((
+            quote! { ::germanic::dynamic::schema_def::FieldType::Table },
+            Some(ty.clone()),
+            true,
+        )),
+    }
+}
+
+// ============================================================================
+// CODE GENERATION: SERIALIZATION
+// ============================================================================
+
+/// Generates `GermanicSerialize` plus a builder helper from the
+/// `flatbuffer = "..."` attribute.
+///
+/// The attribute names the generated FlatBuffer table type; the Args
+/// struct is derived by appending `Args` (flatc convention). Nested
+/// schema fields are serialized through their own generated
+/// `germanic_create_fb`, so every struct in the tree carries its own
+/// `flatbuffer` attribute and no struct needs to know the FlatBuffer
+/// layout of its children.
+fn generate_serialize(
+    struct_name: &Ident,
+    generics: &syn::Generics,
+    flatbuffer_path: &str,
+    fields: &[FieldOptions],
+) -> Result<TokenStream2, darling::Error> {
+    let fb_path: syn::Path = syn::parse_str(flatbuffer_path).map_err(|_| {
+        darling::Error::custom(format!(
+            "flatbuffer attribute is not a valid type path: '{flatbuffer_path}'"
+        ))
+    })?;
+
+    // Args struct: flatc appends "Args" to the table name
+    let mut args_path = fb_path.clone();
+    let last = args_path
+        .segments
+        .last_mut()
+        .ok_or_else(|| darling::Error::custom("flatbuffer attribute path is empty"))?;
+    last.ident = Ident::new(&format!("{}Args", last.ident), last.ident.span());
+
+    let mut preparations = Vec::new();
+    let mut args = Vec::new();
+
+    for field in fields {
+        let Some(field_name) = field.ident.as_ref() else {
+            continue;
+        };
+        if field.skip.is_present() {
+            continue;
+        }
+
+        // The parent derive cannot see a flattened struct's fields, so
+        // it cannot spread them into the create() args
+        if field.flatten.is_present() {
+            return Err(darling::Error::custom(format!(
+                "field `{field_name}`: flatten is not supported together with flatbuffer serialization"
+            )));
+        }
+
+        // Renamed fields: the FlatBuffer schema uses the published name
+        let fb_name: Ident = match &field.rename {
+            Some(renamed) => syn::parse_str(renamed).map_err(|_| {
+                darling::Error::custom(format!(
+                    "field `{field_name}`: rename `{renamed}` is not a valid FlatBuffer field name"
+                ))
+            })?,
+            None => field_name.clone(),
+        };
+
+        // Enum fields serialize as their schema string
+        if field.enumeration.is_present() {
+            match fb_field_kind(&field.ty) {
+                FbFieldKind::RequiredTable => {
+                    preparations.push(quote! {
+                        let #field_name = builder.create_string(self.#field_name.as_str());
+                    });
+                    args.push(quote! { #fb_name: Some(#field_name), });
+                }
+                FbFieldKind::OptionalTable => {
+                    preparations.push(quote! {
+                        let #field_name = self.#field_name
+                            .as_ref()
+                            .map(|e| builder.create_string(e.as_str()));
+                    });
+                    args.push(quote! { #fb_name: #field_name, });
+                }
+                FbFieldKind::TableVector => {
+                    preparations.push(quote! {
+                        let #field_name = if self.#field_name.is_empty() {
+                            None
+                        } else {
+                            let offsets: Vec<_> = self.#field_name
+                                .iter()
+                                .map(|e| builder.create_string(e.as_str()))
+                                .collect();
+                            Some(builder.create_vector(&offsets))
+                        };
+                    });
+                    args.push(quote! { #fb_name: #field_name, });
+                }
+                _ => {
+                    return Err(darling::Error::custom(format!(
+                        "field `{field_name}`: enumeration flag on a non-enum type"
+                    )));
+                }
+            }
+            continue;
+        }
+
+        match fb_field_kind(&field.ty) {
+            // Strings become offsets; required ones are always present
+            FbFieldKind::RequiredString => {
+                preparations.push(quote! {
+                    let #field_name = builder.create_string(&self.#field_name);
+                });
+                args.push(quote! { #fb_name: Some(#field_name), });
+            }
+            FbFieldKind::OptionalString => {
+                preparations.push(quote! {
+                    let #field_name = self.#field_name.as_ref().map(|s| builder.create_string(s));
+                });
+                args.push(quote! { #fb_name: #field_name, });
+            }
+            // Scalars are stored inline — copy them straight into the args
+            FbFieldKind::Scalar | FbFieldKind::OptionalScalar => {
+                args.push(quote! { #fb_name: self.#field_name, });
+            }
+            // Empty vectors are omitted (absent slot instead of empty vector)
+            FbFieldKind::StringVector => {
+                preparations.push(quote! {
+                    let #field_name = if self.#field_name.is_empty() {
+                        None
+                    } else {
+                        let offsets: Vec<_> = self.#field_name
+                            .iter()
+                            .map(|s| builder.create_string(s))
+                            .collect();
+                        Some(builder.create_vector(&offsets))
+                    };
+                });
+                args.push(quote! { #fb_name: #field_name, });
+            }
+            FbFieldKind::ScalarVector => {
+                preparations.push(quote! {
+                    let #field_name = if self.#field_name.is_empty() {
+                        None
+                    } else {
+                        Some(builder.create_vector(&self.#field_name))
+                    };
+                });
+                args.push(quote! { #fb_name: #field_name, });
+            }
+            // Nested schemas serialize themselves into the same builder
+            FbFieldKind::RequiredTable => {
+                preparations.push(quote! {
+                    let #field_name = self.#field_name.germanic_create_fb(builder);
+                });
+                args.push(quote! { #fb_name: Some(#field_name), });
+            }
+            FbFieldKind::OptionalTable => {
+                preparations.push(quote! {
+                    let #field_name = self.#field_name
+                        .as_ref()
+                        .map(|nested| nested.germanic_create_fb(builder));
+                });
+                args.push(quote! { #fb_name: #field_name, });
+            }
+            FbFieldKind::TableVector => {
+                preparations.push(quote! {
+                    let #field_name = if self.#field_name.is_empty() {
+                        None
+                    } else {
+                        let offsets: Vec<_> = self.#field_name
+                            .iter()
+                            .map(|nested| nested.germanic_create_fb(builder))
+                            .collect();
+                        Some(builder.create_vector(&offsets))
+                    };
+                });
+                args.push(quote! { #fb_name: #field_name, });
+            }
+        }
+    }
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #struct_name #ty_generics #where_clause {
+            /// Creates this record as a FlatBuffer table in an existing builder.
+            ///
+            /// Generated from `#[germanic(flatbuffer = "...")]`. Used by
+            /// `to_bytes` and by parent schemas that embed this one as a
+            /// nested table.
+            pub fn germanic_create_fb<'fbb, A: ::germanic::flatbuffers::Allocator + 'fbb>(
+                &self,
+                builder: &mut ::germanic::flatbuffers::FlatBufferBuilder<'fbb, A>,
+            ) -> ::germanic::flatbuffers::WIPOffset<#fb_path<'fbb>> {
+                #(#preparations)*
+
+                #fb_path::create(
+                    builder,
+                    &#args_path {
+                        #(#args)*
+                    },
+                )
+            }
+        }
+
+        impl #impl_generics ::germanic::schema::GermanicSerialize for #struct_name #ty_generics
+        #where_clause
+        {
+            fn to_bytes(&self) -> Vec<u8> {
+                let mut builder = ::germanic::flatbuffers::FlatBufferBuilder::with_capacity(1024);
+                let root = self.germanic_create_fb(&mut builder);
+                builder.finish(root, None);
+                builder.finished_data().to_vec()
+            }
+        }
+    })
+}
+
 // ============================================================================
 // TYPE CATEGORIZATION
 // ============================================================================
@@ -309,25 +1721,113 @@ fn generate_default_value(field: &FieldOptions) -> TokenStream2 {
 enum TypeCategory {
     String,
     Bool,
+    /// i8–i64, u8–u64
+    Int,
+    /// f32, f64
+    Float,
     Option,
     Vec,
     Other,
 }
 
+/// How a field is written into the FlatBuffer (finer-grained than
+/// [`TypeCategory`] — serialization needs to tell scalars, strings and
+/// nested tables apart inside `Option` and `Vec`).
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum FbFieldKind {
+    RequiredString,
+    OptionalString,
+    /// bool, integers, floats — stored inline, args take the value directly
+    Scalar,
+    /// `Option<scalar>` — flatc maps `= null` defaults to `Option` args
+    OptionalScalar,
+    StringVector,
+    ScalarVector,
+    /// Nested schema struct (serialized via its own `germanic_create_fb`)
+    RequiredTable,
+    OptionalTable,
+    TableVector,
+}
+
+/// The last path segment of a type, with references and parentheses
+/// peeled off.
+///
+/// Classification works on this segment instead of the rendered token
+/// string, so `std::option::Option<String>` and `Option<String>` (and
+/// `&str` vs `str`) resolve identically.
+fn last_path_segment(ty: &Type) -> Option<&syn::PathSegment> {
+    match ty {
+        Type::Path(type_path) if type_path.qself.is_none() => type_path.path.segments.last(),
+        Type::Reference(reference) => last_path_segment(&reference.elem),
+        Type::Group(group) => last_path_segment(&group.elem),
+        Type::Paren(paren) => last_path_segment(&paren.elem),
+        _ => None,
+    }
+}
+
+/// The single generic type argument of a wrapper like `Option<...>` or
+/// `Vec<...>` — `None` if the type is not that wrapper.
+fn generic_inner_type<'a>(ty: &'a Type, wrapper: &str) -> Option<&'a Type> {
+    let segment = last_path_segment(ty)?;
+    if segment.ident != wrapper {
+        return None;
+    }
+    let syn::PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+        return None;
+    };
+    let mut type_args = arguments.args.iter().filter_map(|arg| match arg {
+        syn::GenericArgument::Type(inner) => Some(inner),
+        _ => None,
+    });
+    let inner = type_args.next()?;
+    // Two type parameters (e.g. HashMap<K, V>) are no wrapper
+    if type_args.next().is_some() {
+        return None;
+    }
+    Some(inner)
+}
+
+/// Determines how a field type maps onto the FlatBuffer wire format.
+fn fb_field_kind(ty: &Type) -> FbFieldKind {
+    if let Some(inner) = generic_inner_type(ty, "Option") {
+        return match type_category(inner) {
+            TypeCategory::String => FbFieldKind::OptionalString,
+            TypeCategory::Bool | TypeCategory::Int | TypeCategory::Float => {
+                FbFieldKind::OptionalScalar
+            }
+            _ => FbFieldKind::OptionalTable,
+        };
+    }
+    if let Some(inner) = generic_inner_type(ty, "Vec") {
+        return match type_category(inner) {
+            TypeCategory::String => FbFieldKind::StringVector,
+            TypeCategory::Bool | TypeCategory::Int | TypeCategory::Float => {
+                FbFieldKind::ScalarVector
+            }
+            _ => FbFieldKind::TableVector,
+        };
+    }
+    match type_category(ty) {
+        TypeCategory::String => FbFieldKind::RequiredString,
+        TypeCategory::Bool | TypeCategory::Int | TypeCategory::Float => FbFieldKind::Scalar,
+        _ => FbFieldKind::RequiredTable,
+    }
+}
+
 /// Analyzes a type and determines its category.
 fn type_category(ty: &Type) -> TypeCategory {
-    let ty_string = quote!(#ty).to_string();
-
-    if ty_string == "String" || ty_string.contains("& str") {
-        TypeCategory::String
-    } else if ty_string == "bool" {
-        TypeCategory::Bool
-    } else if ty_string.starts_with("Option <") || ty_string.starts_with("Option<") {
-        TypeCategory::Option
-    } else if ty_string.starts_with("Vec <") || ty_string.starts_with("Vec<") {
-        TypeCategory::Vec
-    } else {
-        TypeCategory::Other
+    let Some(segment) = last_path_segment(ty) else {
+        return TypeCategory::Other;
+    };
+
+    match segment.ident.to_string().as_str() {
+        "String" | "str" => TypeCategory::String,
+        "bool" => TypeCategory::Bool,
+        "i8" | "i16" | "i32" | "i64" | "u8" | "u16" | "u32" | "u64" => TypeCategory::Int,
+        "f32" | "f64" => TypeCategory::Float,
+        "Option" if generic_inner_type(ty, "Option").is_some() => TypeCategory::Option,
+        "Vec" if generic_inner_type(ty, "Vec").is_some() => TypeCategory::Vec,
+        _ => TypeCategory::Other,
     }
 }
 
@@ -364,8 +1864,118 @@ mod tests {
     }
 
     #[test]
-    fn test_type_category_i32() {
+    fn test_type_category_numbers() {
         let ty: Type = syn::parse_quote!(i32);
+        assert_eq!(type_category(&ty), TypeCategory::Int);
+        let ty: Type = syn::parse_quote!(u64);
+        assert_eq!(type_category(&ty), TypeCategory::Int);
+        let ty: Type = syn::parse_quote!(f64);
+        assert_eq!(type_category(&ty), TypeCategory::Float);
+    }
+
+    #[test]
+    fn test_option_inner_category() {
+        let ty: Type = syn::parse_quote!(Option<i64>);
+        assert_eq!(option_inner_category(&ty), TypeCategory::Int);
+        let ty: Type = syn::parse_quote!(Option<String>);
+        assert_eq!(option_inner_category(&ty), TypeCategory::String);
+    }
+
+    #[test]
+    fn test_numeric_literal() {
+        assert_eq!(numeric_literal("42", false).unwrap().to_string(), "42");
+        assert_eq!(numeric_literal("-7", false).unwrap().to_string(), "- 7");
+        assert_eq!(numeric_literal("19.99", true).unwrap().to_string(), "19.99");
+        assert_eq!(numeric_literal("10", true).unwrap().to_string(), "10.0");
+        assert!(numeric_literal("abc", false).is_none());
+    }
+
+    #[test]
+    fn test_fb_field_kind_strings() {
+        let ty: Type = syn::parse_quote!(String);
+        assert_eq!(fb_field_kind(&ty), FbFieldKind::RequiredString);
+        let ty: Type = syn::parse_quote!(Option<String>);
+        assert_eq!(fb_field_kind(&ty), FbFieldKind::OptionalString);
+        let ty: Type = syn::parse_quote!(Vec<String>);
+        assert_eq!(fb_field_kind(&ty), FbFieldKind::StringVector);
+    }
+
+    #[test]
+    fn test_fb_field_kind_scalars() {
+        let ty: Type = syn::parse_quote!(bool);
+        assert_eq!(fb_field_kind(&ty), FbFieldKind::Scalar);
+        let ty: Type = syn::parse_quote!(Option<f64>);
+        assert_eq!(fb_field_kind(&ty), FbFieldKind::OptionalScalar);
+        let ty: Type = syn::parse_quote!(Vec<i64>);
+        assert_eq!(fb_field_kind(&ty), FbFieldKind::ScalarVector);
+    }
+
+    #[test]
+    fn test_fb_field_kind_nested_tables() {
+        let ty: Type = syn::parse_quote!(AdresseSchema);
+        assert_eq!(fb_field_kind(&ty), FbFieldKind::RequiredTable);
+        let ty: Type = syn::parse_quote!(Option<GehaltSchema>);
+        assert_eq!(fb_field_kind(&ty), FbFieldKind::OptionalTable);
+        let ty: Type = syn::parse_quote!(Vec<SchliesszeitSchema>);
+        assert_eq!(fb_field_kind(&ty), FbFieldKind::TableVector);
+    }
+
+    #[test]
+    fn test_qualified_paths_classify_like_plain_names() {
+        // Fully qualified std paths are no longer opaque `Other` types
+        let ty: Type = syn::parse_quote!(std::option::Option<String>);
+        assert_eq!(type_category(&ty), TypeCategory::Option);
+        assert_eq!(fb_field_kind(&ty), FbFieldKind::OptionalString);
+
+        let ty: Type = syn::parse_quote!(std::vec::Vec<i32>);
+        assert_eq!(type_category(&ty), TypeCategory::Vec);
+        assert_eq!(fb_field_kind(&ty), FbFieldKind::ScalarVector);
+
+        let ty: Type = syn::parse_quote!(std::string::String);
+        assert_eq!(type_category(&ty), TypeCategory::String);
+
+        let ty: Type = syn::parse_quote!(Option<std::string::String>);
+        assert_eq!(option_inner_category(&ty), TypeCategory::String);
+    }
+
+    #[test]
+    fn test_references_and_non_wrappers() {
+        // References peel down to the underlying type
+        let ty: Type = syn::parse_quote!(&str);
+        assert_eq!(type_category(&ty), TypeCategory::String);
+
+        // Two type parameters are no single-value wrapper
+        let ty: Type = syn::parse_quote!(HashMap<String, String>);
         assert_eq!(type_category(&ty), TypeCategory::Other);
+        assert!(generic_inner_type(&ty, "Option").is_none());
+
+        // A bare `Option` without arguments is not an Option field
+        let ty: Type = syn::parse_quote!(Option);
+        assert_eq!(type_category(&ty), TypeCategory::Other);
+    }
+
+    #[test]
+    fn test_parse_schema_version_valid() {
+        assert_eq!(parse_schema_version("de.gesundheit.praxis.v1").unwrap(), 1);
+        assert_eq!(parse_schema_version("test.validation.v1").unwrap(), 1);
+        assert_eq!(parse_schema_version("de.arbeit.stellenanzeige.v255").unwrap(), 255);
+    }
+
+    #[test]
+    fn test_parse_schema_version_invalid_format() {
+        // Too few segments
+        assert!(parse_schema_version("praxis.v1").is_err());
+        // Missing version suffix
+        assert!(parse_schema_version("de.gesundheit.praxis").is_err());
+        assert!(parse_schema_version("de.gesundheit.praxis.1").is_err());
+        // Uppercase and empty segments
+        assert!(parse_schema_version("de.Gesundheit.praxis.v1").is_err());
+        assert!(parse_schema_version("de..praxis.v1").is_err());
+    }
+
+    #[test]
+    fn test_parse_schema_version_out_of_range() {
+        assert!(parse_schema_version("de.gesundheit.praxis.v0").is_err());
+        assert!(parse_schema_version("de.gesundheit.praxis.v256").is_err());
     }
 }
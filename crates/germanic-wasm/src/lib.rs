@@ -0,0 +1,172 @@
+//! # GERMANIC WASM Bindings
+//!
+//! The .grm read path for JavaScript environments: browsers, Node and
+//! edge workers. Compilation stays native — agents and workers only need
+//! to validate and decode files they fetch.
+//!
+//! ```text
+//! ┌─────────────────────────────────────────────────────────────────────┐
+//! │                     WASM READ PATH                                  │
+//! ├─────────────────────────────────────────────────────────────────────┤
+//! │                                                                     │
+//! │   fetch("/data.grm")                                                │
+//! │        │                                                            │
+//! │        ▼                                                            │
+//! │   parseHeader(bytes)      → { schemaId, language, signed }          │
+//! │   validateGrm(bytes)      → { valid, schemaId, error }              │
+//! │   decodeGrm(schema, bytes)→ { header, data }   (JSON)               │
+//! │                                                                     │
+//! └─────────────────────────────────────────────────────────────────────┘
+//! ```
+//!
+//! All functions return JSON strings so no JS glue types are needed;
+//! errors surface as thrown `Error` objects on the JS side.
+
+use wasm_bindgen::prelude::*;
+
+use germanic::dynamic::schema_def::SchemaDefinition;
+use germanic::types::GrmHeader;
+
+// ============================================================================
+// HEADER PARSING
+// ============================================================================
+
+/// Parses the .grm header and returns it as a JSON string:
+/// `{"schema_id": "...", "language": "de-DE" | null, "signed": bool,
+///   "header_len": n}`.
+///
+/// Throws if the bytes are not a valid .grm header.
+#[wasm_bindgen(js_name = parseHeader)]
+pub fn parse_header(bytes: &[u8]) -> Result<String, JsError> {
+    header_json(bytes).map_err(|e| JsError::new(&e))
+}
+
+/// Native core of [`parse_header`] — kept separate so it can be unit
+/// tested without a wasm runtime.
+fn header_json(bytes: &[u8]) -> Result<String, String> {
+    let (header, header_len) = GrmHeader::from_bytes(bytes).map_err(|e| e.to_string())?;
+    Ok(serde_json::json!({
+        "schema_id": header.schema_id,
+        "language": header.language,
+        "signed": header.signature.is_some(),
+        "header_len": header_len,
+    })
+    .to_string())
+}
+
+// ============================================================================
+// STRUCTURAL VALIDATION
+// ============================================================================
+
+/// Validates a .grm file structurally (magic bytes, header, payload
+/// sanity) and returns a JSON string:
+/// `{"valid": bool, "schema_id": "..." | null, "error": "..." | null}`.
+///
+/// Never throws for invalid files — invalidity is part of the result.
+#[wasm_bindgen(js_name = validateGrm)]
+pub fn validate_grm(bytes: &[u8]) -> Result<String, JsError> {
+    validation_json(bytes).map_err(|e| JsError::new(&e))
+}
+
+/// Native core of [`validate_grm`].
+fn validation_json(bytes: &[u8]) -> Result<String, String> {
+    let validation = germanic::validator::validate_grm(bytes).map_err(|e| e.to_string())?;
+    Ok(serde_json::json!({
+        "valid": validation.valid,
+        "schema_id": validation.schema_id,
+        "error": validation.error,
+    })
+    .to_string())
+}
+
+// ============================================================================
+// DYNAMIC DECODING
+// ============================================================================
+
+/// Decodes a .grm payload back to JSON using a dynamic schema
+/// definition (the `.schema.json` the file was compiled with).
+///
+/// Returns `{"header": {...}, "data": {...}}` as a JSON string; throws
+/// if the schema doesn't parse or the payload doesn't match it.
+#[wasm_bindgen(js_name = decodeGrm)]
+pub fn decode_grm(schema_json: &str, bytes: &[u8]) -> Result<String, JsError> {
+    decode_json(schema_json, bytes).map_err(|e| JsError::new(&e))
+}
+
+/// Native core of [`decode_grm`].
+fn decode_json(schema_json: &str, bytes: &[u8]) -> Result<String, String> {
+    let schema: SchemaDefinition = serde_json::from_str(schema_json).map_err(|e| e.to_string())?;
+    let (header, data) = germanic::reader::decode_grm(&schema, bytes).map_err(|e| e.to_string())?;
+    Ok(serde_json::json!({
+        "header": {
+            "schema_id": header.schema_id,
+            "language": header.language,
+            "signed": header.signature.is_some(),
+        },
+        "data": data,
+    })
+    .to_string())
+}
+
+// ============================================================================
+// TESTS
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use germanic::dynamic::schema_def::{FieldDefinition, FieldType};
+
+    fn schema() -> SchemaDefinition {
+        let mut fields = indexmap::IndexMap::new();
+        fields.insert(
+            "name".into(),
+            FieldDefinition {
+                required: true,
+                ..FieldDefinition::new(FieldType::String)
+            },
+        );
+        SchemaDefinition {
+            schema_id: "test.v1".into(),
+            version: 1,
+            description: None,
+            limits: None,
+            strict: false,
+            fields,
+        }
+    }
+
+    fn grm_bytes() -> Vec<u8> {
+        let data = serde_json::json!({ "name": "Bistro" });
+        germanic::dynamic::compile_dynamic_from_values(&schema(), &data).unwrap()
+    }
+
+    #[test]
+    fn test_header_json_roundtrip() {
+        let json = header_json(&grm_bytes()).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed["schema_id"], "test.v1");
+        assert_eq!(parsed["signed"], false);
+    }
+
+    #[test]
+    fn test_validation_json_flags_garbage() {
+        let json = validation_json(&[0u8; 16]).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed["valid"], false);
+    }
+
+    #[test]
+    fn test_decode_json_returns_data() {
+        let schema_str = serde_json::to_string(&schema()).unwrap();
+        let json = decode_json(&schema_str, &grm_bytes()).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed["data"]["name"], "Bistro");
+        assert_eq!(parsed["header"]["schema_id"], "test.v1");
+    }
+
+    #[test]
+    fn test_decode_json_rejects_bad_schema() {
+        assert!(decode_json("not json", &grm_bytes()).is_err());
+    }
+}
@@ -0,0 +1,269 @@
+//! # Fixture Anonymization
+//!
+//! Replaces personal values in JSON data with format-preserving fake data,
+//! guided by a schema definition.
+//!
+//! ```text
+//! production data.json ──► anonymize_value() ──► shareable fixture
+//!
+//!   "Dr. Maria Sonnenschein"  →  "Qk. Tqjns Wplkgxmdvrrp"
+//!   "+49 123 9876543"         →  "+81 904 1268825"
+//!   "info@praxis.example"     →  "wmhy@bkzcns.ozqkqng"
+//! ```
+//!
+//! The substitution is:
+//! - **Format-preserving**: letters map to letters (same case), digits to
+//!   digits, punctuation/whitespace stays in place. An email keeps its `@`
+//!   and dots, a phone number keeps its `+` and grouping.
+//! - **Deterministic**: the same input string always maps to the same fake
+//!   string, so values that are equal in the original stay equal in the
+//!   fixture (e.g. a name repeated in two fields).
+//! - **Structure-preserving**: anonymized data still validates against the
+//!   same schema — types, field order, and nesting are untouched.
+//!
+//! Numbers and booleans are passed through unchanged; personal data in
+//! practice lives in strings (names, phones, emails, addresses).
+
+use crate::dynamic::schema_def::SchemaDefinition;
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+/// Anonymizes JSON data guided by a schema definition.
+///
+/// Every string value (including strings in arrays and nested tables) is
+/// replaced by a format-preserving fake. The schema is used to keep the
+/// walk aligned with what will later be compiled, but unknown fields are
+/// anonymized too — they must not leak either.
+pub fn anonymize_value(_schema: &SchemaDefinition, data: &serde_json::Value) -> serde_json::Value {
+    anonymize_json(data)
+}
+
+/// Redacts only the fields a schema tags with `pii: true`.
+///
+/// Unlike [`anonymize_value`], which fakes every string, this produces the
+/// public variant of a record: PII-tagged strings are replaced by their
+/// format-preserving fake, PII-tagged numbers are zeroed, and a PII tag on
+/// a table or array redacts the whole subtree. Untagged fields pass
+/// through unchanged (nested tables are walked for their own tags).
+pub fn redact_pii_value(schema: &SchemaDefinition, data: &serde_json::Value) -> serde_json::Value {
+    redact_fields(&schema.fields, data)
+}
+
+/// Walks one object level guided by the field definitions.
+fn redact_fields(
+    fields: &indexmap::IndexMap<String, crate::dynamic::schema_def::FieldDefinition>,
+    data: &serde_json::Value,
+) -> serde_json::Value {
+    let serde_json::Value::Object(map) = data else {
+        return data.clone();
+    };
+
+    let mut out = serde_json::Map::new();
+    for (key, val) in map {
+        let redacted = match fields.get(key) {
+            Some(def) if def.pii => redact_subtree(val),
+            Some(def) => match (&def.fields, val) {
+                (Some(nested), serde_json::Value::Object(_)) => redact_fields(nested, val),
+                (Some(nested), serde_json::Value::Array(arr)) => serde_json::Value::Array(
+                    arr.iter().map(|e| redact_fields(nested, e)).collect(),
+                ),
+                _ => val.clone(),
+            },
+            // Unknown fields carry no tag — leave them alone
+            None => val.clone(),
+        };
+        out.insert(key.clone(), redacted);
+    }
+    serde_json::Value::Object(out)
+}
+
+/// Redacts an entire value subtree: strings become format-preserving
+/// fakes, numbers become zero, structure is preserved.
+fn redact_subtree(value: &serde_json::Value) -> serde_json::Value {
+    match value {
+        serde_json::Value::String(s) => serde_json::Value::String(anonymize_string(s)),
+        serde_json::Value::Number(_) => serde_json::Value::Number(0.into()),
+        serde_json::Value::Array(arr) => {
+            serde_json::Value::Array(arr.iter().map(redact_subtree).collect())
+        }
+        serde_json::Value::Object(map) => {
+            let mut out = serde_json::Map::new();
+            for (key, val) in map {
+                out.insert(key.clone(), redact_subtree(val));
+            }
+            serde_json::Value::Object(out)
+        }
+        other => other.clone(),
+    }
+}
+
+/// Recursively anonymizes a JSON value.
+fn anonymize_json(value: &serde_json::Value) -> serde_json::Value {
+    match value {
+        serde_json::Value::String(s) => serde_json::Value::String(anonymize_string(s)),
+        serde_json::Value::Array(arr) => {
+            serde_json::Value::Array(arr.iter().map(anonymize_json).collect())
+        }
+        serde_json::Value::Object(map) => {
+            let mut out = serde_json::Map::new();
+            for (key, val) in map {
+                // Keys are schema structure, not data — keep them
+                out.insert(key.clone(), anonymize_json(val));
+            }
+            serde_json::Value::Object(out)
+        }
+        // Numbers, booleans, null: pass through unchanged
+        other => other.clone(),
+    }
+}
+
+/// Replaces a string with a format-preserving, deterministic fake.
+///
+/// Character classes are preserved: lowercase → lowercase, uppercase →
+/// uppercase, digit → digit. Everything else (spaces, `@`, `.`, `+`, `-`)
+/// stays in place, so the overall shape of emails, phone numbers and
+/// postal codes survives.
+pub fn anonymize_string(s: &str) -> String {
+    // Seed from the input so the mapping is deterministic per value
+    let mut hasher = DefaultHasher::new();
+    s.hash(&mut hasher);
+    let mut state = hasher.finish() | 1; // never zero
+
+    let mut next = move || {
+        // xorshift64 — cheap, reproducible, no external dependency
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    };
+
+    s.chars()
+        .map(|c| {
+            if c.is_ascii_lowercase() || (c.is_alphabetic() && c.is_lowercase()) {
+                (b'a' + (next() % 26) as u8) as char
+            } else if c.is_ascii_uppercase() || (c.is_alphabetic() && c.is_uppercase()) {
+                (b'A' + (next() % 26) as u8) as char
+            } else if c.is_ascii_digit() {
+                (b'0' + (next() % 10) as u8) as char
+            } else {
+                c
+            }
+        })
+        .collect()
+}
+
+// ============================================================================
+// TESTS
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dynamic::schema_def::{FieldDefinition, FieldType};
+    use crate::dynamic::validate::validate_against_schema;
+    use indexmap::IndexMap;
+
+    fn sample_schema() -> SchemaDefinition {
+        let mut fields = IndexMap::new();
+        fields.insert(
+            "name".into(),
+            FieldDefinition {
+                required: true,
+                ..FieldDefinition::new(FieldType::String)
+            },
+        );
+        fields.insert("telefon".into(), FieldDefinition::new(FieldType::String));
+        SchemaDefinition {
+            schema_id: "test.v1".into(),
+            version: 1,
+            description: None,
+            limits: None,
+            strict: false,
+            fields,
+        }
+    }
+
+    #[test]
+    fn test_anonymize_preserves_format() {
+        let fake = anonymize_string("+49 123 9876543");
+        assert_eq!(fake.len(), "+49 123 9876543".len());
+        assert!(fake.starts_with('+'));
+        assert_eq!(fake.chars().nth(3), Some(' '));
+        assert!(fake.chars().filter(|c| *c != '+' && *c != ' ').all(|c| c.is_ascii_digit()));
+    }
+
+    #[test]
+    fn test_anonymize_email_keeps_shape() {
+        let fake = anonymize_string("info@praxis-sonnenschein.example");
+        assert_eq!(fake.matches('@').count(), 1);
+        assert!(fake.contains('.'));
+        assert_ne!(fake, "info@praxis-sonnenschein.example");
+    }
+
+    #[test]
+    fn test_anonymize_deterministic() {
+        assert_eq!(
+            anonymize_string("Dr. Maria Sonnenschein"),
+            anonymize_string("Dr. Maria Sonnenschein")
+        );
+    }
+
+    #[test]
+    fn test_anonymized_data_still_validates() {
+        let schema = sample_schema();
+        let data = serde_json::json!({
+            "name": "Dr. Maria Sonnenschein",
+            "telefon": "+49 123 9876543"
+        });
+
+        let anon = anonymize_value(&schema, &data);
+        assert!(validate_against_schema(&schema, &anon).is_ok());
+        assert_ne!(anon["name"], data["name"]);
+    }
+
+    #[test]
+    fn test_redact_pii_only_touches_tagged_fields() {
+        let mut schema = sample_schema();
+        schema.fields["telefon"].pii = true;
+
+        let data = serde_json::json!({
+            "name": "Praxis Sonnenschein",
+            "telefon": "+49 123 9876543"
+        });
+        let redacted = redact_pii_value(&schema, &data);
+
+        // Untagged field untouched, tagged field faked but format-preserving
+        assert_eq!(redacted["name"], data["name"]);
+        assert_ne!(redacted["telefon"], data["telefon"]);
+        assert!(redacted["telefon"].as_str().unwrap().starts_with('+'));
+        assert!(validate_against_schema(&schema, &redacted).is_ok());
+    }
+
+    #[test]
+    fn test_redact_pii_covers_whole_subtree() {
+        let mut schema = sample_schema();
+        let mut inhaber = FieldDefinition {
+            pii: true,
+            ..FieldDefinition::new(FieldType::Table)
+        };
+        inhaber.fields = Some(IndexMap::new());
+        schema.fields.insert("inhaber".into(), inhaber);
+
+        let data = serde_json::json!({
+            "name": "Praxis Sonnenschein",
+            "inhaber": { "name": "Dr. Maria Sonnenschein", "geburtsjahr": 1970 }
+        });
+        let redacted = redact_pii_value(&schema, &data);
+
+        assert_ne!(redacted["inhaber"]["name"], data["inhaber"]["name"]);
+        assert_eq!(redacted["inhaber"]["geburtsjahr"], serde_json::json!(0));
+    }
+
+    #[test]
+    fn test_anonymize_passes_numbers_through() {
+        let schema = sample_schema();
+        let data = serde_json::json!({ "name": "X", "rating": 4.5, "active": true });
+        let anon = anonymize_value(&schema, &data);
+        assert_eq!(anon["rating"], serde_json::json!(4.5));
+        assert_eq!(anon["active"], serde_json::json!(true));
+    }
+}
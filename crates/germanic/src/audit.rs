@@ -0,0 +1,99 @@
+//! # Structured Audit Events
+//!
+//! Emits structured audit events for security-relevant operations
+//! (compilations, validation failures, signature checks) through the
+//! `tracing` layer under the dedicated target `germanic::audit`.
+//!
+//! ```text
+//! MCP / daemon handler ──► audit::compile_succeeded(...) ──► tracing event
+//!                                                                │
+//!                                    stderr ◄── fmt subscriber ──┘
+//!                                      │
+//!                                      ▼
+//!                            journald / syslog (systemd captures
+//!                            stderr of the service unit)
+//! ```
+//!
+//! Operators integrate this into existing log pipelines by filtering on
+//! the `germanic::audit` target; every event carries structured fields
+//! (`schema_id`, `input`, `outcome`, ...) rather than free-form prose.
+
+/// Target under which all audit events are emitted.
+///
+/// Filter example: `RUST_LOG=germanic::audit=info`
+pub const AUDIT_TARGET: &str = "germanic::audit";
+
+/// Records a successful compilation.
+pub fn compile_succeeded(schema_id: &str, input: &str, output: &str, bytes: usize) {
+    tracing::info!(
+        target: "germanic::audit",
+        event = "compile",
+        outcome = "success",
+        schema_id,
+        input,
+        output,
+        bytes,
+        "compiled {input} → {output}"
+    );
+}
+
+/// Records a failed compilation (validation, build, or IO error).
+pub fn compile_failed(schema: &str, input: &str, error: &str) {
+    tracing::warn!(
+        target: "germanic::audit",
+        event = "compile",
+        outcome = "failure",
+        schema,
+        input,
+        error,
+        "compilation of {input} failed"
+    );
+}
+
+/// Records the result of a .grm file validation.
+pub fn validation_result(file: &str, valid: bool, error: Option<&str>) {
+    if valid {
+        tracing::info!(
+            target: "germanic::audit",
+            event = "validate",
+            outcome = "success",
+            file,
+            "validated {file}"
+        );
+    } else {
+        tracing::warn!(
+            target: "germanic::audit",
+            event = "validate",
+            outcome = "failure",
+            file,
+            error = error.unwrap_or("unknown"),
+            "validation of {file} failed"
+        );
+    }
+}
+
+/// Records a signature verification result.
+///
+/// Sign/verify is not implemented yet (the header slot exists), but the
+/// audit vocabulary is fixed here so pipelines don't need to change later.
+pub fn signature_checked(file: &str, schema_id: &str, verified: bool) {
+    if verified {
+        tracing::info!(
+            target: "germanic::audit",
+            event = "signature",
+            outcome = "verified",
+            file,
+            schema_id,
+            "signature on {file} verified"
+        );
+    } else {
+        tracing::warn!(
+            target: "germanic::audit",
+            event = "signature",
+            outcome = "rejected",
+            file,
+            schema_id,
+            "signature on {file} rejected"
+        );
+    }
+}
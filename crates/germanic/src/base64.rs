@@ -0,0 +1,124 @@
+//! # Minimal Base64 Codec
+//!
+//! Standard-alphabet base64 (RFC 4648, with padding) for the `bytes`
+//! field type: JSON carries base64 strings, the FlatBuffer stores raw
+//! bytes. Hand-rolled to keep the dependency tree flat — the codec is
+//! ~60 lines and the inputs are small (logos, attachments).
+
+/// Standard base64 alphabet.
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Encodes bytes as padded base64.
+pub fn encode(data: &[u8]) -> String {
+    let mut out = String::with_capacity(data.len().div_ceil(3) * 4);
+
+    for chunk in data.chunks(3) {
+        let b0 = chunk[0] as u32;
+        let b1 = chunk.get(1).copied().unwrap_or(0) as u32;
+        let b2 = chunk.get(2).copied().unwrap_or(0) as u32;
+        let triple = (b0 << 16) | (b1 << 8) | b2;
+
+        out.push(ALPHABET[(triple >> 18) as usize & 0x3F] as char);
+        out.push(ALPHABET[(triple >> 12) as usize & 0x3F] as char);
+        out.push(if chunk.len() > 1 {
+            ALPHABET[(triple >> 6) as usize & 0x3F] as char
+        } else {
+            '='
+        });
+        out.push(if chunk.len() > 2 {
+            ALPHABET[triple as usize & 0x3F] as char
+        } else {
+            '='
+        });
+    }
+
+    out
+}
+
+/// Decodes padded base64; rejects invalid characters and bad padding.
+pub fn decode(input: &str) -> Result<Vec<u8>, String> {
+    let bytes = input.as_bytes();
+    if bytes.len() % 4 != 0 {
+        return Err("base64 length must be a multiple of 4".to_string());
+    }
+
+    let mut out = Vec::with_capacity(bytes.len() / 4 * 3);
+
+    for (i, chunk) in bytes.chunks(4).enumerate() {
+        let is_last = (i + 1) * 4 == bytes.len();
+        let mut triple: u32 = 0;
+        let mut pad = 0;
+
+        for (j, &c) in chunk.iter().enumerate() {
+            let value = if c == b'=' {
+                // Padding only in the last chunk, last two positions
+                if !is_last || j < 2 {
+                    return Err("unexpected '=' padding".to_string());
+                }
+                pad += 1;
+                0
+            } else if pad > 0 {
+                return Err("data after '=' padding".to_string());
+            } else {
+                decode_char(c).ok_or_else(|| format!("invalid base64 character '{}'", c as char))?
+            };
+            triple = (triple << 6) | value as u32;
+        }
+
+        out.push((triple >> 16) as u8);
+        if pad < 2 {
+            out.push((triple >> 8) as u8);
+        }
+        if pad < 1 {
+            out.push(triple as u8);
+        }
+    }
+
+    Ok(out)
+}
+
+/// Maps one base64 character to its 6-bit value.
+fn decode_char(c: u8) -> Option<u8> {
+    match c {
+        b'A'..=b'Z' => Some(c - b'A'),
+        b'a'..=b'z' => Some(c - b'a' + 26),
+        b'0'..=b'9' => Some(c - b'0' + 52),
+        b'+' => Some(62),
+        b'/' => Some(63),
+        _ => None,
+    }
+}
+
+// ============================================================================
+// TESTS
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_known_vectors() {
+        // RFC 4648 test vectors
+        assert_eq!(encode(b""), "");
+        assert_eq!(encode(b"f"), "Zg==");
+        assert_eq!(encode(b"fo"), "Zm8=");
+        assert_eq!(encode(b"foo"), "Zm9v");
+        assert_eq!(encode(b"foobar"), "Zm9vYmFy");
+    }
+
+    #[test]
+    fn test_decode_roundtrip() {
+        for data in [&b""[..], b"f", b"fo", b"foo", b"\x00\xFF\x80", b"foobar"] {
+            assert_eq!(decode(&encode(data)).unwrap(), data);
+        }
+    }
+
+    #[test]
+    fn test_decode_rejects_invalid() {
+        assert!(decode("Zm9").is_err()); // bad length
+        assert!(decode("Zm!=").is_err()); // invalid char
+        assert!(decode("=m9v").is_err()); // padding up front
+        assert!(decode("Z=9v").is_err()); // data after padding
+    }
+}
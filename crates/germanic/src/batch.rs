@@ -0,0 +1,127 @@
+//! # Parallel Batch Compilation
+//!
+//! Compiles many records against one schema, using all cores when the
+//! `parallel` feature is enabled.
+//!
+//! ```text
+//! records[0..n] ──► compile_batch() ──► Vec<Result<.grm bytes>>
+//!                        │
+//!                        ├── parallel feature: rayon work-stealing pool
+//!                        └── otherwise: plain sequential iteration
+//! ```
+//!
+//! Each record succeeds or fails independently — one malformed entry in a
+//! directory import must not sink the other thousand. The CLI batch mode
+//! and the multi-record container build on this.
+
+use crate::dynamic::schema_def::SchemaDefinition;
+use crate::dynamic::{compile_dynamic_from_values_with_options, CompileOptions};
+use crate::error::GermanicResult;
+
+/// Compiles a batch of records against one schema.
+///
+/// Returns one result per record, in input order. With the `parallel`
+/// feature enabled the records are compiled on rayon's global thread
+/// pool; without it the batch runs sequentially with identical results.
+pub fn compile_batch<I>(schema: &SchemaDefinition, records: I) -> Vec<GermanicResult<Vec<u8>>>
+where
+    I: IntoIterator<Item = serde_json::Value>,
+{
+    compile_batch_with_options(schema, records, &CompileOptions::default())
+}
+
+/// Like [`compile_batch`], but with explicit [`CompileOptions`]
+/// applied to every record.
+pub fn compile_batch_with_options<I>(
+    schema: &SchemaDefinition,
+    records: I,
+    options: &CompileOptions,
+) -> Vec<GermanicResult<Vec<u8>>>
+where
+    I: IntoIterator<Item = serde_json::Value>,
+{
+    // Collect first: rayon needs an indexable source, and the sequential
+    // path keeps the same ordering semantics.
+    let records: Vec<serde_json::Value> = records.into_iter().collect();
+
+    #[cfg(feature = "parallel")]
+    {
+        use rayon::prelude::*;
+        records
+            .par_iter()
+            .map(|record| compile_dynamic_from_values_with_options(schema, record, options))
+            .collect()
+    }
+
+    #[cfg(not(feature = "parallel"))]
+    {
+        records
+            .iter()
+            .map(|record| compile_dynamic_from_values_with_options(schema, record, options))
+            .collect()
+    }
+}
+
+// ============================================================================
+// TESTS
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dynamic::schema_def::{FieldDefinition, FieldType};
+    use indexmap::IndexMap;
+
+    fn sample_schema() -> SchemaDefinition {
+        let mut fields = IndexMap::new();
+        fields.insert(
+            "name".into(),
+            FieldDefinition {
+                required: true,
+                ..FieldDefinition::new(FieldType::String)
+            },
+        );
+        SchemaDefinition {
+            schema_id: "test.v1".into(),
+            version: 1,
+            description: None,
+            limits: None,
+            strict: false,
+            fields,
+        }
+    }
+
+    #[test]
+    fn test_batch_preserves_input_order() {
+        let schema = sample_schema();
+        let records = vec![
+            serde_json::json!({ "name": "Praxis Sonnenschein" }),
+            serde_json::json!({ "name": "Praxis Mondlicht" }),
+            serde_json::json!({ "name": "Praxis Sternenhimmel" }),
+        ];
+
+        let results = compile_batch(&schema, records.clone());
+        assert_eq!(results.len(), 3);
+
+        for (result, record) in results.iter().zip(&records) {
+            let expected =
+                crate::dynamic::compile_dynamic_from_values(&schema, record).unwrap();
+            assert_eq!(result.as_ref().unwrap(), &expected);
+        }
+    }
+
+    #[test]
+    fn test_batch_failures_are_per_record() {
+        let schema = sample_schema();
+        let records = vec![
+            serde_json::json!({ "name": "Praxis Sonnenschein" }),
+            serde_json::json!({ "falsch": true }),
+            serde_json::json!({ "name": "Praxis Mondlicht" }),
+        ];
+
+        let results = compile_batch(&schema, records);
+        assert!(results[0].is_ok());
+        assert!(results[1].is_err(), "Missing required field must fail");
+        assert!(results[2].is_ok(), "Later records must still compile");
+    }
+}
@@ -0,0 +1,78 @@
+//! # Capability Report
+//!
+//! Machine-readable description of what this build of GERMANIC supports:
+//! header versions, field types, limits, optional features.
+//!
+//! ```text
+//! orchestrator ──► germanic --capabilities ──► JSON
+//!      │                                        │
+//!      └── feature-detects ("header v2? → use --lang") ◄──┘
+//! ```
+//!
+//! Tools (plugins, MCP clients, CI) should feature-detect against this
+//! document instead of parsing version numbers — new capabilities appear
+//! as new entries, never as silent semantic changes of old ones.
+
+use serde_json::json;
+
+/// Builds the capability report for the installed binary.
+///
+/// The document is additive across releases: consumers must ignore
+/// unknown keys, and existing keys never change meaning.
+pub fn capabilities() -> serde_json::Value {
+    json!({
+        "name": "germanic",
+        "version": env!("CARGO_PKG_VERSION"),
+        "header_versions": [1, 2],
+        "field_types": ["string", "bool", "int", "float", "[string]", "[int]", "[bool]", "bytes", "uuid", "table", "[table]", "enum", "union"],
+        "constraints": [],
+        "formats": crate::formats::builtin_format_names(),
+        "plugins": crate::plugin::registered_plugins(),
+        "codegen_languages": ["rust", "ts"],
+        "limits": {
+            "max_input_size": crate::pre_validate::MAX_INPUT_SIZE,
+            "max_string_length": crate::pre_validate::MAX_STRING_LENGTH,
+            "max_array_elements": crate::pre_validate::MAX_ARRAY_ELEMENTS,
+            "max_nesting_depth": crate::pre_validate::MAX_NESTING_DEPTH,
+        },
+        "features": {
+            "mcp": cfg!(feature = "mcp"),
+            "compat_mappings": true,
+            "impact_analysis": true,
+            "anonymize": true,
+            "decompile": true,
+        },
+        "schema_formats": ["germanic", "json-schema-draft-7"],
+    })
+}
+
+// ============================================================================
+// TESTS
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_capabilities_core_keys() {
+        let caps = capabilities();
+        assert_eq!(caps["name"], "germanic");
+        assert_eq!(caps["version"], env!("CARGO_PKG_VERSION"));
+        assert_eq!(caps["header_versions"], json!([1, 2]));
+        assert!(caps["field_types"].as_array().unwrap().contains(&json!("table")));
+    }
+
+    #[test]
+    fn test_capabilities_limits_match_pre_validate() {
+        let caps = capabilities();
+        assert_eq!(
+            caps["limits"]["max_input_size"],
+            crate::pre_validate::MAX_INPUT_SIZE
+        );
+        assert_eq!(
+            caps["limits"]["max_nesting_depth"],
+            crate::pre_validate::MAX_NESTING_DEPTH
+        );
+    }
+}
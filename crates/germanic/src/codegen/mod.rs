@@ -0,0 +1,104 @@
+//! # Code Generation
+//!
+//! Turns dynamic `.schema.json` definitions into typed source code, so
+//! teams that start with dynamic mode (Weg 3) can graduate to static
+//! typed structs without hand-porting every field.
+//!
+//! ```text
+//! my.schema.json ──► germanic generate --lang rust ──► my.rs
+//!                                                       │
+//!                          #[derive(GermanicSchema, ...)] struct
+//!                          (same field order, defaults, required flags)
+//! ```
+
+pub mod rust;
+pub mod ts;
+
+/// Target language for code generation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Language {
+    Rust,
+    TypeScript,
+}
+
+impl Language {
+    /// Parses a language name from the CLI (case-insensitive).
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "rust" | "rs" => Some(Language::Rust),
+            "ts" | "typescript" => Some(Language::TypeScript),
+            _ => None,
+        }
+    }
+}
+
+/// Derives a Rust-style type name from a schema ID.
+///
+/// Takes the last non-version segment and appends "Schema":
+/// `de.dining.restaurant.v1` → `RestaurantSchema`
+pub(crate) fn struct_name_from_schema_id(schema_id: &str) -> String {
+    let base = schema_id
+        .split('.')
+        .rfind(|seg| !is_version_segment(seg))
+        .unwrap_or("Generated");
+    format!("{}Schema", pascal_case(base))
+}
+
+/// True for segments like "v1", "v12".
+fn is_version_segment(seg: &str) -> bool {
+    let mut chars = seg.chars();
+    chars.next() == Some('v') && chars.clone().count() > 0 && chars.all(|c| c.is_ascii_digit())
+}
+
+/// Converts an arbitrary identifier to PascalCase.
+pub(crate) fn pascal_case(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut upper_next = true;
+    for c in s.chars() {
+        if c.is_alphanumeric() {
+            if upper_next {
+                out.extend(c.to_uppercase());
+                upper_next = false;
+            } else {
+                out.push(c);
+            }
+        } else {
+            upper_next = true;
+        }
+    }
+    out
+}
+
+// ============================================================================
+// TESTS
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_struct_name_strips_version() {
+        assert_eq!(
+            struct_name_from_schema_id("de.dining.restaurant.v1"),
+            "RestaurantSchema"
+        );
+        assert_eq!(
+            struct_name_from_schema_id("de.gesundheit.praxis.v12"),
+            "PraxisSchema"
+        );
+    }
+
+    #[test]
+    fn test_pascal_case_handles_separators() {
+        assert_eq!(pascal_case("opening_hours"), "OpeningHours");
+        assert_eq!(pascal_case("kontakt-daten"), "KontaktDaten");
+    }
+
+    #[test]
+    fn test_language_parse() {
+        assert_eq!(Language::parse("Rust"), Some(Language::Rust));
+        assert_eq!(Language::parse("ts"), Some(Language::TypeScript));
+        assert_eq!(Language::parse("cobol"), None);
+    }
+}
@@ -0,0 +1,334 @@
+//! # Rust Code Generation
+//!
+//! Emits a Rust module with `#[derive(GermanicSchema, Deserialize)]`
+//! structs matching a dynamic schema definition. Field order, required
+//! flags and defaults are carried over one-to-one, so the generated
+//! structs compile to byte-identical .grm payloads.
+//!
+//! Nested tables become their own structs (emitted before the root,
+//! leaves first — same layout as the hand-written practice schema).
+
+use super::{is_version_segment, pascal_case, struct_name_from_schema_id};
+use crate::dynamic::schema_def::{FieldDefinition, FieldType, SchemaDefinition};
+use indexmap::IndexMap;
+use std::fmt::Write;
+
+/// Generates a complete Rust module for the given schema.
+pub fn generate(schema: &SchemaDefinition) -> String {
+    let root_name = struct_name_from_schema_id(&schema.schema_id);
+
+    let mut out = String::new();
+    let _ = writeln!(out, "//! Generated by `germanic generate --lang rust`");
+    let _ = writeln!(out, "//! from schema `{}` — do not edit by hand.", schema.schema_id);
+    let _ = writeln!(out);
+    let _ = writeln!(out, "use germanic::GermanicSchema;");
+    let _ = writeln!(out, "use serde::{{Deserialize, Serialize}};");
+
+    // Nested table structs are pushed before their parent during
+    // recursion, so iteration order is already leaves-first.
+    let mut structs = Vec::new();
+    collect_structs(&root_name, &schema.schema_id, &schema.fields, &mut structs);
+    for body in &structs {
+        out.push('\n');
+        out.push_str(body);
+    }
+
+    out
+}
+
+/// Recursively emits struct definitions; nested tables are pushed
+/// before their parent, so `structs` ends up leaves-first.
+fn collect_structs(
+    struct_name: &str,
+    schema_id: &str,
+    fields: &IndexMap<String, FieldDefinition>,
+    structs: &mut Vec<String>,
+) {
+    let mut body = String::new();
+    let _ = writeln!(
+        body,
+        "#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, GermanicSchema)]"
+    );
+    let _ = writeln!(body, "#[germanic(schema_id = \"{}\")]", schema_id);
+    let _ = writeln!(body, "pub struct {} {{", struct_name);
+
+    let mut default_fns = Vec::new();
+
+    for (i, (name, def)) in fields.iter().enumerate() {
+        if i > 0 {
+            body.push('\n');
+        }
+
+        if let Some(description) = &def.description {
+            let _ = writeln!(body, "    /// {}", description);
+        }
+        if def.deprecated {
+            match &def.replaced_by {
+                Some(replacement) => {
+                    let _ = writeln!(body, "    /// Deprecated: use `{}` instead.", replacement);
+                }
+                None => {
+                    let _ = writeln!(body, "    /// Deprecated.");
+                }
+            }
+        }
+
+        let ident = rust_ident(name);
+        if ident != *name {
+            let _ = writeln!(body, "    #[serde(rename = \"{}\")]", name);
+        }
+
+        if def.required {
+            let _ = writeln!(body, "    #[germanic(required)]");
+        }
+
+        match (def.default_string(), &def.field_type) {
+            (Some(value), FieldType::String | FieldType::Bool | FieldType::Int | FieldType::Float) => {
+                let fn_name = format!("default_{}", ident);
+                let _ = writeln!(body, "    #[serde(default = \"{}\")]", fn_name);
+                let _ = writeln!(body, "    #[germanic(default = \"{}\")]", value.replace('"', "\\\""));
+                default_fns.push(default_fn(&fn_name, &def.field_type, &value));
+            }
+            _ if !def.required => {
+                let _ = writeln!(body, "    #[serde(default)]");
+            }
+            _ => {}
+        }
+
+        let ty = rust_type(struct_name, name, def);
+        let _ = writeln!(body, "    pub {}: {},", ident, ty);
+
+        // Recurse into nested tables (single, array elements, union variants)
+        if let (FieldType::Table | FieldType::TableArray | FieldType::Union, Some(nested)) =
+            (&def.field_type, &def.fields)
+        {
+            let nested_name = nested_struct_name(struct_name, name);
+            let nested_id = nested_schema_id(schema_id, name);
+            collect_structs(&nested_name, &nested_id, nested, structs);
+        }
+    }
+
+    let _ = writeln!(body, "}}");
+
+    for f in default_fns {
+        body.push('\n');
+        body.push_str(&f);
+    }
+
+    structs.push(body);
+}
+
+/// Maps a schema field to its Rust type.
+///
+/// Widths match the dynamic builder: int → i32, float → f32.
+fn rust_type(parent: &str, name: &str, def: &FieldDefinition) -> String {
+    let base = match &def.field_type {
+        FieldType::String => "String".to_string(),
+        FieldType::Bool => "bool".to_string(),
+        FieldType::Int => "i32".to_string(),
+        FieldType::Float => "f32".to_string(),
+        FieldType::StringArray => "Vec<String>".to_string(),
+        FieldType::IntArray => "Vec<i32>".to_string(),
+        FieldType::BoolArray => "Vec<bool>".to_string(),
+        // Bytes travel as base64 strings, UUIDs as hyphenated strings in JSON
+        FieldType::Bytes | FieldType::Uuid => "String".to_string(),
+        // Unions become a struct with one optional field per variant
+        FieldType::Table | FieldType::Union => nested_struct_name(parent, name),
+        FieldType::TableArray => format!("Vec<{}>", nested_struct_name(parent, name)),
+        // Enums are strings on the wire; the allowed set is validated at compile time
+        FieldType::Enum => "String".to_string(),
+        // Plugin types are strings on the wire
+        FieldType::Custom(_) => "String".to_string(),
+    };
+
+    // Optional strings become Option<String>; everything else already has
+    // a sensible absent value (false, 0, empty vec, nested Default).
+    if !def.required && def.default.is_none() && def.field_type == FieldType::String {
+        format!("Option<{}>", base)
+    } else {
+        base
+    }
+}
+
+/// Schema ID for a nested table struct: the field name is inserted
+/// BEFORE the version suffix, so the ID still matches the
+/// `namespace.domain.name.vN` format the derive macro enforces
+/// (`de.gastronomie.cafe.v1` + `adresse` → `de.gastronomie.cafe.adresse.v1`).
+fn nested_schema_id(parent_id: &str, field: &str) -> String {
+    let segment: String = field
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect();
+
+    match parent_id.rsplit_once('.') {
+        Some((base, version)) if is_version_segment(version) => {
+            format!("{}.{}.{}", base, segment, version)
+        }
+        // No version suffix to preserve — append (the macro will complain,
+        // but so would it about the parent ID itself)
+        _ => format!("{}.{}", parent_id, segment),
+    }
+}
+
+/// Struct name for a nested table field, prefixed with the parent to
+/// avoid collisions between same-named tables at different levels.
+fn nested_struct_name(parent: &str, field: &str) -> String {
+    let base = pascal_case(field);
+    let parent_base = parent.strip_suffix("Schema").unwrap_or(parent);
+    if base == parent_base {
+        format!("{}{}Schema", parent_base, base)
+    } else {
+        format!("{}Schema", base)
+    }
+}
+
+/// Emits a serde default function for a scalar field.
+fn default_fn(fn_name: &str, field_type: &FieldType, value: &str) -> String {
+    match field_type {
+        FieldType::String => format!(
+            "fn {}() -> String {{\n    \"{}\".to_string()\n}}\n",
+            fn_name,
+            value.replace('"', "\\\"")
+        ),
+        FieldType::Bool => format!(
+            "fn {}() -> bool {{\n    {}\n}}\n",
+            fn_name,
+            value.parse::<bool>().unwrap_or(false)
+        ),
+        FieldType::Int => format!(
+            "fn {}() -> i32 {{\n    {}\n}}\n",
+            fn_name,
+            value.parse::<i32>().unwrap_or(0)
+        ),
+        FieldType::Float => format!(
+            "fn {}() -> f32 {{\n    {:?}\n}}\n",
+            fn_name,
+            value.parse::<f32>().unwrap_or(0.0)
+        ),
+        _ => unreachable!("default_fn only called for scalar types"),
+    }
+}
+
+/// Sanitizes a schema field name into a valid Rust identifier.
+fn rust_ident(name: &str) -> String {
+    let mut ident: String = name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_lowercase() } else { '_' })
+        .collect();
+    if ident.chars().next().is_some_and(|c| c.is_ascii_digit()) {
+        ident.insert(0, '_');
+    }
+    if matches!(ident.as_str(), "type" | "ref" | "move" | "async" | "use" | "mod") {
+        format!("r#{}", ident)
+    } else {
+        ident
+    }
+}
+
+// ============================================================================
+// TESTS
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn restaurant_schema() -> SchemaDefinition {
+        let json = r#"{
+            "schema_id": "de.dining.restaurant.v1",
+            "version": 1,
+            "fields": {
+                "name": { "type": "string", "required": true },
+                "telefon": { "type": "string", "required": false },
+                "land": { "type": "string", "required": false, "default": "DE" },
+                "sitzplaetze": { "type": "int", "required": false },
+                "bewertung": { "type": "float", "required": false },
+                "lieferdienst": { "type": "bool", "required": false },
+                "kuechen": { "type": "[string]", "required": false },
+                "adresse": {
+                    "type": "table",
+                    "required": true,
+                    "fields": {
+                        "strasse": { "type": "string", "required": true },
+                        "plz": { "type": "string", "required": true }
+                    }
+                }
+            }
+        }"#;
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn test_generate_root_struct() {
+        let code = generate(&restaurant_schema());
+
+        assert!(code.contains("pub struct RestaurantSchema {"));
+        assert!(code.contains("#[germanic(schema_id = \"de.dining.restaurant.v1\")]"));
+        assert!(code.contains("#[germanic(required)]\n    pub name: String,"));
+        assert!(code.contains("pub telefon: Option<String>,"));
+        assert!(code.contains("pub sitzplaetze: i32,"));
+        assert!(code.contains("pub bewertung: f32,"));
+        assert!(code.contains("pub lieferdienst: bool,"));
+        assert!(code.contains("pub kuechen: Vec<String>,"));
+    }
+
+    #[test]
+    fn test_generate_preserves_field_order() {
+        let code = generate(&restaurant_schema());
+        let name_pos = code.find("pub name:").unwrap();
+        let telefon_pos = code.find("pub telefon:").unwrap();
+        let adresse_pos = code.find("pub adresse:").unwrap();
+        assert!(name_pos < telefon_pos && telefon_pos < adresse_pos);
+    }
+
+    #[test]
+    fn test_generate_nested_table_before_root() {
+        let code = generate(&restaurant_schema());
+        let nested_pos = code.find("pub struct AdresseSchema {").unwrap();
+        let root_pos = code.find("pub struct RestaurantSchema {").unwrap();
+        assert!(nested_pos < root_pos, "nested struct must come first");
+        assert!(code.contains("pub adresse: AdresseSchema,"));
+    }
+
+    #[test]
+    fn test_generate_default_fn() {
+        let code = generate(&restaurant_schema());
+        assert!(code.contains("#[serde(default = \"default_land\")]"));
+        assert!(code.contains("#[germanic(default = \"DE\")]"));
+        assert!(code.contains("fn default_land() -> String {\n    \"DE\".to_string()\n}"));
+    }
+
+    #[test]
+    fn test_nested_schema_id_keeps_version_suffix() {
+        assert_eq!(
+            nested_schema_id("de.dining.restaurant.v1", "adresse"),
+            "de.dining.restaurant.adresse.v1"
+        );
+        assert_eq!(
+            nested_schema_id("de.dining.restaurant.v12", "opening-hours"),
+            "de.dining.restaurant.opening_hours.v12"
+        );
+    }
+
+    #[test]
+    fn test_generate_nested_id_is_macro_conforming() {
+        let code = generate(&restaurant_schema());
+        assert!(
+            code.contains("#[germanic(schema_id = \"de.dining.restaurant.adresse.v1\")]"),
+            "nested struct must carry a vN-suffixed schema_id:\n{code}"
+        );
+    }
+
+    #[test]
+    fn test_rust_ident_sanitizes() {
+        assert_eq!(rust_ident("opening-hours"), "opening_hours");
+        assert_eq!(rust_ident("1st_field"), "_1st_field");
+        assert_eq!(rust_ident("type"), "r#type");
+    }
+}
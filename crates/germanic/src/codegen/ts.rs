@@ -0,0 +1,454 @@
+//! # TypeScript Code Generation
+//!
+//! Emits a self-contained `.ts` module from a schema definition:
+//! typed interfaces for the root and nested tables, plus a small
+//! dependency-free decoder that reads .grm files directly.
+//!
+//! ```text
+//! my.schema.json ──► germanic generate --lang ts ──► my.ts
+//!                                                     │
+//!                      export interface Restaurant { ... }
+//!                      export function decodeGrm(bytes): { header, data }
+//! ```
+//!
+//! The decoder is a generated port of the Rust reader: it walks the
+//! FlatBuffer vtables with the schema embedded as a constant, so frontend
+//! and Node-based agents need neither flatc output nor a runtime library.
+
+use super::pascal_case;
+use crate::dynamic::schema_def::{FieldDefinition, FieldType, SchemaDefinition};
+use std::fmt::Write;
+
+/// Generates a complete TypeScript module for the given schema.
+pub fn generate(schema: &SchemaDefinition) -> String {
+    let root_name = root_interface_name(&schema.schema_id);
+
+    let mut out = String::new();
+    let _ = writeln!(out, "// Generated by `germanic generate --lang ts`");
+    let _ = writeln!(out, "// from schema `{}` — do not edit by hand.", schema.schema_id);
+
+    // Interfaces (leaves first, same order as the Rust generator)
+    let mut interfaces = Vec::new();
+    collect_interfaces(&root_name, &schema.fields, &mut interfaces);
+    for body in &interfaces {
+        out.push('\n');
+        out.push_str(body);
+    }
+
+    // Embedded schema + decoder template
+    let schema_json =
+        serde_json::to_string_pretty(schema).expect("schema serialization cannot fail");
+    out.push('\n');
+    let _ = writeln!(out, "const SCHEMA = {} as const;", schema_json);
+    out.push('\n');
+    out.push_str(&decoder_template(&root_name));
+
+    out
+}
+
+/// Interface name for the root table, e.g. `de.dining.restaurant.v1` → `Restaurant`.
+fn root_interface_name(schema_id: &str) -> String {
+    super::struct_name_from_schema_id(schema_id)
+        .strip_suffix("Schema")
+        .unwrap_or("Generated")
+        .to_string()
+}
+
+/// Recursively emits interface definitions, leaves before parents.
+fn collect_interfaces(
+    name: &str,
+    fields: &indexmap::IndexMap<String, FieldDefinition>,
+    interfaces: &mut Vec<String>,
+) {
+    let mut body = String::new();
+    let _ = writeln!(body, "export interface {} {{", name);
+
+    for (field_name, def) in fields {
+        if let (FieldType::Table | FieldType::TableArray | FieldType::Union, Some(nested)) =
+            (&def.field_type, &def.fields)
+        {
+            let nested_name = nested_interface_name(name, field_name);
+            collect_interfaces(&nested_name, nested, interfaces);
+        }
+
+        if let Some(description) = &def.description {
+            let _ = writeln!(body, "    /** {} */", description);
+        }
+        if def.deprecated {
+            let note = match &def.replaced_by {
+                Some(replacement) => format!(" use {} instead", replacement),
+                None => String::new(),
+            };
+            let _ = writeln!(body, "    /** @deprecated{} */", note);
+        }
+
+        let ts_type = ts_type(name, field_name, def);
+        let optional = if def.required { "" } else { "?" };
+        let _ = writeln!(body, "    {:?}{}: {};", field_name, optional, ts_type);
+    }
+
+    let _ = writeln!(body, "}}");
+    interfaces.push(body);
+}
+
+/// Maps a schema field to its TypeScript type.
+fn ts_type(parent: &str, name: &str, def: &FieldDefinition) -> String {
+    match &def.field_type {
+        FieldType::String => "string".to_string(),
+        FieldType::Bool => "boolean".to_string(),
+        FieldType::Int | FieldType::Float => "number".to_string(),
+        FieldType::StringArray => "string[]".to_string(),
+        FieldType::IntArray => "number[]".to_string(),
+        FieldType::BoolArray => "boolean[]".to_string(),
+        // Bytes travel as base64 strings, UUIDs as hyphenated strings in JSON
+        FieldType::Bytes | FieldType::Uuid => "string".to_string(),
+        // Unions become an interface with one optional field per variant
+        FieldType::Table | FieldType::Union => nested_interface_name(parent, name),
+        FieldType::TableArray => format!("{}[]", nested_interface_name(parent, name)),
+        // Enums become string literal unions when the allowed set is known
+        FieldType::Enum => match &def.values {
+            Some(values) if !values.is_empty() => values
+                .iter()
+                .map(|v| format!("{:?}", v))
+                .collect::<Vec<_>>()
+                .join(" | "),
+            _ => "string".to_string(),
+        },
+        // Plugin types are strings on the wire
+        FieldType::Custom(_) => "string".to_string(),
+    }
+}
+
+/// Interface name for a nested table field, parent-prefixed on collision.
+fn nested_interface_name(parent: &str, field: &str) -> String {
+    let base = pascal_case(field);
+    if base == parent {
+        format!("{}{}", parent, base)
+    } else {
+        base
+    }
+}
+
+/// The fixed decoder body; only the root type name varies per schema.
+fn decoder_template(root_name: &str) -> String {
+    format!(
+        r#"export interface GrmHeader {{
+    schemaId: string;
+    language?: string;
+    signed: boolean;
+}}
+
+type FieldDef = {{
+    type: string;
+    required?: boolean;
+    default?: string;
+    values?: readonly string[];
+    fields?: Record<string, FieldDef>;
+}};
+
+const SIGNATURE_LENGTH = 64;
+const MAX_NESTING_DEPTH = 32;
+
+function fail(reason: string): never {{
+    throw new Error(`Malformed .grm file: ${{reason}}`);
+}}
+
+function readU16(view: DataView, pos: number): number {{
+    if (pos + 2 > view.byteLength) fail("u16 out of bounds");
+    return view.getUint16(pos, true);
+}}
+
+function readU32(view: DataView, pos: number): number {{
+    if (pos + 4 > view.byteLength) fail("u32 out of bounds");
+    return view.getUint32(pos, true);
+}}
+
+function readI32(view: DataView, pos: number): number {{
+    if (pos + 4 > view.byteLength) fail("i32 out of bounds");
+    return view.getInt32(pos, true);
+}}
+
+function readF32(view: DataView, pos: number): number {{
+    if (pos + 4 > view.byteLength) fail("f32 out of bounds");
+    // Round through the shortest decimal representation so 4.7f32
+    // decodes as 4.7, matching the reference (Rust) reader.
+    return Number(view.getFloat32(pos, true).toPrecision(7).replace(/0+$/, ""));
+}}
+
+function readString(bytes: Uint8Array, view: DataView, pos: number): string {{
+    const len = readU32(view, pos);
+    if (pos + 4 + len > bytes.length) fail("string out of bounds");
+    return new TextDecoder("utf-8").decode(bytes.subarray(pos + 4, pos + 4 + len));
+}}
+
+// Matches the standard alphabet the reference (Rust) reader emits.
+const BASE64_ALPHABET = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+function toBase64(data: Uint8Array): string {{
+    let out = "";
+    for (let i = 0; i < data.length; i += 3) {{
+        const b0 = data[i];
+        const b1 = i + 1 < data.length ? data[i + 1] : 0;
+        const b2 = i + 2 < data.length ? data[i + 2] : 0;
+        const triple = (b0 << 16) | (b1 << 8) | b2;
+        out += BASE64_ALPHABET[(triple >> 18) & 0x3f];
+        out += BASE64_ALPHABET[(triple >> 12) & 0x3f];
+        out += i + 1 < data.length ? BASE64_ALPHABET[(triple >> 6) & 0x3f] : "=";
+        out += i + 2 < data.length ? BASE64_ALPHABET[triple & 0x3f] : "=";
+    }}
+    return out;
+}}
+
+function followOffset(view: DataView, pos: number): number {{
+    const offset = readU32(view, pos);
+    const target = pos + offset;
+    if (target >= view.byteLength) fail("offset out of bounds");
+    return target;
+}}
+
+function decodeTable(
+    bytes: Uint8Array,
+    view: DataView,
+    tablePos: number,
+    fields: Record<string, FieldDef>,
+    depth: number,
+): Record<string, unknown> {{
+    if (depth > MAX_NESTING_DEPTH) fail("nesting too deep");
+
+    const soffset = readI32(view, tablePos);
+    const vtablePos = tablePos - soffset;
+    if (vtablePos < 0) fail("vtable out of bounds");
+    const vtableSize = readU16(view, vtablePos);
+
+    const result: Record<string, unknown> = {{}};
+    let index = 0;
+    for (const [name, def] of Object.entries(fields)) {{
+        const voffset = 4 + 2 * index;
+        index += 1;
+
+        let fieldOffset = 0;
+        if (voffset < vtableSize) {{
+            fieldOffset = readU16(view, vtablePos + voffset);
+        }}
+
+        if (fieldOffset === 0) {{
+            // Absent slot: scalar defaults were elided at build time.
+            // Defaults may be native JSON values or their string form.
+            if (def.default !== undefined) {{
+                if (typeof def.default !== "string") {{
+                    if (def.type === "bool" || def.type === "int" || def.type === "float") {{
+                        result[name] = def.default;
+                    }}
+                }}
+                else if (def.type === "bool") result[name] = def.default === "true";
+                else if (def.type === "int") result[name] = parseInt(def.default, 10) || 0;
+                else if (def.type === "float") result[name] = parseFloat(def.default) || 0;
+            }}
+            continue;
+        }}
+
+        const fieldPos = tablePos + fieldOffset;
+        switch (def.type) {{
+            case "string":
+            case "enum":
+                result[name] = readString(bytes, view, followOffset(view, fieldPos));
+                break;
+            case "bool":
+                if (fieldPos >= bytes.length) fail("bool out of bounds");
+                result[name] = bytes[fieldPos] !== 0;
+                break;
+            case "int":
+                result[name] = readI32(view, fieldPos);
+                break;
+            case "float":
+                result[name] = readF32(view, fieldPos);
+                break;
+            case "[string]": {{
+                const vecPos = followOffset(view, fieldPos);
+                const len = readU32(view, vecPos);
+                const items: string[] = [];
+                for (let i = 0; i < len; i++) {{
+                    items.push(readString(bytes, view, followOffset(view, vecPos + 4 + 4 * i)));
+                }}
+                result[name] = items;
+                break;
+            }}
+            case "[int]": {{
+                const vecPos = followOffset(view, fieldPos);
+                const len = readU32(view, vecPos);
+                const items: number[] = [];
+                for (let i = 0; i < len; i++) {{
+                    items.push(readI32(view, vecPos + 4 + 4 * i));
+                }}
+                result[name] = items;
+                break;
+            }}
+            case "[bool]": {{
+                const vecPos = followOffset(view, fieldPos);
+                const len = readU32(view, vecPos);
+                const items: boolean[] = [];
+                for (let i = 0; i < len; i++) {{
+                    if (vecPos + 4 + i >= bytes.length) fail("bool array out of bounds");
+                    items.push(bytes[vecPos + 4 + i] !== 0);
+                }}
+                result[name] = items;
+                break;
+            }}
+            case "bytes": {{
+                const vecPos = followOffset(view, fieldPos);
+                const len = readU32(view, vecPos);
+                if (vecPos + 4 + len > bytes.length) fail("byte vector out of bounds");
+                result[name] = toBase64(bytes.subarray(vecPos + 4, vecPos + 4 + len));
+                break;
+            }}
+            case "uuid": {{
+                const vecPos = followOffset(view, fieldPos);
+                const len = readU32(view, vecPos);
+                if (len !== 16 || vecPos + 4 + len > bytes.length) fail("uuid is not 16 bytes");
+                let uuid = "";
+                for (let i = 0; i < 16; i++) {{
+                    if (i === 4 || i === 6 || i === 8 || i === 10) uuid += "-";
+                    uuid += bytes[vecPos + 4 + i].toString(16).padStart(2, "0");
+                }}
+                result[name] = uuid;
+                break;
+            }}
+            case "table":
+            case "union":
+                result[name] = decodeTable(
+                    bytes, view, followOffset(view, fieldPos), def.fields ?? {{}}, depth + 1,
+                );
+                break;
+            case "[table]": {{
+                const vecPos = followOffset(view, fieldPos);
+                const len = readU32(view, vecPos);
+                const items: Record<string, unknown>[] = [];
+                for (let i = 0; i < len; i++) {{
+                    items.push(decodeTable(
+                        bytes, view, followOffset(view, vecPos + 4 + 4 * i),
+                        def.fields ?? {{}}, depth + 1,
+                    ));
+                }}
+                result[name] = items;
+                break;
+            }}
+            default:
+                fail(`unknown field type '${{def.type}}'`);
+        }}
+    }}
+
+    return result;
+}}
+
+function parseHeader(bytes: Uint8Array, view: DataView): [GrmHeader, number] {{
+    if (bytes.length < 4 || bytes[0] !== 0x47 || bytes[1] !== 0x52 || bytes[2] !== 0x4d) {{
+        fail("bad magic");
+    }}
+    const version = bytes[3];
+    if (version !== 1 && version !== 2) fail(`unsupported version ${{version}}`);
+
+    let pos = 4;
+    const idLen = readU16(view, pos);
+    pos += 2;
+    if (pos + idLen > bytes.length) fail("schema id out of bounds");
+    const schemaId = new TextDecoder("utf-8").decode(bytes.subarray(pos, pos + idLen));
+    pos += idLen;
+
+    let language: string | undefined;
+    if (version === 2) {{
+        if (pos >= bytes.length) fail("language tag out of bounds");
+        const langLen = bytes[pos];
+        pos += 1;
+        if (pos + langLen > bytes.length) fail("language tag out of bounds");
+        language = new TextDecoder("utf-8").decode(bytes.subarray(pos, pos + langLen));
+        pos += langLen;
+    }}
+
+    if (pos + SIGNATURE_LENGTH > bytes.length) fail("signature out of bounds");
+    const signed = bytes.subarray(pos, pos + SIGNATURE_LENGTH).some((b) => b !== 0);
+    pos += SIGNATURE_LENGTH;
+
+    return [{{ schemaId, language, signed }}, pos];
+}}
+
+/** Decodes a complete .grm file (header + FlatBuffer payload). */
+export function decodeGrm(bytes: Uint8Array): {{ header: GrmHeader; data: {root} }} {{
+    const view = new DataView(bytes.buffer, bytes.byteOffset, bytes.byteLength);
+    const [header, headerLength] = parseHeader(bytes, view);
+    const payload = bytes.subarray(headerLength);
+    return {{ header, data: decodePayload(payload) }};
+}}
+
+/** Decodes a bare FlatBuffer payload (no .grm header). */
+export function decodePayload(payload: Uint8Array): {root} {{
+    const view = new DataView(payload.buffer, payload.byteOffset, payload.byteLength);
+    const rootPos = followOffset(view, 0);
+    const fields = SCHEMA.fields as unknown as Record<string, FieldDef>;
+    return decodeTable(payload, view, rootPos, fields, 0) as unknown as {root};
+}}
+"#,
+        root = root_name
+    )
+}
+
+// ============================================================================
+// TESTS
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn restaurant_schema() -> SchemaDefinition {
+        let json = r#"{
+            "schema_id": "de.dining.restaurant.v1",
+            "version": 1,
+            "fields": {
+                "name": { "type": "string", "required": true },
+                "telefon": { "type": "string", "required": false },
+                "bewertung": { "type": "float", "required": false },
+                "kuechen": { "type": "[string]", "required": false },
+                "adresse": {
+                    "type": "table",
+                    "required": true,
+                    "fields": {
+                        "strasse": { "type": "string", "required": true },
+                        "plz": { "type": "string", "required": true }
+                    }
+                }
+            }
+        }"#;
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn test_generate_interfaces() {
+        let code = generate(&restaurant_schema());
+
+        assert!(code.contains("export interface Restaurant {"));
+        assert!(code.contains("\"name\": string;"));
+        assert!(code.contains("\"telefon\"?: string;"));
+        assert!(code.contains("\"bewertung\"?: number;"));
+        assert!(code.contains("\"kuechen\"?: string[];"));
+        assert!(code.contains("\"adresse\": Adresse;"));
+        assert!(code.contains("export interface Adresse {"));
+    }
+
+    #[test]
+    fn test_generate_nested_interface_before_root() {
+        let code = generate(&restaurant_schema());
+        let nested = code.find("export interface Adresse {").unwrap();
+        let root = code.find("export interface Restaurant {").unwrap();
+        assert!(nested < root);
+    }
+
+    #[test]
+    fn test_generate_embeds_schema_and_decoder() {
+        let code = generate(&restaurant_schema());
+        assert!(code.contains("const SCHEMA = {"));
+        assert!(code.contains("\"schema_id\": \"de.dining.restaurant.v1\""));
+        assert!(code.contains("export function decodeGrm(bytes: Uint8Array)"));
+        assert!(code.contains("data: Restaurant"));
+        assert!(code.contains("export function decodePayload(payload: Uint8Array): Restaurant"));
+    }
+}
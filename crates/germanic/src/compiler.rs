@@ -71,6 +71,20 @@ use std::path::Path;
 pub fn compile<S>(schema: &S) -> GermanicResult<Vec<u8>>
 where
     S: SchemaMetadata + Validate + GermanicSerialize,
+{
+    let mut output = Vec::new();
+    compile_to_writer(schema, &mut output)?;
+    Ok(output)
+}
+
+/// Like [`compile`], but streams header and payload into a writer.
+///
+/// Avoids the intermediate Vec that concatenates header and payload —
+/// useful when the .grm bytes go straight to a file or socket anyway.
+pub fn compile_to_writer<S, W>(schema: &S, writer: &mut W) -> GermanicResult<()>
+where
+    S: SchemaMetadata + Validate + GermanicSerialize,
+    W: std::io::Write,
 {
     // 1. Validate required fields
     schema.validate().map_err(GermanicError::Validation)?;
@@ -84,12 +98,11 @@ where
     // 3. Serialize schema to FlatBuffer
     let payload_bytes = schema.to_bytes();
 
-    // 4. Combine header + payload
-    let mut output = Vec::with_capacity(header_bytes.len() + payload_bytes.len());
-    output.extend_from_slice(&header_bytes);
-    output.extend_from_slice(&payload_bytes);
+    // 4. Write header + payload straight to the sink
+    writer.write_all(&header_bytes)?;
+    writer.write_all(&payload_bytes)?;
 
-    Ok(output)
+    Ok(())
 }
 
 /// Compiles JSON string to .grm bytes.
@@ -146,7 +159,11 @@ where
     compile_json::<S>(&json)
 }
 
-/// Writes .grm bytes to a file.
+/// Writes .grm bytes to a file atomically.
+///
+/// Writes to a temporary file in the same directory, fsyncs, then renames
+/// over the target. A crash mid-write can therefore never leave a truncated
+/// `data.grm` behind — the webserver either serves the old file or the new one.
 ///
 /// ## Example
 ///
@@ -155,8 +172,36 @@ where
 /// write_grm(&bytes, Path::new("practice.grm"))?;
 /// ```
 pub fn write_grm(data: &[u8], path: &Path) -> GermanicResult<()> {
-    std::fs::write(path, data)?;
-    Ok(())
+    use std::io::Write;
+
+    // Temp file must live in the SAME directory as the target,
+    // otherwise the final rename is not guaranteed to be atomic.
+    let dir = match path.parent() {
+        Some(p) if !p.as_os_str().is_empty() => p.to_path_buf(),
+        _ => std::path::PathBuf::from("."),
+    };
+    let file_name = path
+        .file_name()
+        .ok_or_else(|| GermanicError::General(format!("Invalid output path: {}", path.display())))?
+        .to_string_lossy()
+        .into_owned();
+    let tmp_path = dir.join(format!(".{}.tmp.{}", file_name, std::process::id()));
+
+    let result = (|| -> std::io::Result<()> {
+        let mut file = std::fs::File::create(&tmp_path)?;
+        file.write_all(data)?;
+        // Flush file contents to disk BEFORE the rename makes them visible
+        file.sync_all()?;
+        std::fs::rename(&tmp_path, path)?;
+        Ok(())
+    })();
+
+    if result.is_err() {
+        // Best-effort cleanup — the original target is still intact
+        let _ = std::fs::remove_file(&tmp_path);
+    }
+
+    result.map_err(GermanicError::Io)
 }
 
 // ============================================================================
@@ -171,6 +216,8 @@ pub fn write_grm(data: &[u8], path: &Path) -> GermanicResult<()> {
 pub enum SchemaType {
     /// Practice schema for healthcare practitioners
     Practice,
+    /// Hospital schema
+    Krankenhaus,
 }
 
 impl SchemaType {
@@ -178,6 +225,7 @@ impl SchemaType {
     pub fn parse(name: &str) -> Option<Self> {
         match name.to_lowercase().as_str() {
             "praxis" | "practice" => Some(Self::Practice),
+            "krankenhaus" | "hospital" => Some(Self::Krankenhaus),
             _ => None,
         }
     }
@@ -186,6 +234,7 @@ impl SchemaType {
     pub fn name(&self) -> &'static str {
         match self {
             Self::Practice => "practice",
+            Self::Krankenhaus => "krankenhaus",
         }
     }
 
@@ -193,6 +242,18 @@ impl SchemaType {
     pub fn schema_id(&self) -> &'static str {
         match self {
             Self::Practice => "de.gesundheit.praxis.v1",
+            Self::Krankenhaus => "de.gesundheit.krankenhaus.v1",
+        }
+    }
+
+    /// Returns the embedded `.schema.json` definition the dynamic
+    /// pipeline compiles this built-in with.
+    pub fn definition(&self) -> &'static str {
+        match self {
+            Self::Practice => include_str!("../schemas/de.gesundheit.praxis.v1.schema.json"),
+            Self::Krankenhaus => {
+                include_str!("../schemas/de.gesundheit.krankenhaus.v1.schema.json")
+            }
         }
     }
 }
@@ -211,9 +272,27 @@ mod tests {
         assert_eq!(SchemaType::parse("praxis"), Some(SchemaType::Practice));
         assert_eq!(SchemaType::parse("practice"), Some(SchemaType::Practice));
         assert_eq!(SchemaType::parse("PRAXIS"), Some(SchemaType::Practice));
+        assert_eq!(
+            SchemaType::parse("krankenhaus"),
+            Some(SchemaType::Krankenhaus)
+        );
+        assert_eq!(
+            SchemaType::parse("hospital"),
+            Some(SchemaType::Krankenhaus)
+        );
         assert_eq!(SchemaType::parse("unknown"), None);
     }
 
+    #[test]
+    fn test_schema_type_definitions_parse() {
+        for schema_type in [SchemaType::Practice, SchemaType::Krankenhaus] {
+            let schema: crate::dynamic::schema_def::SchemaDefinition =
+                serde_json::from_str(schema_type.definition())
+                    .expect("embedded definition invalid");
+            assert_eq!(schema.schema_id, schema_type.schema_id());
+        }
+    }
+
     #[test]
     fn test_compile_practice() {
         let practice = PraxisSchema {
@@ -258,6 +337,48 @@ mod tests {
         assert_eq!(&bytes[0..3], b"GRM");
     }
 
+    #[test]
+    fn test_write_grm_atomic() {
+        let dir = tempfile::tempdir().unwrap();
+        let target = dir.path().join("data.grm");
+
+        // Pre-existing file must be replaced, not truncated in place
+        std::fs::write(&target, b"old contents").unwrap();
+
+        write_grm(b"new contents", &target).expect("Atomic write should succeed");
+
+        assert_eq!(std::fs::read(&target).unwrap(), b"new contents");
+
+        // No temp files left behind
+        let leftovers: Vec<_> = std::fs::read_dir(dir.path())
+            .unwrap()
+            .filter_map(|e| e.ok())
+            .filter(|e| e.file_name().to_string_lossy().contains(".tmp."))
+            .collect();
+        assert!(leftovers.is_empty(), "Temp file not cleaned up");
+    }
+
+    #[test]
+    fn test_compile_to_writer_matches_compile() {
+        let practice = PraxisSchema {
+            name: "Test".to_string(),
+            bezeichnung: "Arzt".to_string(),
+            adresse: AdresseSchema {
+                strasse: "Teststr.".to_string(),
+                hausnummer: None,
+                plz: "12345".to_string(),
+                ort: "Berlin".to_string(),
+                land: "DE".to_string(),
+            },
+            ..Default::default()
+        };
+
+        let mut streamed = Vec::new();
+        compile_to_writer(&practice, &mut streamed).expect("Streaming compile should succeed");
+
+        assert_eq!(streamed, compile(&practice).unwrap());
+    }
+
     #[test]
     fn test_compile_validation_error() {
         let practice = PraxisSchema::default(); // All required fields empty
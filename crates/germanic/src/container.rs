@@ -0,0 +1,322 @@
+//! # Multi-Record .grm Containers
+//!
+//! A container bundles many records of the SAME schema in one file, so a
+//! directory with thousands of entries (e.g. a practice registry) can be
+//! published and scanned as a single artifact.
+//!
+//! ## Format
+//!
+//! ```text
+//! ┌──────────┬───────┬──────────────────────────────────────────────────┐
+//! │  Offset  │ Size  │ Content                                          │
+//! ├──────────┼───────┼──────────────────────────────────────────────────┤
+//! │   0      │ 3     │ Magic: "GRM"                                     │
+//! │   3      │ 1     │ Version: 0x43 ('C' for container)                │
+//! │   4      │ 2     │ Schema-ID length (LE u16)                        │
+//! │   6      │ n     │ Schema-ID (UTF-8), shared by all records         │
+//! │   ...    │ 4     │ Record count (LE u32)                            │
+//! │   ...    │ 4+m   │ Per record: payload length (LE u32) + payload    │
+//! └──────────┴───────┴──────────────────────────────────────────────────┘
+//! ```
+//!
+//! Records are raw FlatBuffer payloads — the same bytes a single-record
+//! .grm carries after its header.
+//!
+//! ## Lazy scanning
+//!
+//! [`GrmContainer::records`] walks the length prefixes and yields raw
+//! payload slices; nothing is decoded until the caller asks. Decoding a
+//! single record out of thousands costs exactly one [`decode_payload`]
+//! call.
+//!
+//! ```rust,ignore
+//! let bytes = std::fs::read("praxen.grmc")?;
+//! let container = GrmContainer::from_bytes(&bytes)?;
+//! for record in container.decoded(&schema).take(10) {
+//!     println!("{}", record?);
+//! }
+//! ```
+
+use crate::dynamic::schema_def::SchemaDefinition;
+use crate::error::{GermanicError, GermanicResult};
+use crate::reader::decode_payload;
+use serde_json::Value;
+
+/// Format version byte for multi-record containers ('C').
+///
+/// Shares the "GRM" magic with single-record files; the version byte is
+/// what distinguishes a container from versions 1 and 2.
+pub const GRM_VERSION_CONTAINER: u8 = 0x43;
+
+// ============================================================================
+// CONTAINER
+// ============================================================================
+
+/// A parsed container header over a borrowed byte buffer.
+///
+/// Construction only reads the fixed header — records stay untouched
+/// until iterated.
+#[derive(Debug)]
+pub struct GrmContainer<'a> {
+    /// Schema-ID shared by every record in the container.
+    pub schema_id: String,
+
+    /// Full container bytes (borrowed, zero-copy).
+    buf: &'a [u8],
+
+    /// Byte offset of the first record's length prefix.
+    records_offset: usize,
+
+    /// Number of records the header declares.
+    count: u32,
+}
+
+impl<'a> GrmContainer<'a> {
+    /// Parses the container header. Records are NOT validated here —
+    /// malformed record boundaries surface as errors during iteration.
+    pub fn from_bytes(buf: &'a [u8]) -> GermanicResult<Self> {
+        if buf.len() < 10 {
+            return Err(GermanicError::General(
+                "Container too short for header".to_string(),
+            ));
+        }
+        if &buf[0..3] != b"GRM" || buf[3] != GRM_VERSION_CONTAINER {
+            return Err(GermanicError::General(format!(
+                "Not a .grm container: magic {:02X?}",
+                &buf[0..4]
+            )));
+        }
+
+        let id_len = u16::from_le_bytes([buf[4], buf[5]]) as usize;
+        let id_end = 6 + id_len;
+        if id_end + 4 > buf.len() {
+            return Err(GermanicError::General(
+                "Container truncated in schema-ID".to_string(),
+            ));
+        }
+        let schema_id = std::str::from_utf8(&buf[6..id_end])
+            .map_err(|_| GermanicError::General("Schema-ID is not valid UTF-8".to_string()))?
+            .to_string();
+
+        let count = u32::from_le_bytes([buf[id_end], buf[id_end + 1], buf[id_end + 2], buf[id_end + 3]]);
+
+        Ok(Self {
+            schema_id,
+            buf,
+            records_offset: id_end + 4,
+            count,
+        })
+    }
+
+    /// Number of records the container declares.
+    pub fn len(&self) -> usize {
+        self.count as usize
+    }
+
+    /// True if the container declares zero records.
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Lazy iterator over raw record payload slices.
+    ///
+    /// Each item is the FlatBuffer payload of one record — the bytes a
+    /// single-record .grm would carry after its header. Truncated or
+    /// inconsistent length prefixes yield one `Err` and end iteration.
+    pub fn records(&self) -> Records<'a> {
+        Records {
+            buf: self.buf,
+            pos: self.records_offset,
+            remaining: self.count,
+        }
+    }
+
+    /// Lazy iterator over DECODED records.
+    ///
+    /// Convenience wrapper around [`records`](Self::records) that runs
+    /// [`decode_payload`] per item, so `take(10)` on a container with
+    /// thousands of records decodes exactly ten.
+    pub fn decoded(
+        &self,
+        schema: &'a SchemaDefinition,
+    ) -> impl Iterator<Item = GermanicResult<Value>> + 'a {
+        self.records()
+            .map(move |record| record.and_then(|payload| decode_payload(schema, payload)))
+    }
+}
+
+// ============================================================================
+// RECORD ITERATOR
+// ============================================================================
+
+/// Iterator state for [`GrmContainer::records`].
+#[derive(Debug)]
+pub struct Records<'a> {
+    buf: &'a [u8],
+    pos: usize,
+    remaining: u32,
+}
+
+impl<'a> Iterator for Records<'a> {
+    type Item = GermanicResult<&'a [u8]>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+
+        let Some(len_bytes) = self.buf.get(self.pos..self.pos + 4) else {
+            self.remaining = 0;
+            return Some(Err(GermanicError::General(
+                "Container truncated in record length".to_string(),
+            )));
+        };
+        let len = u32::from_le_bytes([len_bytes[0], len_bytes[1], len_bytes[2], len_bytes[3]])
+            as usize;
+
+        let start = self.pos + 4;
+        let Some(payload) = self.buf.get(start..start + len) else {
+            self.remaining = 0;
+            return Some(Err(GermanicError::General(format!(
+                "Record length {} exceeds container",
+                len
+            ))));
+        };
+
+        self.pos = start + len;
+        Some(Ok(payload))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.remaining as usize))
+    }
+}
+
+// ============================================================================
+// BUILDING
+// ============================================================================
+
+/// Serializes raw FlatBuffer payloads into a container.
+///
+/// Counterpart to [`GrmContainer::from_bytes`]. Payloads come from
+/// [`crate::dynamic::builder::build_flatbuffer`] or by stripping the
+/// header off existing single-record .grm files.
+pub fn build_container(schema_id: &str, payloads: &[Vec<u8>]) -> GermanicResult<Vec<u8>> {
+    let id_bytes = schema_id.as_bytes();
+    let id_len = u16::try_from(id_bytes.len())
+        .map_err(|_| GermanicError::General("Schema-ID too long for container".to_string()))?;
+    let count = u32::try_from(payloads.len())
+        .map_err(|_| GermanicError::General("Too many records for container".to_string()))?;
+
+    let mut out = Vec::with_capacity(10 + id_bytes.len());
+    out.extend_from_slice(b"GRM");
+    out.push(GRM_VERSION_CONTAINER);
+    out.extend_from_slice(&id_len.to_le_bytes());
+    out.extend_from_slice(id_bytes);
+    out.extend_from_slice(&count.to_le_bytes());
+
+    for payload in payloads {
+        let len = u32::try_from(payload.len())
+            .map_err(|_| GermanicError::General("Record too large for container".to_string()))?;
+        out.extend_from_slice(&len.to_le_bytes());
+        out.extend_from_slice(payload);
+    }
+
+    Ok(out)
+}
+
+// ============================================================================
+// TESTS
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dynamic::schema_def::{FieldDefinition, FieldType};
+    use indexmap::IndexMap;
+
+    fn schema() -> SchemaDefinition {
+        let mut fields = IndexMap::new();
+        fields.insert(
+            "name".into(),
+            FieldDefinition {
+                required: true,
+                ..FieldDefinition::new(FieldType::String)
+            },
+        );
+        SchemaDefinition {
+            schema_id: "test.v1".into(),
+            version: 1,
+            description: None,
+            limits: None,
+            strict: false,
+            fields,
+        }
+    }
+
+    /// Strips the single-record header to get a raw payload.
+    fn payload_for(name: &str) -> Vec<u8> {
+        let data = serde_json::json!({ "name": name });
+        let grm = crate::dynamic::compile_dynamic_from_values(&schema(), &data).unwrap();
+        let header_len = crate::types::GrmHeader::from_bytes(&grm).unwrap().1;
+        grm[header_len..].to_vec()
+    }
+
+    #[test]
+    fn test_container_roundtrip() {
+        let payloads = vec![payload_for("Alpha"), payload_for("Beta"), payload_for("Gamma")];
+        let bytes = build_container("test.v1", &payloads).unwrap();
+
+        let container = GrmContainer::from_bytes(&bytes).unwrap();
+        assert_eq!(container.schema_id, "test.v1");
+        assert_eq!(container.len(), 3);
+
+        let names: Vec<String> = container
+            .decoded(&schema())
+            .map(|r| r.unwrap()["name"].as_str().unwrap().to_string())
+            .collect();
+        assert_eq!(names, vec!["Alpha", "Beta", "Gamma"]);
+    }
+
+    #[test]
+    fn test_records_are_lazy() {
+        let payloads = vec![payload_for("Alpha"), payload_for("Beta"), payload_for("Gamma")];
+        let bytes = build_container("test.v1", &payloads).unwrap();
+        let container = GrmContainer::from_bytes(&bytes).unwrap();
+
+        // Only the first record is touched; the rest stay undecoded.
+        let first = container.decoded(&schema()).next().unwrap().unwrap();
+        assert_eq!(first["name"], "Alpha");
+    }
+
+    #[test]
+    fn test_empty_container() {
+        let bytes = build_container("test.v1", &[]).unwrap();
+        let container = GrmContainer::from_bytes(&bytes).unwrap();
+        assert!(container.is_empty());
+        assert_eq!(container.records().count(), 0);
+    }
+
+    #[test]
+    fn test_rejects_single_record_magic() {
+        // A regular v1 .grm is NOT a container
+        let data = serde_json::json!({ "name": "Alpha" });
+        let grm = crate::dynamic::compile_dynamic_from_values(&schema(), &data).unwrap();
+        assert!(GrmContainer::from_bytes(&grm).is_err());
+    }
+
+    #[test]
+    fn test_truncated_record_errors_not_panics() {
+        let payloads = vec![payload_for("Alpha"), payload_for("Beta")];
+        let mut bytes = build_container("test.v1", &payloads).unwrap();
+        bytes.truncate(bytes.len() - 10);
+
+        let container = GrmContainer::from_bytes(&bytes).unwrap();
+        let results: Vec<_> = container.records().collect();
+        assert!(results[0].is_ok());
+        assert!(results[1].is_err());
+        // Iteration ends after the error instead of looping
+        assert_eq!(results.len(), 2);
+    }
+}
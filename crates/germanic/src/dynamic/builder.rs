@@ -33,6 +33,12 @@ use crate::error::GermanicError;
 use flatbuffers::FlatBufferBuilder;
 use indexmap::IndexMap;
 
+/// Maximum table recursion depth the builder will follow.
+///
+/// Matches the absolute data nesting cap in [`crate::pre_validate`], so
+/// anything that passed pre-validation also fits through the builder.
+pub const MAX_BUILD_DEPTH: usize = crate::pre_validate::MAX_NESTING_DEPTH_CAP;
+
 /// Builds FlatBuffer bytes from a schema definition and JSON data.
 ///
 /// Returns the raw FlatBuffer payload (WITHOUT .grm header).
@@ -41,16 +47,87 @@ pub fn build_flatbuffer(
     schema: &SchemaDefinition,
     data: &serde_json::Value,
 ) -> Result<Vec<u8>, GermanicError> {
+    build_flatbuffer_inner(schema, data, false)
+}
+
+/// Like [`build_flatbuffer`], but deduplicates identical strings.
+///
+/// Directory datasets repeat the same strings (city names, specialties)
+/// across thousands of records; interning them via `create_shared_string`
+/// shrinks multi-record output substantially. Opt-in because the interning
+/// pass costs a hash lookup per string and changes the byte layout
+/// (equal content, different offsets) compared to the plain builder.
+pub fn build_flatbuffer_deduped(
+    schema: &SchemaDefinition,
+    data: &serde_json::Value,
+) -> Result<Vec<u8>, GermanicError> {
+    build_flatbuffer_inner(schema, data, true)
+}
+
+/// Like [`build_flatbuffer`], but reuses a caller-owned builder.
+///
+/// Resets the builder, builds the payload and returns a borrow of the
+/// finished bytes — no copy. Servers compiling many records per minute
+/// keep one `FlatBufferBuilder` per worker instead of paying for a fresh
+/// builder allocation (plus the `to_vec` copy) on every record.
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// let mut builder = FlatBufferBuilder::with_capacity(4096);
+/// for record in records {
+///     let payload = build_flatbuffer_into(&mut builder, &schema, record)?;
+///     write_grm(payload)?;
+/// }
+/// ```
+pub fn build_flatbuffer_into<'a>(
+    builder: &'a mut FlatBufferBuilder<'_>,
+    schema: &SchemaDefinition,
+    data: &serde_json::Value,
+) -> Result<&'a [u8], GermanicError> {
+    build_into(builder, schema, data, false)
+}
+
+fn build_flatbuffer_inner(
+    schema: &SchemaDefinition,
+    data: &serde_json::Value,
+    dedup: bool,
+) -> Result<Vec<u8>, GermanicError> {
+    let mut builder = FlatBufferBuilder::with_capacity(1024);
+    Ok(build_into(&mut builder, schema, data, dedup)?.to_vec())
+}
+
+fn build_into<'a>(
+    builder: &'a mut FlatBufferBuilder<'_>,
+    schema: &SchemaDefinition,
+    data: &serde_json::Value,
+    dedup: bool,
+) -> Result<&'a [u8], GermanicError> {
     let obj = data
         .as_object()
         .ok_or_else(|| GermanicError::General("Root data must be a JSON object".into()))?;
 
-    let mut builder = FlatBufferBuilder::with_capacity(1024);
+    // Reset clears previous output but keeps the allocation, which is
+    // the whole point of reusing the builder.
+    builder.reset();
 
-    let root = build_table(&mut builder, &schema.fields, obj)?;
+    let root = build_table(builder, &schema.fields, obj, dedup, 0)?;
 
     builder.finish_minimal(root);
-    Ok(builder.finished_data().to_vec())
+    Ok(builder.finished_data())
+}
+
+/// Creates a string offset, shared (interned) when deduplication is on.
+fn make_string<'fbb>(
+    builder: &mut FlatBufferBuilder<'fbb>,
+    s: &str,
+    dedup: bool,
+) -> flatbuffers::WIPOffset<&'fbb str> {
+    if dedup {
+        builder.create_shared_string(s)
+    } else {
+        builder.create_string(s)
+    }
 }
 
 /// A field value prepared for insertion into the FlatBuffer.
@@ -81,22 +158,35 @@ fn build_table(
     builder: &mut FlatBufferBuilder<'_>,
     fields: &IndexMap<String, FieldDefinition>,
     data: &serde_json::Map<String, serde_json::Value>,
+    dedup: bool,
+    depth: usize,
 ) -> Result<flatbuffers::WIPOffset<flatbuffers::TableFinishedWIPOffset>, GermanicError> {
+    // Defense in depth: pre_validate bounds the data and check_layout
+    // bounds file-loaded schemas, but the builder is also reachable with
+    // hand-built SchemaDefinitions — guard its own recursion explicitly.
+    if depth > MAX_BUILD_DEPTH {
+        return Err(GermanicError::General(format!(
+            "Nesting depth exceeds builder maximum of {}",
+            MAX_BUILD_DEPTH
+        )));
+    }
+
     // Phase 1: Pre-create all offset values (strings, vectors, nested tables)
     // We must create these BEFORE starting the table.
     let mut prepared: IndexMap<String, PreparedField> = IndexMap::new();
 
     for (name, def) in fields {
         let value = data.get(name);
-        let prep = prepare_field(builder, def, value)?;
+        let prep = prepare_field(builder, name, def, value, data, dedup, depth)?;
         prepared.insert(name.clone(), prep);
     }
 
-    // Phase 2: Start table and push slots
+    // Phase 2: Start table and push slots (explicit ids override position)
+    let slots = crate::dynamic::schema_def::field_slots(fields)?;
     let table_start = builder.start_table();
 
     for (index, (name, _def)) in fields.iter().enumerate() {
-        let voffset = 4 + (2 * index) as u16;
+        let voffset = 4 + (2 * slots[index]) as u16;
         let prep = &prepared[name];
 
         match prep {
@@ -125,43 +215,70 @@ fn build_table(
 }
 
 /// Prepares a single field value for FlatBuffer insertion.
+///
+/// Type mismatches are hard errors, not silent coercions: validation runs
+/// first, but the builder is the last gate before bytes are written, so
+/// data that slips past it (e.g. via a malformed default) must not encode
+/// as a wrong zero value.
 fn prepare_field(
     builder: &mut FlatBufferBuilder<'_>,
+    name: &str,
     def: &FieldDefinition,
     value: Option<&serde_json::Value>,
+    data: &serde_json::Map<String, serde_json::Value>,
+    dedup: bool,
+    depth: usize,
 ) -> Result<PreparedField, GermanicError> {
     let Some(value) = value else {
-        // Field not present — check for default
-        return Ok(match &def.default {
-            Some(d) => match def.field_type {
-                FieldType::String => PreparedField::Offset(builder.create_string(d).value()),
-                FieldType::Bool => PreparedField::Bool(d.parse().unwrap_or(false), false),
-                FieldType::Int => PreparedField::Int(d.parse().unwrap_or(0), 0),
-                FieldType::Float => PreparedField::Float(d.parse().unwrap_or(0.0), 0.0),
-                _ => PreparedField::Absent,
+        // Field not present — apply the schema default (if any) through
+        // the same encode path a present value would take. Scalars write
+        // against a zero FlatBuffer default so the slot is not elided.
+        return match def.default_value() {
+            Some(d) => match &def.field_type {
+                FieldType::Bool => Ok(PreparedField::Bool(
+                    d.as_bool()
+                        .ok_or_else(|| type_mismatch(name, "bool", &d))?,
+                    false,
+                )),
+                FieldType::Int => Ok(PreparedField::Int(
+                    d.as_i64()
+                        .ok_or_else(|| type_mismatch(name, "integer", &d))?
+                        as i32,
+                    0,
+                )),
+                FieldType::Float => Ok(PreparedField::Float(
+                    d.as_f64()
+                        .ok_or_else(|| type_mismatch(name, "number", &d))?
+                        as f32,
+                    0.0,
+                )),
+                _ => prepare_field(builder, name, def, Some(&d), data, dedup, depth),
             },
-            None => PreparedField::Absent,
-        });
+            None => Ok(PreparedField::Absent),
+        };
     };
 
-    match def.field_type {
-        FieldType::String => {
-            let s = value.as_str().unwrap_or("");
-            Ok(PreparedField::Offset(builder.create_string(s).value()))
+    match &def.field_type {
+        // Enums store their canonical string — same wire format as String
+        FieldType::String | FieldType::Enum => {
+            let s = value
+                .as_str()
+                .ok_or_else(|| type_mismatch(name, "string", value))?;
+            Ok(PreparedField::Offset(make_string(builder, s, dedup).value()))
         }
 
         FieldType::Bool => {
-            let v = value.as_bool().unwrap_or(false);
-            let default: bool = def
-                .default
-                .as_ref()
-                .and_then(|d| d.parse().ok())
-                .unwrap_or(false);
+            let v = value
+                .as_bool()
+                .ok_or_else(|| type_mismatch(name, "bool", value))?;
+            let default = def.default_bool().unwrap_or(false);
             Ok(PreparedField::Bool(v, default))
         }
 
         FieldType::Int => {
-            let v64 = value.as_i64().unwrap_or(0);
+            let v64 = value
+                .as_i64()
+                .ok_or_else(|| type_mismatch(name, "integer", value))?;
             if v64 > i32::MAX as i64 || v64 < i32::MIN as i64 {
                 return Err(GermanicError::General(format!(
                     "Integer overflow: {} exceeds i32 range [{}, {}]",
@@ -171,16 +288,14 @@ fn prepare_field(
                 )));
             }
             let v = v64 as i32;
-            let default: i32 = def
-                .default
-                .as_ref()
-                .and_then(|d| d.parse().ok())
-                .unwrap_or(0);
+            let default = def.default_i32().unwrap_or(0);
             Ok(PreparedField::Int(v, default))
         }
 
         FieldType::Float => {
-            let v64 = value.as_f64().unwrap_or(0.0);
+            let v64 = value
+                .as_f64()
+                .ok_or_else(|| type_mismatch(name, "number", value))?;
             let v = v64 as f32;
             if v.is_infinite() && v64.is_finite() {
                 return Err(GermanicError::General(format!(
@@ -188,31 +303,33 @@ fn prepare_field(
                     v64
                 )));
             }
-            let default: f32 = def
-                .default
-                .as_ref()
-                .and_then(|d| d.parse().ok())
-                .unwrap_or(0.0);
+            let default = def.default_f32().unwrap_or(0.0);
             Ok(PreparedField::Float(v, default))
         }
 
         FieldType::StringArray => match value.as_array() {
             Some(arr) if !arr.is_empty() => {
-                let offsets: Vec<_> = arr
-                    .iter()
-                    .map(|v| builder.create_string(v.as_str().unwrap_or("")))
-                    .collect();
+                let mut offsets = Vec::with_capacity(arr.len());
+                for v in arr {
+                    let s = v
+                        .as_str()
+                        .ok_or_else(|| type_mismatch(name, "string array element", v))?;
+                    offsets.push(make_string(builder, s, dedup));
+                }
                 let vec_offset = builder.create_vector(&offsets);
                 Ok(PreparedField::Offset(vec_offset.value()))
             }
-            _ => Ok(PreparedField::Absent),
+            Some(_) => Ok(PreparedField::Absent),
+            None => Err(type_mismatch(name, "array", value)),
         },
 
         FieldType::IntArray => match value.as_array() {
             Some(arr) if !arr.is_empty() => {
                 let mut values = Vec::with_capacity(arr.len());
                 for v in arr {
-                    let v64 = v.as_i64().unwrap_or(0);
+                    let v64 = v
+                        .as_i64()
+                        .ok_or_else(|| type_mismatch(name, "integer array element", v))?;
                     if v64 > i32::MAX as i64 || v64 < i32::MIN as i64 {
                         return Err(GermanicError::General(format!(
                             "Integer overflow in array element: {} exceeds i32 range [{}, {}]",
@@ -226,25 +343,122 @@ fn prepare_field(
                 let vec_offset = builder.create_vector(&values);
                 Ok(PreparedField::Offset(vec_offset.value()))
             }
-            _ => Ok(PreparedField::Absent),
+            Some(_) => Ok(PreparedField::Absent),
+            None => Err(type_mismatch(name, "array", value)),
+        },
+
+        FieldType::Bytes => {
+            let s = value
+                .as_str()
+                .ok_or_else(|| type_mismatch(name, "base64 string", value))?;
+            let bytes = crate::base64::decode(s)
+                .map_err(|e| GermanicError::General(format!("bytes field: {}", e)))?;
+            if bytes.is_empty() {
+                Ok(PreparedField::Absent)
+            } else {
+                let vec_offset = builder.create_vector(&bytes);
+                Ok(PreparedField::Offset(vec_offset.value()))
+            }
+        }
+
+        FieldType::Uuid => {
+            let s = value
+                .as_str()
+                .ok_or_else(|| type_mismatch(name, "UUID string", value))?;
+            let bytes = crate::formats::parse_uuid(s).map_err(GermanicError::General)?;
+            let vec_offset = builder.create_vector(&bytes);
+            Ok(PreparedField::Offset(vec_offset.value()))
+        }
+
+        FieldType::BoolArray => match value.as_array() {
+            Some(arr) if !arr.is_empty() => {
+                let mut values = Vec::with_capacity(arr.len());
+                for v in arr {
+                    values.push(
+                        v.as_bool()
+                            .ok_or_else(|| type_mismatch(name, "bool array element", v))?,
+                    );
+                }
+                let vec_offset = builder.create_vector(&values);
+                Ok(PreparedField::Offset(vec_offset.value()))
+            }
+            Some(_) => Ok(PreparedField::Absent),
+            None => Err(type_mismatch(name, "array", value)),
         },
 
-        FieldType::Table => {
+        // Unions share the table layout: one slot per variant, only the
+        // active variant present in the data — the rest stay absent.
+        FieldType::Table | FieldType::Union => {
             let nested_fields = def.fields.as_ref().ok_or_else(|| {
                 GermanicError::General("Table field has no nested field definitions".into())
             })?;
 
             match value.as_object() {
                 Some(obj) => {
-                    let table_offset = build_table(builder, nested_fields, obj)?;
+                    let table_offset = build_table(builder, nested_fields, obj, dedup, depth + 1)?;
                     Ok(PreparedField::Offset(table_offset.value()))
                 }
-                None => Ok(PreparedField::Absent),
+                None => Err(type_mismatch(name, "object", value)),
+            }
+        }
+
+        FieldType::TableArray => {
+            let nested_fields = def.fields.as_ref().ok_or_else(|| {
+                GermanicError::General("[table] field has no nested field definitions".into())
+            })?;
+
+            match value.as_array() {
+                Some(arr) if !arr.is_empty() => {
+                    // Build each element table first, then the vector of offsets
+                    let mut offsets = Vec::with_capacity(arr.len());
+                    for element in arr {
+                        let obj = element.as_object().ok_or_else(|| {
+                            GermanicError::General(
+                                "[table] array element is not an object".into(),
+                            )
+                        })?;
+                        offsets.push(build_table(builder, nested_fields, obj, dedup, depth + 1)?);
+                    }
+                    let vec_offset = builder.create_vector(&offsets);
+                    Ok(PreparedField::Offset(vec_offset.value()))
+                }
+                Some(_) => Ok(PreparedField::Absent),
+                None => Err(type_mismatch(name, "array", value)),
             }
         }
+
+        FieldType::Custom(plugin_name) => {
+            let plugin = crate::plugin::lookup_plugin(plugin_name).ok_or_else(|| {
+                GermanicError::General(format!(
+                    "No plugin registered for field type '{}'",
+                    plugin_name
+                ))
+            })?;
+            let ctx = crate::plugin::PluginContext { siblings: data };
+            let s = plugin
+                .encode_with_context(value, &ctx)
+                .map_err(|e| GermanicError::General(format!("Plugin '{}': {}", plugin_name, e)))?;
+            Ok(PreparedField::Offset(make_string(builder, &s, dedup).value()))
+        }
     }
 }
 
+/// Builds the error for a value whose JSON type does not match the schema.
+fn type_mismatch(name: &str, expected: &str, found: &serde_json::Value) -> GermanicError {
+    let found = match found {
+        serde_json::Value::Null => "null",
+        serde_json::Value::Bool(_) => "bool",
+        serde_json::Value::Number(_) => "number",
+        serde_json::Value::String(_) => "string",
+        serde_json::Value::Array(_) => "array",
+        serde_json::Value::Object(_) => "object",
+    };
+    GermanicError::General(format!(
+        "Type mismatch for field '{}': expected {}, found {}",
+        name, expected, found
+    ))
+}
+
 // ============================================================================
 // TESTS
 // ============================================================================
@@ -260,15 +474,16 @@ mod tests {
         fields.insert(
             "name".into(),
             FieldDefinition {
-                field_type: FieldType::String,
                 required: true,
-                default: None,
-                fields: None,
+                ..FieldDefinition::new(FieldType::String)
             },
         );
         SchemaDefinition {
             schema_id: "test.v1".into(),
             version: 1,
+            description: None,
+            limits: None,
+            strict: false,
             fields,
         }
     }
@@ -287,25 +502,24 @@ mod tests {
         fields.insert(
             "name".into(),
             FieldDefinition {
-                field_type: FieldType::String,
                 required: true,
-                default: None,
-                fields: None,
+                ..FieldDefinition::new(FieldType::String)
             },
         );
         fields.insert(
             "active".into(),
             FieldDefinition {
-                field_type: FieldType::Bool,
-                required: false,
                 default: Some("false".into()),
-                fields: None,
+                ..FieldDefinition::new(FieldType::Bool)
             },
         );
 
         let schema = SchemaDefinition {
             schema_id: "test.v1".into(),
             version: 1,
+            description: None,
+            limits: None,
+            strict: false,
             fields,
         };
 
@@ -320,19 +534,15 @@ mod tests {
         addr_fields.insert(
             "street".into(),
             FieldDefinition {
-                field_type: FieldType::String,
                 required: true,
-                default: None,
-                fields: None,
+                ..FieldDefinition::new(FieldType::String)
             },
         );
         addr_fields.insert(
             "city".into(),
             FieldDefinition {
-                field_type: FieldType::String,
                 required: true,
-                default: None,
-                fields: None,
+                ..FieldDefinition::new(FieldType::String)
             },
         );
 
@@ -340,25 +550,25 @@ mod tests {
         fields.insert(
             "name".into(),
             FieldDefinition {
-                field_type: FieldType::String,
                 required: true,
-                default: None,
-                fields: None,
+                ..FieldDefinition::new(FieldType::String)
             },
         );
         fields.insert(
             "address".into(),
             FieldDefinition {
-                field_type: FieldType::Table,
                 required: true,
-                default: None,
                 fields: Some(addr_fields),
+                ..FieldDefinition::new(FieldType::Table)
             },
         );
 
         let schema = SchemaDefinition {
             schema_id: "test.v1".into(),
             version: 1,
+            description: None,
+            limits: None,
+            strict: false,
             fields,
         };
 
@@ -381,16 +591,17 @@ mod tests {
         fields.insert(
             "count".into(),
             FieldDefinition {
-                field_type: FieldType::Int,
                 required: true,
-                default: None,
-                fields: None,
+                ..FieldDefinition::new(FieldType::Int)
             },
         );
 
         let schema = SchemaDefinition {
             schema_id: "test.v1".into(),
             version: 1,
+            description: None,
+            limits: None,
+            strict: false,
             fields,
         };
 
@@ -409,16 +620,17 @@ mod tests {
         fields.insert(
             "count".into(),
             FieldDefinition {
-                field_type: FieldType::Int,
                 required: true,
-                default: None,
-                fields: None,
+                ..FieldDefinition::new(FieldType::Int)
             },
         );
 
         let schema = SchemaDefinition {
             schema_id: "test.v1".into(),
             version: 1,
+            description: None,
+            limits: None,
+            strict: false,
             fields,
         };
 
@@ -433,30 +645,149 @@ mod tests {
         fields.insert(
             "name".into(),
             FieldDefinition {
-                field_type: FieldType::String,
                 required: true,
-                default: None,
-                fields: None,
+                ..FieldDefinition::new(FieldType::String)
             },
         );
+        fields.insert("tags".into(), FieldDefinition::new(FieldType::StringArray));
+
+        let schema = SchemaDefinition {
+            schema_id: "test.v1".into(),
+            version: 1,
+            description: None,
+            limits: None,
+            strict: false,
+            fields,
+        };
+
+        let data = serde_json::json!({ "name": "Test", "tags": ["a", "b", "c"] });
+        let bytes = build_flatbuffer(&schema, &data).unwrap();
+        assert!(!bytes.is_empty());
+    }
+
+    #[test]
+    fn test_build_type_mismatch_rejected() {
+        // The builder runs after validation, but must not silently encode
+        // a wrong-typed value that reaches it directly.
+        let schema = minimal_schema();
+        let data = serde_json::json!({ "name": 42 });
+        let err = build_flatbuffer(&schema, &data).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Type mismatch for field 'name': expected string, found number"),
+            "Got: {}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_build_array_element_type_mismatch_rejected() {
+        let mut fields = IndexMap::new();
         fields.insert(
-            "tags".into(),
+            "plz".into(),
             FieldDefinition {
-                field_type: FieldType::StringArray,
-                required: false,
-                default: None,
-                fields: None,
+                required: true,
+                ..FieldDefinition::new(FieldType::IntArray)
             },
         );
 
         let schema = SchemaDefinition {
             schema_id: "test.v1".into(),
             version: 1,
+            description: None,
+            limits: None,
+            strict: false,
             fields,
         };
 
-        let data = serde_json::json!({ "name": "Test", "tags": ["a", "b", "c"] });
-        let bytes = build_flatbuffer(&schema, &data).unwrap();
-        assert!(!bytes.is_empty());
+        let data = serde_json::json!({ "plz": [10115, "10117"] });
+        let err = build_flatbuffer(&schema, &data).unwrap_err();
+        assert!(err.to_string().contains("integer array element"), "Got: {}", err);
+    }
+
+    #[test]
+    fn test_dedup_shrinks_repeated_strings() {
+        let mut fields = IndexMap::new();
+        fields.insert(
+            "orte".into(),
+            FieldDefinition {
+                required: true,
+                ..FieldDefinition::new(FieldType::StringArray)
+            },
+        );
+
+        let schema = SchemaDefinition {
+            schema_id: "test.v1".into(),
+            version: 1,
+            description: None,
+            limits: None,
+            strict: false,
+            fields,
+        };
+
+        let data = serde_json::json!({
+            "orte": ["Berlin-Charlottenburg", "Berlin-Charlottenburg", "Berlin-Charlottenburg"]
+        });
+        let plain = build_flatbuffer(&schema, &data).unwrap();
+        let deduped = build_flatbuffer_deduped(&schema, &data).unwrap();
+        assert!(
+            deduped.len() < plain.len(),
+            "Deduped ({}) must be smaller than plain ({})",
+            deduped.len(),
+            plain.len()
+        );
+    }
+
+    #[test]
+    fn test_reused_builder_matches_fresh_builds() {
+        let schema = minimal_schema();
+        let first = serde_json::json!({ "name": "Praxis Sonnenschein" });
+        let second = serde_json::json!({ "name": "Praxis Mondlicht" });
+
+        let mut builder = FlatBufferBuilder::with_capacity(1024);
+        let reused_first = build_flatbuffer_into(&mut builder, &schema, &first)
+            .unwrap()
+            .to_vec();
+        let reused_second = build_flatbuffer_into(&mut builder, &schema, &second)
+            .unwrap()
+            .to_vec();
+
+        // Leftover state from the first build must not leak into the second
+        assert_eq!(reused_first, build_flatbuffer(&schema, &first).unwrap());
+        assert_eq!(reused_second, build_flatbuffer(&schema, &second).unwrap());
+    }
+
+    #[test]
+    fn test_build_depth_guard_rejects_deep_recursion() {
+        // Build a schema/data pair nested past the builder's own ceiling,
+        // bypassing pre_validate and check_layout entirely.
+        let mut def = FieldDefinition::new(FieldType::String);
+        let mut data = serde_json::json!("tief");
+        for _ in 0..=MAX_BUILD_DEPTH + 1 {
+            let mut nested = IndexMap::new();
+            nested.insert("ebene".to_string(), def);
+            def = FieldDefinition {
+                fields: Some(nested),
+                ..FieldDefinition::new(FieldType::Table)
+            };
+            data = serde_json::json!({ "ebene": data });
+        }
+        let mut fields = IndexMap::new();
+        fields.insert("ebene".to_string(), def);
+        let schema = SchemaDefinition {
+            schema_id: "test.v1".into(),
+            version: 1,
+            description: None,
+            limits: None,
+            strict: false,
+            fields,
+        };
+
+        let err = build_flatbuffer(&schema, &data).unwrap_err();
+        assert!(
+            err.to_string().contains("builder maximum"),
+            "Got: {}",
+            err
+        );
     }
 }
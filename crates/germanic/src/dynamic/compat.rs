@@ -0,0 +1,220 @@
+//! # Compatibility Shims
+//!
+//! Lets old exports keep compiling under a newer schema version during a
+//! transition window. A mapping file stored alongside the schema declares
+//! how v1 data is lifted to the v2 shape:
+//!
+//! ```text
+//! restaurant.schema.json        ← the new (v2) schema
+//! restaurant.compat.json        ← the shim, picked up automatically
+//! ```
+//!
+//! ```json
+//! {
+//!     "schema_id": "de.dining.restaurant.v2",
+//!     "renames":  { "tel": "telefon" },
+//!     "defaults": { "land": "DE" }
+//! }
+//! ```
+//!
+//! - `renames` moves a top-level field to its new name.
+//! - `defaults` fills a top-level field that old exports don't have.
+//!
+//! Every applied rule produces a warning naming the record's dependence
+//! on the shim, so operators can see who still needs migrating before
+//! the window closes.
+
+use crate::error::{GermanicError, GermanicResult};
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Declared mapping rules for compiling old data under a new schema.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompatMapping {
+    /// Schema ID the shim belongs to (checked against the schema at load).
+    pub schema_id: String,
+
+    /// Old field name → new field name (top level).
+    #[serde(default)]
+    pub renames: IndexMap<String, String>,
+
+    /// New field name → value to fill when absent (top level).
+    #[serde(default)]
+    pub defaults: IndexMap<String, serde_json::Value>,
+}
+
+impl CompatMapping {
+    /// Loads a mapping from a .compat.json file.
+    pub fn from_file(path: &Path) -> GermanicResult<Self> {
+        let content = std::fs::read_to_string(path)?;
+        let mapping: CompatMapping = serde_json::from_str(&content)?;
+        Ok(mapping)
+    }
+
+    /// Applies the mapping to a data value.
+    ///
+    /// Returns the lifted value and one warning per applied rule. A rule
+    /// only fires when the data actually needs it — records already in
+    /// the new shape pass through untouched with no warnings.
+    pub fn apply(&self, data: &serde_json::Value) -> (serde_json::Value, Vec<String>) {
+        let mut warnings = Vec::new();
+
+        let serde_json::Value::Object(map) = data else {
+            return (data.clone(), warnings);
+        };
+        let mut map = map.clone();
+
+        for (old, new) in &self.renames {
+            if map.contains_key(old) && !map.contains_key(new) {
+                let value = map.shift_remove(old).expect("key checked above");
+                map.insert(new.clone(), value);
+                warnings.push(format!(
+                    "compat: field '{}' renamed to '{}' — record still uses the old name",
+                    old, new
+                ));
+            }
+        }
+
+        for (field, value) in &self.defaults {
+            if !map.contains_key(field) {
+                map.insert(field.clone(), value.clone());
+                warnings.push(format!(
+                    "compat: field '{}' filled with shim default — record predates it",
+                    field
+                ));
+            }
+        }
+
+        (serde_json::Value::Object(map), warnings)
+    }
+}
+
+/// Path of the compat mapping that belongs to a schema file.
+///
+/// `restaurant.schema.json` → `restaurant.compat.json`
+/// (falls back to appending `.compat.json` for unconventional names).
+pub fn compat_path_for(schema_path: &Path) -> PathBuf {
+    let name = schema_path
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default();
+
+    let compat_name = match name.strip_suffix(".schema.json") {
+        Some(stem) => format!("{}.compat.json", stem),
+        None => format!("{}.compat.json", name),
+    };
+
+    schema_path.with_file_name(compat_name)
+}
+
+/// Loads the compat mapping next to a schema file, if one exists.
+///
+/// Errors if the mapping exists but is malformed or declares a different
+/// schema ID — a silently ignored shim would be worse than none.
+pub fn load_compat_for(
+    schema_path: &Path,
+    schema_id: &str,
+) -> GermanicResult<Option<CompatMapping>> {
+    let path = compat_path_for(schema_path);
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let mapping = CompatMapping::from_file(&path)?;
+    if mapping.schema_id != schema_id {
+        return Err(GermanicError::General(format!(
+            "Compat mapping {} declares schema '{}' but schema is '{}'",
+            path.display(),
+            mapping.schema_id,
+            schema_id
+        )));
+    }
+
+    Ok(Some(mapping))
+}
+
+// ============================================================================
+// TESTS
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mapping() -> CompatMapping {
+        serde_json::from_str(
+            r#"{
+                "schema_id": "test.v2",
+                "renames": { "tel": "telefon" },
+                "defaults": { "land": "DE" }
+            }"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_apply_rename_and_default() {
+        let data = serde_json::json!({ "name": "A", "tel": "+49 1" });
+        let (lifted, warnings) = mapping().apply(&data);
+
+        assert_eq!(lifted["telefon"], "+49 1");
+        assert!(lifted.get("tel").is_none());
+        assert_eq!(lifted["land"], "DE");
+        assert_eq!(warnings.len(), 2);
+        assert!(warnings[0].contains("'tel'"));
+        assert!(warnings[1].contains("'land'"));
+    }
+
+    #[test]
+    fn test_apply_noop_on_new_shape() {
+        let data = serde_json::json!({ "name": "A", "telefon": "+49 1", "land": "AT" });
+        let (lifted, warnings) = mapping().apply(&data);
+
+        assert_eq!(lifted, data);
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn test_rename_never_overwrites_new_field() {
+        let data = serde_json::json!({ "tel": "old", "telefon": "new" });
+        let (lifted, warnings) = mapping().apply(&data);
+
+        assert_eq!(lifted["telefon"], "new");
+        assert_eq!(lifted["tel"], "old");
+        assert_eq!(warnings.len(), 1); // only the 'land' default fires
+    }
+
+    #[test]
+    fn test_compat_path_for() {
+        assert_eq!(
+            compat_path_for(Path::new("dir/restaurant.schema.json")),
+            PathBuf::from("dir/restaurant.compat.json")
+        );
+        assert_eq!(
+            compat_path_for(Path::new("odd.json")),
+            PathBuf::from("odd.json.compat.json")
+        );
+    }
+
+    #[test]
+    fn test_load_compat_rejects_wrong_schema_id() {
+        let dir = tempfile::tempdir().unwrap();
+        let schema_path = dir.path().join("x.schema.json");
+        std::fs::write(
+            dir.path().join("x.compat.json"),
+            r#"{ "schema_id": "other.v1" }"#,
+        )
+        .unwrap();
+
+        let result = load_compat_for(&schema_path, "test.v2");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_load_compat_absent_is_none() {
+        let dir = tempfile::tempdir().unwrap();
+        let schema_path = dir.path().join("x.schema.json");
+        assert!(load_compat_for(&schema_path, "test.v2").unwrap().is_none());
+    }
+}
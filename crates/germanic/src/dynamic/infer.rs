@@ -8,21 +8,38 @@
 //! JSON Value              →  FieldType
 //! ─────────────────────────────────────
 //! "hello"                 →  String
+//! "2024-01-15"            →  date (format detection, see below)
 //! true / false            →  Bool
 //! 42 (integer)            →  Int
 //! 3.14 (has decimal)      →  Float
 //! ["a", "b"]              →  StringArray
 //! [1, 2, 3]               →  IntArray
+//! [true, false]           →  BoolArray
+//! [{...}, {...}]          →  TableArray (recurse into first element)
 //! { "key": ... }          →  Table (recurse)
 //! null                    →  String (fallback)
 //! ```
 //!
-//! All fields default to `required: false`. The user edits
-//! the generated .schema.json to mark required fields.
+//! String values that look like dates, datetimes, UUIDs, email
+//! addresses, URLs or international phone numbers become the matching
+//! typed field instead of a plain `String`.
+//!
+//! With a single sample all fields default to `required: false` and the
+//! user edits the generated .schema.json to mark required fields. With
+//! multiple samples ([`infer_schema_from_samples`]) a field that is
+//! present and non-empty in *every* sample is marked `required: true`.
 
 use crate::dynamic::schema_def::{FieldDefinition, FieldType, SchemaDefinition};
+use crate::error::GermanicError;
 use indexmap::IndexMap;
 
+/// Maximum number of distinct string values for enum detection.
+const ENUM_MAX_VALUES: usize = 8;
+
+/// Minimum number of string occurrences before enum detection kicks in.
+/// With fewer samples every free-text field looks like an enum.
+const ENUM_MIN_OCCURRENCES: usize = 3;
+
 /// Infers a schema definition from example JSON data.
 ///
 /// The schema_id must be provided (cannot be inferred from data).
@@ -35,10 +52,388 @@ pub fn infer_schema(data: &serde_json::Value, schema_id: &str) -> Option<SchemaD
     Some(SchemaDefinition {
         schema_id: schema_id.to_string(),
         version: 1,
+        description: None,
+        limits: None,
+        strict: false,
         fields,
     })
 }
 
+/// Infers a schema definition from several example records of the same shape.
+///
+/// Field types come from the first sample that carries a value for the
+/// field; the field set is the union across all samples, in order of
+/// first appearance. With `mark_required` a field that is present and
+/// non-empty (not null, `""`, `[]` or `{}`) in every sample is marked
+/// `required: true` — disable it to get the all-optional single-sample
+/// behaviour.
+pub fn infer_schema_from_samples(
+    samples: &[serde_json::Value],
+    schema_id: &str,
+    mark_required: bool,
+) -> Option<SchemaDefinition> {
+    let objects: Vec<&serde_json::Map<String, serde_json::Value>> =
+        samples.iter().map(|s| s.as_object()).collect::<Option<_>>()?;
+    if objects.is_empty() {
+        return None;
+    }
+
+    Some(SchemaDefinition {
+        schema_id: schema_id.to_string(),
+        version: 1,
+        description: None,
+        limits: None,
+        strict: false,
+        fields: merge_fields(&objects, mark_required),
+    })
+}
+
+/// Merges field definitions across several sample objects.
+fn merge_fields(
+    samples: &[&serde_json::Map<String, serde_json::Value>],
+    mark_required: bool,
+) -> IndexMap<String, FieldDefinition> {
+    let mut fields = IndexMap::new();
+
+    for sample in samples {
+        for (key, value) in *sample {
+            if fields.contains_key(key) {
+                continue;
+            }
+            // Type from the first non-null occurrence, so a null in an
+            // early sample does not pin the field to the String fallback.
+            let typed = samples
+                .iter()
+                .find_map(|s| s.get(key).filter(|v| !v.is_null()))
+                .unwrap_or(value);
+            let mut def = infer_field(typed);
+
+            // A detected format must hold in every sample — one
+            // "unbekannt" next to two dates and the field is free text.
+            if matches!(def.field_type, FieldType::Custom(_) | FieldType::Uuid)
+                && !samples.iter().all(|s| match s.get(key) {
+                    Some(serde_json::Value::String(v)) => {
+                        detect_string_format(v).as_ref() == Some(&def.field_type)
+                    }
+                    _ => true,
+                })
+            {
+                def.field_type = FieldType::String;
+            }
+
+            // A numeric or bool type next to a string occurrence falls
+            // back to String — "80331" and "01067" in the same column
+            // must not end up with different types.
+            if matches!(
+                def.field_type,
+                FieldType::Int | FieldType::Float | FieldType::Bool
+            ) && samples
+                .iter()
+                .any(|s| matches!(s.get(key), Some(serde_json::Value::String(_))))
+            {
+                def.field_type = FieldType::String;
+            }
+
+            // Int widens to Float when any sample carries a decimal —
+            // otherwise `4` in the example pins the type and `4.5` in
+            // later data fails validation.
+            if def.field_type == FieldType::Int
+                && samples
+                    .iter()
+                    .any(|s| s.get(key).is_some_and(|v| v.as_i64().is_none() && v.is_number()))
+            {
+                def.field_type = FieldType::Float;
+                def.description = Some(
+                    "Widened to float — samples contain both integer and decimal values"
+                        .to_string(),
+                );
+            }
+
+            // Repeated small value sets become enums instead of free text.
+            if def.field_type == FieldType::String {
+                if let Some(values) = detect_enum_values(samples, key) {
+                    def.field_type = FieldType::Enum;
+                    def.values = Some(values);
+                    def.description =
+                        Some("Inferred enum — verify the value list is complete".to_string());
+                }
+            }
+
+            // Nested tables merge across all samples that carry them.
+            if def.field_type == FieldType::Table {
+                let nested: Vec<&serde_json::Map<String, serde_json::Value>> = samples
+                    .iter()
+                    .filter_map(|s| s.get(key).and_then(|v| v.as_object()))
+                    .collect();
+                def.fields = Some(merge_fields(&nested, mark_required));
+            }
+
+            // Table arrays merge element fields across *all* samples;
+            // required there means present in every element.
+            if def.field_type == FieldType::TableArray {
+                let elements: Vec<&serde_json::Map<String, serde_json::Value>> = samples
+                    .iter()
+                    .filter_map(|s| s.get(key).and_then(|v| v.as_array()))
+                    .flatten()
+                    .filter_map(|v| v.as_object())
+                    .collect();
+                if !elements.is_empty() {
+                    def.fields = Some(merge_fields(&elements, mark_required));
+                }
+            }
+
+            def.required = mark_required
+                && samples
+                    .iter()
+                    .all(|s| s.get(key).is_some_and(is_non_empty));
+            fields.insert(key.clone(), def);
+        }
+    }
+
+    fields
+}
+
+// ============================================================================
+// EXAMPLE ANNOTATIONS
+// ============================================================================
+
+/// Maximum length of an example value recorded in a description.
+const EXAMPLE_MAX_LEN: usize = 40;
+
+/// Records the sample values as field descriptions ("Example: …"), so
+/// the person editing the generated .schema.json knows what each
+/// cryptic key actually contained. Descriptions inference already set
+/// (enum notes, widening notes) are kept.
+pub fn annotate_examples(schema: &mut SchemaDefinition, sample: &serde_json::Value) {
+    if let Some(obj) = sample.as_object() {
+        annotate_fields(&mut schema.fields, obj);
+    }
+}
+
+/// Walks one object level and fills in missing descriptions.
+fn annotate_fields(
+    fields: &mut IndexMap<String, FieldDefinition>,
+    sample: &serde_json::Map<String, serde_json::Value>,
+) {
+    for (key, def) in fields {
+        let Some(value) = sample.get(key) else {
+            continue;
+        };
+        match def.field_type {
+            FieldType::Table => {
+                if let (Some(nested), Some(nested_sample)) = (def.fields.as_mut(), value.as_object())
+                {
+                    annotate_fields(nested, nested_sample);
+                }
+            }
+            FieldType::TableArray => {
+                let first = value.as_array().and_then(|arr| arr.first());
+                if let (Some(nested), Some(elem)) =
+                    (def.fields.as_mut(), first.and_then(|v| v.as_object()))
+                {
+                    annotate_fields(nested, elem);
+                }
+            }
+            _ => {
+                if def.description.is_none() && !value.is_null() {
+                    def.description = Some(format!("Example: {}", render_example(value)));
+                }
+            }
+        }
+    }
+}
+
+/// Renders a sample value for a description, truncated to a sane length.
+fn render_example(value: &serde_json::Value) -> String {
+    let rendered = match value {
+        serde_json::Value::String(s) => s.clone(),
+        other => other.to_string(),
+    };
+    if rendered.chars().count() > EXAMPLE_MAX_LEN {
+        let truncated: String = rendered.chars().take(EXAMPLE_MAX_LEN).collect();
+        format!("{}…", truncated)
+    } else {
+        rendered
+    }
+}
+
+// ============================================================================
+// CSV INPUT
+// ============================================================================
+
+/// Converts CSV content into one JSON object per data row, ready for
+/// [`infer_schema_from_samples`]. The header row provides the field
+/// names; cell values are sniffed (`true`/`false` → bool, integers,
+/// decimals, empty cells → null, everything else string) so the usual
+/// type, format and enum detection applies to spreadsheet exports too.
+///
+/// Accepts `,` or `;` as delimiter (German spreadsheet exports default
+/// to `;`), quoted cells with `""` escapes, and CRLF line endings.
+pub fn csv_to_samples(content: &str) -> Result<Vec<serde_json::Value>, GermanicError> {
+    let delimiter = sniff_delimiter(content);
+    let rows = parse_csv(content, delimiter)?;
+    let mut iter = rows.into_iter();
+
+    let header = iter
+        .next()
+        .ok_or_else(|| GermanicError::General("CSV is empty — no header row".to_string()))?;
+
+    let mut samples = Vec::new();
+    for (line, row) in iter.enumerate() {
+        if row.len() != header.len() {
+            return Err(GermanicError::General(format!(
+                "CSV row {} has {} cells, header has {}",
+                line + 2,
+                row.len(),
+                header.len()
+            )));
+        }
+        let mut obj = serde_json::Map::new();
+        for (name, cell) in header.iter().zip(row) {
+            obj.insert(name.clone(), sniff_cell(&cell));
+        }
+        samples.push(serde_json::Value::Object(obj));
+    }
+
+    if samples.is_empty() {
+        return Err(GermanicError::General(
+            "CSV has a header but no data rows".to_string(),
+        ));
+    }
+    Ok(samples)
+}
+
+/// Picks `;` when the first line uses it and carries no comma.
+fn sniff_delimiter(content: &str) -> char {
+    let first_line = content.lines().next().unwrap_or("");
+    if first_line.contains(';') && !first_line.contains(',') {
+        ';'
+    } else {
+        ','
+    }
+}
+
+/// Minimal RFC 4180 parser: quoted cells, `""` escapes, CRLF endings.
+fn parse_csv(content: &str, delimiter: char) -> Result<Vec<Vec<String>>, GermanicError> {
+    let mut rows = Vec::new();
+    let mut row: Vec<String> = Vec::new();
+    let mut cell = String::new();
+    let mut in_quotes = false;
+    let mut chars = content.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    cell.push('"');
+                }
+                '"' => in_quotes = false,
+                _ => cell.push(c),
+            }
+        } else {
+            match c {
+                '"' => in_quotes = true,
+                '\r' => {}
+                '\n' => {
+                    row.push(std::mem::take(&mut cell));
+                    if row.len() > 1 || !row[0].is_empty() {
+                        rows.push(std::mem::take(&mut row));
+                    } else {
+                        row.clear();
+                    }
+                }
+                c if c == delimiter => row.push(std::mem::take(&mut cell)),
+                _ => cell.push(c),
+            }
+        }
+    }
+    if in_quotes {
+        return Err(GermanicError::General(
+            "CSV ends inside a quoted cell".to_string(),
+        ));
+    }
+    if !cell.is_empty() || !row.is_empty() {
+        row.push(cell);
+        rows.push(row);
+    }
+    Ok(rows)
+}
+
+/// Sniffs the JSON value a CSV cell represents.
+fn sniff_cell(cell: &str) -> serde_json::Value {
+    if cell.is_empty() {
+        return serde_json::Value::Null;
+    }
+    match cell {
+        "true" => return serde_json::Value::Bool(true),
+        "false" => return serde_json::Value::Bool(false),
+        _ => {}
+    }
+    // Leading zeros (postal codes) and a leading `+` (phone numbers)
+    // would parse as numbers but are really strings.
+    if (cell.starts_with('0') && cell.len() > 1) || cell.starts_with('+') {
+        return serde_json::Value::String(cell.to_string());
+    }
+    if let Ok(n) = cell.parse::<i64>() {
+        return serde_json::Value::Number(n.into());
+    }
+    if let Ok(f) = cell.parse::<f64>() {
+        if let Some(n) = serde_json::Number::from_f64(f) {
+            return serde_json::Value::Number(n);
+        }
+    }
+    serde_json::Value::String(cell.to_string())
+}
+
+/// Detects whether a string field only ever takes a small, repeating set
+/// of values across the samples ("ja"/"nein", weekday names, …).
+///
+/// Requires at least [`ENUM_MIN_OCCURRENCES`] non-empty string values, at
+/// most [`ENUM_MAX_VALUES`] distinct ones, and at least one repetition —
+/// otherwise free-text fields in small sample sets would all become enums.
+fn detect_enum_values(
+    samples: &[&serde_json::Map<String, serde_json::Value>],
+    key: &str,
+) -> Option<Vec<String>> {
+    let mut occurrences = 0;
+    let mut distinct: Vec<String> = Vec::new();
+
+    for sample in samples {
+        match sample.get(key) {
+            Some(serde_json::Value::String(s)) if !s.is_empty() => {
+                occurrences += 1;
+                if !distinct.contains(s) {
+                    distinct.push(s.clone());
+                }
+            }
+            Some(serde_json::Value::Null) | None => {}
+            // A non-string occurrence rules the field out entirely.
+            Some(_) => return None,
+        }
+    }
+
+    if occurrences >= ENUM_MIN_OCCURRENCES
+        && distinct.len() <= ENUM_MAX_VALUES
+        && distinct.len() < occurrences
+    {
+        Some(distinct)
+    } else {
+        None
+    }
+}
+
+/// Whether a sample value counts as "filled in" for required inference.
+fn is_non_empty(value: &serde_json::Value) -> bool {
+    match value {
+        serde_json::Value::Null => false,
+        serde_json::Value::String(s) => !s.is_empty(),
+        serde_json::Value::Array(arr) => !arr.is_empty(),
+        serde_json::Value::Object(obj) => !obj.is_empty(),
+        _ => true,
+    }
+}
+
 /// Infers field definitions from a JSON object.
 fn infer_fields(
     obj: &serde_json::Map<String, serde_json::Value>,
@@ -56,18 +451,13 @@ fn infer_fields(
 /// Infers a single field definition from a JSON value.
 fn infer_field(value: &serde_json::Value) -> FieldDefinition {
     match value {
-        serde_json::Value::String(_) => FieldDefinition {
-            field_type: FieldType::String,
-            required: false,
-            default: None,
-            fields: None,
-        },
+        serde_json::Value::String(s) => {
+            FieldDefinition::new(detect_string_format(s).unwrap_or(FieldType::String))
+        }
 
         serde_json::Value::Bool(_) => FieldDefinition {
-            field_type: FieldType::Bool,
-            required: false,
             default: Some("false".into()),
-            fields: None,
+            ..FieldDefinition::new(FieldType::Bool)
         },
 
         serde_json::Value::Number(n) => {
@@ -76,52 +466,74 @@ fn infer_field(value: &serde_json::Value) -> FieldDefinition {
             } else {
                 FieldType::Int
             };
-            FieldDefinition {
-                field_type,
-                required: false,
-                default: None,
-                fields: None,
-            }
+            FieldDefinition::new(field_type)
         }
 
         serde_json::Value::Array(arr) => {
-            let field_type = infer_array_type(arr);
-            FieldDefinition {
-                field_type,
-                required: false,
-                default: None,
-                fields: None,
+            // Arrays of objects become [table] with fields merged from
+            // the union of element keys, so a key only the second
+            // element carries still makes it into the schema.
+            if arr.first().is_some_and(|v| v.is_object()) && arr.iter().all(|v| v.is_object()) {
+                let elements: Vec<&serde_json::Map<String, serde_json::Value>> =
+                    arr.iter().filter_map(|v| v.as_object()).collect();
+                return FieldDefinition {
+                    fields: Some(merge_fields(&elements, false)),
+                    ..FieldDefinition::new(FieldType::TableArray)
+                };
             }
+            FieldDefinition::new(infer_array_type(arr))
         }
 
-        serde_json::Value::Object(obj) => {
-            let nested = infer_fields(obj);
-            FieldDefinition {
-                field_type: FieldType::Table,
-                required: false,
-                default: None,
-                fields: Some(nested),
-            }
-        }
-
-        serde_json::Value::Null => FieldDefinition {
-            field_type: FieldType::String,
-            required: false,
-            default: None,
-            fields: None,
+        serde_json::Value::Object(obj) => FieldDefinition {
+            fields: Some(infer_fields(obj)),
+            ..FieldDefinition::new(FieldType::Table)
         },
+
+        serde_json::Value::Null => FieldDefinition::new(FieldType::String),
+    }
+}
+
+/// Recognizes common value shapes in example strings and maps them to
+/// the corresponding typed field, so the generated schema validates
+/// dates, contact data and identifiers from the start.
+///
+/// Deliberately conservative: only values the built-in format plugins
+/// would accept are upgraded; everything else stays `String`. Phone
+/// numbers are only recognized in international `+49 …` notation —
+/// bare digit strings are too often postal codes or internal IDs.
+fn detect_string_format(s: &str) -> Option<FieldType> {
+    if crate::formats::valid_date(s) {
+        Some(FieldType::Custom("date".to_string()))
+    } else if crate::formats::valid_datetime(s) {
+        Some(FieldType::Custom("datetime".to_string()))
+    } else if crate::formats::parse_uuid(s).is_ok() {
+        Some(FieldType::Uuid)
+    } else if crate::formats::valid_email(s) {
+        Some(FieldType::Custom("email".to_string()))
+    } else if crate::formats::valid_url(s) {
+        Some(FieldType::Custom("url".to_string()))
+    } else if s.starts_with('+') && crate::formats::normalize_phone(s, None).is_ok() {
+        Some(FieldType::Custom("phone".to_string()))
+    } else {
+        None
     }
 }
 
 /// Infers array element type. Defaults to StringArray if empty or mixed.
+///
+/// Number arrays only become `[int]` when every element actually fits an
+/// integer — there is no float array type, so an array with decimals
+/// takes the mixed fallback rather than a type its own example fails.
 fn infer_array_type(arr: &[serde_json::Value]) -> FieldType {
     if arr.is_empty() {
         return FieldType::StringArray;
     }
 
     let first = &arr[0];
-    if first.is_number() && arr.iter().all(|v| v.is_number()) {
+    if first.is_number() && arr.iter().all(|v| v.as_i64().is_some()) {
         FieldType::IntArray
+    } else if first.is_boolean() && arr.iter().all(|v| v.is_boolean()) {
+        FieldType::BoolArray
     } else {
         FieldType::StringArray
     }
@@ -167,6 +579,46 @@ mod tests {
         assert_eq!(nested["street"].field_type, FieldType::String);
     }
 
+    #[test]
+    fn test_infer_array_of_objects() {
+        let json: serde_json::Value = serde_json::json!({
+            "aerzte": [
+                { "name": "Dr. A", "fachgebiet": "Allgemein" },
+                { "name": "Dr. B", "fachgebiet": "Kardiologie" }
+            ]
+        });
+
+        let schema = infer_schema(&json, "test.v1").unwrap();
+        assert_eq!(schema.fields["aerzte"].field_type, FieldType::TableArray);
+        let nested = schema.fields["aerzte"].fields.as_ref().unwrap();
+        assert_eq!(nested["name"].field_type, FieldType::String);
+    }
+
+    #[test]
+    fn test_infer_table_array_unions_element_keys() {
+        let json: serde_json::Value = serde_json::json!({
+            "aerzte": [
+                { "name": "Dr. A" },
+                { "name": "Dr. B", "fachgebiet": "Kardiologie" }
+            ]
+        });
+
+        let schema = infer_schema(&json, "test.v1").unwrap();
+        let nested = schema.fields["aerzte"].fields.as_ref().unwrap();
+        // The key only the second element carries is still inferred
+        assert_eq!(nested["fachgebiet"].field_type, FieldType::String);
+    }
+
+    #[test]
+    fn test_infer_bool_array() {
+        let json: serde_json::Value = serde_json::json!({
+            "offen": [true, true, false]
+        });
+
+        let schema = infer_schema(&json, "test.v1").unwrap();
+        assert_eq!(schema.fields["offen"].field_type, FieldType::BoolArray);
+    }
+
     #[test]
     fn test_infer_all_optional() {
         let json: serde_json::Value = serde_json::json!({ "name": "X" });
@@ -174,6 +626,275 @@ mod tests {
         assert!(!schema.fields["name"].required);
     }
 
+    #[test]
+    fn test_samples_mark_common_fields_required() {
+        let samples = vec![
+            serde_json::json!({ "name": "Praxis Sonnenschein", "telefon": "+49 30 1", "fax": "" }),
+            serde_json::json!({ "name": "Gasthaus zur Linde", "telefon": "+49 89 2" }),
+        ];
+
+        let schema = infer_schema_from_samples(&samples, "test.v1", true).unwrap();
+        assert!(schema.fields["name"].required);
+        assert!(schema.fields["telefon"].required);
+        // Missing in the second sample, empty in the first
+        assert!(!schema.fields["fax"].required);
+    }
+
+    #[test]
+    fn test_samples_union_field_set() {
+        let samples = vec![
+            serde_json::json!({ "name": "A" }),
+            serde_json::json!({ "name": "B", "plz": "80331" }),
+        ];
+
+        let schema = infer_schema_from_samples(&samples, "test.v1", true).unwrap();
+        assert_eq!(schema.fields["plz"].field_type, FieldType::String);
+        assert!(!schema.fields["plz"].required);
+    }
+
+    #[test]
+    fn test_samples_required_inference_can_be_disabled() {
+        let samples = vec![
+            serde_json::json!({ "name": "A" }),
+            serde_json::json!({ "name": "B" }),
+        ];
+
+        let schema = infer_schema_from_samples(&samples, "test.v1", false).unwrap();
+        assert!(!schema.fields["name"].required);
+    }
+
+    #[test]
+    fn test_samples_merge_nested_tables() {
+        let samples = vec![
+            serde_json::json!({ "adresse": { "ort": "München" } }),
+            serde_json::json!({ "adresse": { "ort": "Berlin", "plz": "10115" } }),
+        ];
+
+        let schema = infer_schema_from_samples(&samples, "test.v1", true).unwrap();
+        let nested = schema.fields["adresse"].fields.as_ref().unwrap();
+        assert!(nested["ort"].required);
+        assert!(!nested["plz"].required);
+    }
+
+    #[test]
+    fn test_infer_detects_formats() {
+        let json: serde_json::Value = serde_json::json!({
+            "gegruendet": "1998-04-01",
+            "letzte_pruefung": "2024-01-15T09:30:00Z",
+            "kennung": "550e8400-e29b-41d4-a716-446655440000",
+            "email": "info@praxis-sonnenschein.de",
+            "webseite": "https://praxis-sonnenschein.de",
+            "telefon": "+49 30 1234567",
+            "name": "Praxis Sonnenschein",
+            "plz": "80331"
+        });
+
+        let schema = infer_schema(&json, "test.v1").unwrap();
+        assert_eq!(
+            schema.fields["gegruendet"].field_type,
+            FieldType::Custom("date".into())
+        );
+        assert_eq!(
+            schema.fields["letzte_pruefung"].field_type,
+            FieldType::Custom("datetime".into())
+        );
+        assert_eq!(schema.fields["kennung"].field_type, FieldType::Uuid);
+        assert_eq!(
+            schema.fields["email"].field_type,
+            FieldType::Custom("email".into())
+        );
+        assert_eq!(
+            schema.fields["webseite"].field_type,
+            FieldType::Custom("url".into())
+        );
+        assert_eq!(
+            schema.fields["telefon"].field_type,
+            FieldType::Custom("phone".into())
+        );
+        assert_eq!(schema.fields["name"].field_type, FieldType::String);
+        // Bare digit strings stay String — too often postal codes or IDs
+        assert_eq!(schema.fields["plz"].field_type, FieldType::String);
+    }
+
+    #[test]
+    fn test_samples_format_must_hold_everywhere() {
+        let samples = vec![
+            serde_json::json!({ "geoeffnet_ab": "2024-01-15" }),
+            serde_json::json!({ "geoeffnet_ab": "unbekannt" }),
+        ];
+
+        let schema = infer_schema_from_samples(&samples, "test.v1", true).unwrap();
+        assert_eq!(schema.fields["geoeffnet_ab"].field_type, FieldType::String);
+    }
+
+    #[test]
+    fn test_samples_repeating_values_become_enum() {
+        let samples = vec![
+            serde_json::json!({ "name": "A", "barrierefrei": "ja" }),
+            serde_json::json!({ "name": "B", "barrierefrei": "nein" }),
+            serde_json::json!({ "name": "C", "barrierefrei": "ja" }),
+        ];
+
+        let schema = infer_schema_from_samples(&samples, "test.v1", true).unwrap();
+        let field = &schema.fields["barrierefrei"];
+        assert_eq!(field.field_type, FieldType::Enum);
+        assert_eq!(field.values.as_ref().unwrap(), &["ja", "nein"]);
+        assert!(field.description.is_some());
+        // Free text never repeats, so it stays a plain string
+        assert_eq!(schema.fields["name"].field_type, FieldType::String);
+    }
+
+    #[test]
+    fn test_samples_no_enum_below_occurrence_threshold() {
+        let samples = vec![
+            serde_json::json!({ "status": "offen" }),
+            serde_json::json!({ "status": "offen" }),
+        ];
+
+        let schema = infer_schema_from_samples(&samples, "test.v1", true).unwrap();
+        assert_eq!(schema.fields["status"].field_type, FieldType::String);
+    }
+
+    #[test]
+    fn test_samples_widen_int_to_float() {
+        let samples = vec![
+            serde_json::json!({ "bewertung": 4 }),
+            serde_json::json!({ "bewertung": 4.5 }),
+        ];
+
+        let schema = infer_schema_from_samples(&samples, "test.v1", true).unwrap();
+        let field = &schema.fields["bewertung"];
+        assert_eq!(field.field_type, FieldType::Float);
+        assert!(field.description.as_ref().unwrap().contains("float"));
+    }
+
+    #[test]
+    fn test_infer_int_array_requires_integer_elements() {
+        let json: serde_json::Value = serde_json::json!({
+            "plaetze": [10, 20],
+            "preise": [4.5, 7.9]
+        });
+
+        let schema = infer_schema(&json, "test.v1").unwrap();
+        assert_eq!(schema.fields["plaetze"].field_type, FieldType::IntArray);
+        // No float array type exists — decimals take the mixed fallback
+        assert_eq!(schema.fields["preise"].field_type, FieldType::StringArray);
+    }
+
+    #[test]
+    fn test_samples_merge_table_array_elements() {
+        let samples = vec![
+            serde_json::json!({ "raeume": [{ "bezeichnung": "Labor" }] }),
+            serde_json::json!({ "raeume": [{ "bezeichnung": "Empfang", "etage": 1 }] }),
+        ];
+
+        let schema = infer_schema_from_samples(&samples, "test.v1", true).unwrap();
+        let nested = schema.fields["raeume"].fields.as_ref().unwrap();
+        assert!(nested["bezeichnung"].required);
+        assert_eq!(nested["etage"].field_type, FieldType::Int);
+        assert!(!nested["etage"].required);
+    }
+
+    #[test]
+    fn test_samples_null_does_not_pin_type() {
+        let samples = vec![
+            serde_json::json!({ "bewertung": null }),
+            serde_json::json!({ "bewertung": 4.5 }),
+        ];
+
+        let schema = infer_schema_from_samples(&samples, "test.v1", true).unwrap();
+        assert_eq!(schema.fields["bewertung"].field_type, FieldType::Float);
+        assert!(!schema.fields["bewertung"].required);
+    }
+
+    #[test]
+    fn test_annotate_examples_records_values() {
+        let json: serde_json::Value = serde_json::json!({
+            "name": "Gasthaus zur Linde",
+            "plaetze": 40,
+            "adresse": { "ort": "München" }
+        });
+
+        let mut schema = infer_schema(&json, "test.v1").unwrap();
+        annotate_examples(&mut schema, &json);
+        assert_eq!(
+            schema.fields["name"].description.as_deref(),
+            Some("Example: Gasthaus zur Linde")
+        );
+        assert_eq!(
+            schema.fields["plaetze"].description.as_deref(),
+            Some("Example: 40")
+        );
+        let nested = schema.fields["adresse"].fields.as_ref().unwrap();
+        assert_eq!(nested["ort"].description.as_deref(), Some("Example: München"));
+    }
+
+    #[test]
+    fn test_annotate_examples_truncates_and_keeps_existing() {
+        let samples = vec![
+            serde_json::json!({ "status": "offen", "text": "x".repeat(100) }),
+            serde_json::json!({ "status": "zu", "text": "y" }),
+            serde_json::json!({ "status": "offen", "text": "z" }),
+        ];
+
+        let mut schema = infer_schema_from_samples(&samples, "test.v1", true).unwrap();
+        annotate_examples(&mut schema, &samples[0]);
+        // Enum note from inference wins over the example
+        assert!(schema.fields["status"].description.as_ref().unwrap().contains("enum"));
+        let text = schema.fields["text"].description.as_ref().unwrap();
+        assert!(text.ends_with('…'));
+        assert!(text.chars().count() < 60);
+    }
+
+    #[test]
+    fn test_csv_rows_become_samples() {
+        let csv = "name,plz,plaetze,bewertung\n\
+                   Gasthaus zur Linde,80331,40,4.5\n\
+                   Praxis Sonnenschein,01067,12,4\n";
+
+        let samples = csv_to_samples(csv).unwrap();
+        assert_eq!(samples.len(), 2);
+        let schema = infer_schema_from_samples(&samples, "test.v1", true).unwrap();
+        assert_eq!(schema.fields["name"].field_type, FieldType::String);
+        // Leading zero keeps the postal code a string
+        assert_eq!(schema.fields["plz"].field_type, FieldType::String);
+        assert_eq!(schema.fields["plaetze"].field_type, FieldType::Int);
+        // 4 next to 4.5 widens to float
+        assert_eq!(schema.fields["bewertung"].field_type, FieldType::Float);
+        assert!(schema.fields["name"].required);
+    }
+
+    #[test]
+    fn test_csv_semicolon_delimiter_and_quotes() {
+        let csv = "name;ort\n\"Café \"\"Zur Post\"\"\";München\n";
+
+        let samples = csv_to_samples(csv).unwrap();
+        assert_eq!(samples[0]["name"], "Café \"Zur Post\"");
+        assert_eq!(samples[0]["ort"], "München");
+    }
+
+    #[test]
+    fn test_csv_empty_cells_are_optional() {
+        let csv = "name,fax\nA,030-1\nB,\n";
+
+        let samples = csv_to_samples(csv).unwrap();
+        let schema = infer_schema_from_samples(&samples, "test.v1", true).unwrap();
+        assert!(!schema.fields["fax"].required);
+    }
+
+    #[test]
+    fn test_csv_ragged_row_rejected() {
+        let csv = "name,ort\nA\n";
+        let err = csv_to_samples(csv).unwrap_err();
+        assert!(err.to_string().contains("row 2"));
+    }
+
+    #[test]
+    fn test_csv_without_data_rows_rejected() {
+        assert!(csv_to_samples("name,ort\n").is_err());
+        assert!(csv_to_samples("").is_err());
+    }
+
     #[test]
     fn test_infer_preserves_order() {
         let json: serde_json::Value = serde_json::from_str(
@@ -24,11 +24,21 @@
 //! - `default`: passed through as string
 //! - `properties`: recursive conversion (nested objects become Tables)
 //! - `items`: array item type inference (string/integer arrays)
+//! - `minimum`/`maximum`, `minLength`/`maxLength`, `minItems`/`maxItems`,
+//!   `pattern`: mapped to the native field constraints
+//! - local `$ref` (`#/definitions/...`, `#/$defs/...`): inlined, with
+//!   cycle detection
+//! - `oneOf` of object schemas: converted to a Union, variant names from
+//!   a discriminator const, the alternative's `title`, or position
+//! - `format` on strings: `date`, `date-time`, `email`, `uri` map to the
+//!   built-in format plugins, `uuid` to the native uuid type
+//! - `additionalProperties: false`: strict unknown-key rejection for
+//!   that object
 //!
 //! ## Intentionally Ignored (with warnings)
 //!
-//! `$ref`, `anyOf`, `oneOf`, `allOf`, `enum`, `pattern`, `minimum`,
-//! `maximum`, `format`, `additionalProperties`
+//! external `$ref`, cyclic `$ref`, `anyOf`, scalar `oneOf`, `allOf`,
+//! non-string `enum`, unknown `format`, `additionalProperties` sub-schemas
 
 use indexmap::IndexMap;
 use serde::Deserialize;
@@ -58,8 +68,10 @@ struct JsonSchema {
 
     title: Option<String>,
 
-    #[allow(dead_code)]
     description: Option<String>,
+
+    #[serde(rename = "additionalProperties")]
+    additional_properties: Option<serde_json::Value>,
 }
 
 /// A single property in a JSON Schema object.
@@ -68,11 +80,32 @@ struct JsonSchemaProperty {
     #[serde(rename = "type")]
     typ: Option<String>,
 
+    description: Option<String>,
+
+    format: Option<String>,
+
     properties: Option<IndexMap<String, JsonSchemaProperty>>,
     required: Option<Vec<String>>,
     items: Option<Box<JsonSchemaProperty>>,
     default: Option<serde_json::Value>,
 
+    // Constraint keywords, mapped to FieldDefinition constraints
+    minimum: Option<f64>,
+    maximum: Option<f64>,
+    #[serde(rename = "minLength")]
+    min_length: Option<usize>,
+    #[serde(rename = "maxLength")]
+    max_length: Option<usize>,
+    pattern: Option<String>,
+    #[serde(rename = "minItems")]
+    min_items: Option<usize>,
+    #[serde(rename = "maxItems")]
+    max_items: Option<usize>,
+    #[serde(rename = "exclusiveMinimum")]
+    exclusive_minimum: Option<f64>,
+    #[serde(rename = "exclusiveMaximum")]
+    exclusive_maximum: Option<f64>,
+
     // Recognized but only warned about:
     #[serde(rename = "$ref")]
     reference: Option<String>,
@@ -80,12 +113,14 @@ struct JsonSchemaProperty {
     any_of: Option<serde_json::Value>,
     #[serde(rename = "oneOf")]
     one_of: Option<serde_json::Value>,
+    // OpenAPI-style tag for oneOf unions: { "propertyName": "art", ... }
+    discriminator: Option<serde_json::Value>,
     #[serde(rename = "allOf")]
     all_of: Option<serde_json::Value>,
     #[serde(rename = "enum")]
     enum_values: Option<serde_json::Value>,
-    #[allow(dead_code)]
-    pattern: Option<String>,
+    #[serde(rename = "additionalProperties")]
+    additional_properties: Option<serde_json::Value>,
 }
 
 // ============================================================================
@@ -130,9 +165,29 @@ pub fn is_json_schema(input: &str) -> bool {
 /// - The root type is not `"object"`
 /// - Array items have mixed/unsupported types
 pub fn convert_json_schema(input: &str) -> Result<(SchemaDefinition, Vec<String>), GermanicError> {
-    let js: JsonSchema = serde_json::from_str(input)?;
+    convert_json_schema_with_base(input, None)
+}
+
+/// Like [`convert_json_schema`], but resolves file `$ref`s relative to
+/// `base_dir` (the schema file's directory).
+///
+/// Organizations share definitions like a common address object across
+/// many schemas via `"$ref": "./adresse.schema.json#/definitions/Adresse"`.
+/// Resolution is filesystem-only — network references are never fetched —
+/// and bounded by [`MAX_REF_DEPTH`] and [`MAX_REF_FILE_SIZE`].
+pub fn convert_json_schema_with_base(
+    input: &str,
+    base_dir: Option<&std::path::Path>,
+) -> Result<(SchemaDefinition, Vec<String>), GermanicError> {
     let mut warnings: Vec<String> = Vec::new();
 
+    // Resolve local $ref against definitions/$defs BEFORE the typed
+    // deserialization, so referenced objects become ordinary inline
+    // properties downstream.
+    let mut raw: serde_json::Value = serde_json::from_str(input)?;
+    resolve_local_refs(&mut raw, base_dir, &mut warnings);
+    let js: JsonSchema = serde_json::from_value(raw)?;
+
     // Root must be "type": "object"
     match js.typ.as_deref() {
         Some("object") | None => {} // None is acceptable if properties exist
@@ -157,15 +212,277 @@ pub fn convert_json_schema(input: &str) -> Result<(SchemaDefinition, Vec<String>
         None => IndexMap::new(),
     };
 
+    let strict = additional_properties_strict("schema root", js.additional_properties, &mut warnings);
+
     let schema = SchemaDefinition {
         schema_id,
         version: 1,
+        description: js.description,
+        limits: None,
+        strict,
         fields,
     };
 
     Ok((schema, warnings))
 }
 
+// ============================================================================
+// LOCAL $REF RESOLUTION
+// ============================================================================
+
+/// Largest schema file a `$ref` may pull in (1 MiB).
+pub const MAX_REF_FILE_SIZE: u64 = 1_048_576;
+
+/// Longest chain of files a `$ref` may traverse before resolution stops.
+pub const MAX_REF_DEPTH: usize = 8;
+
+/// Shared resolution state threaded through [`inline_refs`].
+struct RefContext {
+    /// Directory stack: the last entry is the directory of the file whose
+    /// contents are currently being expanded. Empty when no base directory
+    /// is known (string input) — file references then warn instead.
+    dirs: Vec<std::path::PathBuf>,
+    /// Canonicalized paths of files currently being expanded — revisiting
+    /// one means a cross-file cycle.
+    active_files: Vec<std::path::PathBuf>,
+}
+
+/// Inlines `$ref` targets in place: local (`#/definitions/X`, `#/$defs/X`)
+/// and relative-file (`./adresse.schema.json#/definitions/Adresse`).
+///
+/// Real Draft 7 schemas factor shared objects into `definitions` — often
+/// in a separate file shared across a whole organization; dropping those
+/// fields would lose their whole structure. File references resolve
+/// against `base_dir` only, capped by [`MAX_REF_DEPTH`] files and
+/// [`MAX_REF_FILE_SIZE`] per file, and never touch the network. Cyclic
+/// references cannot be inlined (GERMANIC schemas are trees) — they are
+/// dropped with a warning instead of recursing forever. URL references
+/// stay in place for the per-field warning downstream.
+fn resolve_local_refs(
+    root: &mut serde_json::Value,
+    base_dir: Option<&std::path::Path>,
+    warnings: &mut Vec<String>,
+) {
+    let definitions = document_definitions(root);
+    let mut ctx = RefContext {
+        dirs: base_dir.map(std::path::Path::to_path_buf).into_iter().collect(),
+        active_files: Vec::new(),
+    };
+
+    let mut active = Vec::new();
+    inline_refs(root, &definitions, &mut active, &mut ctx, warnings);
+}
+
+/// Collects the `definitions`/`$defs` maps of one schema document.
+fn document_definitions(root: &serde_json::Value) -> serde_json::Map<String, serde_json::Value> {
+    ["definitions", "$defs"]
+        .iter()
+        .filter_map(|key| root.get(key).and_then(|v| v.as_object()).cloned())
+        .flatten()
+        .collect()
+}
+
+/// Strips the local-reference prefix, returning the definition name.
+fn local_ref_name(reference: &str) -> Option<&str> {
+    reference
+        .strip_prefix("#/definitions/")
+        .or_else(|| reference.strip_prefix("#/$defs/"))
+}
+
+/// Splits a relative-file reference into its path and fragment parts.
+///
+/// Returns `None` for URL references (`http://…`) — those are never
+/// fetched — and for purely local fragments handled by [`local_ref_name`].
+fn split_file_ref(reference: &str) -> Option<(&str, &str)> {
+    if reference.contains("://") {
+        return None;
+    }
+    let (file, fragment) = match reference.split_once('#') {
+        Some((file, fragment)) => (file, fragment),
+        None => (reference, ""),
+    };
+    if file.is_empty() {
+        None
+    } else {
+        Some((file, fragment))
+    }
+}
+
+/// Recursively replaces `{"$ref": …}` objects with the referenced body,
+/// whether it lives in this document's definitions or a neighbouring
+/// file. `active` holds the chain of definitions currently being
+/// expanded — revisiting one means a cycle.
+fn inline_refs(
+    value: &mut serde_json::Value,
+    definitions: &serde_json::Map<String, serde_json::Value>,
+    active: &mut Vec<String>,
+    ctx: &mut RefContext,
+    warnings: &mut Vec<String>,
+) {
+    match value {
+        serde_json::Value::Object(map) => {
+            let reference = map.get("$ref").and_then(|v| v.as_str()).map(String::from);
+            if let Some(reference) = reference {
+                if let Some(name) = local_ref_name(&reference) {
+                    if active.iter().any(|a| a == name) {
+                        warnings.push(format!(
+                            "Cyclic $ref \"{}\" not resolved — field structure dropped",
+                            reference
+                        ));
+                        map.remove("$ref");
+                        return;
+                    }
+                    match definitions.get(name) {
+                        Some(target) => {
+                            let mut replacement = target.clone();
+                            active.push(name.to_string());
+                            inline_refs(&mut replacement, definitions, active, ctx, warnings);
+                            active.pop();
+                            *value = replacement;
+                            return;
+                        }
+                        None => {
+                            warnings.push(format!(
+                                "$ref \"{}\" not found in definitions — field structure dropped",
+                                reference
+                            ));
+                            map.remove("$ref");
+                        }
+                    }
+                } else if let Some((file, fragment)) = split_file_ref(&reference) {
+                    match load_file_ref(&reference, file, fragment, ctx, warnings) {
+                        Some(replacement) => {
+                            *value = replacement;
+                            return;
+                        }
+                        None => {
+                            // Warning already pushed; drop the reference so
+                            // the per-field pass does not warn a second time.
+                            map.remove("$ref");
+                        }
+                    }
+                }
+                // URL references stay for the downstream warning
+            }
+            for nested in map.values_mut() {
+                inline_refs(nested, definitions, active, ctx, warnings);
+            }
+        }
+        serde_json::Value::Array(arr) => {
+            for element in arr {
+                inline_refs(element, definitions, active, ctx, warnings);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Loads a relative-file reference and returns the fully inlined target.
+///
+/// Pushes a warning and returns `None` on any failure — a missing file,
+/// an oversized file, a dangling fragment, a cycle across files, or a
+/// chain deeper than [`MAX_REF_DEPTH`].
+fn load_file_ref(
+    reference: &str,
+    file: &str,
+    fragment: &str,
+    ctx: &mut RefContext,
+    warnings: &mut Vec<String>,
+) -> Option<serde_json::Value> {
+    let Some(dir) = ctx.dirs.last() else {
+        warnings.push(format!(
+            "File $ref \"{}\" cannot be resolved without a schema file path — field structure dropped",
+            reference
+        ));
+        return None;
+    };
+
+    if ctx.active_files.len() >= MAX_REF_DEPTH {
+        warnings.push(format!(
+            "$ref \"{}\" exceeds the maximum chain of {} files — field structure dropped",
+            reference, MAX_REF_DEPTH
+        ));
+        return None;
+    }
+
+    let path = match dir.join(file).canonicalize() {
+        Ok(path) => path,
+        Err(e) => {
+            warnings.push(format!(
+                "File $ref \"{}\" could not be opened ({}) — field structure dropped",
+                reference, e
+            ));
+            return None;
+        }
+    };
+
+    if ctx.active_files.contains(&path) {
+        warnings.push(format!(
+            "Cyclic file $ref \"{}\" not resolved — field structure dropped",
+            reference
+        ));
+        return None;
+    }
+
+    match std::fs::metadata(&path) {
+        Ok(meta) if meta.len() > MAX_REF_FILE_SIZE => {
+            warnings.push(format!(
+                "File $ref \"{}\" exceeds {} bytes — field structure dropped",
+                reference, MAX_REF_FILE_SIZE
+            ));
+            return None;
+        }
+        Ok(_) => {}
+        Err(e) => {
+            warnings.push(format!(
+                "File $ref \"{}\" could not be opened ({}) — field structure dropped",
+                reference, e
+            ));
+            return None;
+        }
+    }
+
+    let doc: serde_json::Value = match std::fs::read_to_string(&path)
+        .map_err(GermanicError::from)
+        .and_then(|content| serde_json::from_str(&content).map_err(GermanicError::from))
+    {
+        Ok(doc) => doc,
+        Err(e) => {
+            warnings.push(format!(
+                "File $ref \"{}\" could not be parsed ({}) — field structure dropped",
+                reference, e
+            ));
+            return None;
+        }
+    };
+
+    let Some(mut target) = (if fragment.is_empty() {
+        Some(doc.clone())
+    } else {
+        doc.pointer(fragment).cloned()
+    }) else {
+        warnings.push(format!(
+            "$ref \"{}\": fragment \"{}\" not found in {} — field structure dropped",
+            reference,
+            fragment,
+            path.display()
+        ));
+        return None;
+    };
+
+    // Expand the target against ITS document's definitions, with the
+    // referenced file's directory as the new base for nested file refs.
+    let definitions = document_definitions(&doc);
+    ctx.active_files.push(path.clone());
+    ctx.dirs.push(path.parent().map(std::path::Path::to_path_buf).unwrap_or_default());
+    let mut active = Vec::new();
+    inline_refs(&mut target, &definitions, &mut active, ctx, warnings);
+    ctx.dirs.pop();
+    ctx.active_files.pop();
+
+    Some(target)
+}
+
 // ============================================================================
 // INTERNAL CONVERSION
 // ============================================================================
@@ -194,30 +511,99 @@ fn convert_property(
     required: bool,
     warnings: &mut Vec<String>,
 ) -> Result<FieldDefinition, GermanicError> {
+    let description = prop.description.clone();
+
+    // Constraint keywords: string and array lengths share one field pair
+    let min = prop.minimum;
+    let max = prop.maximum;
+    let min_length = prop.min_length.or(prop.min_items);
+    let max_length = prop.max_length.or(prop.max_items);
+    let pattern = prop.pattern.clone();
+
+    // Native min/max bounds are inclusive — exclusive bounds cannot be
+    // represented exactly, so dropping them is reported instead of silent.
+    if prop.exclusive_minimum.is_some() || prop.exclusive_maximum.is_some() {
+        warnings.push(format!(
+            "Field \"{name}\": exclusiveMinimum/exclusiveMaximum not supported, constraint ignored"
+        ));
+    }
+
     // Emit warnings for unsupported features
+    // Local references were inlined upfront; whatever is left is external
     if prop.reference.is_some() {
         warnings.push(format!(
-            "Field \"{name}\": $ref not resolved (not supported)"
+            "Field \"{name}\": external $ref not resolved (not supported)"
         ));
     }
     if prop.any_of.is_some() {
         warnings.push(format!("Field \"{name}\": anyOf not supported, ignored"));
     }
-    if prop.one_of.is_some() {
+    // oneOf of object alternatives becomes a native union; anything else
+    // (scalar alternatives) still degrades with a warning.
+    if let Some(one_of) = &prop.one_of {
+        if let Some(variants) =
+            convert_one_of(name, one_of, prop.discriminator.as_ref(), warnings)?
+        {
+            return Ok(FieldDefinition {
+                description,
+                required,
+                fields: Some(variants),
+                ..FieldDefinition::new(FieldType::Union)
+            });
+        }
         warnings.push(format!("Field \"{name}\": oneOf not supported, ignored"));
     }
     if prop.all_of.is_some() {
         warnings.push(format!("Field \"{name}\": allOf not supported, ignored"));
     }
-    if prop.enum_values.is_some() {
-        warnings.push(format!("Field \"{name}\": enum constraint ignored"));
+    // String enums map to the native enum type; anything else is dropped
+    // with a warning (GERMANIC enums are string-valued).
+    let enum_values = match prop.enum_values {
+        Some(serde_json::Value::Array(items))
+            if !items.is_empty() && items.iter().all(|v| v.is_string()) =>
+        {
+            Some(
+                items
+                    .iter()
+                    .filter_map(|v| v.as_str().map(String::from))
+                    .collect::<Vec<_>>(),
+            )
+        }
+        Some(serde_json::Value::Array(items)) if items.is_empty() => {
+            warnings.push(format!(
+                "Field \"{name}\": empty enum matches nothing, constraint ignored"
+            ));
+            None
+        }
+        Some(_) => {
+            warnings.push(format!(
+                "Field \"{name}\": non-string enum not supported, constraint ignored"
+            ));
+            None
+        }
+        None => None,
+    };
+
+    if enum_values.is_some() {
+        let default = prop.default;
+        return Ok(FieldDefinition {
+            description,
+            required,
+            default,
+            values: enum_values,
+            ..FieldDefinition::new(FieldType::Enum)
+        });
     }
 
     // Determine field type
     let typ_str = prop.typ.as_deref().unwrap_or("string");
 
+    let mut items_strict = false;
     let (field_type, nested_fields) = match typ_str {
-        "string" => (FieldType::String, None),
+        "string" => (
+            resolve_string_format(name, prop.format.as_deref(), warnings),
+            None,
+        ),
         "boolean" => (FieldType::Bool, None),
         "integer" => (FieldType::Int, None),
         "number" => (FieldType::Float, None),
@@ -230,8 +616,9 @@ fn convert_property(
             (FieldType::Table, nested)
         }
         "array" => {
-            let array_type = resolve_array_type(name, &prop.items)?;
-            (array_type, None)
+            let (array_type, nested, strict) = resolve_array_type(name, prop.items, warnings)?;
+            items_strict = strict;
+            (array_type, nested)
         }
         other => {
             warnings.push(format!(
@@ -241,40 +628,357 @@ fn convert_property(
         }
     };
 
-    // Convert default value to string representation
-    let default = prop.default.map(|v| match v {
-        serde_json::Value::String(s) => s,
-        other => other.to_string(),
-    });
+    // Defaults pass through structurally (arrays/objects included)
+    let default = prop.default;
+
+    // additionalProperties: false carries over as per-table strictness;
+    // for table arrays it comes from the items schema instead
+    let strict = match field_type {
+        FieldType::Table => {
+            additional_properties_strict(name, prop.additional_properties, warnings)
+        }
+        FieldType::TableArray => items_strict,
+        _ => false,
+    };
 
     Ok(FieldDefinition {
-        field_type,
+        description,
         required,
         default,
+        min,
+        max,
+        min_length,
+        max_length,
+        pattern,
+        strict,
         fields: nested_fields,
+        ..FieldDefinition::new(field_type)
     })
 }
 
+/// Interprets `additionalProperties` for one object level.
+///
+/// `false` becomes strict unknown-key rejection; a sub-schema describing
+/// the extra keys cannot be represented and is reported instead.
+fn additional_properties_strict(
+    name: &str,
+    value: Option<serde_json::Value>,
+    warnings: &mut Vec<String>,
+) -> bool {
+    match value {
+        Some(serde_json::Value::Bool(false)) => true,
+        Some(serde_json::Value::Object(_)) => {
+            warnings.push(format!(
+                "\"{name}\": additionalProperties sub-schema not supported, ignored"
+            ));
+            false
+        }
+        _ => false,
+    }
+}
+
+/// Resolves the `format` keyword on string properties to a typed field.
+///
+/// Well-known formats map onto the built-in format plugins
+/// ([`crate::formats`]) or the native `uuid` type, so converted schemas
+/// keep the syntactic validation the source schema promised. Unknown
+/// formats stay plain strings with a warning.
+fn resolve_string_format(
+    name: &str,
+    format: Option<&str>,
+    warnings: &mut Vec<String>,
+) -> FieldType {
+    match format {
+        None => FieldType::String,
+        Some("date") => FieldType::Custom("date".into()),
+        Some("date-time") => FieldType::Custom("datetime".into()),
+        Some("email") => FieldType::Custom("email".into()),
+        Some("uri" | "url") => FieldType::Custom("url".into()),
+        Some("uuid") => FieldType::Uuid,
+        Some(other) => {
+            warnings.push(format!(
+                "Field \"{name}\": format \"{other}\" not supported, kept as plain string"
+            ));
+            FieldType::String
+        }
+    }
+}
+
+/// Converts `oneOf` object alternatives into union variants.
+///
+/// Returns `Ok(None)` when the alternatives are not all object schemas —
+/// the caller then falls back to the unsupported-feature warning. The
+/// variant name comes from the discriminator property's `const` in the
+/// alternative, then the alternative's `title` (lowercased), then the
+/// position (`variante_1`, `variante_2`, …).
+fn convert_one_of(
+    name: &str,
+    one_of: &serde_json::Value,
+    discriminator: Option<&serde_json::Value>,
+    warnings: &mut Vec<String>,
+) -> Result<Option<IndexMap<String, FieldDefinition>>, GermanicError> {
+    let Some(alternatives) = one_of.as_array().filter(|a| !a.is_empty()) else {
+        return Ok(None);
+    };
+    let all_objects = alternatives.iter().all(|alt| {
+        alt.get("type").and_then(|t| t.as_str()) == Some("object") || alt.get("properties").is_some()
+    });
+    if !all_objects {
+        return Ok(None);
+    }
+
+    let tag_property = discriminator
+        .and_then(|d| d.get("propertyName"))
+        .and_then(|p| p.as_str());
+
+    let mut variants = IndexMap::new();
+    for (index, alternative) in alternatives.iter().enumerate() {
+        let variant_name = tag_property
+            .and_then(|tag| alternative.pointer(&format!("/properties/{tag}/const")))
+            .and_then(|c| c.as_str())
+            .map(String::from)
+            .or_else(|| {
+                alternative
+                    .get("title")
+                    .and_then(|t| t.as_str())
+                    .map(str::to_lowercase)
+            })
+            .unwrap_or_else(|| format!("variante_{}", index + 1));
+
+        let parsed: JsonSchemaProperty = serde_json::from_value(alternative.clone())?;
+        let variant = convert_property(&variant_name, parsed, false, warnings)?;
+        if variants.insert(variant_name.clone(), variant).is_some() {
+            warnings.push(format!(
+                "Field \"{name}\": duplicate oneOf variant \"{variant_name}\" — later alternative wins"
+            ));
+        }
+    }
+
+    Ok(Some(variants))
+}
+
+/// Resolved array shape: element type, nested item fields for `[table]`,
+/// and whether the item objects are strict.
+type ResolvedArrayType = (FieldType, Option<IndexMap<String, FieldDefinition>>, bool);
+
 /// Determines the GERMANIC array type from JSON Schema `items`.
+///
+/// `items` of type "object" become `[table]` with nested field
+/// definitions converted from the item properties; the third element
+/// reports whether the item objects are strict (`additionalProperties:
+/// false` on the items schema).
 fn resolve_array_type(
     field_name: &str,
-    items: &Option<Box<JsonSchemaProperty>>,
-) -> Result<FieldType, GermanicError> {
+    items: Option<Box<JsonSchemaProperty>>,
+    warnings: &mut Vec<String>,
+) -> Result<ResolvedArrayType, GermanicError> {
     let Some(items) = items else {
         // No items specified, default to string array
-        return Ok(FieldType::StringArray);
+        return Ok((FieldType::StringArray, None, false));
     };
+    let items = *items;
 
     match items.typ.as_deref() {
-        Some("string") | None => Ok(FieldType::StringArray),
-        Some("integer") => Ok(FieldType::IntArray),
-        Some("number") => Ok(FieldType::IntArray), // Closest mapping
+        Some("string") | None => Ok((FieldType::StringArray, None, false)),
+        Some("integer") => Ok((FieldType::IntArray, None, false)),
+        Some("number") => Ok((FieldType::IntArray, None, false)), // Closest mapping
+        Some("boolean") => Ok((FieldType::BoolArray, None, false)),
+        Some("object") => {
+            let strict =
+                additional_properties_strict(field_name, items.additional_properties, warnings);
+            let nested_required = items.required.unwrap_or_default();
+            let nested = match items.properties {
+                Some(props) => convert_properties(props, &nested_required, warnings)?,
+                None => IndexMap::new(),
+            };
+            Ok((FieldType::TableArray, Some(nested), strict))
+        }
         Some(other) => Err(GermanicError::General(format!(
             "Field \"{field_name}\": unsupported array item type \"{other}\""
         ))),
     }
 }
 
+// ============================================================================
+// EXPORT (SchemaDefinition → JSON Schema)
+// ============================================================================
+
+/// Exports a [`SchemaDefinition`] as a JSON Schema Draft 7 document.
+///
+/// The reverse direction of [`convert_json_schema`]: teams using standard
+/// tooling (editors, CI validators, OpenAPI) get a schema they can reuse.
+/// Required flags, defaults, constraints, enums, nested tables, arrays
+/// and strictness all carry over, so the exported document converts back
+/// to an equivalent definition.
+pub fn export_json_schema(schema: &SchemaDefinition) -> serde_json::Value {
+    let mut root = serde_json::Map::new();
+    root.insert(
+        "$schema".into(),
+        "http://json-schema.org/draft-07/schema#".into(),
+    );
+    root.insert("$id".into(), schema.schema_id.clone().into());
+    if let Some(description) = &schema.description {
+        root.insert("description".into(), description.clone().into());
+    }
+    export_object_level(&schema.fields, schema.strict, &mut root);
+    serde_json::Value::Object(root)
+}
+
+/// Writes one object level: `type`, `properties`, `required`, strictness.
+fn export_object_level(
+    fields: &IndexMap<String, FieldDefinition>,
+    strict: bool,
+    target: &mut serde_json::Map<String, serde_json::Value>,
+) {
+    target.insert("type".into(), "object".into());
+    if strict {
+        target.insert("additionalProperties".into(), false.into());
+    }
+
+    let required: Vec<serde_json::Value> = fields
+        .iter()
+        .filter(|(_, def)| def.required)
+        .map(|(name, _)| name.clone().into())
+        .collect();
+    if !required.is_empty() {
+        target.insert("required".into(), serde_json::Value::Array(required));
+    }
+
+    let mut properties = serde_json::Map::new();
+    for (name, def) in fields {
+        properties.insert(name.clone(), export_field(def));
+    }
+    target.insert("properties".into(), serde_json::Value::Object(properties));
+}
+
+/// Converts one FieldDefinition into its JSON Schema property form.
+fn export_field(def: &FieldDefinition) -> serde_json::Value {
+    let mut prop = serde_json::Map::new();
+
+    match &def.field_type {
+        FieldType::String => {
+            prop.insert("type".into(), "string".into());
+        }
+        FieldType::Bool => {
+            prop.insert("type".into(), "boolean".into());
+        }
+        FieldType::Int => {
+            prop.insert("type".into(), "integer".into());
+        }
+        FieldType::Float => {
+            prop.insert("type".into(), "number".into());
+        }
+        FieldType::StringArray => {
+            prop.insert("type".into(), "array".into());
+            prop.insert("items".into(), serde_json::json!({ "type": "string" }));
+        }
+        FieldType::IntArray => {
+            prop.insert("type".into(), "array".into());
+            prop.insert("items".into(), serde_json::json!({ "type": "integer" }));
+        }
+        FieldType::BoolArray => {
+            prop.insert("type".into(), "array".into());
+            prop.insert("items".into(), serde_json::json!({ "type": "boolean" }));
+        }
+        FieldType::Bytes => {
+            prop.insert("type".into(), "string".into());
+            prop.insert("contentEncoding".into(), "base64".into());
+        }
+        FieldType::Uuid => {
+            prop.insert("type".into(), "string".into());
+            prop.insert("format".into(), "uuid".into());
+        }
+        FieldType::Enum => {
+            prop.insert("type".into(), "string".into());
+            if let Some(values) = &def.values {
+                prop.insert(
+                    "enum".into(),
+                    serde_json::Value::Array(
+                        values.iter().map(|v| v.clone().into()).collect(),
+                    ),
+                );
+            }
+        }
+        FieldType::Table => {
+            let empty = IndexMap::new();
+            let nested = def.fields.as_ref().unwrap_or(&empty);
+            export_object_level(nested, def.strict, &mut prop);
+        }
+        FieldType::TableArray => {
+            prop.insert("type".into(), "array".into());
+            let empty = IndexMap::new();
+            let nested = def.fields.as_ref().unwrap_or(&empty);
+            let mut items = serde_json::Map::new();
+            export_object_level(nested, def.strict, &mut items);
+            prop.insert("items".into(), serde_json::Value::Object(items));
+        }
+        FieldType::Union => {
+            let alternatives: Vec<serde_json::Value> = def
+                .fields
+                .iter()
+                .flatten()
+                .map(|(variant_name, variant)| {
+                    let mut alt = serde_json::Map::new();
+                    alt.insert("title".into(), variant_name.clone().into());
+                    let empty = IndexMap::new();
+                    let nested = variant.fields.as_ref().unwrap_or(&empty);
+                    export_object_level(nested, variant.strict, &mut alt);
+                    serde_json::Value::Object(alt)
+                })
+                .collect();
+            prop.insert("oneOf".into(), serde_json::Value::Array(alternatives));
+        }
+        FieldType::Custom(name) => {
+            prop.insert("type".into(), "string".into());
+            // The built-in format plugins round-trip through `format`
+            let format = match name.as_str() {
+                "date" => Some("date"),
+                "datetime" => Some("date-time"),
+                "email" => Some("email"),
+                "url" => Some("uri"),
+                _ => None,
+            };
+            if let Some(format) = format {
+                prop.insert("format".into(), format.into());
+            }
+        }
+    }
+
+    if let Some(description) = &def.description {
+        prop.insert("description".into(), description.clone().into());
+    }
+    if let Some(default) = &def.default {
+        prop.insert("default".into(), default.clone());
+    }
+    if let Some(min) = def.min {
+        prop.insert("minimum".into(), min.into());
+    }
+    if let Some(max) = def.max {
+        prop.insert("maximum".into(), max.into());
+    }
+    // Length bounds split by shape: arrays get minItems/maxItems
+    let is_array = matches!(
+        def.field_type,
+        FieldType::StringArray
+            | FieldType::IntArray
+            | FieldType::BoolArray
+            | FieldType::TableArray
+    );
+    if let Some(min_length) = def.min_length {
+        let key = if is_array { "minItems" } else { "minLength" };
+        prop.insert(key.into(), min_length.into());
+    }
+    if let Some(max_length) = def.max_length {
+        let key = if is_array { "maxItems" } else { "maxLength" };
+        prop.insert(key.into(), max_length.into());
+    }
+    if let Some(pattern) = &def.pattern {
+        prop.insert("pattern".into(), pattern.clone().into());
+    }
+
+    serde_json::Value::Object(prop)
+}
+
 // ============================================================================
 // TESTS
 // ============================================================================
@@ -373,6 +1077,32 @@ mod tests {
         assert_eq!(schema.fields["scores"].field_type, FieldType::IntArray);
     }
 
+    #[test]
+    fn test_object_array_becomes_table_array() {
+        let input = r#"{
+            "type": "object",
+            "properties": {
+                "aerzte": {
+                    "type": "array",
+                    "items": {
+                        "type": "object",
+                        "required": ["name"],
+                        "properties": {
+                            "name": { "type": "string" },
+                            "fachgebiet": { "type": "string" }
+                        }
+                    }
+                }
+            }
+        }"#;
+
+        let (schema, _) = convert_json_schema(input).unwrap();
+        assert_eq!(schema.fields["aerzte"].field_type, FieldType::TableArray);
+        let nested = schema.fields["aerzte"].fields.as_ref().unwrap();
+        assert!(nested["name"].required);
+        assert!(!nested["fachgebiet"].required);
+    }
+
     #[test]
     fn test_default_values() {
         let input = r#"{
@@ -385,7 +1115,8 @@ mod tests {
 
         let (schema, _) = convert_json_schema(input).unwrap();
         assert_eq!(schema.fields["country"].default, Some("DE".into()));
-        assert_eq!(schema.fields["count"].default, Some("42".into()));
+        // Numeric defaults stay structured instead of being stringified
+        assert_eq!(schema.fields["count"].default, Some(serde_json::json!(42)));
     }
 
     #[test]
@@ -427,51 +1158,314 @@ mod tests {
     }
 
     #[test]
-    fn test_warning_on_any_of() {
-        let input = r#"{
+    fn test_local_ref_resolved_from_definitions() {
+        let input = r##"{
             "type": "object",
+            "definitions": {
+                "Adresse": {
+                    "type": "object",
+                    "required": ["ort"],
+                    "properties": {
+                        "strasse": { "type": "string" },
+                        "ort": { "type": "string" }
+                    }
+                }
+            },
             "properties": {
-                "value": { "anyOf": [{"type": "string"}, {"type": "integer"}] }
+                "adresse": { "$ref": "#/definitions/Adresse" }
             }
-        }"#;
+        }"##;
 
-        let (_, warnings) = convert_json_schema(input).unwrap();
-        assert!(warnings.iter().any(|w| w.contains("anyOf")));
+        let (schema, warnings) = convert_json_schema(input).unwrap();
+        assert!(warnings.is_empty(), "Got: {:?}", warnings);
+        assert_eq!(schema.fields["adresse"].field_type, FieldType::Table);
+        let nested = schema.fields["adresse"].fields.as_ref().unwrap();
+        assert!(nested["ort"].required);
+        assert!(!nested["strasse"].required);
     }
 
     #[test]
-    fn test_error_on_non_object_root() {
-        let input = r#"{ "type": "string" }"#;
+    fn test_local_ref_resolved_from_defs_keyword() {
+        let input = r##"{
+            "type": "object",
+            "$defs": {
+                "Kontakt": {
+                    "type": "object",
+                    "properties": { "telefon": { "type": "string" } }
+                }
+            },
+            "properties": {
+                "kontakt": { "$ref": "#/$defs/Kontakt" }
+            }
+        }"##;
 
-        let result = convert_json_schema(input);
-        assert!(result.is_err());
-        let err = result.unwrap_err().to_string();
-        assert!(err.contains("object"));
+        let (schema, warnings) = convert_json_schema(input).unwrap();
+        assert!(warnings.is_empty(), "Got: {:?}", warnings);
+        assert_eq!(schema.fields["kontakt"].field_type, FieldType::Table);
     }
 
     #[test]
-    fn test_empty_properties() {
-        let input = r#"{
+    fn test_nested_refs_resolved_transitively() {
+        let input = r##"{
             "type": "object",
-            "properties": {}
-        }"#;
+            "definitions": {
+                "Adresse": {
+                    "type": "object",
+                    "properties": { "geo": { "$ref": "#/definitions/Geo" } }
+                },
+                "Geo": {
+                    "type": "object",
+                    "properties": { "lat": { "type": "number" } }
+                }
+            },
+            "properties": {
+                "adresse": { "$ref": "#/definitions/Adresse" }
+            }
+        }"##;
 
         let (schema, warnings) = convert_json_schema(input).unwrap();
-        assert!(schema.fields.is_empty());
-        assert!(warnings.is_empty());
-    }
-
-    #[test]
-    fn test_is_json_schema_with_dollar_schema() {
-        assert!(is_json_schema(
-            r#"{"$schema": "http://json-schema.org/draft-07/schema#", "type": "object"}"#
-        ));
+        assert!(warnings.is_empty(), "Got: {:?}", warnings);
+        let adresse = schema.fields["adresse"].fields.as_ref().unwrap();
+        assert_eq!(adresse["geo"].field_type, FieldType::Table);
+        let geo = adresse["geo"].fields.as_ref().unwrap();
+        assert_eq!(geo["lat"].field_type, FieldType::Float);
     }
 
     #[test]
-    fn test_is_json_schema_with_type_and_properties() {
-        assert!(is_json_schema(
-            r#"{"type": "object", "properties": {"name": {"type": "string"}}}"#
+    fn test_cyclic_ref_warns_instead_of_hanging() {
+        let input = r##"{
+            "type": "object",
+            "definitions": {
+                "Knoten": {
+                    "type": "object",
+                    "properties": {
+                        "name": { "type": "string" },
+                        "kind": { "$ref": "#/definitions/Knoten" }
+                    }
+                }
+            },
+            "properties": {
+                "wurzel": { "$ref": "#/definitions/Knoten" }
+            }
+        }"##;
+
+        let (schema, warnings) = convert_json_schema(input).unwrap();
+        assert!(warnings.iter().any(|w| w.contains("Cyclic $ref")));
+        // The outer level still resolves, only the cycle point is cut
+        assert_eq!(schema.fields["wurzel"].field_type, FieldType::Table);
+    }
+
+    #[test]
+    fn test_external_ref_still_warned() {
+        let input = r##"{
+            "type": "object",
+            "properties": {
+                "other": { "$ref": "https://example.com/other.schema.json" }
+            }
+        }"##;
+
+        let (_, warnings) = convert_json_schema(input).unwrap();
+        assert!(warnings.iter().any(|w| w.contains("external $ref")));
+    }
+
+    #[test]
+    fn test_file_ref_resolved_relative_to_schema_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("adresse.schema.json"),
+            r##"{
+                "definitions": {
+                    "Adresse": {
+                        "type": "object",
+                        "required": ["ort"],
+                        "properties": {
+                            "strasse": { "type": "string" },
+                            "ort": { "type": "string" }
+                        }
+                    }
+                }
+            }"##,
+        )
+        .unwrap();
+
+        let input = r##"{
+            "type": "object",
+            "properties": {
+                "adresse": { "$ref": "./adresse.schema.json#/definitions/Adresse" }
+            }
+        }"##;
+
+        let (schema, warnings) = convert_json_schema_with_base(input, Some(dir.path())).unwrap();
+        assert!(warnings.is_empty(), "Got: {:?}", warnings);
+        assert_eq!(schema.fields["adresse"].field_type, FieldType::Table);
+        let nested = schema.fields["adresse"].fields.as_ref().unwrap();
+        assert!(nested["ort"].required);
+        assert!(!nested["strasse"].required);
+    }
+
+    #[test]
+    fn test_file_ref_chains_across_files() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("adresse.schema.json"),
+            r##"{
+                "definitions": {
+                    "Adresse": {
+                        "type": "object",
+                        "properties": {
+                            "ort": { "type": "string" },
+                            "geo": { "$ref": "./geo.schema.json#/definitions/Geo" }
+                        }
+                    }
+                }
+            }"##,
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("geo.schema.json"),
+            r##"{
+                "definitions": {
+                    "Geo": {
+                        "type": "object",
+                        "properties": { "lat": { "type": "number" } }
+                    }
+                }
+            }"##,
+        )
+        .unwrap();
+
+        let input = r##"{
+            "type": "object",
+            "properties": {
+                "adresse": { "$ref": "./adresse.schema.json#/definitions/Adresse" }
+            }
+        }"##;
+
+        let (schema, warnings) = convert_json_schema_with_base(input, Some(dir.path())).unwrap();
+        assert!(warnings.is_empty(), "Got: {:?}", warnings);
+        let adresse = schema.fields["adresse"].fields.as_ref().unwrap();
+        let geo = adresse["geo"].fields.as_ref().unwrap();
+        assert_eq!(geo["lat"].field_type, FieldType::Float);
+    }
+
+    #[test]
+    fn test_file_ref_without_base_dir_warns() {
+        let input = r##"{
+            "type": "object",
+            "properties": {
+                "adresse": { "$ref": "./adresse.schema.json#/definitions/Adresse" }
+            }
+        }"##;
+
+        let (_, warnings) = convert_json_schema(input).unwrap();
+        assert!(warnings.iter().any(|w| w.contains("schema file path")));
+    }
+
+    #[test]
+    fn test_cyclic_file_refs_warn_instead_of_hanging() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("a.schema.json"),
+            r##"{
+                "definitions": {
+                    "A": {
+                        "type": "object",
+                        "properties": {
+                            "b": { "$ref": "./b.schema.json#/definitions/B" }
+                        }
+                    }
+                }
+            }"##,
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("b.schema.json"),
+            r##"{
+                "definitions": {
+                    "B": {
+                        "type": "object",
+                        "properties": {
+                            "a": { "$ref": "./a.schema.json#/definitions/A" }
+                        }
+                    }
+                }
+            }"##,
+        )
+        .unwrap();
+
+        let input = r##"{
+            "type": "object",
+            "properties": {
+                "wurzel": { "$ref": "./a.schema.json#/definitions/A" }
+            }
+        }"##;
+
+        let (schema, warnings) = convert_json_schema_with_base(input, Some(dir.path())).unwrap();
+        assert!(warnings.iter().any(|w| w.contains("Cyclic file $ref")));
+        // The outer levels still resolve, only the cycle point is cut
+        assert_eq!(schema.fields["wurzel"].field_type, FieldType::Table);
+    }
+
+    #[test]
+    fn test_missing_file_ref_warns() {
+        let dir = tempfile::tempdir().unwrap();
+        let input = r##"{
+            "type": "object",
+            "properties": {
+                "adresse": { "$ref": "./fehlt.schema.json#/definitions/Adresse" }
+            }
+        }"##;
+
+        let (_, warnings) = convert_json_schema_with_base(input, Some(dir.path())).unwrap();
+        assert!(warnings.iter().any(|w| w.contains("could not be opened")));
+    }
+
+    #[test]
+    fn test_warning_on_any_of() {
+        let input = r#"{
+            "type": "object",
+            "properties": {
+                "value": { "anyOf": [{"type": "string"}, {"type": "integer"}] }
+            }
+        }"#;
+
+        let (_, warnings) = convert_json_schema(input).unwrap();
+        assert!(warnings.iter().any(|w| w.contains("anyOf")));
+    }
+
+    #[test]
+    fn test_error_on_non_object_root() {
+        let input = r#"{ "type": "string" }"#;
+
+        let result = convert_json_schema(input);
+        assert!(result.is_err());
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("object"));
+    }
+
+    #[test]
+    fn test_empty_properties() {
+        let input = r#"{
+            "type": "object",
+            "properties": {}
+        }"#;
+
+        let (schema, warnings) = convert_json_schema(input).unwrap();
+        assert!(schema.fields.is_empty());
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn test_is_json_schema_with_dollar_schema() {
+        assert!(is_json_schema(
+            r#"{"$schema": "http://json-schema.org/draft-07/schema#", "type": "object"}"#
+        ));
+    }
+
+    #[test]
+    fn test_is_json_schema_with_type_and_properties() {
+        assert!(is_json_schema(
+            r#"{"type": "object", "properties": {"name": {"type": "string"}}}"#
         ));
     }
 
@@ -590,22 +1584,63 @@ mod tests {
     }
 
     #[test]
-    fn test_warning_on_enum() {
+    fn test_string_enum_converted() {
         let input = r#"{
             "type": "object",
             "properties": {
                 "status": {
                     "type": "string",
-                    "enum": ["active", "inactive"]
+                    "enum": ["active", "inactive"],
+                    "default": "active"
                 }
             }
         }"#;
 
         let (schema, warnings) = convert_json_schema(input).unwrap();
-        assert_eq!(schema.fields["status"].field_type, FieldType::String);
+        assert_eq!(schema.fields["status"].field_type, FieldType::Enum);
+        assert_eq!(
+            schema.fields["status"].values.as_deref(),
+            Some(&["active".to_string(), "inactive".to_string()][..])
+        );
+        assert_eq!(schema.fields["status"].default, Some("active".into()));
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn test_warning_on_non_string_enum() {
+        let input = r#"{
+            "type": "object",
+            "properties": {
+                "level": {
+                    "type": "integer",
+                    "enum": [1, 2, 3]
+                }
+            }
+        }"#;
+
+        let (schema, warnings) = convert_json_schema(input).unwrap();
+        assert_eq!(schema.fields["level"].field_type, FieldType::Int);
         assert!(warnings.iter().any(|w| w.contains("enum")));
     }
 
+    #[test]
+    fn test_warning_on_empty_enum() {
+        let input = r#"{
+            "type": "object",
+            "properties": {
+                "status": {
+                    "type": "string",
+                    "enum": []
+                }
+            }
+        }"#;
+
+        let (schema, warnings) = convert_json_schema(input).unwrap();
+        assert!(warnings.iter().any(|w| w.contains("empty enum")));
+        // Degrades to free-form string instead of an unfillable enum
+        assert_eq!(schema.fields["status"].field_type, FieldType::String);
+    }
+
     #[test]
     fn test_schema_url_detection() {
         // Has $schema but no "type"+"properties" — should still detect
@@ -641,6 +1676,261 @@ mod tests {
         assert_eq!(schema.fields["things"].field_type, FieldType::StringArray);
     }
 
+    #[test]
+    fn test_descriptions_carried_over() {
+        let input = r#"{
+            "type": "object",
+            "title": "Apotheke",
+            "description": "Apotheken mit Notdienst-Informationen",
+            "properties": {
+                "name": { "type": "string", "description": "Name der Apotheke" },
+                "plz": { "type": "string" }
+            }
+        }"#;
+
+        let (schema, warnings) = convert_json_schema(input).unwrap();
+        assert!(warnings.is_empty());
+        assert_eq!(
+            schema.description.as_deref(),
+            Some("Apotheken mit Notdienst-Informationen")
+        );
+        assert_eq!(
+            schema.fields["name"].description.as_deref(),
+            Some("Name der Apotheke")
+        );
+        assert!(schema.fields["plz"].description.is_none());
+    }
+
+    #[test]
+    fn test_constraint_keywords_mapped() {
+        let input = r#"{
+            "type": "object",
+            "properties": {
+                "plz": { "type": "string", "minLength": 5, "maxLength": 5, "pattern": "[0-9]{5}" },
+                "sterne": { "type": "integer", "minimum": 0, "maximum": 5 },
+                "sprachen": { "type": "array", "items": { "type": "string" }, "minItems": 1, "maxItems": 10 }
+            }
+        }"#;
+
+        let (schema, warnings) = convert_json_schema(input).unwrap();
+        assert!(warnings.is_empty());
+
+        let plz = &schema.fields["plz"];
+        assert_eq!(plz.min_length, Some(5));
+        assert_eq!(plz.max_length, Some(5));
+        assert_eq!(plz.pattern.as_deref(), Some("[0-9]{5}"));
+
+        let sterne = &schema.fields["sterne"];
+        assert_eq!(sterne.min, Some(0.0));
+        assert_eq!(sterne.max, Some(5.0));
+
+        let sprachen = &schema.fields["sprachen"];
+        assert_eq!(sprachen.min_length, Some(1));
+        assert_eq!(sprachen.max_length, Some(10));
+    }
+
+    #[test]
+    fn test_export_basic_shapes() {
+        let input = r##"{
+            "type": "object",
+            "required": ["name"],
+            "properties": {
+                "name": { "type": "string", "maxLength": 100 },
+                "plz": { "type": "string", "pattern": "[0-9]{5}" },
+                "betten": { "type": "integer", "minimum": 0 },
+                "fachrichtungen": {
+                    "type": "array",
+                    "items": { "type": "string" },
+                    "maxItems": 20
+                }
+            }
+        }"##;
+        let (schema, _) = convert_json_schema(input).unwrap();
+
+        let exported = export_json_schema(&schema);
+        assert_eq!(
+            exported["$schema"],
+            "http://json-schema.org/draft-07/schema#"
+        );
+        assert_eq!(exported["required"], serde_json::json!(["name"]));
+        assert_eq!(exported["properties"]["name"]["maxLength"], 100);
+        assert_eq!(exported["properties"]["plz"]["pattern"], "[0-9]{5}");
+        assert_eq!(exported["properties"]["betten"]["minimum"], 0.0);
+        assert_eq!(exported["properties"]["fachrichtungen"]["maxItems"], 20);
+        assert_eq!(
+            exported["properties"]["fachrichtungen"]["items"]["type"],
+            "string"
+        );
+    }
+
+    #[test]
+    fn test_export_round_trips_through_convert() {
+        let input = r##"{
+            "type": "object",
+            "additionalProperties": false,
+            "required": ["name", "adresse"],
+            "properties": {
+                "name": { "type": "string" },
+                "status": { "type": "string", "enum": ["aktiv", "geschlossen"], "default": "aktiv" },
+                "adresse": {
+                    "type": "object",
+                    "required": ["ort"],
+                    "properties": {
+                        "strasse": { "type": "string" },
+                        "ort": { "type": "string" }
+                    }
+                },
+                "abteilungen": {
+                    "type": "array",
+                    "items": {
+                        "type": "object",
+                        "properties": { "bezeichnung": { "type": "string" } }
+                    }
+                }
+            }
+        }"##;
+        let (original, _) = convert_json_schema(input).unwrap();
+
+        let exported = serde_json::to_string(&export_json_schema(&original)).unwrap();
+        let (reimported, warnings) = convert_json_schema(&exported).unwrap();
+
+        assert!(warnings.is_empty(), "Got: {:?}", warnings);
+        assert_eq!(
+            serde_json::to_value(&original).unwrap(),
+            serde_json::to_value(&reimported).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_additional_properties_false_enables_strict() {
+        let input = r#"{
+            "type": "object",
+            "additionalProperties": false,
+            "properties": {
+                "name": { "type": "string" },
+                "adresse": {
+                    "type": "object",
+                    "additionalProperties": false,
+                    "properties": {
+                        "ort": { "type": "string" }
+                    }
+                }
+            }
+        }"#;
+
+        let (schema, warnings) = convert_json_schema(input).unwrap();
+        assert!(warnings.is_empty(), "Got: {:?}", warnings);
+        assert!(schema.strict);
+        assert!(schema.fields["adresse"].strict);
+        assert!(!schema.fields["name"].strict);
+    }
+
+    #[test]
+    fn test_strict_items_carry_over_to_table_arrays() {
+        let input = r#"{
+            "type": "object",
+            "properties": {
+                "abteilungen": {
+                    "type": "array",
+                    "items": {
+                        "type": "object",
+                        "additionalProperties": false,
+                        "properties": {
+                            "bezeichnung": { "type": "string" }
+                        }
+                    }
+                }
+            }
+        }"#;
+
+        let (schema, warnings) = convert_json_schema(input).unwrap();
+        assert!(warnings.is_empty(), "Got: {:?}", warnings);
+        let abteilungen = &schema.fields["abteilungen"];
+        assert_eq!(abteilungen.field_type, FieldType::TableArray);
+        assert!(abteilungen.strict);
+    }
+
+    #[test]
+    fn test_additional_properties_schema_warns() {
+        let input = r#"{
+            "type": "object",
+            "additionalProperties": { "type": "string" },
+            "properties": {
+                "name": { "type": "string" }
+            }
+        }"#;
+
+        let (schema, warnings) = convert_json_schema(input).unwrap();
+        assert!(warnings.iter().any(|w| w.contains("additionalProperties")));
+        assert!(!schema.strict);
+    }
+
+    #[test]
+    fn test_format_mapped_to_typed_fields() {
+        let input = r#"{
+            "type": "object",
+            "properties": {
+                "geburtsdatum": { "type": "string", "format": "date" },
+                "geaendert_am": { "type": "string", "format": "date-time" },
+                "kontakt_email": { "type": "string", "format": "email" },
+                "webseite": { "type": "string", "format": "uri" },
+                "einrichtungs_id": { "type": "string", "format": "uuid" }
+            }
+        }"#;
+
+        let (schema, warnings) = convert_json_schema(input).unwrap();
+        assert!(warnings.is_empty(), "Got: {:?}", warnings);
+        assert_eq!(
+            schema.fields["geburtsdatum"].field_type,
+            FieldType::Custom("date".into())
+        );
+        assert_eq!(
+            schema.fields["geaendert_am"].field_type,
+            FieldType::Custom("datetime".into())
+        );
+        assert_eq!(
+            schema.fields["kontakt_email"].field_type,
+            FieldType::Custom("email".into())
+        );
+        assert_eq!(
+            schema.fields["webseite"].field_type,
+            FieldType::Custom("url".into())
+        );
+        assert_eq!(schema.fields["einrichtungs_id"].field_type, FieldType::Uuid);
+    }
+
+    #[test]
+    fn test_unknown_format_warns_and_stays_string() {
+        let input = r#"{
+            "type": "object",
+            "properties": {
+                "host": { "type": "string", "format": "hostname" }
+            }
+        }"#;
+
+        let (schema, warnings) = convert_json_schema(input).unwrap();
+        assert!(warnings.iter().any(|w| w.contains("hostname")));
+        assert_eq!(schema.fields["host"].field_type, FieldType::String);
+    }
+
+    #[test]
+    fn test_warning_on_exclusive_bounds() {
+        let input = r#"{
+            "type": "object",
+            "properties": {
+                "preis": {
+                    "type": "number",
+                    "exclusiveMinimum": 0
+                }
+            }
+        }"#;
+
+        let (schema, warnings) = convert_json_schema(input).unwrap();
+        assert!(warnings.iter().any(|w| w.contains("exclusiveMinimum")));
+        // The representable constraints are unaffected
+        assert!(schema.fields["preis"].min.is_none());
+    }
+
     #[test]
     fn test_warning_on_one_of() {
         let input = r#"{
@@ -654,6 +1944,78 @@ mod tests {
         assert!(warnings.iter().any(|w| w.contains("oneOf")));
     }
 
+    #[test]
+    fn test_one_of_objects_converted_to_union() {
+        let input = r##"{
+            "type": "object",
+            "properties": {
+                "traeger": {
+                    "oneOf": [
+                        {
+                            "title": "Person",
+                            "type": "object",
+                            "required": ["nachname"],
+                            "properties": {
+                                "vorname": { "type": "string" },
+                                "nachname": { "type": "string" }
+                            }
+                        },
+                        {
+                            "title": "Firma",
+                            "type": "object",
+                            "properties": {
+                                "firmenname": { "type": "string" }
+                            }
+                        }
+                    ]
+                }
+            }
+        }"##;
+
+        let (schema, warnings) = convert_json_schema(input).unwrap();
+        assert!(warnings.is_empty(), "Got: {:?}", warnings);
+        let traeger = &schema.fields["traeger"];
+        assert_eq!(traeger.field_type, FieldType::Union);
+        let variants = traeger.fields.as_ref().unwrap();
+        assert_eq!(variants["person"].field_type, FieldType::Table);
+        assert!(variants["person"].fields.as_ref().unwrap()["nachname"].required);
+        assert_eq!(variants["firma"].field_type, FieldType::Table);
+    }
+
+    #[test]
+    fn test_one_of_discriminator_names_variants() {
+        let input = r##"{
+            "type": "object",
+            "properties": {
+                "abrechnung": {
+                    "discriminator": { "propertyName": "art" },
+                    "oneOf": [
+                        {
+                            "type": "object",
+                            "properties": {
+                                "art": { "const": "privat" },
+                                "tarif": { "type": "string" }
+                            }
+                        },
+                        {
+                            "type": "object",
+                            "properties": {
+                                "art": { "const": "kasse" },
+                                "kassen_ik": { "type": "string" }
+                            }
+                        }
+                    ]
+                }
+            }
+        }"##;
+
+        let (schema, warnings) = convert_json_schema(input).unwrap();
+        assert!(warnings.is_empty(), "Got: {:?}", warnings);
+        let variants = schema.fields["abrechnung"].fields.as_ref().unwrap();
+        assert!(variants.contains_key("privat"));
+        assert!(variants.contains_key("kasse"));
+    }
+
     #[test]
     fn test_warning_on_all_of() {
         let input = r#"{
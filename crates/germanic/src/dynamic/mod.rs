@@ -13,9 +13,12 @@
 //! ```
 
 pub mod builder;
+pub mod compat;
 pub mod infer;
 pub mod json_schema;
+pub mod openapi;
 pub mod schema_def;
+pub mod schema_org;
 pub mod validate;
 
 use crate::error::{GermanicError, GermanicResult};
@@ -39,41 +42,131 @@ use std::path::Path;
 ///
 /// `(grm_bytes, warnings)` — warnings list unsupported JSON Schema features.
 pub fn compile_dynamic(schema_path: &Path, data_path: &Path) -> GermanicResult<Vec<u8>> {
+    compile_dynamic_with_lang(schema_path, data_path, None)
+}
+
+/// Options for one dynamic compile run.
+///
+/// Grows with the compile flags; `..Default::default()` keeps call sites
+/// stable when new options are added.
+#[derive(Debug, Clone, Default)]
+pub struct CompileOptions {
+    /// BCP-47 language tag stamped into the header (e.g. "de-DE").
+    pub language: Option<String>,
+    /// Deduplicate identical strings in the FlatBuffer payload.
+    ///
+    /// Worth it for multi-record datasets that repeat city names or
+    /// specialties; costs a hash lookup per string.
+    pub dedup_strings: bool,
+}
+
+/// Like [`compile_dynamic`], but stamps a BCP-47 language tag into the header.
+///
+/// Used by `germanic compile --lang de-DE` so that agents can pick the
+/// right variant when a site publishes the same data in multiple languages.
+pub fn compile_dynamic_with_lang(
+    schema_path: &Path,
+    data_path: &Path,
+    language: Option<&str>,
+) -> GermanicResult<Vec<u8>> {
+    compile_dynamic_with_options(
+        schema_path,
+        data_path,
+        &CompileOptions {
+            language: language.map(String::from),
+            ..Default::default()
+        },
+    )
+}
+
+/// Like [`compile_dynamic`], but with explicit [`CompileOptions`].
+pub fn compile_dynamic_with_options(
+    schema_path: &Path,
+    data_path: &Path,
+    options: &CompileOptions,
+) -> GermanicResult<Vec<u8>> {
+    Ok(compile_dynamic_with_report(schema_path, data_path, options)?.bytes)
+}
+
+/// Outcome of one dynamic compile run: the bytes plus every diagnostic
+/// collected along the way.
+///
+/// The plain `compile_dynamic` entry points drop the warnings; library
+/// users who want the same diagnostics the CLI prints (JSON Schema
+/// conversion notes, deprecations, alias lifts) use this instead.
+#[derive(Debug, Clone)]
+pub struct CompilationReport {
+    /// The finished .grm bytes (header + payload).
+    pub bytes: Vec<u8>,
+    /// Conversion, deprecation and alias warnings, in pipeline order.
+    pub warnings: Vec<String>,
+    /// Size figures for logging and compression bookkeeping.
+    pub stats: CompileStats,
+}
+
+/// Size figures for one compile run.
+#[derive(Debug, Clone, Copy)]
+pub struct CompileStats {
+    /// Raw JSON input size in bytes.
+    pub input_size: usize,
+    /// Finished .grm size in bytes (header + payload).
+    pub output_size: usize,
+    /// Number of top-level fields in the schema.
+    pub field_count: usize,
+}
+
+/// Like [`compile_dynamic_with_options`], but returns a
+/// [`CompilationReport`] instead of discarding the warnings.
+pub fn compile_dynamic_with_report(
+    schema_path: &Path,
+    data_path: &Path,
+    options: &CompileOptions,
+) -> GermanicResult<CompilationReport> {
     // 1. Load schema (auto-detect JSON Schema Draft 7 vs GERMANIC native)
-    let (schema, _warnings) = load_schema_auto(schema_path)?;
+    let (schema, mut warnings) = load_schema_auto(schema_path)?;
 
     // 2. Load data (size check BEFORE parsing to avoid DoS via huge files)
+    let limits = schema.effective_limits();
     let json_str = std::fs::read_to_string(data_path)?;
-    if json_str.len() > crate::pre_validate::MAX_INPUT_SIZE {
+    if json_str.len() > limits.max_input_size {
         return Err(GermanicError::General(format!(
             "input size {} bytes exceeds maximum of {} bytes",
             json_str.len(),
-            crate::pre_validate::MAX_INPUT_SIZE
+            limits.max_input_size
         )));
     }
     let data: serde_json::Value = serde_json::from_str(&json_str)?;
 
     // 3. Pre-validate structural limits (string length, array size, nesting depth)
-    crate::pre_validate::pre_validate(&json_str, &data)
+    crate::pre_validate::pre_validate_with_limits(&json_str, &data, &limits)
         .map_err(|errors| GermanicError::General(errors.join("; ")))?;
 
-    // 4. Validate against schema
+    // 4. Lift alias keys to canonical names, then validate against schema
+    let (data, alias_warnings) = schema.apply_aliases(&data);
+    warnings.extend(alias_warnings);
     validate::validate_against_schema(&schema, &data).map_err(GermanicError::Validation)?;
+    warnings.extend(validate::validation_warnings(&schema, &data));
 
     // 5. Build FlatBuffer
-    let payload = builder::build_flatbuffer(&schema, &data)?;
+    let payload = if options.dedup_strings {
+        builder::build_flatbuffer_deduped(&schema, &data)?
+    } else {
+        builder::build_flatbuffer(&schema, &data)?
+    };
 
     // 6. Prepend header
-    let header = GrmHeader::new(&schema.schema_id);
-    let header_bytes = header
-        .to_bytes()
-        .map_err(|e| GermanicError::General(e.to_string()))?;
-
-    let mut output = Vec::with_capacity(header_bytes.len() + payload.len());
-    output.extend_from_slice(&header_bytes);
-    output.extend_from_slice(&payload);
+    let bytes = wrap_payload(&schema.schema_id, options.language.as_deref(), payload)?;
 
-    Ok(output)
+    let stats = CompileStats {
+        input_size: json_str.len(),
+        output_size: bytes.len(),
+        field_count: schema.field_count(),
+    };
+    Ok(CompilationReport {
+        bytes,
+        warnings,
+        stats,
+    })
 }
 
 /// Compiles JSON data to .grm using a schema definition (in-memory).
@@ -83,18 +176,131 @@ pub fn compile_dynamic_from_values(
     schema: &schema_def::SchemaDefinition,
     data: &serde_json::Value,
 ) -> GermanicResult<Vec<u8>> {
+    compile_dynamic_from_values_with_lang(schema, data, None)
+}
+
+/// Like [`compile_dynamic_from_values`], but stamps a BCP-47 language tag
+/// into the header.
+pub fn compile_dynamic_from_values_with_lang(
+    schema: &schema_def::SchemaDefinition,
+    data: &serde_json::Value,
+    language: Option<&str>,
+) -> GermanicResult<Vec<u8>> {
+    compile_dynamic_from_values_with_options(
+        schema,
+        data,
+        &CompileOptions {
+            language: language.map(String::from),
+            ..Default::default()
+        },
+    )
+}
+
+/// Like [`compile_dynamic_from_values`], but with explicit [`CompileOptions`].
+pub fn compile_dynamic_from_values_with_options(
+    schema: &schema_def::SchemaDefinition,
+    data: &serde_json::Value,
+    options: &CompileOptions,
+) -> GermanicResult<Vec<u8>> {
+    let mut output = Vec::new();
+    compile_dynamic_to_writer_with_options(schema, data, &mut output, options)?;
+    Ok(output)
+}
+
+/// Compiles JSON data to .grm from in-memory strings (no filesystem).
+///
+/// Web services and the MCP server already hold schema and data in
+/// memory — this skips the temp-file dance the `Path` entry points
+/// would force on them. Schema format auto-detection works the same
+/// as for files.
+pub fn compile_dynamic_from_str(schema_json: &str, data_json: &str) -> GermanicResult<Vec<u8>> {
+    compile_dynamic_from_str_with_options(schema_json, data_json, &CompileOptions::default())
+}
+
+/// Like [`compile_dynamic_from_str`], but with explicit [`CompileOptions`].
+pub fn compile_dynamic_from_str_with_options(
+    schema_json: &str,
+    data_json: &str,
+    options: &CompileOptions,
+) -> GermanicResult<Vec<u8>> {
+    let (schema, _warnings) = parse_schema_auto(schema_json)?;
+
+    // Raw size check BEFORE parsing, same as the file-based path
+    let limits = schema.effective_limits();
+    if data_json.len() > limits.max_input_size {
+        return Err(GermanicError::General(format!(
+            "input size {} bytes exceeds maximum of {} bytes",
+            data_json.len(),
+            limits.max_input_size
+        )));
+    }
+    let data: serde_json::Value = serde_json::from_str(data_json)?;
+    crate::pre_validate::pre_validate_with_limits(data_json, &data, &limits)
+        .map_err(|errors| GermanicError::General(errors.join("; ")))?;
+
+    compile_dynamic_from_values_with_options(&schema, &data, options)
+}
+
+/// Like [`compile_dynamic_from_values`], but streams header and payload
+/// straight into a writer.
+///
+/// Skips the intermediate Vec that concatenates header and payload —
+/// worth it when the bytes go directly to a file or socket anyway.
+pub fn compile_dynamic_to_writer<W: std::io::Write>(
+    schema: &schema_def::SchemaDefinition,
+    data: &serde_json::Value,
+    writer: &mut W,
+) -> GermanicResult<()> {
+    compile_dynamic_to_writer_with_options(schema, data, writer, &CompileOptions::default())
+}
+
+/// Like [`compile_dynamic_to_writer`], but with explicit [`CompileOptions`].
+pub fn compile_dynamic_to_writer_with_options<W: std::io::Write>(
+    schema: &schema_def::SchemaDefinition,
+    data: &serde_json::Value,
+    writer: &mut W,
+    options: &CompileOptions,
+) -> GermanicResult<()> {
     // 1. Pre-validate structural limits (string length, array size, nesting depth)
-    crate::pre_validate::pre_validate_value(data)
+    crate::pre_validate::pre_validate_value_with_limits(data, &schema.effective_limits())
         .map_err(|errors| GermanicError::General(errors.join("; ")))?;
 
-    // 2. Validate against schema
-    validate::validate_against_schema(schema, data).map_err(GermanicError::Validation)?;
+    // 2. Lift alias keys to canonical names, then validate against schema
+    let (data, _alias_warnings) = schema.apply_aliases(data);
+    validate::validate_against_schema(schema, &data).map_err(GermanicError::Validation)?;
 
     // 3. Build FlatBuffer
-    let payload = builder::build_flatbuffer(schema, data)?;
+    let payload = if options.dedup_strings {
+        builder::build_flatbuffer_deduped(schema, &data)?
+    } else {
+        builder::build_flatbuffer(schema, &data)?
+    };
+
+    // 4. Write header, then payload
+    let mut header = GrmHeader::new(&schema.schema_id);
+    if let Some(lang) = options.language.as_deref() {
+        header = header.with_language(lang);
+    }
+    let header_bytes = header
+        .to_bytes()
+        .map_err(|e| GermanicError::General(e.to_string()))?;
+
+    writer.write_all(&header_bytes)?;
+    writer.write_all(&payload)?;
 
-    // 4. Prepend header
-    let header = GrmHeader::new(&schema.schema_id);
+    Ok(())
+}
+
+/// Prepends the .grm header (with optional language tag) to a FlatBuffer payload.
+fn wrap_payload(
+    schema_id: &str,
+    language: Option<&str>,
+    payload: Vec<u8>,
+) -> GermanicResult<Vec<u8>> {
+    let mut header = GrmHeader::new(schema_id);
+    if let Some(lang) = language {
+        header = header.with_language(lang);
+    }
     let header_bytes = header
         .to_bytes()
         .map_err(|e| GermanicError::General(e.to_string()))?;
@@ -115,11 +321,103 @@ pub fn load_schema_auto(
     schema_path: &Path,
 ) -> GermanicResult<(schema_def::SchemaDefinition, Vec<String>)> {
     let content = std::fs::read_to_string(schema_path)?;
+    // The schema's own directory anchors relative file $refs
+    parse_schema_auto_with_base(&content, schema_path.parent())
+}
+
+/// Like [`load_schema_auto`], but for schema content already in memory.
+///
+/// Without a file path there is no base directory, so relative file
+/// `$ref`s produce a warning instead of resolving.
+pub fn parse_schema_auto(
+    content: &str,
+) -> GermanicResult<(schema_def::SchemaDefinition, Vec<String>)> {
+    parse_schema_auto_with_base(content, None)
+}
 
-    if json_schema::is_json_schema(&content) {
-        json_schema::convert_json_schema(&content)
+/// Like [`parse_schema_auto`], but resolves file `$ref`s against `base_dir`.
+pub fn parse_schema_auto_with_base(
+    content: &str,
+    base_dir: Option<&Path>,
+) -> GermanicResult<(schema_def::SchemaDefinition, Vec<String>)> {
+    let (schema, mut warnings) = if json_schema::is_json_schema(content) {
+        json_schema::convert_json_schema_with_base(content, base_dir)?
     } else {
-        let schema: schema_def::SchemaDefinition = serde_json::from_str(&content)?;
-        Ok((schema, Vec::new()))
+        let schema: schema_def::SchemaDefinition = serde_json::from_str(content)?;
+        (schema, Vec::new())
+    };
+
+    // Reject layouts the builder cannot represent (too wide, too deep)
+    schema.check_layout()?;
+
+    warnings.extend(schema.deprecation_warnings());
+    Ok((schema, warnings))
+}
+
+// ============================================================================
+// TESTS
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report_carries_warnings_and_stats() {
+        let dir = tempfile::tempdir().unwrap();
+        let schema_path = dir.path().join("praxis.schema.json");
+        let data_path = dir.path().join("praxis.json");
+
+        std::fs::write(
+            &schema_path,
+            r#"{
+                "schema_id": "test.v1",
+                "version": 1,
+                "fields": {
+                    "name": { "type": "string", "required": true },
+                    "fax": { "type": "string", "deprecated": true, "replaced_by": "telefon" },
+                    "telefon": { "type": "string", "aliases": ["phone"] }
+                }
+            }"#,
+        )
+        .unwrap();
+        std::fs::write(
+            &data_path,
+            r#"{ "name": "Praxis Sonnenschein", "phone": "+49 123 456" }"#,
+        )
+        .unwrap();
+
+        let report =
+            compile_dynamic_with_report(&schema_path, &data_path, &CompileOptions::default())
+                .unwrap();
+
+        assert!(!report.bytes.is_empty());
+        assert!(report.warnings.iter().any(|w| w.contains("deprecated")));
+        assert!(report.warnings.iter().any(|w| w.contains("alias")));
+        assert_eq!(report.stats.field_count, 3);
+        assert_eq!(report.stats.output_size, report.bytes.len());
+        assert!(report.stats.input_size > 0);
+    }
+
+    #[test]
+    fn test_compile_from_str_matches_file_based_compile() {
+        let schema_json = r#"{
+            "schema_id": "test.v1",
+            "version": 1,
+            "fields": {
+                "name": { "type": "string", "required": true }
+            }
+        }"#;
+        let data_json = r#"{ "name": "Praxis Sonnenschein" }"#;
+
+        let dir = tempfile::tempdir().unwrap();
+        let schema_path = dir.path().join("test.schema.json");
+        let data_path = dir.path().join("test.json");
+        std::fs::write(&schema_path, schema_json).unwrap();
+        std::fs::write(&data_path, data_json).unwrap();
+
+        let from_str = compile_dynamic_from_str(schema_json, data_json).unwrap();
+        let from_file = compile_dynamic(&schema_path, &data_path).unwrap();
+        assert_eq!(from_str, from_file);
     }
 }
@@ -0,0 +1,195 @@
+//! # OpenAPI 3 Component Import
+//!
+//! Extracts one named component schema from an OpenAPI 3 document and
+//! converts it to a [`SchemaDefinition`]. Many backends already describe
+//! their export format in OpenAPI — this is the third "entry door" next
+//! to the native format and plain JSON Schema.
+//!
+//! ```text
+//!   openapi.yaml ──► components.schemas.Praxis ──► JSON Schema adapter
+//!                          │                              │
+//!                          └── sibling components ──► definitions
+//! ```
+//!
+//! The heavy lifting stays in [`super::json_schema`]: the extracted
+//! component becomes the root document, its sibling components become
+//! `definitions`, and `#/components/schemas/X` references are rewritten
+//! to local `#/definitions/X` so the existing inliner resolves them.
+
+use crate::error::GermanicError;
+
+use super::json_schema;
+use super::schema_def::SchemaDefinition;
+
+/// Converts one component schema of an OpenAPI 3 document (YAML or JSON).
+///
+/// Returns the converted definition plus the adapter's warnings. Errors
+/// if the document is not OpenAPI 3, has no `components.schemas`, or
+/// the named component does not exist (the message lists what does).
+pub fn convert_openapi_component(
+    input: &str,
+    component: &str,
+) -> Result<(SchemaDefinition, Vec<String>), GermanicError> {
+    // YAML is a superset of JSON, so one parser covers .yaml and .json
+    let doc: serde_json::Value = serde_yaml::from_str(input)
+        .map_err(|e| GermanicError::General(format!("Not a valid OpenAPI document: {}", e)))?;
+
+    let version_ok = doc
+        .get("openapi")
+        .and_then(|v| v.as_str())
+        .is_some_and(|v| v.starts_with('3'));
+    if !version_ok {
+        return Err(GermanicError::General(
+            "Missing or unsupported \"openapi\" version — only OpenAPI 3.x documents are supported"
+                .into(),
+        ));
+    }
+
+    let Some(schemas) = doc
+        .pointer("/components/schemas")
+        .and_then(|v| v.as_object())
+    else {
+        return Err(GermanicError::General(
+            "Document has no components.schemas section".into(),
+        ));
+    };
+
+    let Some(target) = schemas.get(component) else {
+        let mut available: Vec<&str> = schemas.keys().map(String::as_str).collect();
+        available.sort_unstable();
+        return Err(GermanicError::General(format!(
+            "Component \"{}\" not found — available: [{}]",
+            component,
+            available.join(", ")
+        )));
+    };
+
+    let mut root = target.clone();
+    rewrite_component_refs(&mut root);
+
+    let mut definitions = serde_json::Map::new();
+    for (name, body) in schemas {
+        if name != component {
+            let mut definition = body.clone();
+            rewrite_component_refs(&mut definition);
+            definitions.insert(name.clone(), definition);
+        }
+    }
+
+    if let Some(obj) = root.as_object_mut() {
+        // The component name becomes the schema_id (via the title rule)
+        obj.entry("title").or_insert_with(|| component.into());
+        if !definitions.is_empty() {
+            obj.insert(
+                "definitions".into(),
+                serde_json::Value::Object(definitions),
+            );
+        }
+    }
+
+    json_schema::convert_json_schema(&serde_json::to_string(&root)?)
+}
+
+/// Rewrites `#/components/schemas/X` references to local `#/definitions/X`.
+fn rewrite_component_refs(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(map) => {
+            if let Some(reference) = map.get_mut("$ref") {
+                if let Some(name) = reference
+                    .as_str()
+                    .and_then(|s| s.strip_prefix("#/components/schemas/"))
+                {
+                    *reference = format!("#/definitions/{}", name).into();
+                }
+            }
+            for nested in map.values_mut() {
+                rewrite_component_refs(nested);
+            }
+        }
+        serde_json::Value::Array(arr) => {
+            for element in arr {
+                rewrite_component_refs(element);
+            }
+        }
+        _ => {}
+    }
+}
+
+// ============================================================================
+// TESTS
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dynamic::schema_def::FieldType;
+
+    const OPENAPI_DOC: &str = r##"
+openapi: "3.0.3"
+info:
+  title: Praxisverzeichnis API
+  version: "1.0"
+paths: {}
+components:
+  schemas:
+    Praxis:
+      type: object
+      required: [name]
+      properties:
+        name:
+          type: string
+        adresse:
+          $ref: "#/components/schemas/Adresse"
+    Adresse:
+      type: object
+      required: [ort]
+      properties:
+        strasse:
+          type: string
+        ort:
+          type: string
+"##;
+
+    #[test]
+    fn test_component_extracted_and_converted() {
+        let (schema, warnings) = convert_openapi_component(OPENAPI_DOC, "Praxis").unwrap();
+        assert!(warnings.is_empty(), "Got: {:?}", warnings);
+        assert_eq!(schema.schema_id, "praxis");
+        assert!(schema.fields["name"].required);
+        assert_eq!(schema.fields["adresse"].field_type, FieldType::Table);
+        let adresse = schema.fields["adresse"].fields.as_ref().unwrap();
+        assert!(adresse["ort"].required);
+    }
+
+    #[test]
+    fn test_unknown_component_lists_available() {
+        let err = convert_openapi_component(OPENAPI_DOC, "Klinik").unwrap_err();
+        let message = err.to_string();
+        assert!(message.contains("Klinik"));
+        assert!(message.contains("Adresse"));
+        assert!(message.contains("Praxis"));
+    }
+
+    #[test]
+    fn test_non_openapi_document_rejected() {
+        let err = convert_openapi_component(r#"{"swagger": "2.0"}"#, "Praxis").unwrap_err();
+        assert!(err.to_string().contains("OpenAPI 3"));
+    }
+
+    #[test]
+    fn test_json_documents_accepted_too() {
+        let input = r##"{
+            "openapi": "3.1.0",
+            "components": {
+                "schemas": {
+                    "Kontakt": {
+                        "type": "object",
+                        "properties": { "telefon": { "type": "string" } }
+                    }
+                }
+            }
+        }"##;
+        let (schema, _) = convert_openapi_component(input, "Kontakt").unwrap();
+        assert_eq!(schema.schema_id, "kontakt");
+    }
+}
@@ -39,11 +39,47 @@ pub struct SchemaDefinition {
     /// Schema version (1-255).
     pub version: u8,
 
+    /// Human context for the whole schema ("Arztpraxis mit ...").
+    /// Carried through serialization, convert and docs generation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Per-schema overrides of the structural limits, clamped to the
+    /// absolute caps in [`crate::pre_validate`]. A hospital feed with
+    /// 15,000 array entries raises `max_array_elements` here instead of
+    /// patching the global constants.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limits: Option<SchemaLimits>,
+
+    /// Rejects unknown top-level keys in the input instead of silently
+    /// dropping them (JSON Schema `additionalProperties: false`).
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub strict: bool,
+
     /// Ordered map of field name → field definition.
     /// ORDER MATTERS: field position determines FlatBuffer vtable slot.
     pub fields: IndexMap<String, FieldDefinition>,
 }
 
+/// Per-schema structural limit overrides (the `limits` block).
+///
+/// Unset values fall back to the global defaults; set values are clamped
+/// to the absolute caps so a schema cannot disable the protection.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SchemaLimits {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_input_size: Option<usize>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_string_length: Option<usize>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_array_elements: Option<usize>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_nesting_depth: Option<usize>,
+}
+
 /// Definition of a single field within a schema.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FieldDefinition {
@@ -51,51 +87,345 @@ pub struct FieldDefinition {
     #[serde(rename = "type")]
     pub field_type: FieldType,
 
+    /// Explicit vtable slot (like the FlatBuffers `id` attribute).
+    /// Either every field of a table sets an id (unique, contiguous
+    /// from 0) or none does; without ids the slot is the field's
+    /// position, so inserting a field mid-schema shifts all later slots.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<u16>,
+
+    /// Human context for this field, surfaced in docs and generated code.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
     /// Whether this field is required (must be non-empty).
     #[serde(default)]
     pub required: bool,
 
-    /// Default value as JSON string (e.g. "DE", "true", "42").
+    /// Marks the field as deprecated. Compilation still accepts it but
+    /// emits a warning; docs and generated code flag it.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub deprecated: bool,
+
+    /// Suggested replacement for a deprecated field.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub replaced_by: Option<String>,
+
+    /// Alternate JSON keys accepted for this field ("tel", "phone").
+    /// Input using an alias is lifted to the canonical name before
+    /// validation and compilation, with a warning per use.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub aliases: Option<Vec<String>>,
+
+    /// Marks the field as personally identifiable. `compile --redact-pii`
+    /// (or [`crate::anonymize::redact_pii_value`]) hashes or blanks it so
+    /// a public variant can be published from the same data.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub pii: bool,
+
+    /// Default value applied when the field is absent. Scalars accept
+    /// either the native JSON value (`true`, `42`) or its string form
+    /// (`"true"`, `"42"`, kept for older schemas); arrays and tables
+    /// take the structured JSON value directly.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<serde_json::Value>,
+
+    /// Allowed values (only for FieldType::Enum).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub values: Option<Vec<String>>,
+
+    /// Maximum decoded size in bytes (only for FieldType::Bytes).
+    /// Defaults to the global string limit when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_size: Option<usize>,
+
+    /// Minimum numeric value (inclusive, for int/float fields).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min: Option<f64>,
+
+    /// Maximum numeric value (inclusive, for int/float fields).
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub default: Option<String>,
+    pub max: Option<f64>,
+
+    /// Minimum length in characters (strings) or elements (arrays).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_length: Option<usize>,
+
+    /// Maximum length in characters (strings) or elements (arrays).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_length: Option<usize>,
+
+    /// Regular expression the full string value must match.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pattern: Option<String>,
+
+    /// Rejects unknown keys inside this table instead of silently
+    /// dropping them (only for FieldType::Table and FieldType::TableArray).
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub strict: bool,
 
-    /// Nested fields (only for FieldType::Table).
+    /// Nested fields (only for FieldType::Table and FieldType::TableArray).
+    /// For FieldType::Union this maps variant name → variant table definition.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub fields: Option<IndexMap<String, FieldDefinition>>,
 }
 
+impl FieldDefinition {
+    /// A bare field of the given type: optional, no constraints, no
+    /// metadata. Callers set what they need afterwards — equivalent to
+    /// the `.schema.json` shorthand `{ "type": "string" }`.
+    pub fn new(field_type: FieldType) -> Self {
+        FieldDefinition {
+            field_type,
+            id: None,
+            description: None,
+            required: false,
+            deprecated: false,
+            replaced_by: None,
+            aliases: None,
+            pii: false,
+            default: None,
+            values: None,
+            max_size: None,
+            min: None,
+            max: None,
+            min_length: None,
+            max_length: None,
+            pattern: None,
+            strict: false,
+            fields: None,
+        }
+    }
+}
+
 /// Supported field types for dynamic schemas.
 ///
-/// Maps directly to FlatBuffer scalar/offset types.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+/// Maps directly to FlatBuffer scalar/offset types. Any type name that is
+/// not built in is parsed as [`FieldType::Custom`] and resolved against
+/// the plugin registry ([`crate::plugin`]) at validation/build time.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum FieldType {
     /// UTF-8 string → FlatBuffer string offset
-    #[serde(rename = "string")]
     String,
 
     /// Boolean → FlatBuffer bool (1 byte)
-    #[serde(rename = "bool")]
     Bool,
 
     /// 32-bit signed integer → FlatBuffer int32
-    #[serde(rename = "int")]
     Int,
 
     /// 32-bit float → FlatBuffer float32
-    #[serde(rename = "float")]
     Float,
 
     /// Vector of strings → FlatBuffer vector of string offsets
-    #[serde(rename = "[string]")]
     StringArray,
 
     /// Vector of integers → FlatBuffer vector of int32
-    #[serde(rename = "[int]")]
     IntArray,
 
+    /// Vector of booleans → FlatBuffer vector of bool (1 byte each)
+    BoolArray,
+
+    /// Raw byte blob → FlatBuffer vector of uint8.
+    /// JSON representation is a base64 string (see [`crate::base64`]).
+    Bytes,
+
+    /// Canonical UUID → FlatBuffer vector of 16 raw bytes.
+    /// JSON representation is the 36-char hyphenated string.
+    Uuid,
+
     /// Nested table → FlatBuffer table offset
-    #[serde(rename = "table")]
     Table,
+
+    /// Vector of nested tables → FlatBuffer vector of table offsets
+    TableArray,
+
+    /// Closed set of string values → FlatBuffer string offset.
+    /// Allowed values live in [`FieldDefinition::values`].
+    Enum,
+
+    /// Tagged union of table shapes → same wire layout as Table, with one
+    /// vtable slot per variant and exactly one populated. JSON form is a
+    /// single-key object: `{ "person": { ... } }`. Variants live in
+    /// [`FieldDefinition::fields`].
+    Union,
+
+    /// Plugin-provided type (wire format: string).
+    /// The name references a registered [`crate::plugin::FieldTypePlugin`].
+    Custom(String),
+}
+
+impl FieldType {
+    /// The type name as written in `.schema.json`.
+    pub fn name(&self) -> &str {
+        match self {
+            FieldType::String => "string",
+            FieldType::Bool => "bool",
+            FieldType::Int => "int",
+            FieldType::Float => "float",
+            FieldType::StringArray => "[string]",
+            FieldType::IntArray => "[int]",
+            FieldType::BoolArray => "[bool]",
+            FieldType::Bytes => "bytes",
+            FieldType::Uuid => "uuid",
+            FieldType::Table => "table",
+            FieldType::TableArray => "[table]",
+            FieldType::Enum => "enum",
+            FieldType::Union => "union",
+            FieldType::Custom(name) => name,
+        }
+    }
+
+    /// Parses a type name; unknown names become [`FieldType::Custom`].
+    pub fn from_name(name: &str) -> Self {
+        match name {
+            "string" => FieldType::String,
+            "bool" => FieldType::Bool,
+            "int" => FieldType::Int,
+            "float" => FieldType::Float,
+            "[string]" => FieldType::StringArray,
+            "[int]" => FieldType::IntArray,
+            "[bool]" => FieldType::BoolArray,
+            "bytes" => FieldType::Bytes,
+            "uuid" => FieldType::Uuid,
+            "table" => FieldType::Table,
+            "[table]" => FieldType::TableArray,
+            "enum" => FieldType::Enum,
+            "union" => FieldType::Union,
+            other => FieldType::Custom(other.to_string()),
+        }
+    }
+}
+
+// Manual serde: the open Custom variant cannot be expressed with
+// per-variant renames, so FieldType serializes as its plain type name.
+impl Serialize for FieldType {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+impl<'de> Deserialize<'de> for FieldType {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        Ok(FieldType::from_name(&name))
+    }
+}
+
+/// Resolves the vtable slot for every field of one table, in field order.
+///
+/// Without explicit ids the slot equals the field position — the historic
+/// behaviour. With ids, every field must carry one and the set must be
+/// unique and contiguous from 0 (the same contract flatc enforces for its
+/// `id` attribute), so fields can be reordered or inserted mid-schema
+/// without shifting the slots of existing data.
+pub fn field_slots(
+    fields: &IndexMap<String, FieldDefinition>,
+) -> Result<Vec<usize>, crate::error::GermanicError> {
+    let with_id = fields.values().filter(|d| d.id.is_some()).count();
+    if with_id == 0 {
+        return Ok((0..fields.len()).collect());
+    }
+    if with_id != fields.len() {
+        return Err(crate::error::GermanicError::General(
+            "Either all fields of a table set an explicit id or none do".into(),
+        ));
+    }
+
+    let mut seen = vec![false; fields.len()];
+    let mut slots = Vec::with_capacity(fields.len());
+    for (name, def) in fields {
+        let slot = def.id.unwrap_or(0) as usize;
+        if slot >= fields.len() {
+            return Err(crate::error::GermanicError::General(format!(
+                "Field '{}': id {} out of range (ids must be contiguous from 0)",
+                name, slot
+            )));
+        }
+        if seen[slot] {
+            return Err(crate::error::GermanicError::General(format!(
+                "Field '{}': duplicate id {}",
+                name, slot
+            )));
+        }
+        seen[slot] = true;
+        slots.push(slot);
+    }
+    Ok(slots)
+}
+
+impl FieldDefinition {
+    /// The declared default as a bool, accepting `true` and `"true"`.
+    pub fn default_bool(&self) -> Option<bool> {
+        match self.default.as_ref()? {
+            serde_json::Value::Bool(b) => Some(*b),
+            serde_json::Value::String(s) => s.parse().ok(),
+            _ => None,
+        }
+    }
+
+    /// The declared default as an i32, accepting `42` and `"42"`.
+    pub fn default_i32(&self) -> Option<i32> {
+        match self.default.as_ref()? {
+            serde_json::Value::Number(n) => i32::try_from(n.as_i64()?).ok(),
+            serde_json::Value::String(s) => s.parse().ok(),
+            _ => None,
+        }
+    }
+
+    /// The declared default as an f32, accepting `1.5` and `"1.5"`.
+    pub fn default_f32(&self) -> Option<f32> {
+        match self.default.as_ref()? {
+            serde_json::Value::Number(n) => n.as_f64().map(|f| f as f32),
+            serde_json::Value::String(s) => s.parse().ok(),
+            _ => None,
+        }
+    }
+
+    /// The declared default in its string form (string/enum fields).
+    pub fn default_string(&self) -> Option<String> {
+        match self.default.as_ref()? {
+            serde_json::Value::String(s) => Some(s.clone()),
+            other @ (serde_json::Value::Bool(_) | serde_json::Value::Number(_)) => {
+                Some(other.to_string())
+            }
+            _ => None,
+        }
+    }
+
+    /// Normalizes the declared default into the JSON shape this field's
+    /// type expects, so it can be fed through the same encode path as a
+    /// present value. Returns None when no default is declared or the
+    /// default does not fit the field type.
+    pub fn default_value(&self) -> Option<serde_json::Value> {
+        let d = self.default.as_ref()?;
+        match &self.field_type {
+            FieldType::Bool => self.default_bool().map(serde_json::Value::Bool),
+            FieldType::Int => self
+                .default_i32()
+                .map(|v| serde_json::Value::Number(v.into())),
+            FieldType::Float => self
+                .default_f32()
+                .and_then(|v| serde_json::Number::from_f64(v as f64))
+                .map(serde_json::Value::Number),
+            FieldType::String
+            | FieldType::Enum
+            | FieldType::Bytes
+            | FieldType::Uuid
+            | FieldType::Custom(_) => self.default_string().map(serde_json::Value::String),
+            // Structured defaults must already have the right JSON shape
+            FieldType::StringArray
+            | FieldType::IntArray
+            | FieldType::BoolArray
+            | FieldType::TableArray
+                if d.is_array() =>
+            {
+                Some(d.clone())
+            }
+            FieldType::Table | FieldType::Union if d.is_object() => Some(d.clone()),
+            _ => None,
+        }
+    }
 }
 
 impl SchemaDefinition {
@@ -117,6 +447,209 @@ impl SchemaDefinition {
     pub fn field_count(&self) -> usize {
         self.fields.len()
     }
+
+    /// Structural limits in effect for this schema: the defaults, with
+    /// any `limits` overrides applied and clamped to the absolute caps.
+    pub fn effective_limits(&self) -> crate::pre_validate::Limits {
+        use crate::pre_validate::{
+            Limits, MAX_ARRAY_ELEMENTS_CAP, MAX_INPUT_SIZE_CAP, MAX_NESTING_DEPTH_CAP,
+            MAX_STRING_LENGTH_CAP,
+        };
+
+        let mut limits = Limits::default();
+        let Some(overrides) = &self.limits else {
+            return limits;
+        };
+
+        if let Some(v) = overrides.max_input_size {
+            limits.max_input_size = v.min(MAX_INPUT_SIZE_CAP);
+        }
+        if let Some(v) = overrides.max_string_length {
+            limits.max_string_length = v.min(MAX_STRING_LENGTH_CAP);
+        }
+        if let Some(v) = overrides.max_array_elements {
+            limits.max_array_elements = v.min(MAX_ARRAY_ELEMENTS_CAP);
+        }
+        if let Some(v) = overrides.max_nesting_depth {
+            limits.max_nesting_depth = v.min(MAX_NESTING_DEPTH_CAP);
+        }
+        limits
+    }
+
+    /// Collects one warning per deprecated field (including nested ones),
+    /// mentioning the replacement when the schema names one.
+    pub fn deprecation_warnings(&self) -> Vec<String> {
+        let mut warnings = Vec::new();
+        collect_deprecations(&self.fields, "", &mut warnings);
+        warnings
+    }
+
+    /// Lifts alias keys in the data to their canonical field names, so
+    /// validation and the builder only ever see canonical keys. Returns
+    /// the lifted value and one warning per alias used. An alias only
+    /// fires when the canonical key is absent.
+    pub fn apply_aliases(&self, data: &serde_json::Value) -> (serde_json::Value, Vec<String>) {
+        let mut warnings = Vec::new();
+        let lifted = lift_aliases(&self.fields, data, "", &mut warnings);
+        (lifted, warnings)
+    }
+
+    /// Rejects schemas whose layout the builder cannot represent.
+    ///
+    /// Two ceilings exist: the vtable offset `4 + 2×slot` is a u16, which
+    /// caps each table at [`MAX_TABLE_FIELDS`] fields, and a schema nested
+    /// deeper than the absolute data depth cap can never validate any
+    /// input. Very wide inferred schemas hit the first one; both would
+    /// otherwise silently produce broken or unusable layouts.
+    pub fn check_layout(&self) -> Result<(), crate::error::GermanicError> {
+        check_table_layout(&self.fields, "", 0)
+    }
+}
+
+/// Highest vtable slot the `4 + 2×slot` voffset formula can address
+/// within a u16, and therefore the most fields one table may declare.
+pub const MAX_TABLE_FIELDS: usize = (u16::MAX as usize - 4) / 2;
+
+/// Checks one table level against the layout ceilings, then recurses.
+fn check_table_layout(
+    fields: &IndexMap<String, FieldDefinition>,
+    prefix: &str,
+    depth: usize,
+) -> Result<(), crate::error::GermanicError> {
+    let table = if prefix.is_empty() { "(root)" } else { prefix };
+
+    if fields.len() > MAX_TABLE_FIELDS {
+        return Err(crate::error::GermanicError::General(format!(
+            "Table \"{}\" declares {} fields, vtable maximum is {}",
+            table,
+            fields.len(),
+            MAX_TABLE_FIELDS
+        )));
+    }
+    if depth > crate::pre_validate::MAX_NESTING_DEPTH_CAP {
+        return Err(crate::error::GermanicError::General(format!(
+            "Table \"{}\" nests deeper than {} levels — no data could ever reach it",
+            table,
+            crate::pre_validate::MAX_NESTING_DEPTH_CAP
+        )));
+    }
+
+    for (name, def) in fields {
+        if let Some(nested) = &def.fields {
+            let path = if prefix.is_empty() {
+                name.clone()
+            } else {
+                format!("{prefix}.{name}")
+            };
+            check_table_layout(nested, &path, depth + 1)?;
+        }
+    }
+    Ok(())
+}
+
+/// Renames alias keys to canonical names in one object level, then
+/// recurses into nested tables, table arrays and union variants.
+fn lift_aliases(
+    fields: &IndexMap<String, FieldDefinition>,
+    data: &serde_json::Value,
+    prefix: &str,
+    warnings: &mut Vec<String>,
+) -> serde_json::Value {
+    let serde_json::Value::Object(map) = data else {
+        return data.clone();
+    };
+    let mut map = map.clone();
+
+    for (name, def) in fields {
+        let path = if prefix.is_empty() {
+            name.clone()
+        } else {
+            format!("{prefix}.{name}")
+        };
+
+        if let Some(aliases) = &def.aliases {
+            for alias in aliases {
+                if map.contains_key(alias) && !map.contains_key(name) {
+                    let value = map.shift_remove(alias).expect("key checked above");
+                    map.insert(name.clone(), value);
+                    warnings.push(format!(
+                        "alias: field \"{}\" provided as \"{}\" — lifted to the canonical name",
+                        path, alias
+                    ));
+                }
+            }
+        }
+
+        let Some(nested) = &def.fields else { continue };
+        match &def.field_type {
+            FieldType::Table => {
+                if let Some(value) = map.get(name) {
+                    let lifted = lift_aliases(nested, value, &path, warnings);
+                    map.insert(name.clone(), lifted);
+                }
+            }
+            FieldType::TableArray => {
+                if let Some(serde_json::Value::Array(arr)) = map.get(name) {
+                    let lifted: Vec<_> = arr
+                        .iter()
+                        .enumerate()
+                        .map(|(i, e)| lift_aliases(nested, e, &format!("{path}[{i}]"), warnings))
+                        .collect();
+                    map.insert(name.clone(), serde_json::Value::Array(lifted));
+                }
+            }
+            FieldType::Union => {
+                if let Some(serde_json::Value::Object(obj)) = map.get(name) {
+                    if let Some((tag, inner)) = obj.iter().next() {
+                        if let Some(variant_def) = nested.get(tag) {
+                            if let Some(variant_fields) = &variant_def.fields {
+                                let variant_path = format!("{path}.{tag}");
+                                let lifted = lift_aliases(
+                                    variant_fields,
+                                    inner,
+                                    &variant_path,
+                                    warnings,
+                                );
+                                let mut wrapped = serde_json::Map::new();
+                                wrapped.insert(tag.clone(), lifted);
+                                map.insert(name.clone(), serde_json::Value::Object(wrapped));
+                            }
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    serde_json::Value::Object(map)
+}
+
+/// Walks a field map recursively and records deprecation warnings
+/// with dotted paths ("adresse.fax").
+fn collect_deprecations(
+    fields: &IndexMap<String, FieldDefinition>,
+    prefix: &str,
+    warnings: &mut Vec<String>,
+) {
+    for (name, def) in fields {
+        let path = if prefix.is_empty() {
+            name.clone()
+        } else {
+            format!("{prefix}.{name}")
+        };
+        if def.deprecated {
+            match &def.replaced_by {
+                Some(replacement) => warnings.push(format!(
+                    "Field \"{path}\" is deprecated, use \"{replacement}\" instead"
+                )),
+                None => warnings.push(format!("Field \"{path}\" is deprecated")),
+            }
+        }
+        if let Some(nested) = &def.fields {
+            collect_deprecations(nested, &path, warnings);
+        }
+    }
 }
 
 // ============================================================================
@@ -132,82 +665,52 @@ mod tests {
         fields.insert(
             "name".into(),
             FieldDefinition {
-                field_type: FieldType::String,
                 required: true,
-                default: None,
-                fields: None,
-            },
-        );
-        fields.insert(
-            "cuisine".into(),
-            FieldDefinition {
-                field_type: FieldType::String,
-                required: false,
-                default: None,
-                fields: None,
-            },
-        );
-        fields.insert(
-            "rating".into(),
-            FieldDefinition {
-                field_type: FieldType::Float,
-                required: false,
-                default: None,
-                fields: None,
-            },
-        );
-        fields.insert(
-            "tags".into(),
-            FieldDefinition {
-                field_type: FieldType::StringArray,
-                required: false,
-                default: None,
-                fields: None,
+                ..FieldDefinition::new(FieldType::String)
             },
         );
+        fields.insert("cuisine".into(), FieldDefinition::new(FieldType::String));
+        fields.insert("rating".into(), FieldDefinition::new(FieldType::Float));
+        fields.insert("tags".into(), FieldDefinition::new(FieldType::StringArray));
 
         let mut addr_fields = IndexMap::new();
         addr_fields.insert(
             "street".into(),
             FieldDefinition {
-                field_type: FieldType::String,
                 required: true,
-                default: None,
-                fields: None,
+                ..FieldDefinition::new(FieldType::String)
             },
         );
         addr_fields.insert(
             "city".into(),
             FieldDefinition {
-                field_type: FieldType::String,
                 required: true,
-                default: None,
-                fields: None,
+                ..FieldDefinition::new(FieldType::String)
             },
         );
         addr_fields.insert(
             "country".into(),
             FieldDefinition {
-                field_type: FieldType::String,
-                required: false,
                 default: Some("DE".into()),
-                fields: None,
+                ..FieldDefinition::new(FieldType::String)
             },
         );
 
         fields.insert(
             "address".into(),
             FieldDefinition {
-                field_type: FieldType::Table,
                 required: true,
-                default: None,
                 fields: Some(addr_fields),
+                ..FieldDefinition::new(FieldType::Table)
             },
         );
 
         SchemaDefinition {
             schema_id: "de.dining.restaurant.v1".into(),
             version: 1,
+            description: None,
+            limits: None,
+            strict: false,
             fields,
         }
     }
@@ -236,6 +739,178 @@ mod tests {
         assert_eq!(field.field_type, FieldType::StringArray);
     }
 
+    fn field_with_id(id: Option<u16>) -> FieldDefinition {
+        FieldDefinition {
+            id,
+            ..FieldDefinition::new(FieldType::String)
+        }
+    }
+
+    #[test]
+    fn test_field_slots_without_ids_follow_position() {
+        let mut fields = IndexMap::new();
+        fields.insert("a".to_string(), field_with_id(None));
+        fields.insert("b".to_string(), field_with_id(None));
+        assert_eq!(field_slots(&fields).unwrap(), vec![0, 1]);
+    }
+
+    #[test]
+    fn test_field_slots_with_ids_override_position() {
+        // Declaration order b, a — slots stay stable via ids
+        let mut fields = IndexMap::new();
+        fields.insert("b".to_string(), field_with_id(Some(1)));
+        fields.insert("a".to_string(), field_with_id(Some(0)));
+        assert_eq!(field_slots(&fields).unwrap(), vec![1, 0]);
+    }
+
+    #[test]
+    fn test_field_slots_reject_partial_duplicate_and_gaps() {
+        let mut partial = IndexMap::new();
+        partial.insert("a".to_string(), field_with_id(Some(0)));
+        partial.insert("b".to_string(), field_with_id(None));
+        assert!(field_slots(&partial).is_err());
+
+        let mut duplicate = IndexMap::new();
+        duplicate.insert("a".to_string(), field_with_id(Some(0)));
+        duplicate.insert("b".to_string(), field_with_id(Some(0)));
+        assert!(field_slots(&duplicate).is_err());
+
+        let mut gap = IndexMap::new();
+        gap.insert("a".to_string(), field_with_id(Some(0)));
+        gap.insert("b".to_string(), field_with_id(Some(2)));
+        assert!(field_slots(&gap).is_err());
+    }
+
+    #[test]
+    fn test_description_roundtrip() {
+        let mut schema = sample_restaurant_schema();
+        schema.description = Some("Restaurants und Gaststätten".to_string());
+        schema.fields["name"].description = Some("Offizieller Name des Restaurants".to_string());
+
+        let json = serde_json::to_string_pretty(&schema).unwrap();
+        let parsed: SchemaDefinition = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            parsed.description.as_deref(),
+            Some("Restaurants und Gaststätten")
+        );
+        assert_eq!(
+            parsed.fields["name"].description.as_deref(),
+            Some("Offizieller Name des Restaurants")
+        );
+        // Fields without a description stay unchanged and omit the key
+        assert!(parsed.fields["cuisine"].description.is_none());
+        assert!(!json.contains("\"cuisine\": {\n      \"description\""));
+    }
+
+    #[test]
+    fn test_deprecation_warnings() {
+        let mut schema = sample_restaurant_schema();
+        schema.fields["rating"].deprecated = true;
+        schema.fields["rating"].replaced_by = Some("sterne".to_string());
+        let addr = schema.fields["address"].fields.as_mut().unwrap();
+        addr["street"].deprecated = true;
+
+        let warnings = schema.deprecation_warnings();
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(
+            warnings[0],
+            "Field \"rating\" is deprecated, use \"sterne\" instead"
+        );
+        assert_eq!(warnings[1], "Field \"address.street\" is deprecated");
+    }
+
+    #[test]
+    fn test_deprecated_serde_roundtrip() {
+        let mut schema = sample_restaurant_schema();
+        schema.fields["rating"].deprecated = true;
+
+        let json = serde_json::to_string_pretty(&schema).unwrap();
+        let parsed: SchemaDefinition = serde_json::from_str(&json).unwrap();
+        assert!(parsed.fields["rating"].deprecated);
+        // Non-deprecated fields omit the key entirely
+        assert_eq!(json.matches("\"deprecated\"").count(), 1);
+    }
+
+    #[test]
+    fn test_aliases_lifted_to_canonical_names() {
+        let mut schema = sample_restaurant_schema();
+        schema.fields["name"].aliases = Some(vec!["title".into(), "bezeichnung".into()]);
+        let addr = schema.fields["address"].fields.as_mut().unwrap();
+        addr["street"].aliases = Some(vec!["strasse".into()]);
+
+        let data = serde_json::json!({
+            "title": "Zur Linde",
+            "address": { "strasse": "Hauptstr. 1", "city": "Berlin", "zip": "10115" }
+        });
+        let (lifted, warnings) = schema.apply_aliases(&data);
+
+        assert_eq!(lifted["name"], "Zur Linde");
+        assert!(lifted.get("title").is_none());
+        assert_eq!(lifted["address"]["street"], "Hauptstr. 1");
+        assert_eq!(warnings.len(), 2);
+        assert!(warnings[0].contains("\"name\" provided as \"title\""));
+        assert!(warnings[1].contains("\"address.street\" provided as \"strasse\""));
+    }
+
+    #[test]
+    fn test_alias_does_not_override_canonical_key() {
+        let mut schema = sample_restaurant_schema();
+        schema.fields["name"].aliases = Some(vec!["title".into()]);
+
+        // Canonical key wins; the alias stays put and no warning fires
+        let data = serde_json::json!({ "name": "Zur Linde", "title": "ignored" });
+        let (lifted, warnings) = schema.apply_aliases(&data);
+
+        assert_eq!(lifted["name"], "Zur Linde");
+        assert_eq!(lifted["title"], "ignored");
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn test_effective_limits_clamped_to_caps() {
+        let mut schema = sample_restaurant_schema();
+        assert_eq!(
+            schema.effective_limits(),
+            crate::pre_validate::Limits::default()
+        );
+
+        schema.limits = Some(SchemaLimits {
+            max_input_size: None,
+            max_string_length: None,
+            max_array_elements: Some(15_000),
+            max_nesting_depth: Some(1_000), // over the cap
+        });
+        let limits = schema.effective_limits();
+        assert_eq!(limits.max_array_elements, 15_000);
+        assert_eq!(
+            limits.max_nesting_depth,
+            crate::pre_validate::MAX_NESTING_DEPTH_CAP
+        );
+        // Untouched values keep their defaults
+        assert_eq!(
+            limits.max_input_size,
+            crate::pre_validate::MAX_INPUT_SIZE
+        );
+    }
+
+    #[test]
+    fn test_limits_serde_roundtrip() {
+        let mut schema = sample_restaurant_schema();
+        schema.limits = Some(SchemaLimits {
+            max_input_size: None,
+            max_string_length: None,
+            max_array_elements: Some(15_000),
+            max_nesting_depth: None,
+        });
+
+        let json = serde_json::to_string_pretty(&schema).unwrap();
+        let parsed: SchemaDefinition = serde_json::from_str(&json).unwrap();
+        let limits = parsed.limits.unwrap();
+        assert_eq!(limits.max_array_elements, Some(15_000));
+        // Unset values stay out of the serialized form
+        assert!(!json.contains("max_input_size"));
+    }
+
     #[test]
     fn test_nested_table_fields() {
         let schema = sample_restaurant_schema();
@@ -245,4 +920,54 @@ mod tests {
         assert_eq!(nested.len(), 3);
         assert!(nested["street"].required);
     }
+
+    #[test]
+    fn test_layout_accepts_normal_schemas() {
+        assert!(sample_restaurant_schema().check_layout().is_ok());
+    }
+
+    #[test]
+    fn test_layout_rejects_too_wide_tables() {
+        let mut fields = IndexMap::new();
+        for i in 0..=MAX_TABLE_FIELDS {
+            fields.insert(format!("feld_{i}"), field_with_id(None));
+        }
+        let schema = SchemaDefinition {
+            schema_id: "test.v1".into(),
+            version: 1,
+            description: None,
+            limits: None,
+            strict: false,
+            fields,
+        };
+
+        let err = schema.check_layout().unwrap_err();
+        assert!(err.to_string().contains("vtable maximum"), "Got: {}", err);
+    }
+
+    #[test]
+    fn test_layout_rejects_unreachable_nesting() {
+        // Build a table chain one level deeper than the absolute data cap
+        let mut inner = field_with_id(None);
+        for _ in 0..=crate::pre_validate::MAX_NESTING_DEPTH_CAP {
+            let mut nested = IndexMap::new();
+            nested.insert("kind".to_string(), inner);
+            inner = field_with_id(None);
+            inner.field_type = FieldType::Table;
+            inner.fields = Some(nested);
+        }
+        let mut fields = IndexMap::new();
+        fields.insert("wurzel".to_string(), inner);
+        let schema = SchemaDefinition {
+            schema_id: "test.v1".into(),
+            version: 1,
+            description: None,
+            limits: None,
+            strict: false,
+            fields,
+        };
+
+        let err = schema.check_layout().unwrap_err();
+        assert!(err.to_string().contains("nests deeper"), "Got: {}", err);
+    }
 }
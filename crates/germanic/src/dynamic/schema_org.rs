@@ -0,0 +1,262 @@
+//! # schema.org Starter Schemas
+//!
+//! Converts common schema.org types into starter [`SchemaDefinition`]s,
+//! so sites migrating from JSON-LD markup can bootstrap a GERMANIC
+//! schema in one command and then trim it to what they actually publish.
+//!
+//! ```text
+//!   schema.org type ──► starter_schema("Restaurant") ──► SchemaDefinition
+//!   (LocalBusiness,                                        │
+//!    MedicalClinic,                                        ▼
+//!    Restaurant,                                      user edits, then
+//!    Event)                                           germanic compile
+//! ```
+//!
+//! Property names stay schema.org spelling (`streetAddress`, `telephone`)
+//! so existing JSON-LD data validates without renaming; required flags
+//! follow what Google's structured-data validator treats as essential.
+
+use indexmap::IndexMap;
+
+use crate::error::GermanicError;
+
+use super::schema_def::{FieldDefinition, FieldType, SchemaDefinition};
+
+/// The schema.org types a starter schema exists for.
+pub const SUPPORTED_TYPES: &[&str] = &["LocalBusiness", "MedicalClinic", "Restaurant", "Event"];
+
+/// Builds a starter schema for one schema.org type.
+///
+/// The result passes layout checks and compiles as-is; it is meant as a
+/// starting point, not a complete mapping of the vocabulary. Unknown
+/// types error with the supported list.
+pub fn starter_schema(type_name: &str) -> Result<SchemaDefinition, GermanicError> {
+    let fields = match type_name {
+        "LocalBusiness" => local_business_fields(),
+        "MedicalClinic" => {
+            let mut fields = local_business_fields();
+            fields.insert(
+                "medicalSpecialty".into(),
+                field(
+                    FieldType::StringArray,
+                    false,
+                    "Specialties offered (e.g. \"Allgemeinmedizin\")",
+                ),
+            );
+            fields.insert(
+                "availableService".into(),
+                field(FieldType::StringArray, false, "Services offered"),
+            );
+            fields
+        }
+        "Restaurant" => {
+            let mut fields = local_business_fields();
+            fields.insert(
+                "servesCuisine".into(),
+                field(FieldType::StringArray, false, "Cuisines served"),
+            );
+            fields.insert(
+                "priceRange".into(),
+                field(FieldType::String, false, "Price band (e.g. \"€€\")"),
+            );
+            fields.insert(
+                "acceptsReservations".into(),
+                field(FieldType::Bool, false, "Whether reservations are accepted"),
+            );
+            fields
+        }
+        "Event" => event_fields(),
+        other => {
+            return Err(GermanicError::General(format!(
+                "No starter schema for schema.org type \"{}\" — supported: [{}]",
+                other,
+                SUPPORTED_TYPES.join(", ")
+            )));
+        }
+    };
+
+    Ok(SchemaDefinition {
+        schema_id: format!("org.schema.{}.v1", type_name.to_lowercase()),
+        version: 1,
+        description: Some(format!(
+            "Starter schema derived from schema.org {} — trim to the fields you actually publish",
+            type_name
+        )),
+        limits: None,
+        strict: false,
+        fields,
+    })
+}
+
+/// The shared LocalBusiness base every business-like type extends.
+fn local_business_fields() -> IndexMap<String, FieldDefinition> {
+    let mut fields = IndexMap::new();
+    fields.insert(
+        "name".into(),
+        field(FieldType::String, true, "Name of the business"),
+    );
+    fields.insert(
+        "description".into(),
+        field(FieldType::String, false, "Short description"),
+    );
+    fields.insert("address".into(), address_field(true));
+    fields.insert("geo".into(), geo_field());
+    fields.insert(
+        "telephone".into(),
+        field(FieldType::Custom("phone".into()), false, "Phone number"),
+    );
+    fields.insert(
+        "email".into(),
+        field(FieldType::Custom("email".into()), false, "Contact email"),
+    );
+    fields.insert(
+        "url".into(),
+        field(FieldType::Custom("url".into()), false, "Website"),
+    );
+    fields.insert(
+        "openingHours".into(),
+        field(
+            FieldType::StringArray,
+            false,
+            "Opening hours in schema.org notation (\"Mo-Fr 09:00-17:00\")",
+        ),
+    );
+    fields
+}
+
+/// Fields for schema.org Event.
+fn event_fields() -> IndexMap<String, FieldDefinition> {
+    let mut fields = IndexMap::new();
+    fields.insert(
+        "name".into(),
+        field(FieldType::String, true, "Name of the event"),
+    );
+    fields.insert(
+        "description".into(),
+        field(FieldType::String, false, "Short description"),
+    );
+    fields.insert(
+        "startDate".into(),
+        field(
+            FieldType::Custom("datetime".into()),
+            true,
+            "Start (ISO 8601)",
+        ),
+    );
+    fields.insert(
+        "endDate".into(),
+        field(FieldType::Custom("datetime".into()), false, "End (ISO 8601)"),
+    );
+    let mut location = IndexMap::new();
+    location.insert(
+        "name".into(),
+        field(FieldType::String, false, "Venue name"),
+    );
+    location.insert("address".into(), address_field(false));
+    fields.insert("location".into(), {
+        let mut def = field(FieldType::Table, false, "Where the event takes place");
+        def.fields = Some(location);
+        def
+    });
+    fields.insert(
+        "organizer".into(),
+        field(FieldType::String, false, "Organizing person or business"),
+    );
+    fields.insert(
+        "url".into(),
+        field(FieldType::Custom("url".into()), false, "Event page"),
+    );
+    fields
+}
+
+/// schema.org PostalAddress as a nested table.
+fn address_field(required: bool) -> FieldDefinition {
+    let mut nested = IndexMap::new();
+    nested.insert(
+        "streetAddress".into(),
+        field(FieldType::String, false, "Street and house number"),
+    );
+    nested.insert(
+        "postalCode".into(),
+        field(FieldType::String, false, "Postal code"),
+    );
+    nested.insert(
+        "addressLocality".into(),
+        field(FieldType::String, true, "City"),
+    );
+    nested.insert(
+        "addressCountry".into(),
+        field(FieldType::String, false, "Country code (\"DE\")"),
+    );
+    let mut def = field(FieldType::Table, required, "Postal address");
+    def.fields = Some(nested);
+    def
+}
+
+/// schema.org GeoCoordinates as a nested table.
+fn geo_field() -> FieldDefinition {
+    let mut nested = IndexMap::new();
+    nested.insert(
+        "latitude".into(),
+        field(FieldType::Float, true, "Latitude (WGS 84)"),
+    );
+    nested.insert(
+        "longitude".into(),
+        field(FieldType::Float, true, "Longitude (WGS 84)"),
+    );
+    let mut def = field(FieldType::Table, false, "Geographic coordinates");
+    def.fields = Some(nested);
+    def
+}
+
+/// Shorthand for a described field of the given type.
+fn field(field_type: FieldType, required: bool, description: &str) -> FieldDefinition {
+    FieldDefinition {
+        description: Some(description.into()),
+        required,
+        ..FieldDefinition::new(field_type)
+    }
+}
+
+// ============================================================================
+// TESTS
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_all_supported_types_build_valid_schemas() {
+        for type_name in SUPPORTED_TYPES {
+            let schema = starter_schema(type_name).unwrap();
+            schema.check_layout().unwrap();
+            assert!(schema.fields["name"].required, "{}", type_name);
+            assert!(schema.schema_id.starts_with("org.schema."));
+        }
+    }
+
+    #[test]
+    fn test_restaurant_data_validates() {
+        let schema = starter_schema("Restaurant").unwrap();
+        let data = serde_json::json!({
+            "name": "Gasthaus zur Linde",
+            "address": {
+                "streetAddress": "Lindenstraße 12",
+                "postalCode": "80331",
+                "addressLocality": "München"
+            },
+            "servesCuisine": ["bayerisch"],
+            "priceRange": "€€"
+        });
+        assert!(super::super::validate::validate_against_schema(&schema, &data).is_ok());
+    }
+
+    #[test]
+    fn test_unknown_type_lists_supported() {
+        let err = starter_schema("Spaceport").unwrap_err();
+        let message = err.to_string();
+        assert!(message.contains("Spaceport"));
+        assert!(message.contains("Restaurant"));
+    }
+}
@@ -12,7 +12,7 @@
 
 use crate::dynamic::schema_def::{FieldDefinition, FieldType, SchemaDefinition};
 use crate::error::ValidationError;
-use crate::pre_validate::{MAX_ARRAY_ELEMENTS, MAX_NESTING_DEPTH, MAX_STRING_LENGTH};
+use crate::pre_validate::Limits;
 
 /// Validates JSON data against a schema definition.
 ///
@@ -28,8 +28,12 @@ pub fn validate_against_schema(
         found: value_type_name(data).into(),
     })?;
 
+    let limits = schema.effective_limits();
     let mut missing = Vec::new();
-    validate_fields(&schema.fields, obj, "", &mut missing, 0);
+    if schema.strict {
+        check_unknown_keys(&schema.fields, obj, "", &mut missing);
+    }
+    validate_fields(&schema.fields, obj, "", &mut missing, 0, &limits);
 
     if missing.is_empty() {
         Ok(())
@@ -38,6 +42,94 @@ pub fn validate_against_schema(
     }
 }
 
+/// Collects non-fatal quality warnings for data that validates fine.
+///
+/// Validation stays binary pass/fail; this is the feedback channel next
+/// to it. Publishers see the warnings as `⚠` lines in the CLI and as
+/// structured content over MCP — nothing here ever blocks compilation.
+///
+/// Current checks:
+/// - deprecated fields that are actually supplied in the data
+/// - empty arrays for optional fields (the builder drops them anyway)
+/// - phone-like fields (`telefon`, `phone`, `fax`, `mobil`) with
+///   suspiciously few digits
+pub fn validation_warnings(schema: &SchemaDefinition, data: &serde_json::Value) -> Vec<String> {
+    let mut warnings = Vec::new();
+    if let Some(obj) = data.as_object() {
+        collect_quality_warnings(&schema.fields, obj, "", &mut warnings);
+    }
+    warnings
+}
+
+/// Walks one object level and records quality warnings with dotted paths.
+fn collect_quality_warnings(
+    fields: &indexmap::IndexMap<String, FieldDefinition>,
+    data: &serde_json::Map<String, serde_json::Value>,
+    prefix: &str,
+    warnings: &mut Vec<String>,
+) {
+    for (name, def) in fields {
+        let Some(value) = data.get(name) else { continue };
+        let path = if prefix.is_empty() {
+            name.clone()
+        } else {
+            format!("{}.{}", prefix, name)
+        };
+
+        if def.deprecated {
+            match &def.replaced_by {
+                Some(replacement) => warnings.push(format!(
+                    "{}: deprecated field supplied — use \"{}\" instead",
+                    path, replacement
+                )),
+                None => warnings.push(format!("{}: deprecated field supplied", path)),
+            }
+        }
+
+        if !def.required && value.as_array().is_some_and(|arr| arr.is_empty()) {
+            warnings.push(format!(
+                "{}: empty array for optional field — omit the field instead",
+                path
+            ));
+        }
+
+        if let Some(s) = value.as_str() {
+            let lower = name.to_lowercase();
+            let phone_like = ["telefon", "phone", "fax", "mobil"]
+                .iter()
+                .any(|hint| lower.contains(hint));
+            if phone_like && s.chars().filter(char::is_ascii_digit).count() < 6 {
+                warnings.push(format!(
+                    "{}: \"{}\" looks too short for a phone number",
+                    path, s
+                ));
+            }
+        }
+
+        // Recurse into nested tables and table arrays
+        if let Some(nested) = &def.fields {
+            match value {
+                serde_json::Value::Object(obj) => {
+                    collect_quality_warnings(nested, obj, &path, warnings);
+                }
+                serde_json::Value::Array(arr) => {
+                    for (i, element) in arr.iter().enumerate() {
+                        if let Some(obj) = element.as_object() {
+                            collect_quality_warnings(
+                                nested,
+                                obj,
+                                &format!("{path}[{i}]"),
+                                warnings,
+                            );
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
 /// Recursively validates fields, collecting all violations with path prefixes.
 ///
 /// Validation chain per field (order matters!):
@@ -45,7 +137,8 @@ pub fn validate_against_schema(
 /// 2. Value == null? → if null and required → error
 /// 3. Type correct?  → if mismatch → error
 /// 4. Empty check    → "" or [] for required → error
-/// 5. Size limits    → string length, array size
+/// 5. Size limits    → string length, array size,
+///    then declared constraints (min/max, length, pattern)
 /// 6. Nested table?  → recurse (with depth limit)
 fn validate_fields(
     fields: &indexmap::IndexMap<String, FieldDefinition>,
@@ -53,12 +146,13 @@ fn validate_fields(
     prefix: &str,
     errors: &mut Vec<String>,
     depth: usize,
+    limits: &Limits,
 ) {
-    if depth > MAX_NESTING_DEPTH {
+    if depth > limits.max_nesting_depth {
         errors.push(format!(
             "{}(depth): nesting depth exceeds maximum of {}",
             if prefix.is_empty() { "" } else { prefix },
-            MAX_NESTING_DEPTH
+            limits.max_nesting_depth
         ));
         return;
     }
@@ -86,16 +180,79 @@ fn validate_fields(
                 }
 
                 // Check 3: Type mismatch
+                // Custom types delegate entirely to their plugin
+                if let FieldType::Custom(plugin_name) = &def.field_type {
+                    match crate::plugin::lookup_plugin(plugin_name) {
+                        None => errors.push(format!(
+                            "{}: unknown field type '{}' — no plugin registered",
+                            path, plugin_name
+                        )),
+                        Some(plugin) => {
+                            let ctx = crate::plugin::PluginContext { siblings: data };
+                            if let Err(msg) = plugin.validate_with_context(value, &ctx) {
+                                errors.push(format!("{}: {}", path, msg));
+                            }
+                        }
+                    }
+                    continue;
+                }
                 if !type_matches(&def.field_type, value) {
                     errors.push(format!(
                         "{}: expected {}, found {}",
                         path,
-                        field_type_name(&def.field_type),
+                        def.field_type.name(),
                         value_type_name(value)
                     ));
                     continue; // No empty-check on wrong type
                 }
 
+                // Check 3b: Enum membership
+                if def.field_type == FieldType::Enum {
+                    let allowed = def.values.as_deref().unwrap_or_default();
+                    if let Some(s) = value.as_str() {
+                        if !allowed.iter().any(|v| v == s) {
+                            errors.push(format!(
+                                "{}: value \"{}\" not in allowed set [{}]",
+                                path,
+                                s,
+                                allowed.join(", ")
+                            ));
+                        }
+                    }
+                    continue;
+                }
+
+                // Check 3c: Bytes content — valid base64 within the size limit
+                if def.field_type == FieldType::Bytes {
+                    if let Some(s) = value.as_str() {
+                        match crate::base64::decode(s) {
+                            Err(msg) => errors.push(format!("{}: {}", path, msg)),
+                            Ok(decoded) => {
+                                let max = def.max_size.unwrap_or(limits.max_string_length);
+                                if decoded.len() > max {
+                                    errors.push(format!(
+                                        "{}: decoded size {} exceeds maximum of {} bytes",
+                                        path,
+                                        decoded.len(),
+                                        max
+                                    ));
+                                }
+                            }
+                        }
+                    }
+                    continue;
+                }
+
+                // Check 3d: UUID content — must parse as canonical UUID
+                if def.field_type == FieldType::Uuid {
+                    if let Some(s) = value.as_str() {
+                        if let Err(msg) = crate::formats::parse_uuid(s) {
+                            errors.push(format!("{}: {}", path, msg));
+                        }
+                    }
+                    continue;
+                }
+
                 // Check 4: Empty check for required fields
                 if def.required {
                     match (&def.field_type, value) {
@@ -111,30 +268,36 @@ fn validate_fields(
 
                 // Check 5: Size limits
                 match value {
-                    serde_json::Value::String(s) if s.len() > MAX_STRING_LENGTH => {
+                    serde_json::Value::String(s) if s.len() > limits.max_string_length => {
                         errors.push(format!(
                             "{}: string length {} exceeds maximum of {} bytes",
                             path,
                             s.len(),
-                            MAX_STRING_LENGTH
+                            limits.max_string_length
                         ));
                     }
-                    serde_json::Value::Array(a) if a.len() > MAX_ARRAY_ELEMENTS => {
+                    serde_json::Value::Array(a) if a.len() > limits.max_array_elements => {
                         errors.push(format!(
                             "{}: array has {} elements, maximum is {}",
                             path,
                             a.len(),
-                            MAX_ARRAY_ELEMENTS
+                            limits.max_array_elements
                         ));
                     }
                     _ => {}
                 }
 
+                // Check 5b: Declared constraints (min/max, length, pattern)
+                check_constraints(def, value, &path, errors);
+
                 // Check 6: Recurse into nested tables
                 if def.field_type == FieldType::Table {
                     if let Some(nested_fields) = &def.fields {
                         if let Some(nested_obj) = value.as_object() {
-                            validate_fields(nested_fields, nested_obj, &path, errors, depth + 1);
+                            if def.strict {
+                                check_unknown_keys(nested_fields, nested_obj, &path, errors);
+                            }
+                            validate_fields(nested_fields, nested_obj, &path, errors, depth + 1, limits);
                         } else if def.required {
                             errors.push(format!(
                                 "{}: expected table, found {}",
@@ -144,6 +307,177 @@ fn validate_fields(
                         }
                     }
                 }
+
+                // Check 6b: Union — exactly one known variant, then recurse
+                if def.field_type == FieldType::Union {
+                    if let (Some(variants), Some(obj)) = (&def.fields, value.as_object()) {
+                        if obj.len() != 1 {
+                            errors.push(format!(
+                                "{}: union value must have exactly one variant key, found {}",
+                                path,
+                                obj.len()
+                            ));
+                        } else if let Some((tag, inner)) = obj.iter().next() {
+                            match variants.get(tag) {
+                                None => {
+                                    let known: Vec<&str> =
+                                        variants.keys().map(String::as_str).collect();
+                                    errors.push(format!(
+                                        "{}: unknown union variant \"{}\" (expected one of [{}])",
+                                        path,
+                                        tag,
+                                        known.join(", ")
+                                    ));
+                                }
+                                Some(variant_def) => {
+                                    let variant_path = format!("{}.{}", path, tag);
+                                    if let (Some(nested_fields), Some(inner_obj)) =
+                                        (&variant_def.fields, inner.as_object())
+                                    {
+                                        validate_fields(
+                                            nested_fields,
+                                            inner_obj,
+                                            &variant_path,
+                                            errors,
+                                            depth + 1,
+                                            limits,
+                                        );
+                                    } else if !inner.is_object() {
+                                        errors.push(format!(
+                                            "{}: expected table, found {}",
+                                            variant_path,
+                                            value_type_name(inner)
+                                        ));
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                // Check 6c: Recurse into each element of a table array
+                if def.field_type == FieldType::TableArray {
+                    if let (Some(nested_fields), Some(arr)) = (&def.fields, value.as_array()) {
+                        for (i, element) in arr.iter().enumerate() {
+                            let elem_path = format!("{}[{}]", path, i);
+                            if let Some(elem_obj) = element.as_object() {
+                                if def.strict {
+                                    check_unknown_keys(nested_fields, elem_obj, &elem_path, errors);
+                                }
+                                validate_fields(
+                                    nested_fields,
+                                    elem_obj,
+                                    &elem_path,
+                                    errors,
+                                    depth + 1,
+                                    limits,
+                                );
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Rejects input keys that no field declares. Only invoked for objects
+/// marked strict (`additionalProperties: false` upstream); aliases are
+/// already lifted to canonical names before validation runs.
+fn check_unknown_keys(
+    fields: &indexmap::IndexMap<String, FieldDefinition>,
+    data: &serde_json::Map<String, serde_json::Value>,
+    prefix: &str,
+    errors: &mut Vec<String>,
+) {
+    for key in data.keys() {
+        if fields.contains_key(key) {
+            continue;
+        }
+        let path = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{}.{}", prefix, key)
+        };
+        errors.push(format!("{}: unknown field not allowed in strict mode", path));
+    }
+}
+
+/// Enforces the optional per-field constraints. The type is already
+/// checked at this point, so each constraint only fires on the value
+/// shape it applies to.
+fn check_constraints(
+    def: &FieldDefinition,
+    value: &serde_json::Value,
+    path: &str,
+    errors: &mut Vec<String>,
+) {
+    if let Some(n) = value.as_f64() {
+        if let Some(min) = def.min {
+            if n < min {
+                errors.push(format!("{}: value {} is below minimum {}", path, n, min));
+            }
+        }
+        if let Some(max) = def.max {
+            if n > max {
+                errors.push(format!("{}: value {} exceeds maximum {}", path, n, max));
+            }
+        }
+    }
+
+    if let Some(s) = value.as_str() {
+        let len = s.chars().count();
+        if let Some(min_length) = def.min_length {
+            if len < min_length {
+                errors.push(format!(
+                    "{}: length {} is below minimum length {}",
+                    path, len, min_length
+                ));
+            }
+        }
+        if let Some(max_length) = def.max_length {
+            if len > max_length {
+                errors.push(format!(
+                    "{}: length {} exceeds maximum length {}",
+                    path, len, max_length
+                ));
+            }
+        }
+        if let Some(pattern) = &def.pattern {
+            // Anchored: the whole value must match the pattern
+            match regex_lite::Regex::new(&format!("^(?:{})$", pattern)) {
+                Err(_) => errors.push(format!("{}: invalid pattern \"{}\"", path, pattern)),
+                Ok(re) => {
+                    if !re.is_match(s) {
+                        errors.push(format!(
+                            "{}: value \"{}\" does not match pattern \"{}\"",
+                            path, s, pattern
+                        ));
+                    }
+                }
+            }
+        }
+    }
+
+    if let Some(a) = value.as_array() {
+        if let Some(min_length) = def.min_length {
+            if a.len() < min_length {
+                errors.push(format!(
+                    "{}: array has {} elements, minimum is {}",
+                    path,
+                    a.len(),
+                    min_length
+                ));
+            }
+        }
+        if let Some(max_length) = def.max_length {
+            if a.len() > max_length {
+                errors.push(format!(
+                    "{}: array has {} elements, maximum is {}",
+                    path,
+                    a.len(),
+                    max_length
+                ));
             }
         }
     }
@@ -183,28 +517,34 @@ fn type_matches(expected: &FieldType, value: &serde_json::Value) -> bool {
         (FieldType::IntArray, serde_json::Value::Array(arr)) => {
             arr.iter().all(|v| v.as_i64().is_some())
         }
+        (FieldType::BoolArray, serde_json::Value::Array(arr)) => {
+            arr.iter().all(|v| v.is_boolean())
+        }
+
+        // Enums are strings on the wire; membership is checked separately
+        (FieldType::Enum, serde_json::Value::String(_)) => true,
+
+        // Bytes arrive as base64 strings; content is checked separately
+        (FieldType::Bytes, serde_json::Value::String(_)) => true,
 
-        // Tables
+        // UUIDs arrive as hyphenated strings; format is checked separately
+        (FieldType::Uuid, serde_json::Value::String(_)) => true,
+
+        // Tables (unions share the object shape; the tag is checked separately)
         (FieldType::Table, serde_json::Value::Object(_)) => true,
+        (FieldType::Union, serde_json::Value::Object(_)) => true,
+        (FieldType::TableArray, serde_json::Value::Array(arr)) => {
+            arr.iter().all(|v| v.is_object())
+        }
+
+        // Custom types: checked by their plugin, never here
+        (FieldType::Custom(_), _) => true,
 
         // Everything else: mismatch
         _ => false,
     }
 }
 
-/// Returns a human-readable name for a FieldType.
-fn field_type_name(ft: &FieldType) -> &'static str {
-    match ft {
-        FieldType::String => "string",
-        FieldType::Bool => "bool",
-        FieldType::Int => "int",
-        FieldType::Float => "float",
-        FieldType::StringArray => "[string]",
-        FieldType::IntArray => "[int]",
-        FieldType::Table => "table",
-    }
-}
-
 // ============================================================================
 // TESTS
 // ============================================================================
@@ -220,24 +560,17 @@ mod tests {
         fields.insert(
             "name".into(),
             FieldDefinition {
-                field_type: FieldType::String,
                 required: true,
-                default: None,
-                fields: None,
-            },
-        );
-        fields.insert(
-            "rating".into(),
-            FieldDefinition {
-                field_type: FieldType::Float,
-                required: false,
-                default: None,
-                fields: None,
+                ..FieldDefinition::new(FieldType::String)
             },
         );
+        fields.insert("rating".into(), FieldDefinition::new(FieldType::Float));
         SchemaDefinition {
             schema_id: "test.v1".into(),
             version: 1,
+            description: None,
+            limits: None,
+            strict: false,
             fields,
         }
     }
@@ -279,29 +612,54 @@ mod tests {
         assert!(validate_against_schema(&schema, &data).is_ok());
     }
 
+    #[test]
+    fn test_strict_rejects_unknown_keys() {
+        let mut schema = simple_schema();
+        schema.strict = true;
+        let data: serde_json::Value = serde_json::json!({
+            "name": "Bistro",
+            "inhaber": "M. Schmidt"
+        });
+        let err = validate_against_schema(&schema, &data).unwrap_err();
+        if let ValidationError::RequiredFieldsMissing(violations) = err {
+            assert!(violations
+                .iter()
+                .any(|v| v.contains("inhaber") && v.contains("strict")));
+        }
+    }
+
+    #[test]
+    fn test_non_strict_accepts_unknown_keys() {
+        let schema = simple_schema();
+        let data: serde_json::Value = serde_json::json!({
+            "name": "Bistro",
+            "inhaber": "M. Schmidt"
+        });
+        assert!(validate_against_schema(&schema, &data).is_ok());
+    }
+
     fn schema_with_string_array() -> SchemaDefinition {
         let mut fields = IndexMap::new();
         fields.insert(
             "name".into(),
             FieldDefinition {
-                field_type: FieldType::String,
                 required: true,
-                default: None,
-                fields: None,
+                ..FieldDefinition::new(FieldType::String)
             },
         );
         fields.insert(
             "tags".into(),
             FieldDefinition {
-                field_type: FieldType::StringArray,
                 required: true,
-                default: None,
-                fields: None,
+                ..FieldDefinition::new(FieldType::StringArray)
             },
         );
         SchemaDefinition {
             schema_id: "test.v1".into(),
             version: 1,
+            description: None,
+            limits: None,
+            strict: false,
             fields,
         }
     }
@@ -311,24 +669,23 @@ mod tests {
         fields.insert(
             "name".into(),
             FieldDefinition {
-                field_type: FieldType::String,
                 required: true,
-                default: None,
-                fields: None,
+                ..FieldDefinition::new(FieldType::String)
             },
         );
         fields.insert(
             "scores".into(),
             FieldDefinition {
-                field_type: FieldType::IntArray,
                 required: true,
-                default: None,
-                fields: None,
+                ..FieldDefinition::new(FieldType::IntArray)
             },
         );
         SchemaDefinition {
             schema_id: "test.v1".into(),
             version: 1,
+            description: None,
+            limits: None,
+            strict: false,
             fields,
         }
     }
@@ -373,10 +730,457 @@ mod tests {
         assert!(validate_against_schema(&schema, &data).is_err());
     }
 
+    fn schema_with_table_array() -> SchemaDefinition {
+        let mut doctor_fields = IndexMap::new();
+        doctor_fields.insert(
+            "name".into(),
+            FieldDefinition {
+                required: true,
+                ..FieldDefinition::new(FieldType::String)
+            },
+        );
+        let mut fields = IndexMap::new();
+        fields.insert(
+            "aerzte".into(),
+            FieldDefinition {
+                fields: Some(doctor_fields),
+                ..FieldDefinition::new(FieldType::TableArray)
+            },
+        );
+        SchemaDefinition {
+            schema_id: "test.v1".into(),
+            version: 1,
+            description: None,
+            limits: None,
+            strict: false,
+            fields,
+        }
+    }
+
+    #[test]
+    fn test_table_array_valid() {
+        let schema = schema_with_table_array();
+        let data = serde_json::json!({ "aerzte": [{ "name": "Dr. A" }, { "name": "Dr. B" }] });
+        assert!(validate_against_schema(&schema, &data).is_ok());
+    }
+
+    #[test]
+    fn test_table_array_rejects_non_object_element() {
+        let schema = schema_with_table_array();
+        let data = serde_json::json!({ "aerzte": [{ "name": "Dr. A" }, "not an object"] });
+        assert!(validate_against_schema(&schema, &data).is_err());
+    }
+
+    #[test]
+    fn test_table_array_element_violation_has_index_path() {
+        let schema = schema_with_table_array();
+        let data = serde_json::json!({ "aerzte": [{ "name": "Dr. A" }, { "fachgebiet": "x" }] });
+        let err = validate_against_schema(&schema, &data).unwrap_err();
+        if let ValidationError::RequiredFieldsMissing(violations) = err {
+            assert!(
+                violations.iter().any(|v| v.starts_with("aerzte[1].name:")),
+                "violations: {:?}",
+                violations
+            );
+        } else {
+            panic!("Expected RequiredFieldsMissing");
+        }
+    }
+
+    fn schema_with_enum() -> SchemaDefinition {
+        let mut fields = IndexMap::new();
+        fields.insert(
+            "status".into(),
+            FieldDefinition {
+                required: true,
+                values: Some(vec!["active".into(), "inactive".into()]),
+                ..FieldDefinition::new(FieldType::Enum)
+            },
+        );
+        SchemaDefinition {
+            schema_id: "test.v1".into(),
+            version: 1,
+            description: None,
+            limits: None,
+            strict: false,
+            fields,
+        }
+    }
+
+    #[test]
+    fn test_enum_valid_value() {
+        let schema = schema_with_enum();
+        let data = serde_json::json!({ "status": "active" });
+        assert!(validate_against_schema(&schema, &data).is_ok());
+    }
+
+    #[test]
+    fn test_enum_rejects_unknown_value() {
+        let schema = schema_with_enum();
+        let data = serde_json::json!({ "status": "pending" });
+        let err = validate_against_schema(&schema, &data).unwrap_err();
+        if let ValidationError::RequiredFieldsMissing(violations) = err {
+            assert!(
+                violations
+                    .iter()
+                    .any(|v| v.contains("not in allowed set") && v.contains("active")),
+                "violations: {:?}",
+                violations
+            );
+        } else {
+            panic!("Expected RequiredFieldsMissing");
+        }
+    }
+
+    #[test]
+    fn test_enum_rejects_non_string() {
+        let schema = schema_with_enum();
+        let data = serde_json::json!({ "status": 1 });
+        assert!(validate_against_schema(&schema, &data).is_err());
+    }
+
     #[test]
     fn test_int_array_rejects_bool_element() {
         let schema = schema_with_int_array();
         let data = serde_json::json!({ "name": "Test", "scores": [1, true, 3] });
         assert!(validate_against_schema(&schema, &data).is_err());
     }
+
+    fn schema_with_bytes(max_size: Option<usize>) -> SchemaDefinition {
+        let mut fields = IndexMap::new();
+        fields.insert(
+            "logo".into(),
+            FieldDefinition {
+                required: true,
+                max_size,
+                ..FieldDefinition::new(FieldType::Bytes)
+            },
+        );
+        SchemaDefinition {
+            schema_id: "test.v1".into(),
+            version: 1,
+            description: None,
+            limits: None,
+            strict: false,
+            fields,
+        }
+    }
+
+    #[test]
+    fn test_bytes_valid_base64() {
+        let schema = schema_with_bytes(None);
+        let data = serde_json::json!({ "logo": "Zm9vYmFy" });
+        assert!(validate_against_schema(&schema, &data).is_ok());
+    }
+
+    #[test]
+    fn test_bytes_rejects_invalid_base64() {
+        let schema = schema_with_bytes(None);
+        let data = serde_json::json!({ "logo": "not base64!" });
+        let err = validate_against_schema(&schema, &data).unwrap_err();
+        if let ValidationError::RequiredFieldsMissing(violations) = err {
+            assert!(violations.iter().any(|v| v.starts_with("logo:")));
+        } else {
+            panic!("Expected RequiredFieldsMissing");
+        }
+    }
+
+    #[test]
+    fn test_bytes_enforces_max_size() {
+        let schema = schema_with_bytes(Some(4));
+        // "Zm9vYmFy" decodes to 6 bytes ("foobar") — over the 4-byte limit
+        let data = serde_json::json!({ "logo": "Zm9vYmFy" });
+        let err = validate_against_schema(&schema, &data).unwrap_err();
+        if let ValidationError::RequiredFieldsMissing(violations) = err {
+            assert!(
+                violations.iter().any(|v| v.contains("exceeds maximum of 4")),
+                "violations: {:?}",
+                violations
+            );
+        } else {
+            panic!("Expected RequiredFieldsMissing");
+        }
+    }
+
+    fn schema_with_union() -> SchemaDefinition {
+        let mut person_fields = IndexMap::new();
+        person_fields.insert(
+            "name".into(),
+            FieldDefinition {
+                required: true,
+                ..FieldDefinition::new(FieldType::String)
+            },
+        );
+        let mut dept_fields = IndexMap::new();
+        dept_fields.insert(
+            "abteilung".into(),
+            FieldDefinition {
+                required: true,
+                ..FieldDefinition::new(FieldType::String)
+            },
+        );
+
+        let mut variants = IndexMap::new();
+        variants.insert(
+            "person".into(),
+            FieldDefinition {
+                fields: Some(person_fields),
+                ..FieldDefinition::new(FieldType::Table)
+            },
+        );
+        variants.insert(
+            "department".into(),
+            FieldDefinition {
+                fields: Some(dept_fields),
+                ..FieldDefinition::new(FieldType::Table)
+            },
+        );
+
+        let mut fields = IndexMap::new();
+        fields.insert(
+            "kontakt".into(),
+            FieldDefinition {
+                required: true,
+                fields: Some(variants),
+                ..FieldDefinition::new(FieldType::Union)
+            },
+        );
+        SchemaDefinition {
+            schema_id: "test.v1".into(),
+            version: 1,
+            description: None,
+            limits: None,
+            strict: false,
+            fields,
+        }
+    }
+
+    #[test]
+    fn test_union_valid_variant() {
+        let schema = schema_with_union();
+        let data = serde_json::json!({ "kontakt": { "person": { "name": "Dr. A" } } });
+        assert!(validate_against_schema(&schema, &data).is_ok());
+
+        let data = serde_json::json!({ "kontakt": { "department": { "abteilung": "Empfang" } } });
+        assert!(validate_against_schema(&schema, &data).is_ok());
+    }
+
+    #[test]
+    fn test_union_rejects_unknown_variant() {
+        let schema = schema_with_union();
+        let data = serde_json::json!({ "kontakt": { "roboter": {} } });
+        let err = validate_against_schema(&schema, &data).unwrap_err();
+        if let ValidationError::RequiredFieldsMissing(violations) = err {
+            assert!(
+                violations.iter().any(|v| v.contains("unknown union variant")
+                    && v.contains("person")),
+                "violations: {:?}",
+                violations
+            );
+        } else {
+            panic!("Expected RequiredFieldsMissing");
+        }
+    }
+
+    #[test]
+    fn test_union_rejects_multiple_variants() {
+        let schema = schema_with_union();
+        let data = serde_json::json!({
+            "kontakt": {
+                "person": { "name": "Dr. A" },
+                "department": { "abteilung": "Empfang" }
+            }
+        });
+        let err = validate_against_schema(&schema, &data).unwrap_err();
+        assert!(err.to_string().contains("exactly one variant"));
+    }
+
+    #[test]
+    fn test_union_recurses_into_variant() {
+        let schema = schema_with_union();
+        let data = serde_json::json!({ "kontakt": { "person": {} } });
+        let err = validate_against_schema(&schema, &data).unwrap_err();
+        if let ValidationError::RequiredFieldsMissing(violations) = err {
+            assert!(
+                violations.iter().any(|v| v.starts_with("kontakt.person.name:")),
+                "violations: {:?}",
+                violations
+            );
+        } else {
+            panic!("Expected RequiredFieldsMissing");
+        }
+    }
+
+    #[test]
+    fn test_bool_array_valid_and_rejects_mixed() {
+        let mut fields = IndexMap::new();
+        fields.insert(
+            "offen".into(),
+            FieldDefinition {
+                required: true,
+                ..FieldDefinition::new(FieldType::BoolArray)
+            },
+        );
+        let schema = SchemaDefinition {
+            schema_id: "test.v1".into(),
+            version: 1,
+            description: None,
+            limits: None,
+            strict: false,
+            fields,
+        };
+
+        let ok = serde_json::json!({ "offen": [true, false, true] });
+        assert!(validate_against_schema(&schema, &ok).is_ok());
+
+        let bad = serde_json::json!({ "offen": [true, 1, "ja"] });
+        assert!(validate_against_schema(&schema, &bad).is_err());
+    }
+
+    fn constrained_field(field_type: FieldType) -> FieldDefinition {
+        FieldDefinition {
+            required: true,
+            ..FieldDefinition::new(field_type)
+        }
+    }
+
+    fn single_field_schema(name: &str, def: FieldDefinition) -> SchemaDefinition {
+        let mut fields = IndexMap::new();
+        fields.insert(name.into(), def);
+        SchemaDefinition {
+            schema_id: "test.v1".into(),
+            version: 1,
+            description: None,
+            limits: None,
+            strict: false,
+            fields,
+        }
+    }
+
+    #[test]
+    fn test_numeric_min_max_constraints() {
+        let mut def = constrained_field(FieldType::Int);
+        def.min = Some(0.0);
+        def.max = Some(5.0);
+        let schema = single_field_schema("sterne", def);
+
+        assert!(validate_against_schema(&schema, &serde_json::json!({ "sterne": 3 })).is_ok());
+        assert!(validate_against_schema(&schema, &serde_json::json!({ "sterne": 0 })).is_ok());
+
+        let err =
+            validate_against_schema(&schema, &serde_json::json!({ "sterne": 7 })).unwrap_err();
+        if let ValidationError::RequiredFieldsMissing(violations) = err {
+            assert!(violations.iter().any(|v| v.contains("exceeds maximum 5")));
+        } else {
+            panic!("Expected RequiredFieldsMissing");
+        }
+    }
+
+    #[test]
+    fn test_string_length_and_pattern_constraints() {
+        let mut def = constrained_field(FieldType::String);
+        def.min_length = Some(5);
+        def.pattern = Some("[0-9]{5}".to_string());
+        let schema = single_field_schema("plz", def);
+
+        assert!(validate_against_schema(&schema, &serde_json::json!({ "plz": "10115" })).is_ok());
+
+        // Too short AND pattern mismatch — both violations collected
+        let err = validate_against_schema(&schema, &serde_json::json!({ "plz": "1a" })).unwrap_err();
+        if let ValidationError::RequiredFieldsMissing(violations) = err {
+            assert_eq!(violations.len(), 2);
+            assert!(violations.iter().any(|v| v.contains("below minimum length")));
+            assert!(violations.iter().any(|v| v.contains("does not match pattern")));
+        } else {
+            panic!("Expected RequiredFieldsMissing");
+        }
+    }
+
+    #[test]
+    fn test_pattern_is_anchored() {
+        let mut def = constrained_field(FieldType::String);
+        def.pattern = Some("[0-9]{5}".to_string());
+        let schema = single_field_schema("plz", def);
+
+        // A partial match inside a longer string must not pass
+        let err = validate_against_schema(&schema, &serde_json::json!({ "plz": "x10115y" }))
+            .unwrap_err();
+        assert!(matches!(err, ValidationError::RequiredFieldsMissing(_)));
+    }
+
+    #[test]
+    fn test_array_length_constraints() {
+        let mut def = constrained_field(FieldType::StringArray);
+        def.min_length = Some(1);
+        def.max_length = Some(2);
+        let schema = single_field_schema("sprachen", def);
+
+        assert!(
+            validate_against_schema(&schema, &serde_json::json!({ "sprachen": ["de"] })).is_ok()
+        );
+
+        let err = validate_against_schema(
+            &schema,
+            &serde_json::json!({ "sprachen": ["de", "en", "fr"] }),
+        )
+        .unwrap_err();
+        if let ValidationError::RequiredFieldsMissing(violations) = err {
+            assert!(violations.iter().any(|v| v.contains("maximum is 2")));
+        } else {
+            panic!("Expected RequiredFieldsMissing");
+        }
+    }
+
+    #[test]
+    fn test_invalid_pattern_reported() {
+        let mut def = constrained_field(FieldType::String);
+        def.pattern = Some("[unclosed".to_string());
+        let schema = single_field_schema("wert", def);
+
+        let err =
+            validate_against_schema(&schema, &serde_json::json!({ "wert": "x" })).unwrap_err();
+        if let ValidationError::RequiredFieldsMissing(violations) = err {
+            assert!(violations.iter().any(|v| v.contains("invalid pattern")));
+        } else {
+            panic!("Expected RequiredFieldsMissing");
+        }
+    }
+
+    #[test]
+    fn test_quality_warnings_do_not_block_validation() {
+        let mut schema = single_field_schema("name", constrained_field(FieldType::String));
+        let mut fax = constrained_field(FieldType::String);
+        fax.required = false;
+        fax.deprecated = true;
+        fax.replaced_by = Some("telefon".into());
+        schema.fields.insert("fax".into(), fax);
+        let mut tags = constrained_field(FieldType::StringArray);
+        tags.required = false;
+        schema.fields.insert("leistungen".into(), tags);
+        let mut telefon = constrained_field(FieldType::String);
+        telefon.required = false;
+        schema.fields.insert("telefon".into(), telefon);
+
+        let data = serde_json::json!({
+            "name": "Praxis Sonnenschein",
+            "fax": "+49 123 456789",
+            "leistungen": [],
+            "telefon": "123"
+        });
+
+        // Still a pass — warnings never block
+        assert!(validate_against_schema(&schema, &data).is_ok());
+
+        let warnings = validation_warnings(&schema, &data);
+        assert!(warnings.iter().any(|w| w.contains("deprecated")));
+        assert!(warnings.iter().any(|w| w.contains("empty array")));
+        assert!(warnings.iter().any(|w| w.contains("too short for a phone")));
+    }
+
+    #[test]
+    fn test_quality_warnings_empty_for_clean_data() {
+        let schema = single_field_schema("name", constrained_field(FieldType::String));
+        let data = serde_json::json!({ "name": "Praxis Sonnenschein" });
+        assert!(validation_warnings(&schema, &data).is_empty());
+    }
 }
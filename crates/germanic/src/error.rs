@@ -157,6 +157,102 @@ pub enum CompilationError {
     },
 }
 
+// ============================================================================
+// STABLE ERROR CODES (FFI / BINDINGS)
+// ============================================================================
+
+/// Stable numeric error codes for FFI, WASM and language bindings.
+///
+/// ## Stability Contract
+///
+/// These values are part of the binary interface: once released, a code
+/// NEVER changes meaning and is NEVER reused. New errors get new codes.
+/// Bindings pair the code with the UTF-8 message from `Display`
+/// (`error.to_string()`) — never match on message strings.
+///
+/// ## Code Space
+///
+/// ```text
+/// 0        OK (reserved, never returned as an error)
+/// 100–199  Validation      (required fields, types, constraints)
+/// 200–299  Input parsing   (JSON)
+/// 300–399  IO / filesystem
+/// 400–499  Schema resolution
+/// 900–999  Uncategorized
+/// ```
+#[repr(i32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorCode {
+    /// No error (reserved for bindings' success value).
+    Ok = 0,
+
+    /// Required fields missing or empty.
+    ValidationRequired = 100,
+
+    /// Field value has the wrong type.
+    ValidationType = 101,
+
+    /// Field value violates a constraint.
+    ValidationConstraint = 102,
+
+    /// Input is not valid JSON.
+    JsonParse = 200,
+
+    /// Filesystem or IO failure.
+    Io = 300,
+
+    /// Schema not found / not resolvable.
+    UnknownSchema = 400,
+
+    /// Error without a dedicated code (malformed buffers, limits, ...).
+    General = 900,
+}
+
+impl ErrorCode {
+    /// The numeric value passed across the FFI boundary.
+    pub fn as_i32(self) -> i32 {
+        self as i32
+    }
+
+    /// Stable symbolic name (for binding constants, e.g. `GERMANIC_ERR_IO`).
+    pub fn name(self) -> &'static str {
+        match self {
+            ErrorCode::Ok => "OK",
+            ErrorCode::ValidationRequired => "VALIDATION_REQUIRED",
+            ErrorCode::ValidationType => "VALIDATION_TYPE",
+            ErrorCode::ValidationConstraint => "VALIDATION_CONSTRAINT",
+            ErrorCode::JsonParse => "JSON_PARSE",
+            ErrorCode::Io => "IO",
+            ErrorCode::UnknownSchema => "UNKNOWN_SCHEMA",
+            ErrorCode::General => "GENERAL",
+        }
+    }
+}
+
+impl GermanicError {
+    /// Maps the error to its stable numeric code.
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            GermanicError::Validation(e) => e.code(),
+            GermanicError::Json(_) => ErrorCode::JsonParse,
+            GermanicError::Io(_) => ErrorCode::Io,
+            GermanicError::UnknownSchema(_) => ErrorCode::UnknownSchema,
+            GermanicError::General(_) => ErrorCode::General,
+        }
+    }
+}
+
+impl ValidationError {
+    /// Maps the error to its stable numeric code.
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            ValidationError::RequiredFieldsMissing(_) => ErrorCode::ValidationRequired,
+            ValidationError::TypeError { .. } => ErrorCode::ValidationType,
+            ValidationError::ConstraintViolation { .. } => ErrorCode::ValidationConstraint,
+        }
+    }
+}
+
 // ============================================================================
 // RESULT TYPE ALIAS
 // ============================================================================
@@ -192,6 +288,33 @@ mod tests {
         assert_eq!(error.to_string(), "Required fields missing: (none)");
     }
 
+    #[test]
+    fn test_error_codes_are_stable() {
+        // These numeric values are part of the FFI contract — a failing
+        // assertion here means an ABI break, not a test to update.
+        assert_eq!(ErrorCode::Ok.as_i32(), 0);
+        assert_eq!(ErrorCode::ValidationRequired.as_i32(), 100);
+        assert_eq!(ErrorCode::ValidationType.as_i32(), 101);
+        assert_eq!(ErrorCode::ValidationConstraint.as_i32(), 102);
+        assert_eq!(ErrorCode::JsonParse.as_i32(), 200);
+        assert_eq!(ErrorCode::Io.as_i32(), 300);
+        assert_eq!(ErrorCode::UnknownSchema.as_i32(), 400);
+        assert_eq!(ErrorCode::General.as_i32(), 900);
+    }
+
+    #[test]
+    fn test_error_code_mapping() {
+        let e = GermanicError::Validation(ValidationError::RequiredFieldsMissing(vec![]));
+        assert_eq!(e.code(), ErrorCode::ValidationRequired);
+
+        let e = GermanicError::UnknownSchema("x".into());
+        assert_eq!(e.code(), ErrorCode::UnknownSchema);
+        assert_eq!(e.code().name(), "UNKNOWN_SCHEMA");
+
+        let e = GermanicError::General("boom".into());
+        assert_eq!(e.code(), ErrorCode::General);
+    }
+
     #[test]
     fn test_error_conversion() {
         let validation_error = ValidationError::RequiredFieldsMissing(vec!["name".into()]);
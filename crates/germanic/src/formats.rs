@@ -0,0 +1,938 @@
+//! # Built-in Format Types
+//!
+//! Well-known string formats shipped as pre-registered
+//! [`FieldTypePlugin`]s, so `.schema.json` can say `"type": "date"`
+//! instead of hiding machine-checkable structure in free-text strings.
+//!
+//! ```text
+//! "type": "date"      → 2024-03-31            (full-date, RFC 3339)
+//! "type": "datetime"  → 2024-03-31T14:30:00Z  (date-time, RFC 3339)
+//! "type": "url"       → https://beispiel.de   (absolute http(s))
+//! "type": "email"     → praxis@beispiel.de    (local@domain.tld)
+//! "type": "phone"     → +4930123456           (E.164, normalized)
+//! "type": "money"     → 1250 EUR              (minor units + ISO 4217)
+//! ```
+//!
+//! Like every plugin type, formats are plain FlatBuffer strings on the
+//! wire — readers without format knowledge still see a readable value.
+//! `encode` normalizes case (`t`/`z` → `T`/`Z`) so equal instants
+//! compare byte-equal in .grm files.
+
+use crate::plugin::{FieldTypePlugin, PluginContext};
+use serde_json::Value;
+use std::sync::Arc;
+
+/// All built-in format plugins, in registration order.
+///
+/// Called once by the plugin registry on first use.
+pub(crate) fn builtin_plugins() -> Vec<Arc<dyn FieldTypePlugin>> {
+    vec![
+        Arc::new(DatePlugin),
+        Arc::new(DateTimePlugin),
+        Arc::new(UrlPlugin),
+        Arc::new(EmailPlugin),
+        Arc::new(PhonePlugin),
+        Arc::new(MoneyPlugin),
+    ]
+}
+
+/// Names of the built-in formats (for capability reports).
+pub fn builtin_format_names() -> Vec<&'static str> {
+    vec!["date", "datetime", "url", "email", "phone", "money"]
+}
+
+// ============================================================================
+// DATE
+// ============================================================================
+
+/// RFC 3339 full-date: `YYYY-MM-DD` with real month/day ranges.
+struct DatePlugin;
+
+impl FieldTypePlugin for DatePlugin {
+    fn name(&self) -> &'static str {
+        "date"
+    }
+
+    fn validate(&self, value: &Value) -> Result<(), String> {
+        let s = value.as_str().ok_or("expected string")?;
+        if valid_date(s) {
+            Ok(())
+        } else {
+            Err(format!("'{}' is not a valid date (expected YYYY-MM-DD)", s))
+        }
+    }
+}
+
+// ============================================================================
+// DATETIME
+// ============================================================================
+
+/// RFC 3339 date-time: `YYYY-MM-DDThh:mm:ss[.frac](Z|±hh:mm)`.
+struct DateTimePlugin;
+
+impl FieldTypePlugin for DateTimePlugin {
+    fn name(&self) -> &'static str {
+        "datetime"
+    }
+
+    fn validate(&self, value: &Value) -> Result<(), String> {
+        let s = value.as_str().ok_or("expected string")?;
+        if valid_datetime(s) {
+            Ok(())
+        } else {
+            Err(format!(
+                "'{}' is not a valid datetime (expected RFC 3339, e.g. 2024-03-31T14:30:00Z)",
+                s
+            ))
+        }
+    }
+
+    fn encode(&self, value: &Value) -> Result<String, String> {
+        let s = value.as_str().ok_or("expected string")?;
+        // RFC 3339 allows lowercase 't' and 'z'; normalize so equal
+        // instants are byte-equal on the wire
+        Ok(s.replace('t', "T").replace('z', "Z"))
+    }
+}
+
+// ============================================================================
+// URL
+// ============================================================================
+
+/// Absolute http(s) URL: scheme, `://`, a plausible host, optional rest.
+struct UrlPlugin;
+
+impl FieldTypePlugin for UrlPlugin {
+    fn name(&self) -> &'static str {
+        "url"
+    }
+
+    fn validate(&self, value: &Value) -> Result<(), String> {
+        let s = value.as_str().ok_or("expected string")?;
+        if valid_url(s) {
+            Ok(())
+        } else {
+            Err(format!(
+                "'{}' is not a valid URL (expected absolute http:// or https://)",
+                s
+            ))
+        }
+    }
+}
+
+// ============================================================================
+// EMAIL
+// ============================================================================
+
+/// Email address: one `@`, plausible local part and dotted domain.
+///
+/// `encode` lowercases the domain (domains are case-insensitive, local
+/// parts technically are not), which also normalizes IDN domains the
+/// publisher already wrote in Unicode consistently.
+struct EmailPlugin;
+
+impl FieldTypePlugin for EmailPlugin {
+    fn name(&self) -> &'static str {
+        "email"
+    }
+
+    fn validate(&self, value: &Value) -> Result<(), String> {
+        let s = value.as_str().ok_or("expected string")?;
+        if valid_email(s) {
+            Ok(())
+        } else {
+            Err(format!(
+                "'{}' is not a valid email address (expected local@domain.tld)",
+                s
+            ))
+        }
+    }
+
+    fn encode(&self, value: &Value) -> Result<String, String> {
+        let s = value.as_str().ok_or("expected string")?;
+        Ok(match s.rsplit_once('@') {
+            Some((local, domain)) => format!("{}@{}", local, domain.to_lowercase()),
+            None => s.to_string(),
+        })
+    }
+}
+
+// ============================================================================
+// PHONE
+// ============================================================================
+
+/// Phone number, normalized to E.164 on the wire.
+///
+/// Publishers write numbers however their website shows them
+/// (`030 / 123 456-78`, `+49 30 1234567`); `encode` strips formatting
+/// and resolves the national prefix against the record's country: a
+/// sibling `land` or `country` field next to the number. Without a
+/// known country, national-format numbers are rejected rather than
+/// silently assumed domestic.
+struct PhonePlugin;
+
+/// Resolves the calling code from a sibling `land`/`country` field.
+fn phone_country_hint(ctx: &PluginContext<'_>) -> Option<&'static str> {
+    let alpha2 = ctx
+        .siblings
+        .get("land")
+        .or_else(|| ctx.siblings.get("country"))?
+        .as_str()?;
+    calling_code(alpha2)
+}
+
+impl FieldTypePlugin for PhonePlugin {
+    fn name(&self) -> &'static str {
+        "phone"
+    }
+
+    fn validate(&self, value: &Value) -> Result<(), String> {
+        let s = value.as_str().ok_or("expected string")?;
+        normalize_phone(s, None).map(|_| ())
+    }
+
+    fn encode(&self, value: &Value) -> Result<String, String> {
+        let s = value.as_str().ok_or("expected string")?;
+        normalize_phone(s, None)
+    }
+
+    fn validate_with_context(&self, value: &Value, ctx: &PluginContext<'_>) -> Result<(), String> {
+        let s = value.as_str().ok_or("expected string")?;
+        normalize_phone(s, phone_country_hint(ctx)).map(|_| ())
+    }
+
+    fn encode_with_context(&self, value: &Value, ctx: &PluginContext<'_>) -> Result<String, String> {
+        let s = value.as_str().ok_or("expected string")?;
+        normalize_phone(s, phone_country_hint(ctx))
+    }
+}
+
+// ============================================================================
+// MONEY
+// ============================================================================
+
+/// Amount of money: minor units integer + ISO 4217 currency code.
+///
+/// JSON representation is an object so prices are never floats:
+///
+/// ```json
+/// { "amount": 1250, "currency": "EUR" }   // 12,50 €
+/// ```
+///
+/// Wire string is `"<amount> <currency>"` (`"1250 EUR"`); `decode`
+/// reconstructs the object, so the round trip is lossless.
+struct MoneyPlugin;
+
+impl FieldTypePlugin for MoneyPlugin {
+    fn name(&self) -> &'static str {
+        "money"
+    }
+
+    fn validate(&self, value: &Value) -> Result<(), String> {
+        parse_money(value).map(|_| ())
+    }
+
+    fn encode(&self, value: &Value) -> Result<String, String> {
+        let (amount, currency) = parse_money(value)?;
+        Ok(format!("{} {}", amount, currency))
+    }
+
+    fn decode(&self, raw: &str) -> Value {
+        // "1250 EUR" → { "amount": 1250, "currency": "EUR" }
+        if let Some((amount, currency)) = raw.split_once(' ') {
+            if let Ok(amount) = amount.parse::<i64>() {
+                return serde_json::json!({ "amount": amount, "currency": currency });
+            }
+        }
+        // Unparseable wire string: surface it rather than invent a value
+        Value::String(raw.to_string())
+    }
+}
+
+/// Extracts (minor units, ISO 4217 code) from a money object.
+fn parse_money(value: &Value) -> Result<(i64, String), String> {
+    let obj = value
+        .as_object()
+        .ok_or("expected object { \"amount\": <minor units>, \"currency\": \"EUR\" }")?;
+
+    let amount = obj
+        .get("amount")
+        .and_then(|v| v.as_i64())
+        .ok_or("'amount' must be an integer in minor units (cents), not a float or string")?;
+
+    let currency = obj
+        .get("currency")
+        .and_then(|v| v.as_str())
+        .ok_or("'currency' must be a string")?;
+    if currency.len() != 3 || !currency.chars().all(|c| c.is_ascii_uppercase()) {
+        return Err(format!(
+            "'{}' is not an ISO 4217 currency code (expected three uppercase letters, e.g. EUR)",
+            currency
+        ));
+    }
+
+    let unknown: Vec<&String> = obj
+        .keys()
+        .filter(|k| k.as_str() != "amount" && k.as_str() != "currency")
+        .collect();
+    if !unknown.is_empty() {
+        return Err(format!("unexpected keys in money object: {:?}", unknown));
+    }
+
+    Ok((amount, currency.to_string()))
+}
+
+// ============================================================================
+// UUID
+// ============================================================================
+//
+// Unlike the plugin formats above, `uuid` is a first-class field type
+// ([`crate::dynamic::schema_def::FieldType::Uuid`]) because its wire
+// layout differs: 16 raw bytes instead of the 36-char string. The codec
+// lives here with the other well-known formats.
+
+/// Parses a canonical hyphenated UUID (`8-4-4-4-12` hex digits).
+///
+/// Accepts upper- and lowercase hex; [`format_uuid`] always emits
+/// lowercase, so mixed-case input is normalized by a round trip.
+pub(crate) fn parse_uuid(s: &str) -> Result<[u8; 16], String> {
+    let b = s.as_bytes();
+    if b.len() != 36 || b[8] != b'-' || b[13] != b'-' || b[18] != b'-' || b[23] != b'-' {
+        return Err(format!(
+            "'{}' is not a valid UUID (expected xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx)",
+            s
+        ));
+    }
+
+    let mut out = [0u8; 16];
+    let mut index = 0;
+    let mut iter = b.iter().filter(|c| **c != b'-');
+    while let (Some(hi), Some(lo)) = (iter.next(), iter.next()) {
+        let (Some(hi), Some(lo)) = (hex_value(*hi), hex_value(*lo)) else {
+            return Err(format!("'{}' is not a valid UUID (non-hex digit)", s));
+        };
+        out[index] = (hi << 4) | lo;
+        index += 1;
+    }
+    Ok(out)
+}
+
+/// Formats 16 bytes as a lowercase hyphenated UUID string.
+pub(crate) fn format_uuid(bytes: &[u8; 16]) -> String {
+    let mut out = String::with_capacity(36);
+    for (i, byte) in bytes.iter().enumerate() {
+        if matches!(i, 4 | 6 | 8 | 10) {
+            out.push('-');
+        }
+        out.push(char::from_digit((byte >> 4) as u32, 16).unwrap_or('0'));
+        out.push(char::from_digit((byte & 0x0F) as u32, 16).unwrap_or('0'));
+    }
+    out
+}
+
+/// Maps one hex digit to its value.
+fn hex_value(c: u8) -> Option<u8> {
+    match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        b'A'..=b'F' => Some(c - b'A' + 10),
+        _ => None,
+    }
+}
+
+// ============================================================================
+// PARSING (dependency-free, strict)
+// ============================================================================
+
+/// Checks an absolute http(s) URL: scheme + `://` + host, no whitespace.
+///
+/// Deliberately shallow — the goal is catching typos like `htps:/oops`,
+/// not full WHATWG URL parsing. The host must contain a dot or be
+/// `localhost`, with only hostname characters before port/path.
+pub(crate) fn valid_url(s: &str) -> bool {
+    let rest = if let Some(r) = s.strip_prefix("https://") {
+        r
+    } else if let Some(r) = s.strip_prefix("http://") {
+        r
+    } else {
+        return false;
+    };
+
+    if s.chars().any(|c| c.is_whitespace() || c.is_control()) {
+        return false;
+    }
+
+    // Host ends at the first '/', '?', '#' or ':' (port)
+    let host_end = rest
+        .find(['/', '?', '#', ':'])
+        .unwrap_or(rest.len());
+    let host = &rest[..host_end];
+
+    if host.is_empty() || host.starts_with('.') || host.ends_with('.') || host.starts_with('-') {
+        return false;
+    }
+    if !host
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '.' || c == '-')
+    {
+        return false;
+    }
+    host.contains('.') || host == "localhost"
+}
+
+/// Maps an ISO 3166-1 alpha-2 country code to its E.164 calling code.
+///
+/// Covers Germany and its neighbours — the markets the shipped schemas
+/// target. Unknown codes yield `None`, which makes national-format
+/// numbers from that country an error rather than a wrong guess.
+pub(crate) fn calling_code(alpha2: &str) -> Option<&'static str> {
+    match alpha2.to_ascii_uppercase().as_str() {
+        "DE" => Some("49"),
+        "AT" => Some("43"),
+        "CH" => Some("41"),
+        "NL" => Some("31"),
+        "BE" => Some("32"),
+        "LU" => Some("352"),
+        "FR" => Some("33"),
+        "DK" => Some("45"),
+        "PL" => Some("48"),
+        "CZ" => Some("420"),
+        _ => None,
+    }
+}
+
+/// Normalizes a phone number to E.164 (`+<country><number>`).
+///
+/// Accepted separators: spaces, `-`, `/`, `(`, `)`, `.`. Prefix rules:
+/// `+49…` kept, `0049…` → `+49…`, national `030…` resolved against
+/// `default_country` (an E.164 calling code such as `"49"`, typically
+/// derived from a sibling `land` field via [`calling_code`]). With no
+/// known country a national-format number is rejected, not guessed.
+/// E.164 allows at most 15 digits; fewer than 7 is treated as a typo.
+pub(crate) fn normalize_phone(s: &str, default_country: Option<&str>) -> Result<String, String> {
+    let mut digits = String::new();
+    let mut plus = false;
+
+    for (i, c) in s.chars().enumerate() {
+        match c {
+            '+' if i == 0 => plus = true,
+            '0'..='9' => digits.push(c),
+            ' ' | '-' | '/' | '(' | ')' | '.' => {}
+            _ => {
+                return Err(format!(
+                    "'{}' is not a valid phone number (unexpected character '{}')",
+                    s, c
+                ));
+            }
+        }
+    }
+
+    // Resolve prefix to international form
+    let e164 = if plus {
+        digits
+    } else if let Some(rest) = digits.strip_prefix("00") {
+        rest.to_string()
+    } else if let Some(rest) = digits.strip_prefix('0') {
+        match default_country {
+            Some(cc) => format!("{}{}", cc, rest),
+            None => {
+                return Err(format!(
+                    "'{}' is in national format but no country is known — \
+                     write it internationally (+49 …) or add a 'land' field",
+                    s
+                ));
+            }
+        }
+    } else {
+        return Err(format!(
+            "'{}' is not a valid phone number (expected +, 00 or 0 prefix)",
+            s
+        ));
+    };
+
+    if e164.starts_with('0') {
+        return Err(format!("'{}' is not a valid phone number (zero after country code)", s));
+    }
+    if !(7..=15).contains(&e164.len()) {
+        return Err(format!(
+            "'{}' is not a valid phone number (E.164 allows 7-15 digits, got {})",
+            s,
+            e164.len()
+        ));
+    }
+
+    Ok(format!("+{}", e164))
+}
+
+/// Checks a plausible email address.
+///
+/// Syntactic only (no MX lookup): exactly one `@` split, non-empty
+/// local part without whitespace, domain with at least one dot and no
+/// empty labels. Unicode (IDN) domains pass — they are normalized by
+/// the plugin's `encode`, not rejected here.
+pub(crate) fn valid_email(s: &str) -> bool {
+    let Some((local, domain)) = s.rsplit_once('@') else {
+        return false;
+    };
+
+    if local.is_empty()
+        || local.len() > 64
+        || local.contains('@')
+        || local.chars().any(|c| c.is_whitespace() || c.is_control())
+    {
+        return false;
+    }
+    if local.starts_with('.') || local.ends_with('.') || local.contains("..") {
+        return false;
+    }
+
+    if domain.is_empty()
+        || !domain.contains('.')
+        || domain.chars().any(|c| c.is_whitespace() || c.is_control())
+    {
+        return false;
+    }
+    // No empty labels, no leading/trailing hyphens per label
+    domain
+        .split('.')
+        .all(|label| !label.is_empty() && !label.starts_with('-') && !label.ends_with('-'))
+}
+
+/// Checks `YYYY-MM-DD` including month lengths and leap years.
+pub(crate) fn valid_date(s: &str) -> bool {
+    let b = s.as_bytes();
+    if b.len() != 10 || b[4] != b'-' || b[7] != b'-' {
+        return false;
+    }
+    let Some(year) = parse_digits(&b[0..4]) else {
+        return false;
+    };
+    let Some(month) = parse_digits(&b[5..7]) else {
+        return false;
+    };
+    let Some(day) = parse_digits(&b[8..10]) else {
+        return false;
+    };
+    (1..=12).contains(&month) && day >= 1 && day <= days_in_month(year, month)
+}
+
+/// Checks a full RFC 3339 date-time.
+pub(crate) fn valid_datetime(s: &str) -> bool {
+    let b = s.as_bytes();
+    if b.len() < 11 || !valid_date(&s[0..10]) {
+        return false;
+    }
+    if b[10] != b'T' && b[10] != b't' {
+        return false;
+    }
+    valid_time_with_offset(&s[11..])
+}
+
+/// Checks `hh:mm:ss[.frac](Z|±hh:mm)`.
+fn valid_time_with_offset(s: &str) -> bool {
+    let b = s.as_bytes();
+    if b.len() < 9 || b[2] != b':' || b[5] != b':' {
+        return false;
+    }
+    let Some(hour) = parse_digits(&b[0..2]) else {
+        return false;
+    };
+    let Some(minute) = parse_digits(&b[3..5]) else {
+        return false;
+    };
+    let Some(second) = parse_digits(&b[6..8]) else {
+        return false;
+    };
+    if hour > 23 || minute > 59 || second > 60 {
+        // 60 allows leap seconds, per RFC 3339
+        return false;
+    }
+
+    // Optional fraction: '.' followed by at least one digit
+    let mut rest = &b[8..];
+    if rest.first() == Some(&b'.') {
+        let digits = rest[1..].iter().take_while(|c| c.is_ascii_digit()).count();
+        if digits == 0 {
+            return false;
+        }
+        rest = &rest[1 + digits..];
+    }
+
+    valid_offset(rest)
+}
+
+/// Checks `Z` / `z` / `±hh:mm`.
+fn valid_offset(b: &[u8]) -> bool {
+    match b {
+        [b'Z'] | [b'z'] => true,
+        [sign, h1, h2, b':', m1, m2] if *sign == b'+' || *sign == b'-' => {
+            let Some(hour) = parse_digits(&[*h1, *h2]) else {
+                return false;
+            };
+            let Some(minute) = parse_digits(&[*m1, *m2]) else {
+                return false;
+            };
+            hour <= 23 && minute <= 59
+        }
+        _ => false,
+    }
+}
+
+/// Parses an all-digit byte slice as u32.
+fn parse_digits(b: &[u8]) -> Option<u32> {
+    if b.is_empty() || !b.iter().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let mut n: u32 = 0;
+    for c in b {
+        n = n.checked_mul(10)?.checked_add((c - b'0') as u32)?;
+    }
+    Some(n)
+}
+
+/// Days per month, with Gregorian leap years for February.
+fn days_in_month(year: u32, month: u32) -> u32 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 => {
+            let leap = (year % 4 == 0 && year % 100 != 0) || year % 400 == 0;
+            if leap { 29 } else { 28 }
+        }
+        _ => 0,
+    }
+}
+
+// ============================================================================
+// TESTS
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dynamic::schema_def::{FieldDefinition, FieldType, SchemaDefinition};
+    use indexmap::IndexMap;
+
+    #[test]
+    fn test_valid_dates() {
+        assert!(valid_date("2024-03-31"));
+        assert!(valid_date("2024-02-29")); // leap year
+        assert!(valid_date("2000-02-29")); // 400-year leap rule
+    }
+
+    #[test]
+    fn test_invalid_dates() {
+        assert!(!valid_date("2023-02-29")); // not a leap year
+        assert!(!valid_date("1900-02-29")); // 100-year exception
+        assert!(!valid_date("2024-13-01"));
+        assert!(!valid_date("2024-04-31"));
+        assert!(!valid_date("2024-00-10"));
+        assert!(!valid_date("24-03-31"));
+        assert!(!valid_date("2024/03/31"));
+        assert!(!valid_date("morgen"));
+    }
+
+    #[test]
+    fn test_valid_datetimes() {
+        assert!(valid_datetime("2024-03-31T14:30:00Z"));
+        assert!(valid_datetime("2024-03-31t14:30:00z"));
+        assert!(valid_datetime("2024-03-31T14:30:00.123Z"));
+        assert!(valid_datetime("2024-03-31T14:30:00+02:00"));
+        assert!(valid_datetime("2024-03-31T23:59:60Z")); // leap second
+    }
+
+    #[test]
+    fn test_invalid_datetimes() {
+        assert!(!valid_datetime("2024-03-31"));
+        assert!(!valid_datetime("2024-03-31T24:00:00Z"));
+        assert!(!valid_datetime("2024-03-31T14:30:00")); // missing offset
+        assert!(!valid_datetime("2024-03-31T14:30:00.Z")); // empty fraction
+        assert!(!valid_datetime("2024-03-31 14:30:00Z")); // space separator
+    }
+
+    #[test]
+    fn test_valid_urls() {
+        assert!(valid_url("https://praxis-mueller.de"));
+        assert!(valid_url("https://praxis-mueller.de/kontakt?lang=de#oben"));
+        assert!(valid_url("http://localhost:8080/grm"));
+        assert!(valid_url("https://www.beispiel.de:443"));
+    }
+
+    #[test]
+    fn test_invalid_urls() {
+        assert!(!valid_url("htps:/oops")); // the typo from the bug report
+        assert!(!valid_url("ftp://beispiel.de")); // wrong scheme
+        assert!(!valid_url("https://")); // no host
+        assert!(!valid_url("https://kein punkt.de")); // whitespace
+        assert!(!valid_url("beispiel.de")); // relative
+        assert!(!valid_url("https://nopunkt"));
+    }
+
+    #[test]
+    fn test_valid_emails() {
+        assert!(valid_email("praxis@beispiel.de"));
+        assert!(valid_email("dr.mueller+termine@praxis-mueller.de"));
+        assert!(valid_email("info@müller-praxis.de")); // IDN domain
+    }
+
+    #[test]
+    fn test_invalid_emails() {
+        assert!(!valid_email("keine-adresse"));
+        assert!(!valid_email("@beispiel.de")); // empty local part
+        assert!(!valid_email("info@")); // empty domain
+        assert!(!valid_email("info@nopunkt"));
+        assert!(!valid_email("info@beispiel..de")); // empty label
+        assert!(!valid_email("in fo@beispiel.de")); // whitespace
+        assert!(!valid_email(".info@beispiel.de")); // leading dot
+    }
+
+    #[test]
+    fn test_normalize_phone_variants() {
+        // Same practice number in the formats websites actually use
+        assert_eq!(
+            normalize_phone("+49 30 1234567", None).unwrap(),
+            "+49301234567"
+        );
+        assert_eq!(
+            normalize_phone("030 / 123 45 67", Some("49")).unwrap(),
+            "+49301234567"
+        );
+        assert_eq!(
+            normalize_phone("0049 30 1234567", None).unwrap(),
+            "+49301234567"
+        );
+        assert_eq!(
+            normalize_phone("(030) 123-4567", Some("49")).unwrap(),
+            "+49301234567"
+        );
+        // Non-German numbers keep their country code
+        assert_eq!(normalize_phone("+43 1 234 5678", None).unwrap(), "+4312345678");
+        // National format resolves against the record's country, not +49
+        assert_eq!(
+            normalize_phone("01 234 5678", Some("43")).unwrap(),
+            "+4312345678"
+        );
+    }
+
+    #[test]
+    fn test_normalize_phone_rejects_invalid() {
+        assert!(normalize_phone("Termin nach Vereinbarung", Some("49")).is_err());
+        assert!(normalize_phone("1234567", Some("49")).is_err()); // no prefix
+        assert!(normalize_phone("030 12", Some("49")).is_err()); // too short
+        assert!(normalize_phone("+49 00 1234567890123456", None).is_err()); // too long
+    }
+
+    #[test]
+    fn test_normalize_phone_national_needs_known_country() {
+        // Without a country there is nothing to resolve '0…' against
+        let err = normalize_phone("030 / 123 45 67", None).unwrap_err();
+        assert!(err.contains("no country is known"));
+        // International input never needs one
+        assert!(normalize_phone("+49 30 1234567", None).is_ok());
+    }
+
+    #[test]
+    fn test_calling_codes() {
+        assert_eq!(calling_code("DE"), Some("49"));
+        assert_eq!(calling_code("at"), Some("43")); // case-insensitive
+        assert_eq!(calling_code("US"), None); // outside the shipped markets
+    }
+
+    fn phone_schema() -> SchemaDefinition {
+        let mut fields = IndexMap::new();
+        fields.insert(
+            "telefon".into(),
+            FieldDefinition {
+                required: true,
+                ..FieldDefinition::new(FieldType::Custom("phone".into()))
+            },
+        );
+        fields.insert("land".into(), FieldDefinition::new(FieldType::String));
+        SchemaDefinition {
+            schema_id: "test.v1".into(),
+            version: 1,
+            description: None,
+            limits: None,
+            strict: false,
+            fields,
+        }
+    }
+
+    #[test]
+    fn test_phone_roundtrip_normalizes() {
+        let schema = phone_schema();
+
+        // The sibling 'land' field supplies the country for national format
+        let data = serde_json::json!({ "telefon": "030 / 123 45 67", "land": "DE" });
+        assert!(crate::dynamic::validate::validate_against_schema(&schema, &data).is_ok());
+
+        let payload = crate::dynamic::builder::build_flatbuffer(&schema, &data).unwrap();
+        let decoded = crate::reader::decode_payload(&schema, &payload).unwrap();
+        assert_eq!(decoded["telefon"], "+49301234567");
+    }
+
+    #[test]
+    fn test_phone_national_without_country_rejected() {
+        let schema = phone_schema();
+
+        // No 'land' sibling: national format cannot be resolved
+        let data = serde_json::json!({ "telefon": "030 / 123 45 67" });
+        let err = crate::dynamic::validate::validate_against_schema(&schema, &data).unwrap_err();
+        assert!(err.to_string().contains("no country is known"));
+        assert!(crate::dynamic::builder::build_flatbuffer(&schema, &data).is_err());
+
+        // International format needs no country hint
+        let data = serde_json::json!({ "telefon": "+49 30 123 45 67" });
+        assert!(crate::dynamic::validate::validate_against_schema(&schema, &data).is_ok());
+    }
+
+    #[test]
+    fn test_parse_money_valid() {
+        let v = serde_json::json!({ "amount": 1250, "currency": "EUR" });
+        assert_eq!(parse_money(&v).unwrap(), (1250, "EUR".to_string()));
+    }
+
+    #[test]
+    fn test_parse_money_rejects_floats_and_bad_codes() {
+        // A float amount is exactly the ambiguity this type exists to kill
+        assert!(parse_money(&serde_json::json!({ "amount": 12.50, "currency": "EUR" })).is_err());
+        assert!(parse_money(&serde_json::json!({ "amount": "1250", "currency": "EUR" })).is_err());
+        assert!(parse_money(&serde_json::json!({ "amount": 1250, "currency": "eur" })).is_err());
+        assert!(parse_money(&serde_json::json!({ "amount": 1250, "currency": "EURO" })).is_err());
+        assert!(parse_money(&serde_json::json!("12,50 €")).is_err());
+        assert!(parse_money(&serde_json::json!({ "amount": 1250 })).is_err());
+    }
+
+    #[test]
+    fn test_money_roundtrip() {
+        let mut fields = IndexMap::new();
+        fields.insert(
+            "preis".into(),
+            FieldDefinition {
+                required: true,
+                ..FieldDefinition::new(FieldType::Custom("money".into()))
+            },
+        );
+        let schema = SchemaDefinition {
+            schema_id: "test.v1".into(),
+            version: 1,
+            description: None,
+            limits: None,
+            strict: false,
+            fields,
+        };
+
+        let data = serde_json::json!({ "preis": { "amount": 1250, "currency": "EUR" } });
+        assert!(crate::dynamic::validate::validate_against_schema(&schema, &data).is_ok());
+
+        let payload = crate::dynamic::builder::build_flatbuffer(&schema, &data).unwrap();
+        let decoded = crate::reader::decode_payload(&schema, &payload).unwrap();
+        assert_eq!(decoded, data);
+    }
+
+    #[test]
+    fn test_email_encode_lowercases_domain() {
+        let mut fields = IndexMap::new();
+        fields.insert(
+            "kontakt".into(),
+            FieldDefinition {
+                required: true,
+                ..FieldDefinition::new(FieldType::Custom("email".into()))
+            },
+        );
+        let schema = SchemaDefinition {
+            schema_id: "test.v1".into(),
+            version: 1,
+            description: None,
+            limits: None,
+            strict: false,
+            fields,
+        };
+
+        let data = serde_json::json!({ "kontakt": "Info@Beispiel.DE" });
+        let payload = crate::dynamic::builder::build_flatbuffer(&schema, &data).unwrap();
+        let decoded = crate::reader::decode_payload(&schema, &payload).unwrap();
+        // Local-part case kept, domain lowercased
+        assert_eq!(decoded["kontakt"], "Info@beispiel.de");
+    }
+
+    #[test]
+    fn test_parse_uuid_roundtrip() {
+        let s = "550e8400-e29b-41d4-a716-446655440000";
+        let bytes = parse_uuid(s).unwrap();
+        assert_eq!(format_uuid(&bytes), s);
+
+        // Uppercase input normalizes to lowercase
+        let upper = parse_uuid(&s.to_uppercase()).unwrap();
+        assert_eq!(format_uuid(&upper), s);
+    }
+
+    #[test]
+    fn test_parse_uuid_rejects_invalid() {
+        assert!(parse_uuid("550e8400e29b41d4a716446655440000").is_err()); // no hyphens
+        assert!(parse_uuid("550e8400-e29b-41d4-a716-44665544000g").is_err()); // non-hex
+        assert!(parse_uuid("550e8400-e29b-41d4-a716").is_err()); // too short
+        assert!(parse_uuid("irgendwas").is_err());
+    }
+
+    #[test]
+    fn test_builtin_plugins_are_registered() {
+        assert!(crate::plugin::lookup_plugin("date").is_some());
+        assert!(crate::plugin::lookup_plugin("datetime").is_some());
+        assert!(crate::plugin::lookup_plugin("url").is_some());
+        assert!(crate::plugin::lookup_plugin("email").is_some());
+        assert!(crate::plugin::lookup_plugin("phone").is_some());
+        assert!(crate::plugin::lookup_plugin("money").is_some());
+    }
+
+    #[test]
+    fn test_datetime_roundtrip_normalizes_case() {
+        let mut fields = IndexMap::new();
+        fields.insert(
+            "geaendert".into(),
+            FieldDefinition {
+                required: true,
+                ..FieldDefinition::new(FieldType::Custom("datetime".into()))
+            },
+        );
+        let schema = SchemaDefinition {
+            schema_id: "test.v1".into(),
+            version: 1,
+            description: None,
+            limits: None,
+            strict: false,
+            fields,
+        };
+
+        let data = serde_json::json!({ "geaendert": "2024-03-31t14:30:00z" });
+        assert!(crate::dynamic::validate::validate_against_schema(&schema, &data).is_ok());
+
+        let payload = crate::dynamic::builder::build_flatbuffer(&schema, &data).unwrap();
+        let decoded = crate::reader::decode_payload(&schema, &payload).unwrap();
+        assert_eq!(decoded["geaendert"], "2024-03-31T14:30:00Z");
+    }
+
+    #[test]
+    fn test_date_validation_in_schema() {
+        let mut fields = IndexMap::new();
+        fields.insert(
+            "geburtstag".into(),
+            FieldDefinition {
+                required: true,
+                ..FieldDefinition::new(FieldType::Custom("date".into()))
+            },
+        );
+        let schema = SchemaDefinition {
+            schema_id: "test.v1".into(),
+            version: 1,
+            description: None,
+            limits: None,
+            strict: false,
+            fields,
+        };
+
+        let bad = serde_json::json!({ "geburtstag": "31.03.2024" });
+        let err = crate::dynamic::validate::validate_against_schema(&schema, &bad).unwrap_err();
+        assert!(err.to_string().contains("not a valid date"));
+    }
+}
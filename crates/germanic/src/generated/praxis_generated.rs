@@ -184,6 +184,280 @@ impl ::core::fmt::Debug for Adresse<'_> {
       ds.finish()
   }
 }
+pub enum OeffnungsintervallOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// Ein einzelnes Öffnungsintervall an einem Wochentag.
+/// Ein Tag mit Mittagspause bekommt zwei Intervalle.
+pub struct Oeffnungsintervall<'a> {
+  pub _tab: ::flatbuffers::Table<'a>,
+}
+
+impl<'a> ::flatbuffers::Follow<'a> for Oeffnungsintervall<'a> {
+  type Inner = Oeffnungsintervall<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { ::flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Oeffnungsintervall<'a> {
+  pub const VT_TAG: ::flatbuffers::VOffsetT = 4;
+  pub const VT_VON: ::flatbuffers::VOffsetT = 6;
+  pub const VT_BIS: ::flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: ::flatbuffers::Table<'a>) -> Self {
+    Oeffnungsintervall { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: ::flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut ::flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args OeffnungsintervallArgs<'args>
+  ) -> ::flatbuffers::WIPOffset<Oeffnungsintervall<'bldr>> {
+    let mut builder = OeffnungsintervallBuilder::new(_fbb);
+    if let Some(x) = args.bis { builder.add_bis(x); }
+    if let Some(x) = args.von { builder.add_von(x); }
+    if let Some(x) = args.tag { builder.add_tag(x); }
+    builder.finish()
+  }
+
+
+  /// Wochentags-Kürzel ("Mo", "Di", "Mi", "Do", "Fr", "Sa", "So")
+  #[inline]
+  pub fn tag(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<&str>>(Oeffnungsintervall::VT_TAG, None).unwrap()}
+  }
+  /// Öffnungszeit im 24h-Format, z.B. "09:00"
+  #[inline]
+  pub fn von(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<&str>>(Oeffnungsintervall::VT_VON, None).unwrap()}
+  }
+  /// Schließzeit im 24h-Format, z.B. "17:00"
+  #[inline]
+  pub fn bis(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<&str>>(Oeffnungsintervall::VT_BIS, None).unwrap()}
+  }
+}
+
+impl ::flatbuffers::Verifiable for Oeffnungsintervall<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut ::flatbuffers::Verifier, pos: usize
+  ) -> Result<(), ::flatbuffers::InvalidFlatbuffer> {
+    v.visit_table(pos)?
+     .visit_field::<::flatbuffers::ForwardsUOffset<&str>>("tag", Self::VT_TAG, true)?
+     .visit_field::<::flatbuffers::ForwardsUOffset<&str>>("von", Self::VT_VON, true)?
+     .visit_field::<::flatbuffers::ForwardsUOffset<&str>>("bis", Self::VT_BIS, true)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct OeffnungsintervallArgs<'a> {
+    pub tag: Option<::flatbuffers::WIPOffset<&'a str>>,
+    pub von: Option<::flatbuffers::WIPOffset<&'a str>>,
+    pub bis: Option<::flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for OeffnungsintervallArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    OeffnungsintervallArgs {
+      tag: None, // required field
+      von: None, // required field
+      bis: None, // required field
+    }
+  }
+}
+
+pub struct OeffnungsintervallBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: ::flatbuffers::WIPOffset<::flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> OeffnungsintervallBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_tag(&mut self, tag: ::flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(Oeffnungsintervall::VT_TAG, tag);
+  }
+  #[inline]
+  pub fn add_von(&mut self, von: ::flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(Oeffnungsintervall::VT_VON, von);
+  }
+  #[inline]
+  pub fn add_bis(&mut self, bis: ::flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(Oeffnungsintervall::VT_BIS, bis);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> OeffnungsintervallBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    OeffnungsintervallBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> ::flatbuffers::WIPOffset<Oeffnungsintervall<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, Oeffnungsintervall::VT_TAG,"tag");
+    self.fbb_.required(o, Oeffnungsintervall::VT_VON,"von");
+    self.fbb_.required(o, Oeffnungsintervall::VT_BIS,"bis");
+    ::flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl ::core::fmt::Debug for Oeffnungsintervall<'_> {
+  fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+    let mut ds = f.debug_struct("Oeffnungsintervall");
+      ds.field("tag", &self.tag());
+      ds.field("von", &self.von());
+      ds.field("bis", &self.bis());
+      ds.finish()
+  }
+}
+pub enum SchliesszeitOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// Eine geschlossene Periode (Urlaub, Fortbildung, Renovierung).
+pub struct Schliesszeit<'a> {
+  pub _tab: ::flatbuffers::Table<'a>,
+}
+
+impl<'a> ::flatbuffers::Follow<'a> for Schliesszeit<'a> {
+  type Inner = Schliesszeit<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { ::flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Schliesszeit<'a> {
+  pub const VT_VON: ::flatbuffers::VOffsetT = 4;
+  pub const VT_BIS: ::flatbuffers::VOffsetT = 6;
+  pub const VT_GRUND: ::flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: ::flatbuffers::Table<'a>) -> Self {
+    Schliesszeit { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: ::flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut ::flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args SchliesszeitArgs<'args>
+  ) -> ::flatbuffers::WIPOffset<Schliesszeit<'bldr>> {
+    let mut builder = SchliesszeitBuilder::new(_fbb);
+    if let Some(x) = args.grund { builder.add_grund(x); }
+    if let Some(x) = args.bis { builder.add_bis(x); }
+    if let Some(x) = args.von { builder.add_von(x); }
+    builder.finish()
+  }
+
+
+  /// Erster geschlossener Tag (ISO 8601 Datum, z.B. "2026-08-03")
+  #[inline]
+  pub fn von(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<&str>>(Schliesszeit::VT_VON, None).unwrap()}
+  }
+  /// Letzter geschlossener Tag (ISO 8601 Datum)
+  #[inline]
+  pub fn bis(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<&str>>(Schliesszeit::VT_BIS, None).unwrap()}
+  }
+  /// Grund für Patienten, z.B. "Urlaub" oder "Fortbildung"
+  #[inline]
+  pub fn grund(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<&str>>(Schliesszeit::VT_GRUND, None)}
+  }
+}
+
+impl ::flatbuffers::Verifiable for Schliesszeit<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut ::flatbuffers::Verifier, pos: usize
+  ) -> Result<(), ::flatbuffers::InvalidFlatbuffer> {
+    v.visit_table(pos)?
+     .visit_field::<::flatbuffers::ForwardsUOffset<&str>>("von", Self::VT_VON, true)?
+     .visit_field::<::flatbuffers::ForwardsUOffset<&str>>("bis", Self::VT_BIS, true)?
+     .visit_field::<::flatbuffers::ForwardsUOffset<&str>>("grund", Self::VT_GRUND, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct SchliesszeitArgs<'a> {
+    pub von: Option<::flatbuffers::WIPOffset<&'a str>>,
+    pub bis: Option<::flatbuffers::WIPOffset<&'a str>>,
+    pub grund: Option<::flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for SchliesszeitArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    SchliesszeitArgs {
+      von: None, // required field
+      bis: None, // required field
+      grund: None,
+    }
+  }
+}
+
+pub struct SchliesszeitBuilder<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: ::flatbuffers::WIPOffset<::flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> SchliesszeitBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_von(&mut self, von: ::flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(Schliesszeit::VT_VON, von);
+  }
+  #[inline]
+  pub fn add_bis(&mut self, bis: ::flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(Schliesszeit::VT_BIS, bis);
+  }
+  #[inline]
+  pub fn add_grund(&mut self, grund: ::flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(Schliesszeit::VT_GRUND, grund);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> SchliesszeitBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    SchliesszeitBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> ::flatbuffers::WIPOffset<Schliesszeit<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, Schliesszeit::VT_VON,"von");
+    self.fbb_.required(o, Schliesszeit::VT_BIS,"bis");
+    ::flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl ::core::fmt::Debug for Schliesszeit<'_> {
+  fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+    let mut ds = f.debug_struct("Schliesszeit");
+      ds.field("von", &self.von());
+      ds.field("bis", &self.bis());
+      ds.field("grund", &self.grund());
+      ds.finish()
+  }
+}
 pub enum PraxisOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
@@ -227,6 +501,15 @@ impl<'a> Praxis<'a> {
   pub const VT_KASSENPATIENTEN: ::flatbuffers::VOffsetT = 30;
   pub const VT_SPRACHEN: ::flatbuffers::VOffsetT = 32;
   pub const VT_KURZBESCHREIBUNG: ::flatbuffers::VOffsetT = 34;
+  pub const VT_BREITENGRAD: ::flatbuffers::VOffsetT = 36;
+  pub const VT_LAENGENGRAD: ::flatbuffers::VOffsetT = 38;
+  pub const VT_OEFFNUNGSZEITEN_STRUKTUR: ::flatbuffers::VOffsetT = 40;
+  pub const VT_SCHLIESSZEITEN: ::flatbuffers::VOffsetT = 42;
+  pub const VT_KASSEN: ::flatbuffers::VOffsetT = 44;
+  pub const VT_KASSENZULASSUNG: ::flatbuffers::VOffsetT = 46;
+  pub const VT_BARRIEREFREIHEIT: ::flatbuffers::VOffsetT = 48;
+  pub const VT_PARKPLAETZE: ::flatbuffers::VOffsetT = 50;
+  pub const VT_TELEMEDIZIN: ::flatbuffers::VOffsetT = 52;
 
   #[inline]
   pub unsafe fn init_from_table(table: ::flatbuffers::Table<'a>) -> Self {
@@ -238,6 +521,12 @@ impl<'a> Praxis<'a> {
     args: &'args PraxisArgs<'args>
   ) -> ::flatbuffers::WIPOffset<Praxis<'bldr>> {
     let mut builder = PraxisBuilder::new(_fbb);
+    if let Some(x) = args.parkplaetze { builder.add_parkplaetze(x); }
+    if let Some(x) = args.laengengrad { builder.add_laengengrad(x); }
+    if let Some(x) = args.breitengrad { builder.add_breitengrad(x); }
+    if let Some(x) = args.kassen { builder.add_kassen(x); }
+    if let Some(x) = args.schliesszeiten { builder.add_schliesszeiten(x); }
+    if let Some(x) = args.oeffnungszeiten_struktur { builder.add_oeffnungszeiten_struktur(x); }
     if let Some(x) = args.kurzbeschreibung { builder.add_kurzbeschreibung(x); }
     if let Some(x) = args.sprachen { builder.add_sprachen(x); }
     if let Some(x) = args.oeffnungszeiten { builder.add_oeffnungszeiten(x); }
@@ -252,6 +541,9 @@ impl<'a> Praxis<'a> {
     if let Some(x) = args.praxisname { builder.add_praxisname(x); }
     if let Some(x) = args.bezeichnung { builder.add_bezeichnung(x); }
     if let Some(x) = args.name { builder.add_name(x); }
+    builder.add_telemedizin(args.telemedizin);
+    builder.add_barrierefreiheit(args.barrierefreiheit);
+    builder.add_kassenzulassung(args.kassenzulassung);
     builder.add_kassenpatienten(args.kassenpatienten);
     builder.add_privatpatienten(args.privatpatienten);
     builder.finish()
@@ -393,6 +685,81 @@ impl<'a> Praxis<'a> {
     // which contains a valid value in this slot
     unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<&str>>(Praxis::VT_KURZBESCHREIBUNG, None)}
   }
+  /// Breitengrad in Dezimalgrad (WGS 84), Bereich -90..90
+  /// Ermöglicht "nächste Praxis"-Anfragen ohne Geocoding
+  #[inline]
+  pub fn breitengrad(&self) -> Option<f64> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f64>(Praxis::VT_BREITENGRAD, None)}
+  }
+  /// Längengrad in Dezimalgrad (WGS 84), Bereich -180..180
+  #[inline]
+  pub fn laengengrad(&self) -> Option<f64> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f64>(Praxis::VT_LAENGENGRAD, None)}
+  }
+  /// Öffnungsintervalle pro Tag
+  /// Maschinenlesbare Alternative zum Freitext oeffnungszeiten
+  #[inline]
+  pub fn oeffnungszeiten_struktur(&self) -> Option<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<Oeffnungsintervall<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<Oeffnungsintervall>>>>(Praxis::VT_OEFFNUNGSZEITEN_STRUKTUR, None)}
+  }
+  /// Geschlossene Perioden (Urlaub etc.)
+  #[inline]
+  pub fn schliesszeiten(&self) -> Option<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<Schliesszeit<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<Schliesszeit>>>>(Praxis::VT_SCHLIESSZEITEN, None)}
+  }
+  /// Akzeptierte gesetzliche Krankenkassen
+  /// z.B. ["AOK", "TK", "Barmer"]
+  #[inline]
+  pub fn kassen(&self) -> Option<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<&'a str>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<&'a str>>>>(Praxis::VT_KASSEN, None)}
+  }
+  /// Hat eine Kassenzulassung?
+  #[inline]
+  pub fn kassenzulassung(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(Praxis::VT_KASSENZULASSUNG, Some(false)).unwrap()}
+  }
+  /// Barrierefreier Zugang (Rollstuhl, Aufzug)?
+  #[inline]
+  pub fn barrierefreiheit(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(Praxis::VT_BARRIEREFREIHEIT, Some(false)).unwrap()}
+  }
+  /// Anzahl Parkplätze an der Praxis
+  #[inline]
+  pub fn parkplaetze(&self) -> Option<i64> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i64>(Praxis::VT_PARKPLAETZE, None)}
+  }
+  /// Bietet Telemedizin / Videosprechstunden an?
+  #[inline]
+  pub fn telemedizin(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(Praxis::VT_TELEMEDIZIN, Some(false)).unwrap()}
+  }
 }
 
 impl ::flatbuffers::Verifiable for Praxis<'_> {
@@ -417,6 +784,15 @@ impl ::flatbuffers::Verifiable for Praxis<'_> {
      .visit_field::<bool>("kassenpatienten", Self::VT_KASSENPATIENTEN, false)?
      .visit_field::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'_, ::flatbuffers::ForwardsUOffset<&'_ str>>>>("sprachen", Self::VT_SPRACHEN, false)?
      .visit_field::<::flatbuffers::ForwardsUOffset<&str>>("kurzbeschreibung", Self::VT_KURZBESCHREIBUNG, false)?
+     .visit_field::<f64>("breitengrad", Self::VT_BREITENGRAD, false)?
+     .visit_field::<f64>("laengengrad", Self::VT_LAENGENGRAD, false)?
+     .visit_field::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'_, ::flatbuffers::ForwardsUOffset<Oeffnungsintervall>>>>("oeffnungszeiten_struktur", Self::VT_OEFFNUNGSZEITEN_STRUKTUR, false)?
+     .visit_field::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'_, ::flatbuffers::ForwardsUOffset<Schliesszeit>>>>("schliesszeiten", Self::VT_SCHLIESSZEITEN, false)?
+     .visit_field::<::flatbuffers::ForwardsUOffset<::flatbuffers::Vector<'_, ::flatbuffers::ForwardsUOffset<&'_ str>>>>("kassen", Self::VT_KASSEN, false)?
+     .visit_field::<bool>("kassenzulassung", Self::VT_KASSENZULASSUNG, false)?
+     .visit_field::<bool>("barrierefreiheit", Self::VT_BARRIEREFREIHEIT, false)?
+     .visit_field::<i64>("parkplaetze", Self::VT_PARKPLAETZE, false)?
+     .visit_field::<bool>("telemedizin", Self::VT_TELEMEDIZIN, false)?
      .finish();
     Ok(())
   }
@@ -438,6 +814,15 @@ pub struct PraxisArgs<'a> {
     pub kassenpatienten: bool,
     pub sprachen: Option<::flatbuffers::WIPOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<&'a str>>>>,
     pub kurzbeschreibung: Option<::flatbuffers::WIPOffset<&'a str>>,
+    pub breitengrad: Option<f64>,
+    pub laengengrad: Option<f64>,
+    pub oeffnungszeiten_struktur: Option<::flatbuffers::WIPOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<Oeffnungsintervall<'a>>>>>,
+    pub schliesszeiten: Option<::flatbuffers::WIPOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<Schliesszeit<'a>>>>>,
+    pub kassen: Option<::flatbuffers::WIPOffset<::flatbuffers::Vector<'a, ::flatbuffers::ForwardsUOffset<&'a str>>>>,
+    pub kassenzulassung: bool,
+    pub barrierefreiheit: bool,
+    pub parkplaetze: Option<i64>,
+    pub telemedizin: bool,
 }
 impl<'a> Default for PraxisArgs<'a> {
   #[inline]
@@ -459,6 +844,15 @@ impl<'a> Default for PraxisArgs<'a> {
       kassenpatienten: false,
       sprachen: None,
       kurzbeschreibung: None,
+      breitengrad: None,
+      laengengrad: None,
+      oeffnungszeiten_struktur: None,
+      schliesszeiten: None,
+      kassen: None,
+      kassenzulassung: false,
+      barrierefreiheit: false,
+      parkplaetze: None,
+      telemedizin: false,
     }
   }
 }
@@ -533,6 +927,42 @@ impl<'a: 'b, 'b, A: ::flatbuffers::Allocator + 'a> PraxisBuilder<'a, 'b, A> {
     self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(Praxis::VT_KURZBESCHREIBUNG, kurzbeschreibung);
   }
   #[inline]
+  pub fn add_breitengrad(&mut self, breitengrad: f64) {
+    self.fbb_.push_slot_always::<f64>(Praxis::VT_BREITENGRAD, breitengrad);
+  }
+  #[inline]
+  pub fn add_laengengrad(&mut self, laengengrad: f64) {
+    self.fbb_.push_slot_always::<f64>(Praxis::VT_LAENGENGRAD, laengengrad);
+  }
+  #[inline]
+  pub fn add_oeffnungszeiten_struktur(&mut self, oeffnungszeiten_struktur: ::flatbuffers::WIPOffset<::flatbuffers::Vector<'b , ::flatbuffers::ForwardsUOffset<Oeffnungsintervall<'b >>>>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(Praxis::VT_OEFFNUNGSZEITEN_STRUKTUR, oeffnungszeiten_struktur);
+  }
+  #[inline]
+  pub fn add_schliesszeiten(&mut self, schliesszeiten: ::flatbuffers::WIPOffset<::flatbuffers::Vector<'b , ::flatbuffers::ForwardsUOffset<Schliesszeit<'b >>>>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(Praxis::VT_SCHLIESSZEITEN, schliesszeiten);
+  }
+  #[inline]
+  pub fn add_kassen(&mut self, kassen: ::flatbuffers::WIPOffset<::flatbuffers::Vector<'b , ::flatbuffers::ForwardsUOffset<&'b  str>>>) {
+    self.fbb_.push_slot_always::<::flatbuffers::WIPOffset<_>>(Praxis::VT_KASSEN, kassen);
+  }
+  #[inline]
+  pub fn add_kassenzulassung(&mut self, kassenzulassung: bool) {
+    self.fbb_.push_slot::<bool>(Praxis::VT_KASSENZULASSUNG, kassenzulassung, false);
+  }
+  #[inline]
+  pub fn add_barrierefreiheit(&mut self, barrierefreiheit: bool) {
+    self.fbb_.push_slot::<bool>(Praxis::VT_BARRIEREFREIHEIT, barrierefreiheit, false);
+  }
+  #[inline]
+  pub fn add_parkplaetze(&mut self, parkplaetze: i64) {
+    self.fbb_.push_slot_always::<i64>(Praxis::VT_PARKPLAETZE, parkplaetze);
+  }
+  #[inline]
+  pub fn add_telemedizin(&mut self, telemedizin: bool) {
+    self.fbb_.push_slot::<bool>(Praxis::VT_TELEMEDIZIN, telemedizin, false);
+  }
+  #[inline]
   pub fn new(_fbb: &'b mut ::flatbuffers::FlatBufferBuilder<'a, A>) -> PraxisBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
     PraxisBuilder {
@@ -569,6 +999,15 @@ impl ::core::fmt::Debug for Praxis<'_> {
       ds.field("kassenpatienten", &self.kassenpatienten());
       ds.field("sprachen", &self.sprachen());
       ds.field("kurzbeschreibung", &self.kurzbeschreibung());
+      ds.field("breitengrad", &self.breitengrad());
+      ds.field("laengengrad", &self.laengengrad());
+      ds.field("oeffnungszeiten_struktur", &self.oeffnungszeiten_struktur());
+      ds.field("schliesszeiten", &self.schliesszeiten());
+      ds.field("kassen", &self.kassen());
+      ds.field("kassenzulassung", &self.kassenzulassung());
+      ds.field("barrierefreiheit", &self.barrierefreiheit());
+      ds.field("parkplaetze", &self.parkplaetze());
+      ds.field("telemedizin", &self.telemedizin());
       ds.finish()
   }
 }
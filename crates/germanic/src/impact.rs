@@ -0,0 +1,259 @@
+//! # Schema Impact Analysis
+//!
+//! Dry-run for schema publication: before a new schema version goes live,
+//! validate a directory of existing data files against it and report which
+//! ones would break.
+//!
+//! ```text
+//! new.schema.json ──┐
+//!                   ├──► analyze_impact() ──► ImpactReport
+//! data/*.json ──────┘         │
+//!                             ├── passed: [a.json, b.json]
+//!                             └── failed: [c.json: "name: required field missing"]
+//! ```
+//!
+//! Schema authors see the blast radius BEFORE flipping the version, instead
+//! of learning about it from broken production compiles.
+
+use crate::dynamic::schema_def::SchemaDefinition;
+use crate::dynamic::validate::validate_against_schema;
+use crate::error::{GermanicError, GermanicResult, ValidationError};
+use std::path::{Path, PathBuf};
+
+/// Result of validating one data file against the candidate schema.
+#[derive(Debug)]
+pub struct FileImpact {
+    /// Path of the data file.
+    pub path: PathBuf,
+
+    /// All validation violations found (empty = file passes).
+    pub errors: Vec<String>,
+}
+
+/// Aggregated dry-run report over a set of data files.
+#[derive(Debug)]
+pub struct ImpactReport {
+    /// Files that validate cleanly under the candidate schema.
+    pub passed: Vec<PathBuf>,
+
+    /// Files that would fail, with their violations.
+    pub failed: Vec<FileImpact>,
+}
+
+impl ImpactReport {
+    /// Total number of analyzed files.
+    pub fn total(&self) -> usize {
+        self.passed.len() + self.failed.len()
+    }
+
+    /// True if every file validates under the candidate schema.
+    pub fn is_clean(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+/// Validates a set of data files against a candidate schema.
+///
+/// Unreadable or unparsable files count as failed — they would also fail
+/// at compile time.
+pub fn analyze_impact(schema: &SchemaDefinition, files: &[PathBuf]) -> ImpactReport {
+    let mut passed = Vec::new();
+    let mut failed = Vec::new();
+
+    for path in files {
+        match check_file(schema, path) {
+            Ok(()) => passed.push(path.clone()),
+            Err(errors) => failed.push(FileImpact {
+                path: path.clone(),
+                errors,
+            }),
+        }
+    }
+
+    ImpactReport { passed, failed }
+}
+
+/// Collects candidate data files (*.json) from a directory.
+///
+/// Schema definition files (*.schema.json) are skipped — they are not data.
+pub fn collect_data_files(dir: &Path) -> GermanicResult<Vec<PathBuf>> {
+    collect_data_files_matching(dir, "*.json")
+}
+
+/// Collects data files from a directory whose names match a glob
+/// pattern (`*` matches any run of characters, `?` exactly one).
+///
+/// Schema definition files (*.schema.json) are skipped — they are not data.
+pub fn collect_data_files_matching(dir: &Path, pattern: &str) -> GermanicResult<Vec<PathBuf>> {
+    if !dir.is_dir() {
+        return Err(GermanicError::General(format!(
+            "Not a directory: {}",
+            dir.display()
+        )));
+    }
+
+    let mut files: Vec<PathBuf> = std::fs::read_dir(dir)?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.is_file()
+                && path.file_name().is_some_and(|name| {
+                    let name = name.to_string_lossy();
+                    glob_match(pattern, &name) && !name.ends_with(".schema.json")
+                })
+        })
+        .collect();
+
+    // Deterministic report order
+    files.sort();
+    Ok(files)
+}
+
+/// Minimal glob matcher for file names: `*` matches any run of
+/// characters, `?` exactly one. No path separators, no character
+/// classes — directory batch mode needs nothing heavier.
+fn glob_match(pattern: &str, name: &str) -> bool {
+    fn matches(pattern: &[char], name: &[char]) -> bool {
+        match pattern.split_first() {
+            None => name.is_empty(),
+            Some(('*', rest)) => (0..=name.len()).any(|skip| matches(rest, &name[skip..])),
+            Some(('?', rest)) => !name.is_empty() && matches(rest, &name[1..]),
+            Some((expected, rest)) => name
+                .split_first()
+                .is_some_and(|(first, tail)| first == expected && matches(rest, tail)),
+        }
+    }
+
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    matches(&pattern, &name)
+}
+
+/// Validates a single file, returning all violations on failure.
+fn check_file(schema: &SchemaDefinition, path: &Path) -> Result<(), Vec<String>> {
+    let json_str =
+        std::fs::read_to_string(path).map_err(|e| vec![format!("could not read file: {}", e)])?;
+
+    let data: serde_json::Value =
+        serde_json::from_str(&json_str).map_err(|e| vec![format!("invalid JSON: {}", e)])?;
+
+    match validate_against_schema(schema, &data) {
+        Ok(()) => Ok(()),
+        Err(ValidationError::RequiredFieldsMissing(violations)) => Err(violations),
+        Err(other) => Err(vec![other.to_string()]),
+    }
+}
+
+// ============================================================================
+// TESTS
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dynamic::schema_def::{FieldDefinition, FieldType};
+    use indexmap::IndexMap;
+
+    fn strict_schema() -> SchemaDefinition {
+        let mut fields = IndexMap::new();
+        fields.insert(
+            "name".into(),
+            FieldDefinition {
+                required: true,
+                ..FieldDefinition::new(FieldType::String)
+            },
+        );
+        fields.insert(
+            "rating".into(),
+            FieldDefinition {
+                required: true,
+                ..FieldDefinition::new(FieldType::Float)
+            },
+        );
+        SchemaDefinition {
+            schema_id: "test.v2".into(),
+            version: 2,
+            description: None,
+            limits: None,
+            strict: false,
+            fields,
+        }
+    }
+
+    #[test]
+    fn test_impact_reports_breaking_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let good = dir.path().join("good.json");
+        let bad = dir.path().join("bad.json");
+        std::fs::write(&good, r#"{"name": "A", "rating": 4.5}"#).unwrap();
+        std::fs::write(&bad, r#"{"name": "B"}"#).unwrap();
+
+        let schema = strict_schema();
+        let files = collect_data_files(dir.path()).unwrap();
+        let report = analyze_impact(&schema, &files);
+
+        assert_eq!(report.total(), 2);
+        assert_eq!(report.passed, vec![good]);
+        assert_eq!(report.failed.len(), 1);
+        assert_eq!(report.failed[0].path, bad);
+        assert!(report.failed[0].errors[0].contains("rating"));
+    }
+
+    #[test]
+    fn test_collect_skips_schema_files() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("data.json"), "{}").unwrap();
+        std::fs::write(dir.path().join("x.schema.json"), "{}").unwrap();
+        std::fs::write(dir.path().join("notes.txt"), "").unwrap();
+
+        let files = collect_data_files(dir.path()).unwrap();
+        assert_eq!(files.len(), 1);
+        assert!(files[0].ends_with("data.json"));
+    }
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match("*.json", "praxis.json"));
+        assert!(glob_match("praxis-*.json", "praxis-001.json"));
+        assert!(glob_match("?.json", "a.json"));
+        assert!(!glob_match("?.json", "ab.json"));
+        assert!(!glob_match("*.json", "praxis.yaml"));
+        assert!(glob_match("*", "anything"));
+    }
+
+    #[test]
+    fn test_collect_with_pattern() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("praxis-001.json"), "{}").unwrap();
+        std::fs::write(dir.path().join("praxis-002.json"), "{}").unwrap();
+        std::fs::write(dir.path().join("other.json"), "{}").unwrap();
+
+        let files = collect_data_files_matching(dir.path(), "praxis-*.json").unwrap();
+        assert_eq!(files.len(), 2);
+        assert!(files.iter().all(|f| {
+            f.file_name()
+                .is_some_and(|n| n.to_string_lossy().starts_with("praxis-"))
+        }));
+    }
+
+    #[test]
+    fn test_unparsable_file_counts_as_failed() {
+        let dir = tempfile::tempdir().unwrap();
+        let broken = dir.path().join("broken.json");
+        std::fs::write(&broken, "{not json").unwrap();
+
+        let report = analyze_impact(&strict_schema(), &[broken]);
+        assert!(!report.is_clean());
+        assert!(report.failed[0].errors[0].contains("invalid JSON"));
+    }
+
+    #[test]
+    fn test_clean_report() {
+        let dir = tempfile::tempdir().unwrap();
+        let good = dir.path().join("good.json");
+        std::fs::write(&good, r#"{"name": "A", "rating": 1.0}"#).unwrap();
+
+        let report = analyze_impact(&strict_schema(), &[good]);
+        assert!(report.is_clean());
+    }
+}
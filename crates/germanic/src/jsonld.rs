@@ -0,0 +1,287 @@
+//! # JSON-LD Export
+//!
+//! Produces a schema.org JSON-LD block from decompiled .grm data, so
+//! publishers keep legacy SEO markup and the binary feed in sync from
+//! one pipeline instead of maintaining the same facts twice.
+//!
+//! ```text
+//!   data.grm ──► decode_grm ──► JSON ──► to_jsonld(mapping) ──► JSON-LD
+//!                                             │
+//!                                       mapping.json
+//!                                 (field path → schema.org name)
+//! ```
+//!
+//! The mapping file drives everything:
+//!
+//! ```json
+//! {
+//!   "@type": "MedicalClinic",
+//!   "properties": {
+//!     "praxisname": "name",
+//!     "adresse": "address",
+//!     "adresse.ort": "addressLocality",
+//!     "interne_nummer": ""
+//!   },
+//!   "types": { "adresse": "PostalAddress" }
+//! }
+//! ```
+
+use indexmap::IndexMap;
+use serde::Deserialize;
+use serde_json::Value;
+
+/// Field mapping for JSON-LD export (the `mapping.json` file).
+#[derive(Debug, Deserialize)]
+pub struct JsonLdMapping {
+    /// The schema.org type of the root object ("Restaurant").
+    #[serde(rename = "@type")]
+    pub type_name: String,
+
+    /// The `@context` URL; defaults to schema.org.
+    #[serde(rename = "@context", default = "default_context")]
+    pub context: String,
+
+    /// Dotted GERMANIC field path → schema.org property name.
+    /// Unmapped fields pass through under their own name; a path mapped
+    /// to `""` is omitted from the markup entirely.
+    #[serde(default)]
+    pub properties: IndexMap<String, String>,
+
+    /// Dotted field path → `@type` for that nested object
+    /// (`"adresse": "PostalAddress"`).
+    #[serde(default)]
+    pub types: IndexMap<String, String>,
+}
+
+fn default_context() -> String {
+    "https://schema.org".into()
+}
+
+/// Converts decompiled .grm data into a JSON-LD block.
+///
+/// Pure value transformation — decode the file first (e.g. with
+/// [`crate::reader::decode_grm`]) and hand the JSON here.
+pub fn to_jsonld(data: &Value, mapping: &JsonLdMapping) -> Value {
+    let mut out = serde_json::Map::new();
+    out.insert("@context".into(), mapping.context.clone().into());
+    out.insert("@type".into(), mapping.type_name.clone().into());
+    if let Some(obj) = data.as_object() {
+        map_object(obj, "", mapping, &mut out);
+    }
+    Value::Object(out)
+}
+
+/// Maps one object level, renaming keys per the mapping.
+fn map_object(
+    source: &serde_json::Map<String, Value>,
+    prefix: &str,
+    mapping: &JsonLdMapping,
+    target: &mut serde_json::Map<String, Value>,
+) {
+    for (name, value) in source {
+        let path = if prefix.is_empty() {
+            name.clone()
+        } else {
+            format!("{}.{}", prefix, name)
+        };
+        let target_name = match mapping.properties.get(&path) {
+            Some(mapped) if mapped.is_empty() => continue,
+            Some(mapped) => mapped.clone(),
+            None => name.clone(),
+        };
+        target.insert(target_name, map_value(value, &path, mapping));
+    }
+}
+
+/// Maps one value, recursing into objects and arrays of objects.
+fn map_value(value: &Value, path: &str, mapping: &JsonLdMapping) -> Value {
+    match value {
+        Value::Object(obj) => {
+            let mut nested = serde_json::Map::new();
+            if let Some(type_name) = mapping.types.get(path) {
+                nested.insert("@type".into(), type_name.clone().into());
+            }
+            map_object(obj, path, mapping, &mut nested);
+            Value::Object(nested)
+        }
+        Value::Array(arr) => Value::Array(
+            arr.iter()
+                .map(|element| map_value(element, path, mapping))
+                .collect(),
+        ),
+        other => other.clone(),
+    }
+}
+
+// ============================================================================
+// JSON-LD EXTRACTION (the reverse direction: page → schema)
+// ============================================================================
+
+/// Extracts every `<script type="application/ld+json">` block from an
+/// HTML page. Blocks that fail to parse as JSON are skipped; an
+/// `@graph` wrapper is flattened into its entries.
+pub fn extract_jsonld_blocks(html: &str) -> Vec<Value> {
+    let mut blocks = Vec::new();
+    let lower = html.to_lowercase();
+    let mut offset = 0;
+
+    while let Some(tag_start) = lower[offset..].find("<script") {
+        let tag_start = offset + tag_start;
+        let Some(tag_end) = lower[tag_start..].find('>') else {
+            break;
+        };
+        let tag_end = tag_start + tag_end + 1;
+        let Some(close) = lower[tag_end..].find("</script") else {
+            break;
+        };
+        let close = tag_end + close;
+
+        let tag = &lower[tag_start..tag_end];
+        if tag.contains("application/ld+json") {
+            if let Ok(value) = serde_json::from_str::<Value>(&html[tag_end..close]) {
+                match value {
+                    Value::Object(ref obj) if obj.contains_key("@graph") => {
+                        if let Some(Value::Array(entries)) = obj.get("@graph") {
+                            blocks.extend(entries.iter().cloned());
+                        }
+                    }
+                    other => blocks.push(other),
+                }
+            }
+        }
+        offset = close;
+    }
+
+    blocks
+}
+
+/// Strips JSON-LD keywords (`@context`, `@type`, …) from a block so
+/// only the actual data fields feed schema inference.
+pub fn strip_jsonld_keywords(value: &Value) -> Value {
+    match value {
+        Value::Object(obj) => Value::Object(
+            obj.iter()
+                .filter(|(key, _)| !key.starts_with('@'))
+                .map(|(key, nested)| (key.clone(), strip_jsonld_keywords(nested)))
+                .collect(),
+        ),
+        Value::Array(arr) => Value::Array(arr.iter().map(strip_jsonld_keywords).collect()),
+        other => other.clone(),
+    }
+}
+
+// ============================================================================
+// TESTS
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn praxis_mapping() -> JsonLdMapping {
+        serde_json::from_value(serde_json::json!({
+            "@type": "MedicalClinic",
+            "properties": {
+                "praxisname": "name",
+                "adresse": "address",
+                "adresse.strasse": "streetAddress",
+                "adresse.ort": "addressLocality",
+                "interne_nummer": ""
+            },
+            "types": { "adresse": "PostalAddress" }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_fields_renamed_and_nested_types_added() {
+        let data = serde_json::json!({
+            "praxisname": "Praxis Sonnenschein",
+            "adresse": { "strasse": "Hauptstraße 1", "ort": "Berlin" },
+            "telefon": "+49 30 1234567"
+        });
+
+        let jsonld = to_jsonld(&data, &praxis_mapping());
+        assert_eq!(jsonld["@context"], "https://schema.org");
+        assert_eq!(jsonld["@type"], "MedicalClinic");
+        assert_eq!(jsonld["name"], "Praxis Sonnenschein");
+        assert_eq!(jsonld["address"]["@type"], "PostalAddress");
+        assert_eq!(jsonld["address"]["streetAddress"], "Hauptstraße 1");
+        assert_eq!(jsonld["address"]["addressLocality"], "Berlin");
+        // Unmapped fields pass through under their own name
+        assert_eq!(jsonld["telefon"], "+49 30 1234567");
+    }
+
+    #[test]
+    fn test_empty_mapping_omits_field() {
+        let data = serde_json::json!({
+            "praxisname": "Praxis Sonnenschein",
+            "interne_nummer": "P-0042"
+        });
+
+        let jsonld = to_jsonld(&data, &praxis_mapping());
+        assert!(jsonld.get("interne_nummer").is_none());
+    }
+
+    #[test]
+    fn test_extract_finds_ld_json_blocks() {
+        let html = r#"<html><head>
+            <script type="text/javascript">var x = 1;</script>
+            <script type="application/ld+json">
+            { "@context": "https://schema.org", "@type": "Restaurant", "name": "Gasthaus zur Linde" }
+            </script>
+        </head><body></body></html>"#;
+
+        let blocks = extract_jsonld_blocks(html);
+        assert_eq!(blocks.len(), 1);
+        assert_eq!(blocks[0]["name"], "Gasthaus zur Linde");
+    }
+
+    #[test]
+    fn test_extract_flattens_graph_and_skips_broken_json() {
+        let html = r#"
+            <script type="application/ld+json">{ not json }</script>
+            <script type="application/ld+json">
+            { "@graph": [ { "@type": "Event", "name": "A" }, { "@type": "Event", "name": "B" } ] }
+            </script>"#;
+
+        let blocks = extract_jsonld_blocks(html);
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(blocks[1]["name"], "B");
+    }
+
+    #[test]
+    fn test_strip_keywords_removes_at_keys_recursively() {
+        let block = serde_json::json!({
+            "@context": "https://schema.org",
+            "@type": "MedicalClinic",
+            "name": "Praxis Sonnenschein",
+            "address": { "@type": "PostalAddress", "addressLocality": "Berlin" }
+        });
+
+        let stripped = strip_jsonld_keywords(&block);
+        assert!(stripped.get("@type").is_none());
+        assert_eq!(stripped["name"], "Praxis Sonnenschein");
+        assert!(stripped["address"].get("@type").is_none());
+        assert_eq!(stripped["address"]["addressLocality"], "Berlin");
+    }
+
+    #[test]
+    fn test_arrays_of_objects_mapped_per_element() {
+        let mapping: JsonLdMapping = serde_json::from_value(serde_json::json!({
+            "@type": "Restaurant",
+            "properties": { "abteilungen.bezeichnung": "name" }
+        }))
+        .unwrap();
+        let data = serde_json::json!({
+            "abteilungen": [
+                { "bezeichnung": "Küche" },
+                { "bezeichnung": "Service" }
+            ]
+        });
+
+        let jsonld = to_jsonld(&data, &mapping);
+        assert_eq!(jsonld["abteilungen"][0]["name"], "Küche");
+        assert_eq!(jsonld["abteilungen"][1]["name"], "Service");
+    }
+}
@@ -58,6 +58,23 @@ extern crate self as germanic;
 /// Allows: `use germanic::GermanicSchema;`
 pub use germanic_macros::GermanicSchema;
 
+/// Re-export of the round-trip checker.
+/// Allows: `germanic::verify_roundtrip(&schema, &data)`
+pub use roundtrip::verify_roundtrip;
+
+/// Re-export of the flatbuffers runtime.
+/// Macro-generated serializers reference it as `::germanic::flatbuffers`,
+/// so users never depend on the crate directly.
+pub use flatbuffers;
+
+/// Re-export of serde_json for macro-generated code
+/// (default values in `schema_definition()`).
+pub use serde_json;
+
+/// Re-export of regex_lite for macro-generated code
+/// (`pattern` constraint checks in `validate()`).
+pub use regex_lite;
+
 // ============================================================================
 // MODULES
 // ============================================================================
@@ -91,9 +108,49 @@ pub mod compiler;
 /// Compiles JSON to .grm using runtime schema definitions.
 pub mod dynamic;
 
+/// Parallel batch compilation of many records against one schema.
+pub mod batch;
+
 /// Pre-validation: schema-agnostic size and depth limits.
 pub mod pre_validate;
 
+/// Minimal base64 codec for the bytes field type.
+pub mod base64;
+
+/// Format-preserving anonymization of JSON fixtures.
+pub mod anonymize;
+
+/// Generic .grm → JSON decoder for dynamic schemas.
+pub mod reader;
+
+/// Multi-record containers with lazy record iteration.
+pub mod container;
+
+/// Dry-run impact analysis for schema publication.
+pub mod impact;
+
+/// schema.org JSON-LD markup: export from decompiled .grm data and
+/// extraction of embedded blocks from live pages.
+pub mod jsonld;
+
+/// Round-trip fidelity checking (compile → decode → diff).
+pub mod roundtrip;
+
+/// Typed source code generation from dynamic schemas.
+pub mod codegen;
+
+/// Machine-readable capability report for feature detection.
+pub mod capabilities;
+
+/// Registration API for custom field type plugins.
+pub mod plugin;
+
+/// Built-in format types (date, datetime) as pre-registered plugins.
+pub mod formats;
+
+/// Embedded copies of the shipped schema definitions, resolvable by schema_id.
+pub mod registry;
+
 /// Validation of JSON against schema.
 pub mod validator;
 
@@ -101,6 +158,14 @@ pub mod validator;
 #[cfg(feature = "mcp")]
 pub mod mcp;
 
+/// Backpressure-aware compile queue for daemon-style frontends.
+#[cfg(feature = "mcp")]
+pub mod queue;
+
+/// Structured audit events for daemon/server modes (syslog/journald via tracing).
+#[cfg(feature = "mcp")]
+pub mod audit;
+
 // ============================================================================
 // PRELUDE
 // ============================================================================
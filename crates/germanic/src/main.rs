@@ -14,6 +14,12 @@
 //! # Compile with dynamic schema
 //! germanic compile --schema restaurant.schema.json --input data.json
 //!
+//! # Compile a whole directory (batch mode)
+//! germanic compile --schema restaurant.schema.json --input data/ --output out/ --glob '*.json'
+//!
+//! # Recompile on every change while iterating
+//! germanic compile --schema restaurant.schema.json --input data.json --watch
+//!
 //! # Validate a .grm file
 //! germanic validate practice.grm
 //!
@@ -50,7 +56,12 @@ Example:
 "#)]
 struct Cli {
     #[command(subcommand)]
-    command: Commands,
+    command: Option<Commands>,
+
+    /// Print a machine-readable JSON report of supported header versions,
+    /// field types, limits and features, then exit
+    #[arg(long)]
+    capabilities: bool,
 }
 
 #[derive(Subcommand)]
@@ -58,7 +69,8 @@ enum Commands {
     /// Compiles JSON to .grm
     ///
     /// Reads a JSON file, validates it against the schema,
-    /// and creates a .grm binary file.
+    /// and creates a .grm binary file. A directory as input
+    /// compiles every matching file (batch mode).
     ///
     /// Built-in: --schema practice (or praxis)
     /// Custom:   --schema path/to/schema.json
@@ -67,21 +79,48 @@ enum Commands {
         #[arg(short, long)]
         schema: String,
 
-        /// Path to JSON input file
+        /// Path to JSON input file, or a directory for batch mode
         #[arg(short, long)]
         input: PathBuf,
 
-        /// Path to .grm output file
+        /// Path to .grm output file (batch mode: output directory)
         /// Default: same name as input with .grm extension
         #[arg(short, long)]
         output: Option<PathBuf>,
+
+        /// BCP-47 language tag stamped into the header (e.g. "de-DE")
+        #[arg(long)]
+        lang: Option<String>,
+
+        /// Redact fields tagged `pii: true` in the schema before compiling
+        #[arg(long)]
+        redact_pii: bool,
+
+        /// Deduplicate identical strings in the payload (smaller output
+        /// for datasets that repeat city names, specialties, etc.)
+        #[arg(long)]
+        dedup_strings: bool,
+
+        /// File name pattern for directory input (`*` and `?` wildcards).
+        /// Only used when --input is a directory
+        #[arg(long, default_value = "*.json")]
+        glob: String,
+
+        /// Keep running and recompile whenever the input or the schema
+        /// changes (exit with Ctrl-C)
+        #[arg(long)]
+        watch: bool,
     },
 
     /// Infers a schema from example JSON
     Init {
-        /// Path to example JSON file
-        #[arg(long)]
-        from: PathBuf,
+        /// Path to example JSON, YAML, TOML or CSV file (repeat for multiple samples)
+        #[arg(long, required_unless_present = "from_url")]
+        from: Vec<PathBuf>,
+
+        /// URL of a live page — infers from its embedded JSON-LD markup
+        #[arg(long, conflicts_with = "from")]
+        from_url: Option<String>,
 
         /// Schema ID (e.g. "de.dining.restaurant.v1")
         #[arg(long)]
@@ -91,6 +130,207 @@ enum Commands {
         /// Default: same directory, schema_id as filename
         #[arg(short, long)]
         output: Option<PathBuf>,
+
+        /// Keep all fields optional even with multiple samples
+        #[arg(long)]
+        no_required: bool,
+
+        /// Record example values as field descriptions
+        #[arg(long)]
+        examples: bool,
+    },
+
+    /// Reconstructs JSON from a .grm file
+    ///
+    /// Inverse of compile: walks the FlatBuffer payload with the schema
+    /// and writes the contained data back out as JSON. Fields that were
+    /// stripped during compilation (unknown to the schema) are gone.
+    Decompile {
+        /// Path to .grm file
+        file: PathBuf,
+
+        /// Path to .schema.json the file was compiled with
+        #[arg(short, long)]
+        schema: PathBuf,
+
+        /// Output path for JSON
+        /// Default: same name as input with .json extension
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+
+    /// Replaces personal values with format-preserving fake data
+    ///
+    /// Produces a fixture that still validates against the schema
+    /// but can be shared in bug reports without leaking client data.
+    Anonymize {
+        /// Path to JSON data file
+        input: PathBuf,
+
+        /// Path to .schema.json the data belongs to
+        #[arg(short, long)]
+        schema: PathBuf,
+
+        /// Output path for anonymized JSON
+        /// Default: input name with .anon.json extension
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+
+    /// Generates typed source code from a .schema.json
+    ///
+    /// Emits a module with structs matching the dynamic schema —
+    /// same field order, required flags and defaults — so teams can
+    /// graduate from dynamic mode to static typed code.
+    Generate {
+        /// Path to .schema.json
+        schema: PathBuf,
+
+        /// Target language (rust, ts)
+        #[arg(long)]
+        lang: String,
+
+        /// Output path
+        /// Default: schema name with language extension
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+
+    /// Imports a schema from an external description format
+    ///
+    /// OpenAPI 3 documents (YAML or JSON): extracts one named component
+    /// schema including references to its siblings and converts it like
+    /// a JSON Schema. Alternatively bootstraps a starter schema from a
+    /// schema.org type for sites migrating from JSON-LD markup.
+    Import {
+        /// Path to the source document (e.g. openapi.yaml)
+        #[arg(long, required_unless_present = "schema_org", conflicts_with = "schema_org")]
+        from: Option<PathBuf>,
+
+        /// Name of the component schema to extract
+        #[arg(long, requires = "from")]
+        component: Option<String>,
+
+        /// schema.org type to start from (LocalBusiness, MedicalClinic,
+        /// Restaurant, Event)
+        #[arg(long)]
+        schema_org: Option<String>,
+
+        /// Output path for .schema.json
+        /// Default: component or type name lowercased + .schema.json
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+
+    /// Exports a schema or compiled data to a standard format
+    ///
+    /// --json-schema: the reverse of JSON Schema import — emits a Draft 7
+    /// document with required flags, defaults, constraints, nested tables
+    /// and arrays carried over, for editors, CI validators and OpenAPI
+    /// tooling. --jsonld: decodes a .grm file and emits a schema.org
+    /// JSON-LD block driven by a mapping file, so legacy SEO markup and
+    /// the binary feed stay in sync from one source of truth.
+    Export {
+        /// Path to .schema.json
+        #[arg(short, long)]
+        schema: PathBuf,
+
+        /// Emit JSON Schema Draft 7
+        #[arg(long, conflicts_with = "jsonld")]
+        json_schema: bool,
+
+        /// Emit a JSON-LD block from this compiled .grm file
+        #[arg(long, value_name = "GRM", requires = "mapping")]
+        jsonld: Option<PathBuf>,
+
+        /// Field mapping for --jsonld (GERMANIC path → schema.org name)
+        #[arg(long)]
+        mapping: Option<PathBuf>,
+
+        /// Output path (default: stdout)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+
+    /// Publishes a schema version (currently dry-run only)
+    ///
+    /// With --dry-run, validates a directory of existing data files against
+    /// the new schema and reports which would break — the blast radius of
+    /// flipping the version, before anything goes live.
+    Publish {
+        /// Path to the new .schema.json
+        #[arg(short, long)]
+        schema: PathBuf,
+
+        /// Directory of existing data files (*.json) to check
+        #[arg(long)]
+        data_dir: PathBuf,
+
+        /// Analyze impact only, publish nothing
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Runs a complete worked example pipeline
+    ///
+    /// Materializes example JSON, infers a schema, compiles it to .grm,
+    /// validates and decodes the result — each step explained. Serves as
+    /// onboarding and as an executable smoke test of the whole pipeline.
+    Demo {
+        /// Directory for the generated files
+        /// Default: a fresh directory under the system temp dir
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+
+    /// Pretty-prints a .grm file (header + decoded payload)
+    ///
+    /// One-stop replacement for `validate` + `inspect --decode`:
+    /// shows the header and the full payload as a colorized tree,
+    /// or as a single JSON document with --format json.
+    Cat {
+        /// Path to .grm file
+        file: PathBuf,
+
+        /// Path to .schema.json (built-in schemas resolve by Schema-ID)
+        #[arg(short, long)]
+        schema: Option<PathBuf>,
+
+        /// Output format: tree (default) or json
+        #[arg(long, default_value = "tree")]
+        format: String,
+    },
+
+    /// Shows payload size attribution per field
+    ///
+    /// Reports how many bytes each field contributes (strings, vectors,
+    /// nested tables), so publishers can see why a .grm is large and
+    /// which field to trim.
+    Stats {
+        /// Path to .grm file
+        file: PathBuf,
+
+        /// Path to .schema.json (built-in schemas resolve by Schema-ID)
+        #[arg(short, long)]
+        schema: Option<PathBuf>,
+    },
+
+    /// Extracts a single field from a .grm file
+    ///
+    /// Prints only the requested value — strings come out raw, everything
+    /// else as JSON — so shell scripts and health checks can use it
+    /// without full decompilation.
+    Query {
+        /// Path to .grm file
+        file: PathBuf,
+
+        /// Path to .schema.json the file was compiled with
+        #[arg(short, long)]
+        schema: PathBuf,
+
+        /// Dotted path (adresse.ort) or JSON Pointer (/adresse/ort)
+        #[arg(short, long)]
+        path: String,
     },
 
     /// Shows available schemas
@@ -101,9 +341,18 @@ enum Commands {
     },
 
     /// Validates a .grm file
+    ///
+    /// Without --schema: structural checks (header, payload sanity).
+    /// With --schema: additionally decodes the payload and re-runs
+    /// required-field and type checks on the decoded values — catches
+    /// files produced by older or buggy compiler versions.
     Validate {
         /// Path to .grm file
         file: PathBuf,
+
+        /// Path to .schema.json for deep payload validation
+        #[arg(short, long)]
+        schema: Option<PathBuf>,
     },
 
     /// Shows header and metadata of a .grm file
@@ -114,6 +363,15 @@ enum Commands {
         /// Also show hex dump of header
         #[arg(long)]
         hex: bool,
+
+        /// Decode the payload and show it as a field tree
+        /// (needs a schema: built-in by Schema-ID, or via --schema)
+        #[arg(long)]
+        decode: bool,
+
+        /// Path to .schema.json for --decode
+        #[arg(short, long)]
+        schema: Option<PathBuf>,
     },
 
     #[cfg(feature = "mcp")]
@@ -124,33 +382,159 @@ enum Commands {
 fn main() -> Result<()> {
     let cli = Cli::parse();
 
-    match cli.command {
+    if cli.capabilities {
+        let report = germanic::capabilities::capabilities();
+        println!("{}", serde_json::to_string_pretty(&report)?);
+        return Ok(());
+    }
+
+    let Some(command) = cli.command else {
+        anyhow::bail!("No command given — see `germanic --help`");
+    };
+
+    match command {
         Commands::Compile {
             schema,
             input,
             output,
+            lang,
+            redact_pii,
+            dedup_strings,
+            glob,
+            watch,
         } => {
-            let schema_path = std::path::Path::new(&schema);
-            if schema_path.extension().is_some_and(|ext| ext == "json") && schema_path.exists() {
-                // Dynamic mode (Weg 3)
-                cmd_compile_dynamic(schema_path, &input, output.as_deref())
+            let run = || {
+                let schema_path = std::path::Path::new(&schema);
+                if input.is_dir() {
+                    // Batch mode: every matching file in the directory
+                    cmd_compile_batch(
+                        &schema,
+                        &input,
+                        output.as_deref(),
+                        &glob,
+                        lang.as_deref(),
+                        redact_pii,
+                        dedup_strings,
+                    )
+                } else if schema_path.extension().is_some_and(|ext| ext == "json")
+                    && schema_path.exists()
+                {
+                    // Dynamic mode (Weg 3)
+                    cmd_compile_dynamic(
+                        schema_path,
+                        &input,
+                        output.as_deref(),
+                        lang.as_deref(),
+                        redact_pii,
+                        dedup_strings,
+                    )
+                } else {
+                    // Static mode (existing)
+                    if redact_pii {
+                        anyhow::bail!("--redact-pii requires a dynamic schema with pii tags");
+                    }
+                    if dedup_strings {
+                        anyhow::bail!("--dedup-strings requires a dynamic schema");
+                    }
+                    cmd_compile(&schema, &input, output.as_deref(), lang.as_deref())
+                }
+            };
+
+            if watch {
+                cmd_watch(&schema, &input, &glob, run)
             } else {
-                // Static mode (existing)
-                cmd_compile(&schema, &input, output.as_deref())
+                run()
             }
         }
 
         Commands::Init {
             from,
+            from_url,
             schema_id,
             output,
-        } => cmd_init(&from, &schema_id, output.as_deref()),
+            no_required,
+            examples,
+        } => cmd_init(
+            &from,
+            from_url.as_deref(),
+            &schema_id,
+            output.as_deref(),
+            no_required,
+            examples,
+        ),
+
+        Commands::Decompile {
+            file,
+            schema,
+            output,
+        } => cmd_decompile(&file, &schema, output.as_deref()),
+
+        Commands::Anonymize {
+            input,
+            schema,
+            output,
+        } => cmd_anonymize(&input, &schema, output.as_deref()),
+
+        Commands::Generate {
+            schema,
+            lang,
+            output,
+        } => cmd_generate(&schema, &lang, output.as_deref()),
+
+        Commands::Import {
+            from,
+            component,
+            schema_org,
+            output,
+        } => cmd_import(
+            from.as_deref(),
+            component.as_deref(),
+            schema_org.as_deref(),
+            output.as_deref(),
+        ),
+
+        Commands::Export {
+            schema,
+            json_schema,
+            jsonld,
+            mapping,
+            output,
+        } => cmd_export(
+            &schema,
+            json_schema,
+            jsonld.as_deref(),
+            mapping.as_deref(),
+            output.as_deref(),
+        ),
+
+        Commands::Publish {
+            schema,
+            data_dir,
+            dry_run,
+        } => cmd_publish(&schema, &data_dir, dry_run),
+
+        Commands::Demo { output } => cmd_demo(output.as_deref()),
+
+        Commands::Cat {
+            file,
+            schema,
+            format,
+        } => cmd_cat(&file, schema.as_deref(), &format),
+
+        Commands::Stats { file, schema } => cmd_stats(&file, schema.as_deref()),
+
+        Commands::Query { file, schema, path } => cmd_query(&file, &schema, &path),
 
         Commands::Schemas { name } => cmd_schemas(name.as_deref()),
 
-        Commands::Validate { file } => cmd_validate(&file),
+        Commands::Validate { file, schema } => cmd_validate(&file, schema.as_deref()),
 
-        Commands::Inspect { file, hex } => cmd_inspect(&file, hex),
+        Commands::Inspect {
+            file,
+            hex,
+            decode,
+            schema,
+        } => cmd_inspect(&file, hex, decode, schema.as_deref()),
 
         #[cfg(feature = "mcp")]
         Commands::ServeMcp => tokio::runtime::Runtime::new()
@@ -161,7 +545,12 @@ fn main() -> Result<()> {
 }
 
 /// Compiles JSON to .grm (built-in schema, routed through Dynamic Mode)
-fn cmd_compile(schema_name: &str, input: &PathBuf, output: Option<&std::path::Path>) -> Result<()> {
+fn cmd_compile(
+    schema_name: &str,
+    input: &PathBuf,
+    output: Option<&std::path::Path>,
+    lang: Option<&str>,
+) -> Result<()> {
     use germanic::compiler::SchemaType;
 
     println!("┌─────────────────────────────────────────");
@@ -170,15 +559,20 @@ fn cmd_compile(schema_name: &str, input: &PathBuf, output: Option<&std::path::Pa
     println!("│ Schema: {}", schema_name);
     println!("│ Input:  {}", input.display());
 
-    // 1. Validate schema type
-    let _schema_type = SchemaType::parse(schema_name).ok_or_else(|| {
-        anyhow::anyhow!(
-            "Unknown schema: '{}'\n\
-             Available schemas: practice, praxis\n\
-             Or provide a .schema.json path for dynamic mode",
-            schema_name
-        )
-    })?;
+    // 1. Resolve the definition: built-in alias first, then the embedded registry
+    let (label, definition) = match SchemaType::parse(schema_name) {
+        Some(schema_type) => (schema_type.name(), schema_type.definition()),
+        None => match germanic::registry::find_definition(schema_name) {
+            Some(definition) => (schema_name, definition),
+            None => anyhow::bail!(
+                "Unknown schema: '{}'\n\
+                 Available schemas: practice, praxis, krankenhaus, hospital,\n\
+                 any shipped schema_id (see `germanic schemas`),\n\
+                 or a .schema.json path for dynamic mode",
+                schema_name
+            ),
+        },
+    };
 
     // 2. Read JSON (size check BEFORE parsing)
     let json = std::fs::read_to_string(input).context("Could not read JSON file")?;
@@ -193,14 +587,13 @@ fn cmd_compile(schema_name: &str, input: &PathBuf, output: Option<&std::path::Pa
     // 3. Compile via Dynamic Mode (unified validation pipeline)
     let grm_bytes = {
         // Embedded schema definition (compile-time)
-        let schema_json = include_str!("../schemas/de.gesundheit.praxis.v1.schema.json");
         let schema: germanic::dynamic::schema_def::SchemaDefinition =
-            serde_json::from_str(schema_json)
-                .context("Built-in practice schema definition invalid")?;
+            serde_json::from_str(definition)
+                .with_context(|| format!("Embedded {} schema definition invalid", label))?;
 
         let data: serde_json::Value = serde_json::from_str(&json).context("Invalid JSON")?;
 
-        germanic::dynamic::compile_dynamic_from_values(&schema, &data)
+        germanic::dynamic::compile_dynamic_from_values_with_lang(&schema, &data, lang)
             .context("Compilation failed")?
     };
 
@@ -209,8 +602,8 @@ fn cmd_compile(schema_name: &str, input: &PathBuf, output: Option<&std::path::Pa
         .map(PathBuf::from)
         .unwrap_or_else(|| input.with_extension("grm"));
 
-    // 5. Write
-    std::fs::write(&output_path, &grm_bytes).context("Write failed")?;
+    // 5. Write (atomic: temp file + rename)
+    germanic::compiler::write_grm(&grm_bytes, &output_path).context("Write failed")?;
 
     println!("│ Output: {}", output_path.display());
     println!("│ Size:   {} bytes", grm_bytes.len());
@@ -229,8 +622,13 @@ fn cmd_compile_dynamic(
     schema_path: &std::path::Path,
     input: &std::path::Path,
     output: Option<&std::path::Path>,
+    lang: Option<&str>,
+    redact_pii: bool,
+    dedup_strings: bool,
 ) -> Result<()> {
-    use germanic::dynamic::{compile_dynamic, load_schema_auto};
+    use germanic::dynamic::{
+        compat, compile_dynamic_from_values_with_options, load_schema_auto, CompileOptions,
+    };
 
     println!("┌─────────────────────────────────────────");
     println!("│ GERMANIC Dynamic Compiler");
@@ -238,21 +636,62 @@ fn cmd_compile_dynamic(
     println!("│ Schema: {}", schema_path.display());
     println!("│ Input:  {}", input.display());
 
-    // Check for JSON Schema warnings (auto-detection happens inside compile_dynamic too,
-    // but we run detection separately here to surface warnings to the user)
-    if let Ok((_, warnings)) = load_schema_auto(schema_path) {
-        for warning in &warnings {
+    let (schema, warnings) = load_schema_auto(schema_path).context("Could not load schema")?;
+    for warning in &warnings {
+        println!("│ ⚠ {}", warning);
+    }
+
+    // Read data (size check BEFORE parsing)
+    let json_str = std::fs::read_to_string(input).context("Could not read JSON file")?;
+    if json_str.len() > germanic::pre_validate::MAX_INPUT_SIZE {
+        anyhow::bail!(
+            "input size {} bytes exceeds maximum of {} bytes",
+            json_str.len(),
+            germanic::pre_validate::MAX_INPUT_SIZE
+        );
+    }
+    let mut data: serde_json::Value = serde_json::from_str(&json_str).context("Invalid JSON")?;
+
+    // Apply compat shim if one is declared next to the schema
+    if let Some(mapping) = compat::load_compat_for(schema_path, &schema.schema_id)
+        .context("Could not load compat mapping")?
+    {
+        let (lifted, shim_warnings) = mapping.apply(&data);
+        for warning in &shim_warnings {
             println!("│ ⚠ {}", warning);
         }
+        data = lifted;
+    }
+
+    // Lift alias keys so the report names every record still using them
+    let (lifted, alias_warnings) = schema.apply_aliases(&data);
+    for warning in &alias_warnings {
+        println!("│ ⚠ {}", warning);
     }
+    data = lifted;
 
-    let grm_bytes = compile_dynamic(schema_path, input).context("Dynamic compilation failed")?;
+    if redact_pii {
+        data = germanic::anonymize::redact_pii_value(&schema, &data);
+        println!("│ ⚠ PII redaction active — tagged fields are hashed/blanked");
+    }
+
+    // Non-fatal quality feedback — never blocks the compile
+    for warning in germanic::dynamic::validate::validation_warnings(&schema, &data) {
+        println!("│ ⚠ {}", warning);
+    }
+
+    let options = CompileOptions {
+        language: lang.map(String::from),
+        dedup_strings,
+    };
+    let grm_bytes = compile_dynamic_from_values_with_options(&schema, &data, &options)
+        .context("Dynamic compilation failed")?;
 
     let output_path = output
         .map(PathBuf::from)
         .unwrap_or_else(|| input.with_extension("grm"));
 
-    std::fs::write(&output_path, &grm_bytes).context("Write failed")?;
+    germanic::compiler::write_grm(&grm_bytes, &output_path).context("Write failed")?;
 
     println!("│ Output: {}", output_path.display());
     println!("│ Size:   {} bytes", grm_bytes.len());
@@ -263,22 +702,314 @@ fn cmd_compile_dynamic(
     Ok(())
 }
 
+/// Compiles every matching JSON file in a directory (batch mode)
+///
+/// Each file succeeds or fails independently; the summary lists failures
+/// with reasons and the exit code is non-zero if any file failed — no
+/// more bash loops around single-file compiles.
+fn cmd_compile_batch(
+    schema_arg: &str,
+    input_dir: &std::path::Path,
+    output_dir: Option<&std::path::Path>,
+    pattern: &str,
+    lang: Option<&str>,
+    redact_pii: bool,
+    dedup_strings: bool,
+) -> Result<()> {
+    use germanic::compiler::SchemaType;
+    use germanic::dynamic::{CompileOptions, load_schema_auto};
+    use germanic::impact::collect_data_files_matching;
+
+    println!("┌─────────────────────────────────────────");
+    println!("│ GERMANIC Batch Compiler");
+    println!("├─────────────────────────────────────────");
+    println!("│ Schema: {}", schema_arg);
+    println!("│ Input:  {} (--glob '{}')", input_dir.display(), pattern);
+
+    // Resolve the schema: .schema.json path, built-in alias or registry ID
+    let schema_path = std::path::Path::new(schema_arg);
+    let schema = if schema_path.extension().is_some_and(|ext| ext == "json") && schema_path.exists()
+    {
+        let (schema, warnings) = load_schema_auto(schema_path).context("Could not load schema")?;
+        for warning in &warnings {
+            println!("│ ⚠ {}", warning);
+        }
+        schema
+    } else {
+        let definition = match SchemaType::parse(schema_arg) {
+            Some(schema_type) => schema_type.definition(),
+            None => germanic::registry::find_definition(schema_arg).ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Unknown schema: '{}' — use a built-in name, a shipped schema_id \
+                     or a .schema.json path",
+                    schema_arg
+                )
+            })?,
+        };
+        serde_json::from_str(definition).context("Embedded schema definition invalid")?
+    };
+
+    let files =
+        collect_data_files_matching(input_dir, pattern).context("Could not read input directory")?;
+    if files.is_empty() {
+        anyhow::bail!("No files matching '{}' in {}", pattern, input_dir.display());
+    }
+    println!("│ Files:  {}", files.len());
+
+    if let Some(dir) = output_dir {
+        std::fs::create_dir_all(dir)
+            .with_context(|| format!("Could not create output directory {}", dir.display()))?;
+    }
+
+    // Phase 1: read + prepare — a broken file fails alone, not the batch
+    let mut records = Vec::new();
+    let mut failures: Vec<(PathBuf, String)> = Vec::new();
+    for path in &files {
+        match read_batch_record(&schema, path, redact_pii) {
+            Ok(data) => records.push((path.clone(), data)),
+            Err(reason) => failures.push((path.clone(), reason)),
+        }
+    }
+
+    // Phase 2: compile all prepared records in one batch
+    // (parallel with the `parallel` feature, sequential otherwise)
+    let options = CompileOptions {
+        language: lang.map(String::from),
+        dedup_strings,
+    };
+    let results = germanic::batch::compile_batch_with_options(
+        &schema,
+        records.iter().map(|(_, data)| data.clone()),
+        &options,
+    );
+
+    // Phase 3: write the outputs
+    let mut compiled = 0usize;
+    for ((path, _), result) in records.iter().zip(results) {
+        match result {
+            Ok(grm_bytes) => {
+                let output_path = match output_dir {
+                    Some(dir) => dir
+                        .join(path.file_name().unwrap_or_default())
+                        .with_extension("grm"),
+                    None => path.with_extension("grm"),
+                };
+                match germanic::compiler::write_grm(&grm_bytes, &output_path) {
+                    Ok(()) => {
+                        compiled += 1;
+                        println!("│   ✓ {} → {}", path.display(), output_path.display());
+                    }
+                    Err(e) => failures.push((path.clone(), format!("write failed: {}", e))),
+                }
+            }
+            Err(e) => failures.push((path.clone(), e.to_string())),
+        }
+    }
+
+    println!("├─────────────────────────────────────────");
+    println!("│ {} ok, {} failed", compiled, failures.len());
+    for (path, reason) in &failures {
+        println!("│   ✗ {}: {}", path.display(), reason);
+    }
+    if failures.is_empty() {
+        println!("│ ✓ Batch compilation successful");
+        println!("└─────────────────────────────────────────");
+        Ok(())
+    } else {
+        println!("└─────────────────────────────────────────");
+        Err(anyhow::anyhow!(
+            "{} of {} file(s) failed",
+            failures.len(),
+            files.len()
+        ))
+    }
+}
+
+/// Reruns a compile whenever the input or the schema changes.
+///
+/// Deliberately polling (1 s interval) instead of a notification
+/// crate: mtime comparison is portable, dependency-free and easily
+/// fast enough for a save-compile feedback loop.
+fn cmd_watch(
+    schema: &str,
+    input: &std::path::Path,
+    pattern: &str,
+    run: impl Fn() -> Result<()>,
+) -> Result<()> {
+    println!("👁 Watch mode — recompiling on change, exit with Ctrl-C");
+    println!();
+
+    // First compile immediately; failures keep the watch alive
+    let mut last_state = watch_snapshot(schema, input, pattern);
+    let mut runs = 1usize;
+    if let Err(error) = run() {
+        eprintln!("✗ {:#}", error);
+    }
+
+    loop {
+        std::thread::sleep(std::time::Duration::from_millis(1000));
+        let state = watch_snapshot(schema, input, pattern);
+        if state != last_state {
+            last_state = state;
+            runs += 1;
+            println!();
+            println!("↻ Change detected — recompiling (run {})", runs);
+            if let Err(error) = run() {
+                eprintln!("✗ {:#}", error);
+            }
+        }
+    }
+}
+
+/// Modification times of everything a compile run reads: the input file
+/// (or the matching files in an input directory) plus the schema, when
+/// the schema is a file path.
+///
+/// A changed snapshot — new mtime, new file, deleted file — triggers a
+/// recompile.
+fn watch_snapshot(
+    schema: &str,
+    input: &std::path::Path,
+    pattern: &str,
+) -> Vec<(PathBuf, Option<std::time::SystemTime>)> {
+    fn mtime(path: &std::path::Path) -> Option<std::time::SystemTime> {
+        std::fs::metadata(path).and_then(|meta| meta.modified()).ok()
+    }
+
+    let mut state = Vec::new();
+
+    let schema_path = PathBuf::from(schema);
+    if schema_path.is_file() {
+        let modified = mtime(&schema_path);
+        state.push((schema_path, modified));
+    }
+
+    if input.is_dir() {
+        if let Ok(files) = germanic::impact::collect_data_files_matching(input, pattern) {
+            for file in files {
+                let modified = mtime(&file);
+                state.push((file, modified));
+            }
+        }
+    } else {
+        state.push((input.to_path_buf(), mtime(input)));
+    }
+
+    state
+}
+
+/// Reads and prepares one record for batch compilation.
+fn read_batch_record(
+    schema: &germanic::dynamic::schema_def::SchemaDefinition,
+    path: &std::path::Path,
+    redact_pii: bool,
+) -> Result<serde_json::Value, String> {
+    let json_str =
+        std::fs::read_to_string(path).map_err(|e| format!("could not read file: {}", e))?;
+    if json_str.len() > germanic::pre_validate::MAX_INPUT_SIZE {
+        return Err(format!(
+            "input size {} bytes exceeds maximum of {} bytes",
+            json_str.len(),
+            germanic::pre_validate::MAX_INPUT_SIZE
+        ));
+    }
+    let mut data: serde_json::Value =
+        serde_json::from_str(&json_str).map_err(|e| format!("invalid JSON: {}", e))?;
+
+    // Alias lifting keeps old field names compiling, like single-file mode
+    let (lifted, _warnings) = schema.apply_aliases(&data);
+    data = lifted;
+
+    if redact_pii {
+        data = germanic::anonymize::redact_pii_value(schema, &data);
+    }
+
+    Ok(data)
+}
+
 /// Infers a schema from example JSON
-fn cmd_init(from: &PathBuf, schema_id: &str, output: Option<&std::path::Path>) -> Result<()> {
-    use germanic::dynamic::infer::infer_schema;
+fn cmd_init(
+    from: &[PathBuf],
+    from_url: Option<&str>,
+    schema_id: &str,
+    output: Option<&std::path::Path>,
+    no_required: bool,
+    examples: bool,
+) -> Result<()> {
+    use germanic::dynamic::infer::{annotate_examples, csv_to_samples, infer_schema_from_samples};
+    use germanic::jsonld::{extract_jsonld_blocks, strip_jsonld_keywords};
 
     println!("┌─────────────────────────────────────────");
     println!("│ GERMANIC Schema Inference");
     println!("├─────────────────────────────────────────");
-    println!("│ Input: {}", from.display());
+    for path in from {
+        println!("│ Input: {}", path.display());
+    }
+    if let Some(url) = from_url {
+        println!("│ Input: {}", url);
+    }
     println!("│ Schema-ID: {}", schema_id);
 
-    let json_str = std::fs::read_to_string(from).context("Could not read JSON file")?;
-    let data: serde_json::Value = serde_json::from_str(&json_str).context("Invalid JSON")?;
+    let mut samples = Vec::with_capacity(from.len());
+
+    if let Some(url) = from_url {
+        let html = ureq::get(url)
+            .call()
+            .with_context(|| format!("Could not fetch {}", url))?
+            .into_string()
+            .context("Could not read response body")?;
+        let blocks = extract_jsonld_blocks(&html);
+        if blocks.is_empty() {
+            anyhow::bail!("No application/ld+json blocks found on {}", url);
+        }
+        println!("│ JSON-LD blocks found: {}", blocks.len());
+        // Each block is one sample; @-keywords are markup, not data
+        samples.extend(blocks.iter().map(strip_jsonld_keywords));
+    }
+    for path in from {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Could not read input file: {}", path.display()))?;
+        let ext = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or("")
+            .to_ascii_lowercase();
+        match ext.as_str() {
+            // Every CSV data row counts as one sample
+            "csv" => {
+                let rows = csv_to_samples(&content)
+                    .map_err(|e| anyhow::anyhow!("Invalid CSV: {}: {}", path.display(), e))?;
+                samples.extend(rows);
+            }
+            "yaml" | "yml" => {
+                let data: serde_json::Value = serde_yaml::from_str(&content)
+                    .with_context(|| format!("Invalid YAML: {}", path.display()))?;
+                samples.push(data);
+            }
+            "toml" => {
+                let data: toml::Value = toml::from_str(&content)
+                    .with_context(|| format!("Invalid TOML: {}", path.display()))?;
+                // toml::Value serializes datetimes as plain strings
+                samples.push(serde_json::to_value(data).context("Could not convert TOML")?);
+            }
+            _ => {
+                let data: serde_json::Value = serde_json::from_str(&content)
+                    .with_context(|| format!("Invalid JSON: {}", path.display()))?;
+                samples.push(data);
+            }
+        }
+    }
 
-    let schema = infer_schema(&data, schema_id)
+    // Required flags only make sense with more than one sample —
+    // a single example would mark every filled-in field required.
+    let mark_required = samples.len() > 1 && !no_required;
+    let mut schema = infer_schema_from_samples(&samples, schema_id, mark_required)
         .ok_or_else(|| anyhow::anyhow!("Could not infer schema — input must be a JSON object"))?;
 
+    if examples {
+        annotate_examples(&mut schema, &samples[0]);
+    }
+
     let output_path = output.map(PathBuf::from).unwrap_or_else(|| {
         let name = schema_id.replace('.', "_");
         PathBuf::from(format!("{}.schema.json", name))
@@ -291,20 +1022,732 @@ fn cmd_init(from: &PathBuf, schema_id: &str, output: Option<&std::path::Path>) -
     println!("│ Output: {}", output_path.display());
     println!("│ Fields: {}", schema.field_count());
     println!("├─────────────────────────────────────────");
-    println!(
-        "│ ✓ Schema inferred — edit {} to mark required fields",
-        output_path.display()
-    );
+    if mark_required {
+        let required = schema.fields.values().filter(|f| f.required).count();
+        println!(
+            "│ ✓ Schema inferred — {} field(s) marked required (present in all {} samples)",
+            required,
+            samples.len()
+        );
+    } else {
+        println!(
+            "│ ✓ Schema inferred — edit {} to mark required fields",
+            output_path.display()
+        );
+    }
     println!("└─────────────────────────────────────────");
 
     Ok(())
 }
 
-/// Shows available schemas
-fn cmd_schemas(name: Option<&str>) -> Result<()> {
-    println!("┌─────────────────────────────────────────");
-    println!("│ GERMANIC Schemas");
-    println!("├─────────────────────────────────────────");
+/// Resolves the schema for `inspect --decode`.
+///
+/// Priority: explicit `--schema` path, then built-in schemas, then the
+/// embedded registry — all by Schema-ID.
+fn resolve_inspect_schema(
+    schema_path: Option<&std::path::Path>,
+    schema_id: &str,
+) -> Result<germanic::dynamic::schema_def::SchemaDefinition> {
+    if let Some(path) = schema_path {
+        let (schema, _warnings) =
+            germanic::dynamic::load_schema_auto(path).context("Could not load schema")?;
+        return Ok(schema);
+    }
+
+    for schema_type in [
+        germanic::compiler::SchemaType::Practice,
+        germanic::compiler::SchemaType::Krankenhaus,
+    ] {
+        if schema_id == schema_type.schema_id() {
+            return serde_json::from_str(schema_type.definition()).with_context(|| {
+                format!("Built-in {} schema definition invalid", schema_type.name())
+            });
+        }
+    }
+
+    if let Some(definition) = germanic::registry::find_definition(schema_id) {
+        let (schema, _warnings) = germanic::dynamic::parse_schema_auto(definition)
+            .with_context(|| format!("Embedded definition for '{}' invalid", schema_id))?;
+        return Ok(schema);
+    }
+
+    anyhow::bail!(
+        "No schema available for '{}' — pass one with --schema path/to/x.schema.json",
+        schema_id
+    )
+}
+
+/// Prints a decoded JSON value as an indented field tree.
+fn print_value_tree(value: &serde_json::Value, indent: usize) {
+    let pad = "  ".repeat(indent);
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, val) in map {
+                match val {
+                    serde_json::Value::Object(_) => {
+                        println!("│ {}{}:", pad, key);
+                        print_value_tree(val, indent + 1);
+                    }
+                    serde_json::Value::Array(arr) => {
+                        println!("│ {}{}: ({} items)", pad, key, arr.len());
+                        for item in arr {
+                            match item {
+                                serde_json::Value::Object(_) => print_value_tree(item, indent + 1),
+                                other => println!("│ {}  - {}", pad, other),
+                            }
+                        }
+                    }
+                    other => println!("│ {}{}: {}", pad, key, other),
+                }
+            }
+        }
+        other => println!("│ {}{}", pad, other),
+    }
+}
+
+/// Reconstructs JSON from a .grm file
+fn cmd_decompile(
+    file: &std::path::Path,
+    schema_path: &std::path::Path,
+    output: Option<&std::path::Path>,
+) -> Result<()> {
+    use germanic::dynamic::load_schema_auto;
+    use germanic::reader::decode_grm;
+
+    println!("┌─────────────────────────────────────────");
+    println!("│ GERMANIC Decompiler");
+    println!("├─────────────────────────────────────────");
+    println!("│ Input:  {}", file.display());
+    println!("│ Schema: {}", schema_path.display());
+
+    let (schema, _warnings) = load_schema_auto(schema_path).context("Could not load schema")?;
+    let data = std::fs::read(file).context("Could not read .grm file")?;
+
+    let (header, value) = decode_grm(&schema, &data).context("Decompilation failed")?;
+
+    if header.schema_id != schema.schema_id {
+        println!(
+            "│ ⚠ Schema-ID mismatch: file says '{}', schema says '{}'",
+            header.schema_id, schema.schema_id
+        );
+    }
+
+    let output_path = output
+        .map(PathBuf::from)
+        .unwrap_or_else(|| file.with_extension("json"));
+
+    let pretty = serde_json::to_string_pretty(&value)?;
+    std::fs::write(&output_path, pretty).context("Could not write JSON file")?;
+
+    println!("│ Schema-ID: {}", header.schema_id);
+    println!("│ Output: {}", output_path.display());
+    println!("├─────────────────────────────────────────");
+    println!("│ ✓ Decompilation successful");
+    println!("└─────────────────────────────────────────");
+
+    Ok(())
+}
+
+/// Replaces personal values with format-preserving fake data
+fn cmd_anonymize(
+    input: &std::path::Path,
+    schema_path: &std::path::Path,
+    output: Option<&std::path::Path>,
+) -> Result<()> {
+    use germanic::anonymize::anonymize_value;
+    use germanic::dynamic::load_schema_auto;
+
+    println!("┌─────────────────────────────────────────");
+    println!("│ GERMANIC Anonymizer");
+    println!("├─────────────────────────────────────────");
+    println!("│ Input:  {}", input.display());
+    println!("│ Schema: {}", schema_path.display());
+
+    let (schema, _warnings) = load_schema_auto(schema_path).context("Could not load schema")?;
+
+    let json_str = std::fs::read_to_string(input).context("Could not read JSON file")?;
+    let data: serde_json::Value = serde_json::from_str(&json_str).context("Invalid JSON")?;
+
+    let anonymized = anonymize_value(&schema, &data);
+
+    let output_path = output.map(PathBuf::from).unwrap_or_else(|| {
+        let stem = input
+            .file_stem()
+            .map(|s| s.to_string_lossy().into_owned())
+            .unwrap_or_else(|| "data".to_string());
+        input.with_file_name(format!("{}.anon.json", stem))
+    });
+
+    let pretty = serde_json::to_string_pretty(&anonymized)?;
+    std::fs::write(&output_path, pretty).context("Could not write anonymized file")?;
+
+    println!("│ Output: {}", output_path.display());
+    println!("├─────────────────────────────────────────");
+    println!("│ ✓ Anonymized — structure intact, values replaced");
+    println!("└─────────────────────────────────────────");
+
+    Ok(())
+}
+
+/// Generates typed source code from a .schema.json
+fn cmd_generate(
+    schema_path: &std::path::Path,
+    lang: &str,
+    output: Option<&std::path::Path>,
+) -> Result<()> {
+    use germanic::codegen::Language;
+    use germanic::dynamic::load_schema_auto;
+
+    let language = Language::parse(lang).ok_or_else(|| {
+        anyhow::anyhow!("Unknown language: '{}'\nAvailable languages: rust, ts", lang)
+    })?;
+
+    println!("┌─────────────────────────────────────────");
+    println!("│ GERMANIC Code Generator");
+    println!("├─────────────────────────────────────────");
+    println!("│ Schema:   {}", schema_path.display());
+    println!("│ Language: {}", lang.to_lowercase());
+
+    let (schema, warnings) = load_schema_auto(schema_path).context("Could not load schema")?;
+    for warning in &warnings {
+        println!("│ ⚠ {}", warning);
+    }
+
+    let (code, extension) = match language {
+        Language::Rust => (germanic::codegen::rust::generate(&schema), "rs"),
+        Language::TypeScript => (germanic::codegen::ts::generate(&schema), "ts"),
+    };
+
+    let output_path = output.map(PathBuf::from).unwrap_or_else(|| {
+        let name = schema
+            .schema_id
+            .replace(['.', '-'], "_")
+            .to_lowercase();
+        PathBuf::from(format!("{}.{}", name, extension))
+    });
+
+    std::fs::write(&output_path, &code).context("Could not write generated code")?;
+
+    println!("│ Output: {}", output_path.display());
+    println!("│ Fields: {}", schema.field_count());
+    println!("├─────────────────────────────────────────");
+    println!("│ ✓ Code generated");
+    println!("└─────────────────────────────────────────");
+
+    Ok(())
+}
+
+/// Imports a schema: OpenAPI 3 component or schema.org starter
+fn cmd_import(
+    from: Option<&std::path::Path>,
+    component: Option<&str>,
+    schema_org: Option<&str>,
+    output: Option<&std::path::Path>,
+) -> Result<()> {
+    use germanic::dynamic::openapi::convert_openapi_component;
+    use germanic::dynamic::schema_org::starter_schema;
+
+    println!("┌─────────────────────────────────────────");
+    println!("│ GERMANIC Schema Import");
+    println!("├─────────────────────────────────────────");
+
+    let (schema, warnings, default_name) = match (from, schema_org) {
+        (Some(from), None) => {
+            let component = component
+                .ok_or_else(|| anyhow::anyhow!("--component is required with --from"))?;
+            println!("│ Source:    {}", from.display());
+            println!("│ Component: {}", component);
+            let input =
+                std::fs::read_to_string(from).context("Could not read source document")?;
+            let (schema, warnings) = convert_openapi_component(&input, component)?;
+            (schema, warnings, component.to_lowercase())
+        }
+        (None, Some(type_name)) => {
+            println!("│ Source:    schema.org");
+            println!("│ Type:      {}", type_name);
+            (starter_schema(type_name)?, Vec::new(), type_name.to_lowercase())
+        }
+        _ => anyhow::bail!("Specify either --from + --component or --schema-org"),
+    };
+    for warning in &warnings {
+        println!("│ ⚠ {}", warning);
+    }
+
+    let output_path = output
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(format!("{}.schema.json", default_name)));
+    let content = serde_json::to_string_pretty(&schema)?;
+    std::fs::write(&output_path, content).context("Could not write schema")?;
+
+    println!("│ Output: {}", output_path.display());
+    println!("│ Fields: {}", schema.field_count());
+    println!("├─────────────────────────────────────────");
+    println!("│ ✓ Schema imported");
+    println!("└─────────────────────────────────────────");
+
+    Ok(())
+}
+
+/// Exports a schema (JSON Schema Draft 7) or compiled data (JSON-LD);
+/// stdout by default, so the document pipes straight into other tooling
+fn cmd_export(
+    schema_path: &std::path::Path,
+    json_schema: bool,
+    jsonld: Option<&std::path::Path>,
+    mapping: Option<&std::path::Path>,
+    output: Option<&std::path::Path>,
+) -> Result<()> {
+    use germanic::dynamic::{json_schema::export_json_schema, load_schema_auto};
+
+    let (schema, warnings) = load_schema_auto(schema_path).context("Could not load schema")?;
+    for warning in &warnings {
+        eprintln!("⚠ {}", warning);
+    }
+
+    let (document, what) = match jsonld {
+        Some(grm_path) => {
+            let mapping_path =
+                mapping.ok_or_else(|| anyhow::anyhow!("--jsonld requires --mapping"))?;
+            let mapping: germanic::jsonld::JsonLdMapping = serde_json::from_str(
+                &std::fs::read_to_string(mapping_path).context("Could not read mapping file")?,
+            )
+            .context("Invalid mapping file")?;
+            let grm = std::fs::read(grm_path).context("Could not read .grm file")?;
+            let (_, value) =
+                germanic::reader::decode_grm(&schema, &grm).context("Decoding failed")?;
+            let block = germanic::jsonld::to_jsonld(&value, &mapping);
+            (serde_json::to_string_pretty(&block)?, "JSON-LD")
+        }
+        None => {
+            if !json_schema {
+                anyhow::bail!(
+                    "No export format given — currently supported: --json-schema, --jsonld"
+                );
+            }
+            (
+                serde_json::to_string_pretty(&export_json_schema(&schema))?,
+                "JSON Schema",
+            )
+        }
+    };
+
+    match output {
+        Some(path) => {
+            std::fs::write(path, &document).context("Could not write export")?;
+            println!("✓ {} written to {}", what, path.display());
+        }
+        None => println!("{}", document),
+    }
+
+    Ok(())
+}
+
+/// Dry-run publication: impact analysis against existing data files
+fn cmd_publish(schema_path: &std::path::Path, data_dir: &std::path::Path, dry_run: bool) -> Result<()> {
+    use germanic::dynamic::load_schema_auto;
+    use germanic::impact::{analyze_impact, collect_data_files};
+
+    if !dry_run {
+        anyhow::bail!(
+            "Registry publication is not implemented yet — run with --dry-run \
+             to analyze the impact of the new schema version"
+        );
+    }
+
+    println!("┌─────────────────────────────────────────");
+    println!("│ GERMANIC Publish (dry run)");
+    println!("├─────────────────────────────────────────");
+    println!("│ Schema:   {}", schema_path.display());
+    println!("│ Data dir: {}", data_dir.display());
+
+    let (schema, warnings) = load_schema_auto(schema_path).context("Could not load schema")?;
+    for warning in &warnings {
+        println!("│ ⚠ {}", warning);
+    }
+    println!("│ Schema-ID: {} (v{})", schema.schema_id, schema.version);
+
+    let files = collect_data_files(data_dir).context("Could not read data directory")?;
+    if files.is_empty() {
+        anyhow::bail!("No data files (*.json) found in {}", data_dir.display());
+    }
+
+    let report = analyze_impact(&schema, &files);
+
+    println!("│");
+    println!("│ Checked {} file(s):", report.total());
+    for path in &report.passed {
+        println!("│   ✓ {}", path.display());
+    }
+    for file in &report.failed {
+        println!("│   ✗ {}", file.path.display());
+        for error in &file.errors {
+            println!("│       {}", error);
+        }
+    }
+
+    println!("├─────────────────────────────────────────");
+    if report.is_clean() {
+        println!("│ ✓ No impact — all files validate under the new version");
+        println!("└─────────────────────────────────────────");
+        Ok(())
+    } else {
+        println!(
+            "│ ✗ {} of {} file(s) would break under the new version",
+            report.failed.len(),
+            report.total()
+        );
+        println!("└─────────────────────────────────────────");
+        Err(anyhow::anyhow!(
+            "Impact analysis found {} breaking file(s)",
+            report.failed.len()
+        ))
+    }
+}
+
+/// Runs the complete pipeline on a worked example, step by step
+fn cmd_demo(output: Option<&std::path::Path>) -> Result<()> {
+    use germanic::dynamic::infer::infer_schema;
+
+    let dir = output.map(PathBuf::from).unwrap_or_else(|| {
+        std::env::temp_dir().join(format!("germanic-demo-{}", std::process::id()))
+    });
+    std::fs::create_dir_all(&dir).context("Could not create demo directory")?;
+
+    println!("┌─────────────────────────────────────────");
+    println!("│ GERMANIC Demo — the full pipeline");
+    println!("├─────────────────────────────────────────");
+    println!("│ Directory: {}", dir.display());
+    println!("│");
+
+    // Step 1: Example data — what a website plugin would export
+    println!("│ [1/5] Writing example JSON (cafe.json)");
+    println!("│       This is what a plugin exports from a website.");
+    let example = serde_json::json!({
+        "name": "Café Beispiel",
+        "kueche": "Kaffeehaus",
+        "bewertung": 4.6,
+        "vegan": true,
+        "schlagworte": ["Kuchen", "Frühstück"],
+        "adresse": {
+            "strasse": "Musterweg 12",
+            "plz": "10115",
+            "ort": "Berlin"
+        }
+    });
+    let json_path = dir.join("cafe.json");
+    std::fs::write(&json_path, serde_json::to_string_pretty(&example)?)?;
+
+    // Step 2: Schema inference — germanic init
+    println!("│");
+    println!("│ [2/5] Inferring a schema (cafe.schema.json)");
+    println!("│       Same as: germanic init --from cafe.json --schema-id ...");
+    println!("│       In practice you would now mark required fields.");
+    let schema = infer_schema(&example, "de.demo.cafe.v1")
+        .ok_or_else(|| anyhow::anyhow!("Inference failed — example must be an object"))?;
+    let schema_path = dir.join("cafe.schema.json");
+    schema.to_file(&schema_path).context("Could not write schema")?;
+    println!("│       {} fields inferred", schema.field_count());
+
+    // Step 3: Compilation — germanic compile
+    println!("│");
+    println!("│ [3/5] Compiling to binary (cafe.grm)");
+    println!("│       Same as: germanic compile --schema cafe.schema.json --input cafe.json");
+    let grm_bytes = germanic::dynamic::compile_dynamic_from_values(&schema, &example)
+        .context("Compilation failed")?;
+    let grm_path = dir.join("cafe.grm");
+    germanic::compiler::write_grm(&grm_bytes, &grm_path).context("Write failed")?;
+    println!("│       {} bytes — header + FlatBuffer payload", grm_bytes.len());
+
+    // Step 4: Validation — germanic validate
+    println!("│");
+    println!("│ [4/5] Validating the .grm file");
+    println!("│       Same as: germanic validate cafe.grm");
+    let result = germanic::validator::validate_grm(&grm_bytes)?;
+    if !result.valid {
+        anyhow::bail!(
+            "Demo pipeline broken: validation failed: {}",
+            result.error.unwrap_or_else(|| "unknown".into())
+        );
+    }
+    println!("│       ✓ valid, Schema-ID: {}", result.schema_id.as_deref().unwrap_or("?"));
+
+    // Step 5: Decoding — germanic decompile
+    println!("│");
+    println!("│ [5/5] Decoding back to JSON");
+    println!("│       Same as: germanic decompile cafe.grm --schema cafe.schema.json");
+    let (_header, decoded) = germanic::reader::decode_grm(&schema, &grm_bytes)
+        .context("Decoding failed")?;
+    if decoded == example {
+        println!("│       ✓ round-trip exact — no data lost");
+    } else {
+        println!("│       ⚠ decoded data differs from input (check float precision)");
+    }
+
+    println!("│");
+    println!("├─────────────────────────────────────────");
+    println!("│ ✓ Pipeline complete — files kept for inspection:");
+    println!("│   {}", json_path.display());
+    println!("│   {}", schema_path.display());
+    println!("│   {}", grm_path.display());
+    println!("│");
+    println!("│ Try next:");
+    println!("│   germanic cat {}", grm_path.display());
+    println!(
+        "│   germanic query {} --schema {} --path adresse.ort",
+        grm_path.display(),
+        schema_path.display()
+    );
+    println!("└─────────────────────────────────────────");
+
+    Ok(())
+}
+
+/// ANSI palette for `cat` — colors only when stdout is a terminal
+/// and NO_COLOR is unset.
+struct Palette {
+    key: &'static str,
+    string: &'static str,
+    number: &'static str,
+    bool_: &'static str,
+    reset: &'static str,
+}
+
+impl Palette {
+    fn detect() -> Self {
+        use std::io::IsTerminal;
+        if std::io::stdout().is_terminal() && std::env::var_os("NO_COLOR").is_none() {
+            Palette {
+                key: "\x1b[36m",    // cyan
+                string: "\x1b[32m", // green
+                number: "\x1b[33m", // yellow
+                bool_: "\x1b[35m",  // magenta
+                reset: "\x1b[0m",
+            }
+        } else {
+            Palette {
+                key: "",
+                string: "",
+                number: "",
+                bool_: "",
+                reset: "",
+            }
+        }
+    }
+
+    fn scalar(&self, value: &serde_json::Value) -> String {
+        match value {
+            serde_json::Value::String(s) => format!("{}{}{}", self.string, s, self.reset),
+            serde_json::Value::Number(n) => format!("{}{}{}", self.number, n, self.reset),
+            serde_json::Value::Bool(b) => format!("{}{}{}", self.bool_, b, self.reset),
+            other => other.to_string(),
+        }
+    }
+}
+
+/// Prints a decoded JSON value as a colorized indented tree.
+fn print_colored_tree(value: &serde_json::Value, indent: usize, palette: &Palette) {
+    let pad = "  ".repeat(indent);
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, val) in map {
+                match val {
+                    serde_json::Value::Object(_) => {
+                        println!("│ {}{}{}{}:", pad, palette.key, key, palette.reset);
+                        print_colored_tree(val, indent + 1, palette);
+                    }
+                    serde_json::Value::Array(arr) => {
+                        println!(
+                            "│ {}{}{}{}: ({} items)",
+                            pad,
+                            palette.key,
+                            key,
+                            palette.reset,
+                            arr.len()
+                        );
+                        for item in arr {
+                            match item {
+                                serde_json::Value::Object(_) => {
+                                    print_colored_tree(item, indent + 1, palette)
+                                }
+                                other => println!("│ {}  - {}", pad, palette.scalar(other)),
+                            }
+                        }
+                    }
+                    other => println!(
+                        "│ {}{}{}{}: {}",
+                        pad,
+                        palette.key,
+                        key,
+                        palette.reset,
+                        palette.scalar(other)
+                    ),
+                }
+            }
+        }
+        other => println!("│ {}{}", pad, palette.scalar(other)),
+    }
+}
+
+/// Pretty-prints a .grm file: header + decoded payload in one view
+fn cmd_cat(file: &std::path::Path, schema_path: Option<&std::path::Path>, format: &str) -> Result<()> {
+    use germanic::types::GrmHeader;
+
+    let data = std::fs::read(file).context("Could not read file")?;
+
+    let (header, header_len) = GrmHeader::from_bytes(&data)
+        .map_err(|e| anyhow::anyhow!("Header parse error: {}", e))?;
+
+    let schema = resolve_inspect_schema(schema_path, &header.schema_id)?;
+    let value = germanic::reader::decode_payload(&schema, &data[header_len..])
+        .context("Payload decoding failed")?;
+
+    match format {
+        "json" => {
+            let doc = serde_json::json!({
+                "header": {
+                    "schema_id": header.schema_id,
+                    "language": header.language,
+                    "signed": header.signature.is_some(),
+                },
+                "data": value,
+            });
+            println!("{}", serde_json::to_string_pretty(&doc)?);
+        }
+        "tree" => {
+            let palette = Palette::detect();
+            println!("┌─────────────────────────────────────────");
+            println!("│ {}", file.display());
+            println!("├─────────────────────────────────────────");
+            println!("│ Schema-ID: {}", header.schema_id);
+            if let Some(ref lang) = header.language {
+                println!("│ Language:  {}", lang);
+            }
+            println!(
+                "│ Signed:    {}",
+                if header.signature.is_some() { "Yes" } else { "No" }
+            );
+            println!("├─────────────────────────────────────────");
+            print_colored_tree(&value, 0, &palette);
+            println!("└─────────────────────────────────────────");
+        }
+        other => anyhow::bail!("Unknown format: '{}' — use tree or json", other),
+    }
+
+    Ok(())
+}
+
+/// Shows payload size attribution per field
+fn cmd_stats(file: &std::path::Path, schema_path: Option<&std::path::Path>) -> Result<()> {
+    use germanic::types::GrmHeader;
+
+    let data = std::fs::read(file).context("Could not read file")?;
+    let (header, header_len) = GrmHeader::from_bytes(&data)
+        .map_err(|e| anyhow::anyhow!("Header parse error: {}", e))?;
+
+    let schema = resolve_inspect_schema(schema_path, &header.schema_id)?;
+    let payload = &data[header_len..];
+    let mut footprints = germanic::reader::measure_payload(&schema, payload)
+        .context("Payload measurement failed")?;
+    footprints.sort_by_key(|f| std::cmp::Reverse(f.bytes));
+
+    let attributed: usize = footprints.iter().map(|f| f.bytes).sum();
+
+    println!("┌─────────────────────────────────────────");
+    println!("│ GERMANIC Stats");
+    println!("├─────────────────────────────────────────");
+    println!("│ File:    {}", file.display());
+    println!("│ Total:   {} bytes", data.len());
+    println!("│ Header:  {} bytes", header_len);
+    println!("│ Payload: {} bytes", payload.len());
+    println!("│");
+    println!("│ Per field (approximate):");
+
+    let widest = footprints.iter().map(|f| f.name.len()).max().unwrap_or(0);
+    for fp in &footprints {
+        let percent = 100.0 * fp.bytes as f64 / payload.len() as f64;
+        let bar_len = (percent / 5.0).round() as usize;
+        println!(
+            "│   {:width$}  {:>8} B  {:>5.1}%  {}",
+            fp.name,
+            fp.bytes,
+            percent,
+            "█".repeat(bar_len),
+            width = widest
+        );
+    }
+
+    let overhead = payload.len().saturating_sub(attributed);
+    println!("│");
+    println!(
+        "│   (structure/padding: {} bytes, {:.1}%)",
+        overhead,
+        100.0 * overhead as f64 / payload.len() as f64
+    );
+    println!("└─────────────────────────────────────────");
+
+    Ok(())
+}
+
+/// Extracts a single field from a .grm file
+///
+/// Output goes to stdout without any framing so it can be captured
+/// directly: `ORT=$(germanic query data.grm --schema s.json --path adresse.ort)`
+fn cmd_query(file: &std::path::Path, schema_path: &std::path::Path, path: &str) -> Result<()> {
+    use germanic::dynamic::load_schema_auto;
+    use germanic::reader::{decode_grm, lookup_path};
+
+    let (schema, _warnings) = load_schema_auto(schema_path).context("Could not load schema")?;
+    let data = std::fs::read(file).context("Could not read .grm file")?;
+
+    let (_header, value) = decode_grm(&schema, &data).context("Could not decode .grm file")?;
+
+    let result = lookup_path(&value, path)
+        .ok_or_else(|| anyhow::anyhow!("Path '{}' not found in {}", path, file.display()))?;
+
+    // Strings raw (shell-friendly), everything else as JSON
+    match result {
+        serde_json::Value::String(s) => println!("{}", s),
+        other => println!("{}", other),
+    }
+
+    Ok(())
+}
+
+/// Prints schema fields with type, required marker and description,
+/// recursing into nested tables.
+fn print_schema_fields(
+    fields: &indexmap::IndexMap<String, germanic::dynamic::schema_def::FieldDefinition>,
+    depth: usize,
+) {
+    let indent = "  ".repeat(depth);
+    for (name, def) in fields {
+        let required = if def.required { " (required)" } else { "" };
+        let deprecated = if def.deprecated { " (deprecated)" } else { "" };
+        let description = def
+            .description
+            .as_deref()
+            .map(|d| format!("  — {}", d))
+            .unwrap_or_default();
+        println!(
+            "│ {}{:<14}: {}{}{}{}",
+            indent,
+            name,
+            def.field_type.name(),
+            required,
+            deprecated,
+            description
+        );
+        if let Some(ref nested) = def.fields {
+            print_schema_fields(nested, depth + 1);
+        }
+    }
+}
+
+/// Shows available schemas
+fn cmd_schemas(name: Option<&str>) -> Result<()> {
+    println!("┌─────────────────────────────────────────");
+    println!("│ GERMANIC Schemas");
+    println!("├─────────────────────────────────────────");
 
     match name {
         Some("praxis") | Some("practice") => {
@@ -325,20 +1768,87 @@ fn cmd_schemas(name: Option<&str>) -> Result<()> {
             println!("│   - praxisname, telefon, email, website");
             println!("│   - schwerpunkte, therapieformen, qualifikationen");
             println!("│   - terminbuchung_url, oeffnungszeiten");
+            println!("│   - oeffnungszeiten_struktur, schliesszeiten");
             println!("│   - privatpatienten, kassenpatienten");
+            println!("│   - kassen, kassenzulassung, telemedizin");
+            println!("│   - barrierefreiheit, parkplaetze");
+            println!("│   - breitengrad, laengengrad");
             println!("│   - sprachen, kurzbeschreibung");
         }
+        Some("krankenhaus") | Some("hospital") => {
+            println!("│");
+            println!("│ Schema: krankenhaus (hospital)");
+            println!("│ ID:     de.gesundheit.krankenhaus.v1");
+            println!("│ Type:   Hospitals and clinics");
+            println!("│");
+            println!("│ Required fields:");
+            println!("│   - name            : String");
+            println!("│   - adresse         : Address");
+            println!("│     - strasse       : String");
+            println!("│     - plz           : String");
+            println!("│     - ort           : String");
+            println!("│   - telefon         : String");
+            println!("│   - notaufnahme     : Table");
+            println!("│     - telefon       : String");
+            println!("│     - rund_um_die_uhr : Bool");
+            println!("│   - fachabteilungen : [String]");
+            println!("│");
+            println!("│ Optional fields:");
+            println!("│   - traeger, bettenanzahl, website");
+            println!("│   - notfall_telefon, besuchszeiten");
+            println!("│   - barrierefreiheit, parkplaetze");
+            println!("│   - stockwerke, kurzbeschreibung");
+        }
+        // A path to a schema file: load it and describe its fields
+        Some(path) if std::path::Path::new(path).is_file() => {
+            let (schema, _warnings) = germanic::dynamic::load_schema_auto(std::path::Path::new(path))
+                .context("Could not load schema")?;
+            println!("│");
+            println!("│ Schema: {}", path);
+            println!("│ ID:     {}", schema.schema_id);
+            println!("│ Version: {}", schema.version);
+            if let Some(ref description) = schema.description {
+                println!("│ Info:   {}", description);
+            }
+            println!("│");
+            println!("│ Fields:");
+            print_schema_fields(&schema.fields, 1);
+        }
+        // A shipped schema_id: describe the embedded definition
+        Some(id) if germanic::registry::find_definition(id).is_some() => {
+            let definition = germanic::registry::find_definition(id).unwrap();
+            let (schema, _warnings) = germanic::dynamic::parse_schema_auto(definition)
+                .context("Embedded schema definition invalid")?;
+            println!("│");
+            println!("│ Schema: {} (embedded)", id);
+            println!("│ Version: {}", schema.version);
+            if let Some(ref description) = schema.description {
+                println!("│ Info:   {}", description);
+            }
+            println!("│");
+            println!("│ Fields:");
+            print_schema_fields(&schema.fields, 1);
+        }
         Some(unknown) => {
             println!("│ ✗ Unknown schema: '{}'", unknown);
             println!("│");
-            println!("│ Available: practice, praxis");
+            println!("│ Available: practice, praxis, krankenhaus, hospital");
+            println!("│ Or any shipped schema_id — see `germanic schemas`");
         }
         None => {
             println!("│");
             println!("│ Available schemas:");
             println!("│");
-            println!("│   practice   Healthcare practitioners, doctors, therapists");
-            println!("│   (praxis)   → germanic compile --schema practice ...");
+            println!("│   practice      Healthcare practitioners, doctors, therapists");
+            println!("│   (praxis)      → germanic compile --schema practice ...");
+            println!("│");
+            println!("│   krankenhaus   Hospitals and clinics");
+            println!("│   (hospital)    → germanic compile --schema krankenhaus ...");
+            println!("│");
+            println!("│ Shipped schemas (embedded, use the schema_id):");
+            for id in germanic::registry::definition_ids() {
+                println!("│   {}", id);
+            }
             println!("│");
             println!("│ Dynamic schemas:");
             println!("│   Any .schema.json file can be used with:");
@@ -351,7 +1861,7 @@ fn cmd_schemas(name: Option<&str>) -> Result<()> {
 }
 
 /// Validates a .grm file
-fn cmd_validate(file: &PathBuf) -> Result<()> {
+fn cmd_validate(file: &PathBuf, schema_path: Option<&std::path::Path>) -> Result<()> {
     use germanic::validator::validate_grm;
 
     println!("Validating {}...", file.display());
@@ -365,6 +1875,24 @@ fn cmd_validate(file: &PathBuf) -> Result<()> {
         if let Some(id) = result.schema_id {
             println!("  Schema-ID: {}", id);
         }
+
+        // Deep validation: decode payload and re-run schema checks
+        if let Some(schema_path) = schema_path {
+            let (schema, _warnings) = germanic::dynamic::load_schema_auto(schema_path)
+                .context("Could not load schema")?;
+            let (_header, value) = germanic::reader::decode_grm(&schema, &data)
+                .context("Payload decoding failed")?;
+
+            match germanic::dynamic::validate::validate_against_schema(&schema, &value) {
+                Ok(()) => println!("✓ Payload matches schema {}", schema.schema_id),
+                Err(e) => {
+                    println!("✗ Payload violates schema:");
+                    println!("  {}", e);
+                    return Err(anyhow::anyhow!("Deep validation failed: {}", e));
+                }
+            }
+        }
+
         Ok(())
     } else {
         println!("✗ File is invalid");
@@ -379,7 +1907,12 @@ fn cmd_validate(file: &PathBuf) -> Result<()> {
 }
 
 /// Shows header and metadata of a .grm file
-fn cmd_inspect(file: &PathBuf, hex: bool) -> Result<()> {
+fn cmd_inspect(
+    file: &PathBuf,
+    hex: bool,
+    decode: bool,
+    schema_path: Option<&std::path::Path>,
+) -> Result<()> {
     use germanic::types::GrmHeader;
 
     println!("┌─────────────────────────────────────────");
@@ -397,6 +1930,9 @@ fn cmd_inspect(file: &PathBuf, hex: bool) -> Result<()> {
         Ok((header, header_len)) => {
             println!("│ Header:");
             println!("│   Schema-ID: {}", header.schema_id);
+            if let Some(ref lang) = header.language {
+                println!("│   Language:  {}", lang);
+            }
             println!(
                 "│   Signed:    {}",
                 if header.signature.is_some() {
@@ -420,6 +1956,18 @@ fn cmd_inspect(file: &PathBuf, hex: bool) -> Result<()> {
                     println!();
                 }
             }
+
+            if decode {
+                println!("│");
+                println!("│ Payload:");
+                let schema = resolve_inspect_schema(schema_path, &header.schema_id)?;
+                if let Some(ref description) = schema.description {
+                    println!("│ Schema: {}", description);
+                }
+                let value = germanic::reader::decode_payload(&schema, &data[header_len..])
+                    .context("Payload decoding failed")?;
+                print_value_tree(&value, 1);
+            }
         }
         Err(e) => {
             println!("│ ✗ Header error: {}", e);
@@ -19,6 +19,7 @@
 //! └──────────────────────────────────────────────────────┘
 //! ```
 
+use crate::queue::{CompileQueue, QueueConfig};
 use rmcp::{
     ServerHandler, ServiceExt, handler::server::router::tool::ToolRouter,
     handler::server::wrapper::Parameters, model::*, tool, tool_handler, tool_router,
@@ -26,6 +27,7 @@ use rmcp::{
 use schemars::JsonSchema;
 use serde::Deserialize;
 use std::path::PathBuf;
+use std::sync::Arc;
 
 // ---------------------------------------------------------------------------
 // Parameter structs
@@ -121,6 +123,10 @@ fn check_file_size(path: &std::path::Path) -> Result<(), ErrorData> {
 #[derive(Debug, Clone)]
 pub struct GermanicServer {
     tool_router: ToolRouter<Self>,
+
+    /// Shared across handler clones, so bulk imports cannot exhaust
+    /// memory by firing unbounded concurrent compile requests.
+    compile_queue: Arc<CompileQueue>,
 }
 
 impl GermanicServer {
@@ -128,6 +134,7 @@ impl GermanicServer {
     pub fn new() -> Self {
         Self {
             tool_router: Self::tool_router(),
+            compile_queue: Arc::new(CompileQueue::new(QueueConfig::default())),
         }
     }
 }
@@ -145,6 +152,9 @@ impl Default for GermanicServer {
 #[tool_router(router = tool_router)]
 impl GermanicServer {
     /// Compile JSON data against a GERMANIC schema into binary .grm.
+    ///
+    /// Goes through the shared [`CompileQueue`] — concurrent requests
+    /// beyond the configured limits get rejected instead of piling up.
     #[tool(
         name = "germanic_compile",
         description = "Compile JSON data against a GERMANIC schema into binary .grm"
@@ -159,27 +169,60 @@ impl GermanicServer {
         check_file_size(&input_path)?;
         check_file_size(schema_path)?;
 
-        match crate::dynamic::compile_dynamic(schema_path, &input_path) {
-            Ok(grm_bytes) => {
+        let compiled = self
+            .compile_queue
+            .run(|| {
+                crate::dynamic::compile_dynamic_with_report(
+                    schema_path,
+                    &input_path,
+                    &crate::dynamic::CompileOptions::default(),
+                )
+            })
+            .await;
+
+        match compiled {
+            Ok(report) => {
+                let grm_bytes = report.bytes;
                 let output_path = params
                     .output
                     .map(PathBuf::from)
                     .unwrap_or_else(|| input_path.with_extension("grm"));
 
-                match std::fs::write(&output_path, &grm_bytes) {
-                    Ok(()) => Ok(CallToolResult::success(vec![Content::text(format!(
-                        "Compiled successfully\n  Output: {}\n  Size: {} bytes",
-                        output_path.display(),
-                        grm_bytes.len()
-                    ))])),
-                    Err(e) => Ok(CallToolResult::error(vec![Content::text(format!(
-                        "Write failed: {e}"
-                    ))])),
+                match crate::compiler::write_grm(&grm_bytes, &output_path) {
+                    Ok(()) => {
+                        crate::audit::compile_succeeded(
+                            "(dynamic)",
+                            &params.data,
+                            &output_path.display().to_string(),
+                            grm_bytes.len(),
+                        );
+                        let mut result = format!(
+                            "Compiled successfully\n  Output: {}\n  Size: {} bytes",
+                            output_path.display(),
+                            grm_bytes.len()
+                        );
+                        if !report.warnings.is_empty() {
+                            result.push_str("\n\n  Warnings:");
+                            for w in &report.warnings {
+                                result.push_str(&format!("\n  - {w}"));
+                            }
+                        }
+                        Ok(CallToolResult::success(vec![Content::text(result)]))
+                    }
+                    Err(e) => {
+                        crate::audit::compile_failed(&params.schema, &params.data, &e.to_string());
+                        Ok(CallToolResult::error(vec![Content::text(format!(
+                            "Write failed: {e}"
+                        ))]))
+                    }
                 }
             }
-            Err(e) => Ok(CallToolResult::error(vec![Content::text(format!(
-                "Compilation failed: {e}"
-            ))])),
+            Err(e) => {
+                crate::audit::compile_failed(&params.schema, &params.data, &e.to_string());
+                Ok(CallToolResult::error(vec![Content::text(format!(
+                    "Compilation failed: {e}"
+                ))]))
+            }
         }
     }
 
@@ -198,6 +241,7 @@ impl GermanicServer {
 
         match crate::validator::validate_grm(&data) {
             Ok(result) if result.valid => {
+                crate::audit::validation_result(&params.file, true, None);
                 let schema_info = result
                     .schema_id
                     .map(|id| format!("\n  Schema-ID: {id}"))
@@ -206,10 +250,13 @@ impl GermanicServer {
                     "Valid .grm file{schema_info}"
                 ))]))
             }
-            Ok(result) => Ok(CallToolResult::error(vec![Content::text(format!(
-                "Invalid: {}",
-                result.error.unwrap_or_else(|| "Unknown error".into())
-            ))])),
+            Ok(result) => {
+                crate::audit::validation_result(&params.file, false, result.error.as_deref());
+                Ok(CallToolResult::error(vec![Content::text(format!(
+                    "Invalid: {}",
+                    result.error.unwrap_or_else(|| "Unknown error".into())
+                ))]))
+            }
             Err(e) => Ok(CallToolResult::error(vec![Content::text(format!(
                 "Validation error: {e}"
             ))])),
@@ -478,6 +525,16 @@ mod tests {
         assert!(names.contains(&"germanic_convert"));
     }
 
+    #[test]
+    fn test_compile_queue_shared_across_clones() {
+        let server = GermanicServer::new();
+        let clone = server.clone();
+        assert!(
+            Arc::ptr_eq(&server.compile_queue, &clone.compile_queue),
+            "handler clones must share one queue, or the limits are per-handler"
+        );
+    }
+
     #[test]
     fn test_inspect_params_deserialize() {
         let json = r#"{"file": "test.grm"}"#;
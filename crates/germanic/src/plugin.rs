@@ -0,0 +1,252 @@
+//! # Field Type Plugins
+//!
+//! Registration API for custom field types. Downstream crates add
+//! domain-specific types (ICD-10 codes, DATEV IDs, ...) without forking
+//! `schema_def.rs` or `builder.rs`:
+//!
+//! ```text
+//! downstream crate ──► register_plugin(Icd10Plugin) ──► process registry
+//!                                                            │
+//! .schema.json: { "type": "icd10" } ──► validate/build/decode via plugin
+//! ```
+//!
+//! On the wire a plugin type is always a FlatBuffer string — the plugin
+//! controls validation (`validate`), the string representation (`encode`)
+//! and reconstruction on decompile (`decode`). Readers without the plugin
+//! still see a plain string, so files stay forward-compatible.
+
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock, RwLock};
+
+/// A custom field type, referenced by name in `.schema.json`.
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// struct Icd10Plugin;
+///
+/// impl FieldTypePlugin for Icd10Plugin {
+///     fn name(&self) -> &'static str { "icd10" }
+///
+///     fn validate(&self, value: &Value) -> Result<(), String> {
+///         let code = value.as_str().ok_or("expected string")?;
+///         if is_valid_icd10(code) { Ok(()) } else { Err(format!("'{code}' is not an ICD-10 code")) }
+///     }
+/// }
+///
+/// germanic::plugin::register_plugin(Icd10Plugin);
+/// ```
+pub trait FieldTypePlugin: Send + Sync {
+    /// Type name as referenced in `.schema.json` (e.g. `"icd10"`).
+    ///
+    /// Must not collide with built-in types (`string`, `bool`, `int`,
+    /// `float`, `[string]`, `[int]`, `table`) — built-ins always win.
+    fn name(&self) -> &'static str;
+
+    /// Validates a JSON value of this type.
+    ///
+    /// The error string becomes part of the violation message
+    /// (`"diagnose: 'X99' is not an ICD-10 code"`).
+    fn validate(&self, value: &Value) -> Result<(), String>;
+
+    /// Converts a JSON value to its wire string.
+    ///
+    /// Default: the value must already be a string.
+    fn encode(&self, value: &Value) -> Result<String, String> {
+        value
+            .as_str()
+            .map(str::to_owned)
+            .ok_or_else(|| "expected string".to_string())
+    }
+
+    /// Reconstructs the JSON value from the wire string on decompile.
+    ///
+    /// Default: the string as-is.
+    fn decode(&self, raw: &str) -> Value {
+        Value::String(raw.to_string())
+    }
+
+    /// Like [`validate`](Self::validate), with the enclosing object
+    /// available — lets a plugin consult sibling fields (the phone
+    /// plugin reads the record's `land` country code, for example).
+    ///
+    /// Default: ignores the context and delegates to `validate`.
+    fn validate_with_context(&self, value: &Value, ctx: &PluginContext<'_>) -> Result<(), String> {
+        let _ = ctx;
+        self.validate(value)
+    }
+
+    /// Like [`encode`](Self::encode), with the enclosing object
+    /// available.
+    ///
+    /// Default: ignores the context and delegates to `encode`.
+    fn encode_with_context(&self, value: &Value, ctx: &PluginContext<'_>) -> Result<String, String> {
+        let _ = ctx;
+        self.encode(value)
+    }
+}
+
+/// Context handed to the `*_with_context` plugin hooks.
+///
+/// Carries the fields of the JSON object that contains the value being
+/// processed (including the value itself), so plugins can resolve
+/// record-level information like a country code without the schema
+/// growing plugin-specific knowledge.
+pub struct PluginContext<'a> {
+    /// Fields of the enclosing JSON object.
+    pub siblings: &'a serde_json::Map<String, Value>,
+}
+
+/// Process-wide plugin registry, seeded with the built-in format
+/// plugins ([`crate::formats`]) on first use.
+fn registry() -> &'static RwLock<HashMap<&'static str, Arc<dyn FieldTypePlugin>>> {
+    static REGISTRY: OnceLock<RwLock<HashMap<&'static str, Arc<dyn FieldTypePlugin>>>> =
+        OnceLock::new();
+    REGISTRY.get_or_init(|| {
+        let mut map: HashMap<&'static str, Arc<dyn FieldTypePlugin>> = HashMap::new();
+        for plugin in crate::formats::builtin_plugins() {
+            map.insert(plugin.name(), plugin);
+        }
+        RwLock::new(map)
+    })
+}
+
+/// Registers a plugin for its declared type name.
+///
+/// Registering the same name twice replaces the earlier plugin (useful
+/// in tests); register at process start, before compiling.
+pub fn register_plugin(plugin: impl FieldTypePlugin + 'static) {
+    let name = plugin.name();
+    registry()
+        .write()
+        .expect("plugin registry poisoned")
+        .insert(name, Arc::new(plugin));
+}
+
+/// Looks up a plugin by type name.
+pub fn lookup_plugin(name: &str) -> Option<Arc<dyn FieldTypePlugin>> {
+    registry()
+        .read()
+        .expect("plugin registry poisoned")
+        .get(name)
+        .cloned()
+}
+
+/// Names of all registered plugins (sorted, for reports).
+pub fn registered_plugins() -> Vec<String> {
+    let mut names: Vec<String> = registry()
+        .read()
+        .expect("plugin registry poisoned")
+        .keys()
+        .map(|n| n.to_string())
+        .collect();
+    names.sort();
+    names
+}
+
+// ============================================================================
+// TESTS
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dynamic::schema_def::{FieldDefinition, FieldType, SchemaDefinition};
+    use indexmap::IndexMap;
+
+    /// Toy domain type: codes must be uppercase letters + digits.
+    struct CodePlugin;
+
+    impl FieldTypePlugin for CodePlugin {
+        fn name(&self) -> &'static str {
+            "testcode"
+        }
+
+        fn validate(&self, value: &Value) -> Result<(), String> {
+            let code = value.as_str().ok_or("expected string")?;
+            if !code.is_empty()
+                && code.chars().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+            {
+                Ok(())
+            } else {
+                Err(format!("'{}' is not a valid code", code))
+            }
+        }
+    }
+
+    fn code_schema() -> SchemaDefinition {
+        let mut fields = IndexMap::new();
+        fields.insert(
+            "diagnose".into(),
+            FieldDefinition {
+                required: true,
+                ..FieldDefinition::new(FieldType::Custom("testcode".into()))
+            },
+        );
+        SchemaDefinition {
+            schema_id: "test.v1".into(),
+            version: 1,
+            description: None,
+            limits: None,
+            strict: false,
+            fields,
+        }
+    }
+
+    #[test]
+    fn test_custom_type_parses_from_schema_json() {
+        let json = r#"{"type": "testcode", "required": true}"#;
+        let field: FieldDefinition = serde_json::from_str(json).unwrap();
+        assert_eq!(field.field_type, FieldType::Custom("testcode".into()));
+    }
+
+    #[test]
+    fn test_plugin_validates() {
+        register_plugin(CodePlugin);
+        let schema = code_schema();
+
+        let ok = serde_json::json!({ "diagnose": "J45" });
+        assert!(crate::dynamic::validate::validate_against_schema(&schema, &ok).is_ok());
+
+        let bad = serde_json::json!({ "diagnose": "nope!" });
+        let err = crate::dynamic::validate::validate_against_schema(&schema, &bad).unwrap_err();
+        assert!(err.to_string().contains("not a valid code"));
+    }
+
+    #[test]
+    fn test_unregistered_plugin_rejected() {
+        let mut fields = IndexMap::new();
+        fields.insert(
+            "x".into(),
+            FieldDefinition {
+                required: true,
+                ..FieldDefinition::new(FieldType::Custom("nosuchplugin".into()))
+            },
+        );
+        let schema = SchemaDefinition {
+            schema_id: "test.v1".into(),
+            version: 1,
+            description: None,
+            limits: None,
+            strict: false,
+            fields,
+        };
+
+        let data = serde_json::json!({ "x": "whatever" });
+        let err = crate::dynamic::validate::validate_against_schema(&schema, &data).unwrap_err();
+        assert!(err.to_string().contains("nosuchplugin"));
+    }
+
+    #[test]
+    fn test_plugin_roundtrip_through_builder_and_reader() {
+        register_plugin(CodePlugin);
+        let schema = code_schema();
+        let data = serde_json::json!({ "diagnose": "J45" });
+
+        let payload = crate::dynamic::builder::build_flatbuffer(&schema, &data).unwrap();
+        let decoded = crate::reader::decode_payload(&schema, &payload).unwrap();
+
+        assert_eq!(decoded, data);
+    }
+}
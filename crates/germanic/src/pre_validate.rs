@@ -27,6 +27,46 @@ pub const MAX_ARRAY_ELEMENTS: usize = 10_000;
 /// Maximum nesting depth for objects/arrays.
 pub const MAX_NESTING_DEPTH: usize = 32;
 
+// Absolute caps a per-schema `limits` block can raise the defaults to.
+// Overrides are clamped here so a hostile schema cannot disable the
+// defense-in-depth entirely.
+
+/// Absolute cap for the total input size (50 MB).
+pub const MAX_INPUT_SIZE_CAP: usize = 52_428_800;
+
+/// Absolute cap for a single string value (10 MB).
+pub const MAX_STRING_LENGTH_CAP: usize = 10_485_760;
+
+/// Absolute cap for array element counts.
+pub const MAX_ARRAY_ELEMENTS_CAP: usize = 100_000;
+
+/// Absolute cap for nesting depth.
+pub const MAX_NESTING_DEPTH_CAP: usize = 64;
+
+/// Structural limits in effect for one validation run.
+///
+/// Defaults to the global constants; schemas can override individual
+/// values (clamped to the absolute caps) via their `limits` block — see
+/// [`crate::dynamic::schema_def::SchemaLimits`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Limits {
+    pub max_input_size: usize,
+    pub max_string_length: usize,
+    pub max_array_elements: usize,
+    pub max_nesting_depth: usize,
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Limits {
+            max_input_size: MAX_INPUT_SIZE,
+            max_string_length: MAX_STRING_LENGTH,
+            max_array_elements: MAX_ARRAY_ELEMENTS,
+            max_nesting_depth: MAX_NESTING_DEPTH,
+        }
+    }
+}
+
 /// Schema-agnostic structural validation.
 ///
 /// Checks the raw JSON input and parsed Value for size/depth violations.
@@ -39,14 +79,24 @@ pub const MAX_NESTING_DEPTH: usize = 32;
 /// pre_validate(&json, &value)?;
 /// ```
 pub fn pre_validate(raw_json: &str, value: &serde_json::Value) -> Result<(), Vec<String>> {
+    pre_validate_with_limits(raw_json, value, &Limits::default())
+}
+
+/// Like [`pre_validate`], but with explicit structural limits
+/// (from a schema's `limits` block).
+pub fn pre_validate_with_limits(
+    raw_json: &str,
+    value: &serde_json::Value,
+    limits: &Limits,
+) -> Result<(), Vec<String>> {
     let mut errors = Vec::new();
 
     // Check 1: Total input size
-    if raw_json.len() > MAX_INPUT_SIZE {
+    if raw_json.len() > limits.max_input_size {
         errors.push(format!(
             "input size {} bytes exceeds maximum of {} bytes",
             raw_json.len(),
-            MAX_INPUT_SIZE
+            limits.max_input_size
         ));
     }
 
@@ -59,7 +109,7 @@ pub fn pre_validate(raw_json: &str, value: &serde_json::Value) -> Result<(), Vec
     }
 
     // Check 3: Recurse into the value tree
-    check_value(value, "", &mut errors, 0);
+    check_value(value, "", &mut errors, 0, limits);
 
     if errors.is_empty() {
         Ok(())
@@ -73,6 +123,14 @@ pub fn pre_validate(raw_json: &str, value: &serde_json::Value) -> Result<(), Vec
 /// Use when the raw JSON string is not available (e.g. pre-parsed `Value`).
 /// Checks string lengths, array sizes, and nesting depth.
 pub fn pre_validate_value(value: &serde_json::Value) -> Result<(), Vec<String>> {
+    pre_validate_value_with_limits(value, &Limits::default())
+}
+
+/// Like [`pre_validate_value`], but with explicit structural limits.
+pub fn pre_validate_value_with_limits(
+    value: &serde_json::Value,
+    limits: &Limits,
+) -> Result<(), Vec<String>> {
     let mut errors = Vec::new();
 
     if !value.is_object() {
@@ -82,7 +140,7 @@ pub fn pre_validate_value(value: &serde_json::Value) -> Result<(), Vec<String>>
         ));
     }
 
-    check_value(value, "", &mut errors, 0);
+    check_value(value, "", &mut errors, 0, limits);
 
     if errors.is_empty() {
         Ok(())
@@ -92,37 +150,43 @@ pub fn pre_validate_value(value: &serde_json::Value) -> Result<(), Vec<String>>
 }
 
 /// Recursively checks a JSON value for size/depth violations.
-fn check_value(value: &serde_json::Value, path: &str, errors: &mut Vec<String>, depth: usize) {
-    if depth > MAX_NESTING_DEPTH {
+fn check_value(
+    value: &serde_json::Value,
+    path: &str,
+    errors: &mut Vec<String>,
+    depth: usize,
+    limits: &Limits,
+) {
+    if depth > limits.max_nesting_depth {
         errors.push(format!(
             "{}: nesting depth exceeds maximum of {}",
             if path.is_empty() { "(root)" } else { path },
-            MAX_NESTING_DEPTH
+            limits.max_nesting_depth
         ));
         return;
     }
 
     match value {
-        serde_json::Value::String(s) if s.len() > MAX_STRING_LENGTH => {
+        serde_json::Value::String(s) if s.len() > limits.max_string_length => {
             errors.push(format!(
                 "{}: string length {} exceeds maximum of {} bytes",
                 if path.is_empty() { "(root)" } else { path },
                 s.len(),
-                MAX_STRING_LENGTH
+                limits.max_string_length
             ));
         }
         serde_json::Value::Array(arr) => {
-            if arr.len() > MAX_ARRAY_ELEMENTS {
+            if arr.len() > limits.max_array_elements {
                 errors.push(format!(
                     "{}: array has {} elements, maximum is {}",
                     if path.is_empty() { "(root)" } else { path },
                     arr.len(),
-                    MAX_ARRAY_ELEMENTS
+                    limits.max_array_elements
                 ));
             }
             for (i, item) in arr.iter().enumerate() {
                 let item_path = format!("{}[{}]", if path.is_empty() { "(root)" } else { path }, i);
-                check_value(item, &item_path, errors, depth + 1);
+                check_value(item, &item_path, errors, depth + 1, limits);
             }
         }
         serde_json::Value::Object(map) => {
@@ -132,7 +196,7 @@ fn check_value(value: &serde_json::Value, path: &str, errors: &mut Vec<String>,
                 } else {
                     format!("{}.{}", path, key)
                 };
-                check_value(val, &field_path, errors, depth + 1);
+                check_value(val, &field_path, errors, depth + 1, limits);
             }
         }
         _ => {}
@@ -247,4 +311,19 @@ mod tests {
         let value = serde_json::json!({"name": "Test", "value": 42});
         assert!(pre_validate_value(&value).is_ok());
     }
+
+    #[test]
+    fn test_custom_limits_raise_array_ceiling() {
+        let items: Vec<u32> = (0..(MAX_ARRAY_ELEMENTS as u32 + 1)).collect();
+        let value = serde_json::json!({ "betten": items });
+
+        // Default limits reject, raised limits accept
+        assert!(pre_validate_value(&value).is_err());
+
+        let limits = Limits {
+            max_array_elements: 15_000,
+            ..Limits::default()
+        };
+        assert!(pre_validate_value_with_limits(&value, &limits).is_ok());
+    }
 }
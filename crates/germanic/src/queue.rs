@@ -0,0 +1,277 @@
+//! # Backpressure-Aware Compile Queue
+//!
+//! Bounds concurrent compilation for daemon-style frontends (MCP today,
+//! REST tomorrow). Bulk imports can fire hundreds of compile requests at
+//! once — without a queue a single tenant could exhaust memory.
+//!
+//! ```text
+//! request ──► admission check ──► wait for permit ──► compile ──► .grm bytes
+//!                 │                     │
+//!                 ▼                     ▼
+//!          QueueFull (→ 429,      Timeout (request
+//!          queue position)        waited too long)
+//! ```
+//!
+//! The queue does not replace the structural limits in [`crate::pre_validate`]
+//! — those still cap each individual request; the queue caps how many of
+//! them are in flight at once.
+
+use crate::dynamic::schema_def::SchemaDefinition;
+use crate::error::GermanicError;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+use tokio::sync::Semaphore;
+
+/// Configuration for a [`CompileQueue`].
+#[derive(Debug, Clone)]
+pub struct QueueConfig {
+    /// Number of compilations allowed to run simultaneously.
+    pub max_concurrency: usize,
+
+    /// Number of requests allowed to wait for a slot.
+    /// Requests beyond this are rejected immediately (HTTP 429 territory).
+    pub max_queued: usize,
+
+    /// Maximum time a request may wait for a slot before timing out.
+    pub timeout: Duration,
+}
+
+impl Default for QueueConfig {
+    fn default() -> Self {
+        Self {
+            max_concurrency: 4,
+            max_queued: 64,
+            timeout: Duration::from_secs(30),
+        }
+    }
+}
+
+/// Why a queued compile request did not produce bytes.
+#[derive(Debug, thiserror::Error)]
+pub enum QueueError {
+    /// The queue is full — the caller should back off and retry.
+    /// Maps to HTTP 429; `queued`/`capacity` give the position information.
+    #[error("Queue full: {queued} requests waiting (capacity: {capacity})")]
+    QueueFull {
+        /// Requests currently waiting for a slot.
+        queued: usize,
+        /// Maximum number of waiting requests.
+        capacity: usize,
+    },
+
+    /// The request waited longer than the configured timeout for a slot.
+    #[error("Request timed out after {waited_ms} ms waiting for a compile slot")]
+    Timeout {
+        /// How long the request waited, in milliseconds.
+        waited_ms: u128,
+    },
+
+    /// The compilation itself failed (validation, build, ...).
+    #[error("Compilation failed: {0}")]
+    Compile(#[from] GermanicError),
+}
+
+/// Bounded queue that serializes access to the dynamic compiler.
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// let queue = CompileQueue::new(QueueConfig::default());
+/// let grm = queue.compile(&schema, &data).await?;
+/// ```
+#[derive(Debug)]
+pub struct CompileQueue {
+    semaphore: Arc<Semaphore>,
+    queued: AtomicUsize,
+    config: QueueConfig,
+}
+
+impl CompileQueue {
+    /// Creates a queue with the given configuration.
+    pub fn new(config: QueueConfig) -> Self {
+        Self {
+            semaphore: Arc::new(Semaphore::new(config.max_concurrency)),
+            queued: AtomicUsize::new(0),
+            config,
+        }
+    }
+
+    /// Number of requests currently waiting for a compile slot.
+    pub fn queued(&self) -> usize {
+        self.queued.load(Ordering::Relaxed)
+    }
+
+    /// Compiles JSON data to .grm bytes, respecting concurrency limits.
+    ///
+    /// Convenience wrapper around [`Self::run`] for callers that already
+    /// hold a parsed schema and data value.
+    pub async fn compile(
+        &self,
+        schema: &SchemaDefinition,
+        data: &serde_json::Value,
+    ) -> Result<Vec<u8>, QueueError> {
+        self.run(|| crate::dynamic::compile_dynamic_from_values(schema, data))
+            .await
+    }
+
+    /// Runs a compile closure under the queue's admission and
+    /// concurrency limits.
+    ///
+    /// A free slot is taken immediately without queueing — `max_queued`
+    /// only counts requests that actually have to wait. For those,
+    /// admission happens in two stages:
+    /// 1. If `max_queued` requests are already waiting → [`QueueError::QueueFull`]
+    /// 2. If no slot frees up within `timeout` → [`QueueError::Timeout`]
+    pub async fn run<T>(
+        &self,
+        compile_fn: impl FnOnce() -> Result<T, GermanicError>,
+    ) -> Result<T, QueueError> {
+        let _permit = match self.semaphore.try_acquire() {
+            // Fast path: free slot, no queueing — max_queued does not apply
+            Ok(permit) => permit,
+            Err(_) => {
+                // Stage 1: admission — reject instead of queueing unboundedly
+                let queued = self.queued.fetch_add(1, Ordering::SeqCst);
+                if queued >= self.config.max_queued {
+                    self.queued.fetch_sub(1, Ordering::SeqCst);
+                    return Err(QueueError::QueueFull {
+                        queued,
+                        capacity: self.config.max_queued,
+                    });
+                }
+
+                // Stage 2: wait for a slot, bounded by the per-request timeout
+                let start = std::time::Instant::now();
+                let permit =
+                    tokio::time::timeout(self.config.timeout, self.semaphore.acquire()).await;
+                self.queued.fetch_sub(1, Ordering::SeqCst);
+
+                match permit {
+                    Ok(Ok(permit)) => permit,
+                    // Ok(Err): semaphore closed — only happens on shutdown
+                    Ok(Err(_)) | Err(_) => {
+                        return Err(QueueError::Timeout {
+                            waited_ms: start.elapsed().as_millis(),
+                        });
+                    }
+                }
+            }
+        };
+
+        // Slot acquired — compile (pre_validate limits apply inside)
+        Ok(compile_fn()?)
+    }
+}
+
+// ============================================================================
+// TESTS
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dynamic::schema_def::{FieldDefinition, FieldType};
+    use indexmap::IndexMap;
+
+    fn test_schema() -> SchemaDefinition {
+        let mut fields = IndexMap::new();
+        fields.insert(
+            "name".into(),
+            FieldDefinition {
+                required: true,
+                ..FieldDefinition::new(FieldType::String)
+            },
+        );
+        SchemaDefinition {
+            schema_id: "test.v1".into(),
+            version: 1,
+            description: None,
+            limits: None,
+            strict: false,
+            fields,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_compile_through_queue() {
+        let queue = CompileQueue::new(QueueConfig::default());
+        let schema = test_schema();
+        let data = serde_json::json!({ "name": "Test" });
+
+        let bytes = queue.compile(&schema, &data).await.unwrap();
+        assert_eq!(&bytes[0..3], b"GRM");
+    }
+
+    #[tokio::test]
+    async fn test_idle_queue_admits_with_zero_wait_capacity() {
+        // max_queued bounds WAITING requests — a request that can start
+        // immediately never queues and must not be rejected
+        let queue = CompileQueue::new(QueueConfig {
+            max_concurrency: 1,
+            max_queued: 0,
+            timeout: Duration::from_secs(1),
+        });
+        let schema = test_schema();
+        let data = serde_json::json!({ "name": "Test" });
+
+        let bytes = queue.compile(&schema, &data).await.unwrap();
+        assert_eq!(&bytes[0..3], b"GRM");
+    }
+
+    #[tokio::test]
+    async fn test_queue_full_rejected() {
+        // All slots busy + zero waiting capacity → rejected at admission
+        let queue = CompileQueue::new(QueueConfig {
+            max_concurrency: 1,
+            max_queued: 0,
+            timeout: Duration::from_secs(1),
+        });
+        let _slot_held = queue.semaphore.acquire().await.unwrap();
+        let schema = test_schema();
+        let data = serde_json::json!({ "name": "Test" });
+
+        let result = queue.compile(&schema, &data).await;
+        assert!(matches!(result, Err(QueueError::QueueFull { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_queue_error_carries_position() {
+        let queue = CompileQueue::new(QueueConfig {
+            max_concurrency: 1,
+            max_queued: 0,
+            timeout: Duration::from_secs(1),
+        });
+        let _slot_held = queue.semaphore.acquire().await.unwrap();
+        let schema = test_schema();
+        let data = serde_json::json!({ "name": "Test" });
+
+        let err = queue.compile(&schema, &data).await.unwrap_err();
+        assert!(err.to_string().contains("capacity: 0"));
+    }
+
+    #[tokio::test]
+    async fn test_waiting_request_times_out() {
+        let queue = CompileQueue::new(QueueConfig {
+            max_concurrency: 1,
+            max_queued: 4,
+            timeout: Duration::from_millis(20),
+        });
+        let _slot_held = queue.semaphore.acquire().await.unwrap();
+        let schema = test_schema();
+        let data = serde_json::json!({ "name": "Test" });
+
+        let result = queue.compile(&schema, &data).await;
+        assert!(matches!(result, Err(QueueError::Timeout { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_compile_error_passes_through() {
+        let queue = CompileQueue::new(QueueConfig::default());
+        let schema = test_schema();
+        let data = serde_json::json!({}); // required field missing
+
+        let result = queue.compile(&schema, &data).await;
+        assert!(matches!(result, Err(QueueError::Compile(_))));
+    }
+}
@@ -0,0 +1,1012 @@
+//! # Generic .grm → JSON Decoder
+//!
+//! Walks a FlatBuffer payload using a [`SchemaDefinition`] and reconstructs
+//! the JSON data. This is the inverse of the dynamic builder — it uses the
+//! same vtable-slot formula, so anything `build_flatbuffer()` writes can be
+//! read back without flatc-generated types.
+//!
+//! ```text
+//! ┌──────────┐     ┌──────────────┐     ┌──────────────┐
+//! │ .grm     │────►│ SchemaDef +  │────►│ serde_json:: │
+//! │ (binary) │     │ decode_grm() │     │ Value        │
+//! └──────────┘     └──────────────┘     └──────────────┘
+//!
+//! vtable slot = 4 + (2 × field_index)   — same formula as builder.rs
+//! ```
+//!
+//! ## Safety
+//!
+//! All reads are bounds-checked. Malformed or truncated buffers produce a
+//! `GermanicError`, never a panic — .grm files come from the network and
+//! must be treated as untrusted input.
+
+use crate::dynamic::schema_def::{FieldDefinition, FieldType, SchemaDefinition};
+use crate::error::{GermanicError, GermanicResult};
+use crate::pre_validate::MAX_NESTING_DEPTH;
+use crate::types::GrmHeader;
+use indexmap::IndexMap;
+use serde_json::Value;
+
+// ============================================================================
+// PUBLIC API
+// ============================================================================
+
+/// Decodes a complete .grm file (header + FlatBuffer payload) to JSON.
+///
+/// Returns the parsed header (schema ID, signature, language tag) together
+/// with the reconstructed data.
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// let schema = SchemaDefinition::from_file(Path::new("restaurant.schema.json"))?;
+/// let bytes = std::fs::read("data.grm")?;
+/// let (header, value) = decode_grm(&schema, &bytes)?;
+/// println!("{}: {}", header.schema_id, value);
+/// ```
+pub fn decode_grm(schema: &SchemaDefinition, data: &[u8]) -> GermanicResult<(GrmHeader, Value)> {
+    let (header, header_len) = GrmHeader::from_bytes(data)
+        .map_err(|e| GermanicError::General(format!("Header error: {}", e)))?;
+
+    let value = decode_payload(schema, &data[header_len..])?;
+    Ok((header, value))
+}
+
+/// Decodes a raw FlatBuffer payload (WITHOUT .grm header) to JSON.
+///
+/// Inverse of [`crate::dynamic::builder::build_flatbuffer`].
+pub fn decode_payload(schema: &SchemaDefinition, payload: &[u8]) -> GermanicResult<Value> {
+    // Root: u32 offset to the root table
+    let root_offset = read_u32(payload, 0)? as usize;
+    let obj = decode_table(payload, root_offset, &schema.fields, 0)?;
+    Ok(Value::Object(obj))
+}
+
+/// Byte footprint of a single top-level field in a payload.
+///
+/// Produced by [`measure_payload`] for `germanic stats`.
+#[derive(Debug)]
+pub struct FieldFootprint {
+    /// Field name (top level of the schema).
+    pub name: String,
+
+    /// Approximate bytes attributable to this field: inline slot plus
+    /// all reachable data (strings, vectors, nested tables).
+    pub bytes: usize,
+}
+
+/// Measures the approximate byte footprint of every top-level field.
+///
+/// Attribution follows the offsets the reader already walks: a string
+/// field counts its length prefix + content, a vector its element
+/// offsets + contents, a nested table its vtable + inline area + data.
+/// Padding and shared vtables make this an approximation — good enough
+/// to answer "which field makes my .grm 400 KB".
+pub fn measure_payload(
+    schema: &SchemaDefinition,
+    payload: &[u8],
+) -> GermanicResult<Vec<FieldFootprint>> {
+    let root_offset = read_u32(payload, 0)? as usize;
+    measure_table(payload, root_offset, &schema.fields, 0)
+}
+
+/// Measures all fields of one table, returning per-field footprints.
+fn measure_table(
+    buf: &[u8],
+    table_pos: usize,
+    fields: &IndexMap<String, FieldDefinition>,
+    depth: usize,
+) -> GermanicResult<Vec<FieldFootprint>> {
+    if depth > MAX_NESTING_DEPTH {
+        return Err(malformed("nesting depth exceeds maximum"));
+    }
+
+    let soffset = read_i32(buf, table_pos)?;
+    let vtable_pos = usize::try_from(table_pos as i64 - soffset as i64)
+        .map_err(|_| malformed("vtable position out of range"))?;
+    let vtable_size = read_u16(buf, vtable_pos)? as usize;
+
+    let mut footprints = Vec::new();
+
+    let slots = crate::dynamic::schema_def::field_slots(fields)?;
+    for (index, (name, def)) in fields.iter().enumerate() {
+        let voffset = 4 + 2 * slots[index];
+        let field_offset = if voffset + 2 <= vtable_size {
+            read_u16(buf, vtable_pos + voffset)? as usize
+        } else {
+            0
+        };
+
+        if field_offset == 0 {
+            continue;
+        }
+
+        let field_pos = table_pos + field_offset;
+        let bytes = measure_field(buf, field_pos, def, depth)?;
+        footprints.push(FieldFootprint {
+            name: name.clone(),
+            bytes,
+        });
+    }
+
+    Ok(footprints)
+}
+
+/// Approximate bytes attributable to one present field.
+fn measure_field(
+    buf: &[u8],
+    field_pos: usize,
+    def: &FieldDefinition,
+    depth: usize,
+) -> GermanicResult<usize> {
+    Ok(match &def.field_type {
+        FieldType::Bool => 1,
+        FieldType::Int | FieldType::Float => 4,
+
+        // Inline offset slot + length prefix + content
+        FieldType::String | FieldType::Enum | FieldType::Custom(_) => {
+            let str_pos = follow_offset(buf, field_pos)?;
+            let len = read_u32(buf, str_pos)? as usize;
+            4 + 4 + len
+        }
+
+        FieldType::StringArray => {
+            let vec_pos = follow_offset(buf, field_pos)?;
+            let len = read_vector_len(buf, vec_pos, 4)?;
+            let mut total = 4 + 4 + 4 * len;
+            for i in 0..len {
+                let elem_pos = vec_pos + 4 + 4 * i;
+                let str_pos = follow_offset(buf, elem_pos)?;
+                total += 4 + read_u32(buf, str_pos)? as usize;
+            }
+            total
+        }
+
+        FieldType::IntArray => {
+            let vec_pos = follow_offset(buf, field_pos)?;
+            let len = read_vector_len(buf, vec_pos, 4)?;
+            4 + 4 + 4 * len
+        }
+
+        // One byte per element/raw byte behind a length prefix
+        FieldType::BoolArray | FieldType::Bytes | FieldType::Uuid => {
+            let vec_pos = follow_offset(buf, field_pos)?;
+            let len = read_vector_len(buf, vec_pos, 1)?;
+            4 + 4 + len
+        }
+
+        FieldType::Table | FieldType::Union => {
+            let nested_fields = def.fields.as_ref().ok_or_else(|| {
+                GermanicError::General("Table field has no nested field definitions".into())
+            })?;
+            let nested_pos = follow_offset(buf, field_pos)?;
+
+            // Structure: offset slot + soffset + vtable
+            let soffset = read_i32(buf, nested_pos)?;
+            let vtable_pos = usize::try_from(nested_pos as i64 - soffset as i64)
+                .map_err(|_| malformed("vtable position out of range"))?;
+            let vtable_size = read_u16(buf, vtable_pos)? as usize;
+
+            let mut total = 4 + 4 + vtable_size;
+            for fp in measure_table(buf, nested_pos, nested_fields, depth + 1)? {
+                total += fp.bytes;
+            }
+            total
+        }
+
+        FieldType::TableArray => {
+            let nested_fields = def.fields.as_ref().ok_or_else(|| {
+                GermanicError::General("[table] field has no nested field definitions".into())
+            })?;
+            let vec_pos = follow_offset(buf, field_pos)?;
+            let len = read_vector_len(buf, vec_pos, 4)?;
+
+            // Offset slot + length prefix + element offsets
+            let mut total = 4 + 4 + 4 * len;
+            for i in 0..len {
+                let elem_pos = vec_pos + 4 + 4 * i;
+                let table_pos = follow_offset(buf, elem_pos)?;
+
+                let soffset = read_i32(buf, table_pos)?;
+                let vtable_pos = usize::try_from(table_pos as i64 - soffset as i64)
+                    .map_err(|_| malformed("vtable position out of range"))?;
+                total += 4 + read_u16(buf, vtable_pos)? as usize;
+                for fp in measure_table(buf, table_pos, nested_fields, depth + 1)? {
+                    total += fp.bytes;
+                }
+            }
+            total
+        }
+    })
+}
+
+/// Looks up a value inside decoded JSON by path expression.
+///
+/// Accepts both dotted paths (`adresse.ort`) and JSON Pointer
+/// (`/adresse/ort`). Numeric segments index into arrays:
+/// `schwerpunkte.0` or `/schwerpunkte/0`.
+///
+/// Returns `None` if any segment does not resolve.
+pub fn lookup_path<'a>(value: &'a Value, path: &str) -> Option<&'a Value> {
+    let segments: Vec<&str> = if let Some(pointer) = path.strip_prefix('/') {
+        pointer.split('/').collect()
+    } else {
+        path.split('.').collect()
+    };
+
+    let mut current = value;
+    for segment in segments {
+        if segment.is_empty() {
+            return None;
+        }
+        current = match current {
+            Value::Object(map) => map.get(segment)?,
+            Value::Array(arr) => arr.get(segment.parse::<usize>().ok()?)?,
+            _ => return None,
+        };
+    }
+    Some(current)
+}
+
+// ============================================================================
+// TABLE DECODING
+// ============================================================================
+
+/// Decodes a single FlatBuffer table into a JSON object.
+///
+/// Absent fields are omitted. Absent scalar fields with a declared schema
+/// default are emitted with that default — the builder skips slots whose
+/// value equals the default, so this restores what was originally meant.
+fn decode_table(
+    buf: &[u8],
+    table_pos: usize,
+    fields: &IndexMap<String, FieldDefinition>,
+    depth: usize,
+) -> GermanicResult<serde_json::Map<String, Value>> {
+    if depth > MAX_NESTING_DEPTH {
+        return Err(malformed("nesting depth exceeds maximum"));
+    }
+
+    // Table starts with i32 offset BACK to its vtable
+    let soffset = read_i32(buf, table_pos)?;
+    let vtable_pos = usize::try_from(table_pos as i64 - soffset as i64)
+        .map_err(|_| malformed("vtable position out of range"))?;
+    let vtable_size = read_u16(buf, vtable_pos)? as usize;
+
+    let mut obj = serde_json::Map::new();
+
+    let slots = crate::dynamic::schema_def::field_slots(fields)?;
+    for (index, (name, def)) in fields.iter().enumerate() {
+        let voffset = 4 + 2 * slots[index];
+
+        // Slot beyond this table's vtable → field absent
+        let field_offset = if voffset + 2 <= vtable_size {
+            read_u16(buf, vtable_pos + voffset)? as usize
+        } else {
+            0
+        };
+
+        if field_offset == 0 {
+            // Absent slot: scalars with a schema default were elided by the
+            // builder — restore them. Everything else stays omitted.
+            if matches!(
+                def.field_type,
+                FieldType::Bool | FieldType::Int | FieldType::Float
+            ) {
+                if let Some(default) = def.default_value() {
+                    obj.insert(name.clone(), default);
+                }
+            }
+            continue;
+        }
+
+        let field_pos = table_pos + field_offset;
+        let value = decode_field(buf, field_pos, def, depth)?;
+        obj.insert(name.clone(), value);
+    }
+
+    Ok(obj)
+}
+
+/// Decodes a single present field at its table-inline position.
+fn decode_field(
+    buf: &[u8],
+    field_pos: usize,
+    def: &FieldDefinition,
+    depth: usize,
+) -> GermanicResult<Value> {
+    match &def.field_type {
+        FieldType::String | FieldType::Enum => {
+            let s = read_string(buf, follow_offset(buf, field_pos)?)?;
+            Ok(Value::String(s))
+        }
+
+        FieldType::Bool => {
+            let byte = *buf
+                .get(field_pos)
+                .ok_or_else(|| malformed("bool field out of bounds"))?;
+            Ok(Value::Bool(byte != 0))
+        }
+
+        FieldType::Int => Ok(Value::Number(read_i32(buf, field_pos)?.into())),
+
+        FieldType::Float => {
+            let f = read_f32(buf, field_pos)?;
+            // Round-trip through the shortest f32 representation so that
+            // e.g. 4.7f32 decodes as 4.7, not 4.699999809265137
+            let f64_val: f64 = f.to_string().parse().unwrap_or(f as f64);
+            serde_json::Number::from_f64(f64_val)
+                .map(Value::Number)
+                .ok_or_else(|| malformed("float field is not finite"))
+        }
+
+        FieldType::StringArray => {
+            let vec_pos = follow_offset(buf, field_pos)?;
+            let len = read_vector_len(buf, vec_pos, 4)?;
+            let mut items = Vec::with_capacity(len);
+            for i in 0..len {
+                let elem_pos = vec_pos + 4 + 4 * i;
+                let s = read_string(buf, follow_offset(buf, elem_pos)?)?;
+                items.push(Value::String(s));
+            }
+            Ok(Value::Array(items))
+        }
+
+        FieldType::IntArray => {
+            let vec_pos = follow_offset(buf, field_pos)?;
+            let len = read_vector_len(buf, vec_pos, 4)?;
+            let mut items = Vec::with_capacity(len);
+            for i in 0..len {
+                let v = read_i32(buf, vec_pos + 4 + 4 * i)?;
+                items.push(Value::Number(v.into()));
+            }
+            Ok(Value::Array(items))
+        }
+
+        FieldType::BoolArray => {
+            let vec_pos = follow_offset(buf, field_pos)?;
+            let len = read_vector_len(buf, vec_pos, 1)?;
+            let mut items = Vec::with_capacity(len);
+            for i in 0..len {
+                let byte = *buf
+                    .get(vec_pos + 4 + i)
+                    .ok_or_else(|| malformed("bool array element out of bounds"))?;
+                items.push(Value::Bool(byte != 0));
+            }
+            Ok(Value::Array(items))
+        }
+
+        FieldType::Bytes => {
+            let vec_pos = follow_offset(buf, field_pos)?;
+            let len = read_u32(buf, vec_pos)? as usize;
+            let start = vec_pos + 4;
+            let end = start
+                .checked_add(len)
+                .filter(|e| *e <= buf.len())
+                .ok_or_else(|| malformed("byte vector extends past end of buffer"))?;
+            Ok(Value::String(crate::base64::encode(&buf[start..end])))
+        }
+
+        FieldType::Uuid => {
+            let vec_pos = follow_offset(buf, field_pos)?;
+            let len = read_u32(buf, vec_pos)? as usize;
+            let bytes: [u8; 16] = buf
+                .get(vec_pos + 4..vec_pos + 4 + len)
+                .filter(|s| s.len() == 16)
+                .and_then(|s| s.try_into().ok())
+                .ok_or_else(|| malformed("uuid field is not 16 bytes"))?;
+            Ok(Value::String(crate::formats::format_uuid(&bytes)))
+        }
+
+        // Unions share the table layout; only the active variant slot is
+        // set, so decoding yields the single-key tagged object back.
+        FieldType::Table | FieldType::Union => {
+            let nested_fields = def.fields.as_ref().ok_or_else(|| {
+                GermanicError::General("Table field has no nested field definitions".into())
+            })?;
+            let nested_pos = follow_offset(buf, field_pos)?;
+            let nested = decode_table(buf, nested_pos, nested_fields, depth + 1)?;
+            Ok(Value::Object(nested))
+        }
+
+        FieldType::TableArray => {
+            let nested_fields = def.fields.as_ref().ok_or_else(|| {
+                GermanicError::General("[table] field has no nested field definitions".into())
+            })?;
+            let vec_pos = follow_offset(buf, field_pos)?;
+            let len = read_vector_len(buf, vec_pos, 4)?;
+            let mut items = Vec::with_capacity(len);
+            for i in 0..len {
+                let elem_pos = vec_pos + 4 + 4 * i;
+                let table_pos = follow_offset(buf, elem_pos)?;
+                let nested = decode_table(buf, table_pos, nested_fields, depth + 1)?;
+                items.push(Value::Object(nested));
+            }
+            Ok(Value::Array(items))
+        }
+
+        FieldType::Custom(plugin_name) => {
+            // Wire format for plugin types is a plain string; without the
+            // plugin the raw string is still readable.
+            let s = read_string(buf, follow_offset(buf, field_pos)?)?;
+            Ok(match crate::plugin::lookup_plugin(plugin_name) {
+                Some(plugin) => plugin.decode(&s),
+                None => Value::String(s),
+            })
+        }
+    }
+}
+
+// ============================================================================
+// BOUNDS-CHECKED PRIMITIVES
+// ============================================================================
+
+/// Reads a vector length prefix at `vec_pos`, rejecting lengths that
+/// cannot possibly fit in the remaining buffer.
+///
+/// Every element occupies at least `min_elem_size` bytes, so a crafted
+/// prefix like `u32::MAX` is detected here — BEFORE it drives a
+/// multi-gigabyte `Vec::with_capacity` that would abort the process
+/// instead of returning an error.
+fn read_vector_len(buf: &[u8], vec_pos: usize, min_elem_size: usize) -> GermanicResult<usize> {
+    let len = read_u32(buf, vec_pos)? as usize;
+    let available = buf.len().saturating_sub(vec_pos + 4);
+    let plausible = len
+        .checked_mul(min_elem_size)
+        .is_some_and(|bytes| bytes <= available);
+    if !plausible {
+        return Err(malformed("vector length exceeds remaining buffer"));
+    }
+    Ok(len)
+}
+
+/// Follows a u32 forward offset stored at `pos` (relative to `pos` itself).
+fn follow_offset(buf: &[u8], pos: usize) -> GermanicResult<usize> {
+    let rel = read_u32(buf, pos)? as usize;
+    pos.checked_add(rel)
+        .filter(|target| *target < buf.len())
+        .ok_or_else(|| malformed("offset points outside buffer"))
+}
+
+/// Reads a FlatBuffer string (u32 length prefix + UTF-8 bytes) at `pos`.
+fn read_string(buf: &[u8], pos: usize) -> GermanicResult<String> {
+    let len = read_u32(buf, pos)? as usize;
+    let start = pos + 4;
+    let end = start
+        .checked_add(len)
+        .filter(|e| *e <= buf.len())
+        .ok_or_else(|| malformed("string extends past end of buffer"))?;
+    String::from_utf8(buf[start..end].to_vec()).map_err(|_| malformed("string is not valid UTF-8"))
+}
+
+fn read_u16(buf: &[u8], pos: usize) -> GermanicResult<u16> {
+    let bytes: [u8; 2] = buf
+        .get(pos..pos + 2)
+        .and_then(|s| s.try_into().ok())
+        .ok_or_else(|| malformed("u16 read out of bounds"))?;
+    Ok(u16::from_le_bytes(bytes))
+}
+
+fn read_u32(buf: &[u8], pos: usize) -> GermanicResult<u32> {
+    let bytes: [u8; 4] = buf
+        .get(pos..pos + 4)
+        .and_then(|s| s.try_into().ok())
+        .ok_or_else(|| malformed("u32 read out of bounds"))?;
+    Ok(u32::from_le_bytes(bytes))
+}
+
+fn read_i32(buf: &[u8], pos: usize) -> GermanicResult<i32> {
+    let bytes: [u8; 4] = buf
+        .get(pos..pos + 4)
+        .and_then(|s| s.try_into().ok())
+        .ok_or_else(|| malformed("i32 read out of bounds"))?;
+    Ok(i32::from_le_bytes(bytes))
+}
+
+fn read_f32(buf: &[u8], pos: usize) -> GermanicResult<f32> {
+    let bytes: [u8; 4] = buf
+        .get(pos..pos + 4)
+        .and_then(|s| s.try_into().ok())
+        .ok_or_else(|| malformed("f32 read out of bounds"))?;
+    Ok(f32::from_le_bytes(bytes))
+}
+
+/// Shorthand for malformed-buffer errors.
+fn malformed(reason: &str) -> GermanicError {
+    GermanicError::General(format!("Malformed FlatBuffer: {}", reason))
+}
+
+// ============================================================================
+// TESTS
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dynamic::builder::build_flatbuffer;
+    use crate::dynamic::schema_def::{FieldDefinition, FieldType};
+    use indexmap::IndexMap;
+
+    fn field(field_type: FieldType) -> FieldDefinition {
+        FieldDefinition::new(field_type)
+    }
+
+    fn full_schema() -> SchemaDefinition {
+        let mut addr_fields = IndexMap::new();
+        addr_fields.insert("street".into(), field(FieldType::String));
+        addr_fields.insert("city".into(), field(FieldType::String));
+
+        let mut fields = IndexMap::new();
+        fields.insert("name".into(), field(FieldType::String));
+        fields.insert("active".into(), field(FieldType::Bool));
+        fields.insert("count".into(), field(FieldType::Int));
+        fields.insert("rating".into(), field(FieldType::Float));
+        fields.insert("tags".into(), field(FieldType::StringArray));
+        fields.insert("scores".into(), field(FieldType::IntArray));
+        fields.insert(
+            "address".into(),
+            FieldDefinition {
+                fields: Some(addr_fields),
+                ..FieldDefinition::new(FieldType::Table)
+            },
+        );
+
+        SchemaDefinition {
+            schema_id: "test.v1".into(),
+            version: 1,
+            description: None,
+            limits: None,
+            strict: false,
+            fields,
+        }
+    }
+
+    #[test]
+    fn test_roundtrip_all_types() {
+        let schema = full_schema();
+        let data = serde_json::json!({
+            "name": "Bistro",
+            "active": true,
+            "count": 42,
+            "rating": 4.5,
+            "tags": ["a", "b"],
+            "scores": [1, 2, 3],
+            "address": { "street": "Main St", "city": "Berlin" }
+        });
+
+        let payload = build_flatbuffer(&schema, &data).unwrap();
+        let decoded = decode_payload(&schema, &payload).unwrap();
+
+        assert_eq!(decoded, data);
+    }
+
+    #[test]
+    fn test_measure_payload_attributes_large_fields() {
+        let schema = full_schema();
+        let data = serde_json::json!({
+            "name": "x".repeat(500),
+            "count": 1,
+            "tags": ["a", "b"],
+            "address": { "street": "Main St", "city": "Berlin" }
+        });
+
+        let payload = build_flatbuffer(&schema, &data).unwrap();
+        let footprints = measure_payload(&schema, &payload).unwrap();
+
+        let name = footprints.iter().find(|f| f.name == "name").unwrap();
+        assert!(name.bytes >= 500, "name footprint: {}", name.bytes);
+
+        let count = footprints.iter().find(|f| f.name == "count").unwrap();
+        assert_eq!(count.bytes, 4);
+
+        // Attribution should not exceed the whole payload by much
+        let total: usize = footprints.iter().map(|f| f.bytes).sum();
+        assert!(total <= payload.len() + 64, "total {} vs payload {}", total, payload.len());
+    }
+
+    #[test]
+    fn test_lookup_path_dotted_and_pointer() {
+        let data = serde_json::json!({
+            "name": "Bistro",
+            "address": { "city": "Berlin" },
+            "tags": ["a", "b"]
+        });
+
+        assert_eq!(lookup_path(&data, "name"), Some(&serde_json::json!("Bistro")));
+        assert_eq!(
+            lookup_path(&data, "address.city"),
+            Some(&serde_json::json!("Berlin"))
+        );
+        assert_eq!(
+            lookup_path(&data, "/address/city"),
+            Some(&serde_json::json!("Berlin"))
+        );
+        assert_eq!(lookup_path(&data, "tags.1"), Some(&serde_json::json!("b")));
+        assert_eq!(lookup_path(&data, "address.plz"), None);
+        assert_eq!(lookup_path(&data, "tags.x"), None);
+    }
+
+    #[test]
+    fn test_roundtrip_table_array() {
+        let mut doctor_fields = IndexMap::new();
+        doctor_fields.insert("name".into(), field(FieldType::String));
+        doctor_fields.insert("fachgebiet".into(), field(FieldType::String));
+
+        let mut fields = IndexMap::new();
+        fields.insert("name".into(), field(FieldType::String));
+        fields.insert(
+            "aerzte".into(),
+            FieldDefinition {
+                fields: Some(doctor_fields),
+                ..FieldDefinition::new(FieldType::TableArray)
+            },
+        );
+        let schema = SchemaDefinition {
+            schema_id: "test.v1".into(),
+            version: 1,
+            description: None,
+            limits: None,
+            strict: false,
+            fields,
+        };
+
+        let data = serde_json::json!({
+            "name": "Praxis",
+            "aerzte": [
+                { "name": "Dr. A", "fachgebiet": "Allgemein" },
+                { "name": "Dr. B", "fachgebiet": "Kardiologie" }
+            ]
+        });
+
+        let payload = build_flatbuffer(&schema, &data).unwrap();
+        let decoded = decode_payload(&schema, &payload).unwrap();
+        assert_eq!(decoded, data);
+
+        // measure_payload attributes the element tables to the array field
+        let footprints = measure_payload(&schema, &payload).unwrap();
+        let aerzte = footprints.iter().find(|f| f.name == "aerzte").unwrap();
+        assert!(aerzte.bytes > 40, "aerzte footprint: {}", aerzte.bytes);
+    }
+
+    #[test]
+    fn test_roundtrip_bool_array() {
+        let mut fields = IndexMap::new();
+        fields.insert("offen".into(), field(FieldType::BoolArray));
+        let schema = SchemaDefinition {
+            schema_id: "test.v1".into(),
+            version: 1,
+            description: None,
+            limits: None,
+            strict: false,
+            fields,
+        };
+
+        // Weekly availability: seven booleans
+        let data = serde_json::json!({ "offen": [true, true, true, true, true, false, false] });
+        let payload = build_flatbuffer(&schema, &data).unwrap();
+        let decoded = decode_payload(&schema, &payload).unwrap();
+        assert_eq!(decoded, data);
+    }
+
+    #[test]
+    fn test_roundtrip_bytes() {
+        let mut fields = IndexMap::new();
+        fields.insert("logo".into(), field(FieldType::Bytes));
+        let schema = SchemaDefinition {
+            schema_id: "test.v1".into(),
+            version: 1,
+            description: None,
+            limits: None,
+            strict: false,
+            fields,
+        };
+
+        // "iVBORw==" is the start of a PNG signature (0x89 0x50 0x4E 0x47)
+        let data = serde_json::json!({ "logo": "iVBORw==" });
+        let payload = build_flatbuffer(&schema, &data).unwrap();
+        let decoded = decode_payload(&schema, &payload).unwrap();
+        assert_eq!(decoded, data);
+    }
+
+    #[test]
+    fn test_roundtrip_uuid() {
+        let mut fields = IndexMap::new();
+        fields.insert("id".into(), field(FieldType::Uuid));
+        let schema = SchemaDefinition {
+            schema_id: "test.v1".into(),
+            version: 1,
+            description: None,
+            limits: None,
+            strict: false,
+            fields,
+        };
+
+        let data = serde_json::json!({ "id": "550e8400-e29b-41d4-a716-446655440000" });
+        let payload = build_flatbuffer(&schema, &data).unwrap();
+        let decoded = decode_payload(&schema, &payload).unwrap();
+        assert_eq!(decoded, data);
+
+        // Payload stores 16 raw bytes, not the 36-char string
+        let footprints = measure_payload(&schema, &payload).unwrap();
+        let id = footprints.iter().find(|f| f.name == "id").unwrap();
+        assert_eq!(id.bytes, 4 + 4 + 16);
+    }
+
+    #[test]
+    fn test_structured_defaults_applied_for_absent_fields() {
+        let mut fields = IndexMap::new();
+        let mut sprachen = field(FieldType::StringArray);
+        sprachen.default = Some(serde_json::json!(["de", "en"]));
+        fields.insert("sprachen".into(), sprachen);
+
+        let mut adresse = field(FieldType::Table);
+        adresse.default = Some(serde_json::json!({ "ort": "Berlin" }));
+        let mut nested = IndexMap::new();
+        nested.insert("ort".into(), field(FieldType::String));
+        adresse.fields = Some(nested);
+        fields.insert("adresse".into(), adresse);
+
+        let schema = SchemaDefinition {
+            schema_id: "test.v1".into(),
+            version: 1,
+            description: None,
+            limits: None,
+            strict: false,
+            fields,
+        };
+
+        // Neither field present — both defaults land in the payload
+        let data = serde_json::json!({});
+        let payload = build_flatbuffer(&schema, &data).unwrap();
+        let decoded = decode_payload(&schema, &payload).unwrap();
+        assert_eq!(
+            decoded,
+            serde_json::json!({
+                "sprachen": ["de", "en"],
+                "adresse": { "ort": "Berlin" }
+            })
+        );
+    }
+
+    #[test]
+    fn test_roundtrip_enum() {
+        let mut fields = IndexMap::new();
+        fields.insert(
+            "status".into(),
+            FieldDefinition {
+                required: true,
+                values: Some(vec!["active".into(), "inactive".into()]),
+                ..FieldDefinition::new(FieldType::Enum)
+            },
+        );
+        let schema = SchemaDefinition {
+            schema_id: "test.v1".into(),
+            version: 1,
+            description: None,
+            limits: None,
+            strict: false,
+            fields,
+        };
+
+        let data = serde_json::json!({ "status": "inactive" });
+        let payload = build_flatbuffer(&schema, &data).unwrap();
+        let decoded = decode_payload(&schema, &payload).unwrap();
+        assert_eq!(decoded, data);
+    }
+
+    #[test]
+    fn test_roundtrip_union() {
+        let mut person_fields = IndexMap::new();
+        person_fields.insert("name".into(), field(FieldType::String));
+        let mut dept_fields = IndexMap::new();
+        dept_fields.insert("abteilung".into(), field(FieldType::String));
+
+        let mut variants = IndexMap::new();
+        variants.insert(
+            "person".into(),
+            FieldDefinition {
+                fields: Some(person_fields),
+                ..FieldDefinition::new(FieldType::Table)
+            },
+        );
+        variants.insert(
+            "department".into(),
+            FieldDefinition {
+                fields: Some(dept_fields),
+                ..FieldDefinition::new(FieldType::Table)
+            },
+        );
+
+        let mut fields = IndexMap::new();
+        fields.insert(
+            "kontakt".into(),
+            FieldDefinition {
+                fields: Some(variants),
+                ..FieldDefinition::new(FieldType::Union)
+            },
+        );
+        let schema = SchemaDefinition {
+            schema_id: "test.v1".into(),
+            version: 1,
+            description: None,
+            limits: None,
+            strict: false,
+            fields,
+        };
+
+        // Only the active variant comes back — absent slots stay omitted
+        let data = serde_json::json!({ "kontakt": { "person": { "name": "Dr. A" } } });
+        let payload = build_flatbuffer(&schema, &data).unwrap();
+        let decoded = decode_payload(&schema, &payload).unwrap();
+        assert_eq!(decoded, data);
+    }
+
+    #[test]
+    fn test_explicit_ids_keep_slots_stable_across_reordering() {
+        // Old schema: name (slot 0), ort (slot 1) — positional
+        let mut old_fields = IndexMap::new();
+        old_fields.insert("name".into(), field(FieldType::String));
+        old_fields.insert("ort".into(), field(FieldType::String));
+        let old_schema = SchemaDefinition {
+            schema_id: "test.v1".into(),
+            version: 1,
+            description: None,
+            limits: None,
+            strict: false,
+            fields: old_fields,
+        };
+
+        // New schema declares the fields in the opposite order but pins
+        // the original slots via explicit ids
+        let with_id = |id: u16| FieldDefinition {
+            id: Some(id),
+            ..FieldDefinition::new(FieldType::String)
+        };
+        let mut new_fields = IndexMap::new();
+        new_fields.insert("ort".into(), with_id(1));
+        new_fields.insert("name".into(), with_id(0));
+        let new_schema = SchemaDefinition {
+            schema_id: "test.v1".into(),
+            version: 1,
+            description: None,
+            limits: None,
+            strict: false,
+            fields: new_fields,
+        };
+
+        let data = serde_json::json!({ "name": "Bistro", "ort": "Berlin" });
+        let payload = build_flatbuffer(&old_schema, &data).unwrap();
+
+        // The reordered schema still reads old payloads correctly
+        let decoded = decode_payload(&new_schema, &payload).unwrap();
+        assert_eq!(decoded["name"], "Bistro");
+        assert_eq!(decoded["ort"], "Berlin");
+
+        // And payloads built with ids decode under the old schema
+        let payload2 = build_flatbuffer(&new_schema, &data).unwrap();
+        let decoded2 = decode_payload(&old_schema, &payload2).unwrap();
+        assert_eq!(decoded2["name"], "Bistro");
+        assert_eq!(decoded2["ort"], "Berlin");
+    }
+
+    #[test]
+    fn test_roundtrip_float_precision() {
+        let mut fields = IndexMap::new();
+        fields.insert("rating".into(), field(FieldType::Float));
+        let schema = SchemaDefinition {
+            schema_id: "test.v1".into(),
+            version: 1,
+            description: None,
+            limits: None,
+            strict: false,
+            fields,
+        };
+
+        let data = serde_json::json!({ "rating": 4.7 });
+        let payload = build_flatbuffer(&schema, &data).unwrap();
+        let decoded = decode_payload(&schema, &payload).unwrap();
+
+        assert_eq!(decoded["rating"], serde_json::json!(4.7));
+    }
+
+    #[test]
+    fn test_absent_optional_omitted() {
+        let schema = full_schema();
+        let data = serde_json::json!({ "name": "Minimal" });
+
+        let payload = build_flatbuffer(&schema, &data).unwrap();
+        let decoded = decode_payload(&schema, &payload).unwrap();
+
+        assert_eq!(decoded["name"], "Minimal");
+        assert!(decoded.get("tags").is_none());
+        assert!(decoded.get("address").is_none());
+    }
+
+    #[test]
+    fn test_absent_scalar_restores_default() {
+        let mut fields = IndexMap::new();
+        fields.insert("name".into(), field(FieldType::String));
+        fields.insert(
+            "active".into(),
+            FieldDefinition {
+                default: Some("false".into()),
+                ..FieldDefinition::new(FieldType::Bool)
+            },
+        );
+        let schema = SchemaDefinition {
+            schema_id: "test.v1".into(),
+            version: 1,
+            description: None,
+            limits: None,
+            strict: false,
+            fields,
+        };
+
+        // "active": false equals the default — builder elides the slot
+        let data = serde_json::json!({ "name": "X", "active": false });
+        let payload = build_flatbuffer(&schema, &data).unwrap();
+        let decoded = decode_payload(&schema, &payload).unwrap();
+
+        assert_eq!(decoded["active"], serde_json::json!(false));
+    }
+
+    #[test]
+    fn test_decode_grm_with_header() {
+        let schema = full_schema();
+        let data = serde_json::json!({ "name": "Bistro" });
+
+        let grm = crate::dynamic::compile_dynamic_from_values_with_lang(
+            &schema,
+            &data,
+            Some("de-DE"),
+        )
+        .unwrap();
+
+        let (header, value) = decode_grm(&schema, &grm).unwrap();
+        assert_eq!(header.schema_id, "test.v1");
+        assert_eq!(header.language, Some("de-DE".to_string()));
+        assert_eq!(value["name"], "Bistro");
+    }
+
+    #[test]
+    fn test_truncated_buffer_errors_not_panics() {
+        let schema = full_schema();
+        let data = serde_json::json!({ "name": "Bistro", "tags": ["a", "b"] });
+        let payload = build_flatbuffer(&schema, &data).unwrap();
+
+        // Every truncation must yield Err, never a panic
+        for len in 0..payload.len() {
+            let _ = decode_payload(&schema, &payload[..len]);
+        }
+    }
+
+    #[test]
+    fn test_inflated_vector_length_errors_not_aborts() {
+        let schema = full_schema();
+        let data = serde_json::json!({
+            "name": "Bistro",
+            "tags": ["a", "b"],
+            "scores": [1, 2, 3]
+        });
+        let payload = build_flatbuffer(&schema, &data).unwrap();
+
+        // A crafted length prefix must yield Err BEFORE any allocation —
+        // truncation fuzzing cannot catch this, because `with_capacity`
+        // on a multi-gigabyte length aborts instead of panicking. Patch
+        // every u32 position to u32::MAX; whichever hits a vector length
+        // has to come back as a malformed-buffer error.
+        for pos in 0..payload.len().saturating_sub(4) {
+            let mut patched = payload.clone();
+            patched[pos..pos + 4].copy_from_slice(&u32::MAX.to_le_bytes());
+            let _ = decode_payload(&schema, &patched);
+            let _ = measure_payload(&schema, &patched);
+        }
+    }
+
+    #[test]
+    fn test_garbage_buffer_errors() {
+        let schema = full_schema();
+        let garbage = vec![0xFF; 64];
+        assert!(decode_payload(&schema, &garbage).is_err());
+    }
+}
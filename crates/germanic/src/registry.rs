@@ -0,0 +1,99 @@
+//! # Embedded Schema Registry
+//!
+//! The shipped `.schema.json` definitions from `schemas/definitions/`
+//! compiled into the binary via [`include_dir`], so
+//!
+//! ```text
+//! germanic compile --schema de.gesundheit.krankenhaus.v1 --input klinik.json
+//! ```
+//!
+//! works without a repo checkout. Definitions are resolved by
+//! `schema_id` — the filename convention `<schema_id>.schema.json`
+//! makes the lookup a pure name match, no parsing required.
+//!
+//! The registry is read-only: project-local `.schema.json` files keep
+//! working through the dynamic path and always win, because the CLI
+//! only consults the registry when the argument is not an existing file.
+
+use include_dir::{Dir, include_dir};
+
+/// All shipped definitions, embedded at compile time.
+///
+/// Mirrors the repository layout (`de/`, `en/` subdirectories).
+static DEFINITIONS: Dir<'_> = include_dir!("$CARGO_MANIFEST_DIR/../../schemas/definitions");
+
+/// Looks up an embedded definition by its schema_id.
+///
+/// Returns the raw `.schema.json` source, ready for
+/// [`crate::dynamic::parse_schema_auto`].
+pub fn find_definition(schema_id: &str) -> Option<&'static str> {
+    let file_name = format!("{schema_id}.schema.json");
+    all_files(&DEFINITIONS)
+        .into_iter()
+        .find(|file| {
+            file.path()
+                .file_name()
+                .is_some_and(|name| name == file_name.as_str())
+        })
+        .and_then(|file| file.contents_utf8())
+}
+
+/// All embedded schema_ids, sorted for stable CLI output.
+pub fn definition_ids() -> Vec<&'static str> {
+    let mut ids: Vec<&'static str> = all_files(&DEFINITIONS)
+        .into_iter()
+        .filter_map(|file| file.path().file_name()?.to_str())
+        .filter_map(|name| name.strip_suffix(".schema.json"))
+        .collect();
+    ids.sort_unstable();
+    ids
+}
+
+/// Collects files from all subdirectories (include_dir has no recursive iterator).
+fn all_files<'a>(dir: &'a Dir<'a>) -> Vec<&'a include_dir::File<'a>> {
+    let mut files: Vec<_> = dir.files().collect();
+    for sub in dir.dirs() {
+        files.extend(all_files(sub));
+    }
+    files
+}
+
+// ============================================================================
+// TESTS
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_definition_by_schema_id() {
+        let definition = find_definition("de.gesundheit.krankenhaus.v1").unwrap();
+        let (schema, _warnings) = crate::dynamic::parse_schema_auto(definition).unwrap();
+        assert_eq!(schema.schema_id, "de.gesundheit.krankenhaus.v1");
+    }
+
+    #[test]
+    fn test_unknown_schema_id_is_none() {
+        assert!(find_definition("de.gibt.es.nicht.v1").is_none());
+    }
+
+    #[test]
+    fn test_definition_ids_are_sorted_and_nonempty() {
+        let ids = definition_ids();
+        assert!(ids.contains(&"de.gesundheit.praxis.v1"));
+        let mut sorted = ids.clone();
+        sorted.sort_unstable();
+        assert_eq!(ids, sorted);
+    }
+
+    #[test]
+    fn test_all_embedded_definitions_parse() {
+        for id in definition_ids() {
+            let definition = find_definition(id).unwrap();
+            let (schema, _warnings) = crate::dynamic::parse_schema_auto(definition)
+                .unwrap_or_else(|e| panic!("{id} does not parse: {e}"));
+            assert_eq!(schema.schema_id, id, "filename and schema_id diverge");
+        }
+    }
+}
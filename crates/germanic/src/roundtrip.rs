@@ -0,0 +1,203 @@
+//! # Round-Trip Fidelity Checker
+//!
+//! Proves (or disproves) that a schema preserves data: compile the JSON,
+//! decode it back, and structurally compare the result to the input.
+//!
+//! ```text
+//! input.json ──► compile ──► .grm ──► decode ──► output.json
+//!     │                                              │
+//!     └───────────── structural diff ────────────────┘
+//!                          │
+//!            dropped:  ["notizen"]          (not in schema)
+//!            coerced:  ["bewertung"]        (f64 → f32 precision)
+//!            restored: ["adresse.land"]     (schema default filled in)
+//! ```
+//!
+//! Schema authors run this before publishing so data loss is a conscious
+//! decision, never a surprise.
+
+use crate::dynamic::schema_def::SchemaDefinition;
+use crate::error::GermanicResult;
+use serde_json::Value;
+
+/// A field whose value changed between input and decoded output.
+#[derive(Debug, Clone)]
+pub struct FieldChange {
+    /// Dotted path of the field.
+    pub path: String,
+
+    /// Value in the input.
+    pub input: Value,
+
+    /// Value after compile + decode.
+    pub decoded: Value,
+}
+
+/// Structural diff between input JSON and its compile/decode round-trip.
+#[derive(Debug, Default)]
+pub struct RoundtripReport {
+    /// Paths present in the input but absent after decoding
+    /// (typically fields the schema doesn't know).
+    pub dropped: Vec<String>,
+
+    /// Paths whose value changed (typically float precision or
+    /// plugin encode/decode normalization).
+    pub coerced: Vec<FieldChange>,
+
+    /// Paths absent in the input but present after decoding
+    /// (schema defaults restored by the reader).
+    pub restored: Vec<String>,
+}
+
+impl RoundtripReport {
+    /// True if the round-trip reproduced the input exactly.
+    ///
+    /// Restored defaults do not count as loss — they add information
+    /// the schema author declared.
+    pub fn is_lossless(&self) -> bool {
+        self.dropped.is_empty() && self.coerced.is_empty()
+    }
+}
+
+/// Compiles `data` with `schema`, decodes the result, and reports every
+/// field that was dropped, coerced, or restored along the way.
+///
+/// Validation runs as part of compilation — invalid data errors out
+/// before any comparison.
+pub fn verify_roundtrip(
+    schema: &SchemaDefinition,
+    data: &Value,
+) -> GermanicResult<RoundtripReport> {
+    let grm_bytes = crate::dynamic::compile_dynamic_from_values(schema, data)?;
+    let (_header, decoded) = crate::reader::decode_grm(schema, &grm_bytes)?;
+
+    let mut report = RoundtripReport::default();
+    diff_values(data, &decoded, "", &mut report);
+    Ok(report)
+}
+
+/// Recursively diffs input against decoded output, collecting paths.
+fn diff_values(input: &Value, decoded: &Value, path: &str, report: &mut RoundtripReport) {
+    match (input, decoded) {
+        (Value::Object(in_map), Value::Object(out_map)) => {
+            for (key, in_val) in in_map {
+                let child_path = join_path(path, key);
+                match out_map.get(key) {
+                    Some(out_val) => diff_values(in_val, out_val, &child_path, report),
+                    // Null inputs are never written — not a schema problem
+                    None if in_val.is_null() => {}
+                    None => report.dropped.push(child_path),
+                }
+            }
+            for key in out_map.keys() {
+                if !in_map.contains_key(key) {
+                    report.restored.push(join_path(path, key));
+                }
+            }
+        }
+
+        (Value::Array(in_arr), Value::Array(out_arr)) => {
+            for (i, in_val) in in_arr.iter().enumerate() {
+                let child_path = join_path(path, &i.to_string());
+                match out_arr.get(i) {
+                    Some(out_val) => diff_values(in_val, out_val, &child_path, report),
+                    None => report.dropped.push(child_path),
+                }
+            }
+        }
+
+        (a, b) if a == b => {}
+
+        (a, b) => report.coerced.push(FieldChange {
+            path: path.to_string(),
+            input: a.clone(),
+            decoded: b.clone(),
+        }),
+    }
+}
+
+/// Joins a parent path and a key with a dot.
+fn join_path(prefix: &str, key: &str) -> String {
+    if prefix.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", prefix, key)
+    }
+}
+
+// ============================================================================
+// TESTS
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dynamic::schema_def::{FieldDefinition, FieldType};
+    use indexmap::IndexMap;
+
+    fn schema() -> SchemaDefinition {
+        let mut fields = IndexMap::new();
+        fields.insert(
+            "name".into(),
+            FieldDefinition {
+                required: true,
+                ..FieldDefinition::new(FieldType::String)
+            },
+        );
+        fields.insert("rating".into(), FieldDefinition::new(FieldType::Float));
+        fields.insert(
+            "land".into(),
+            FieldDefinition {
+                default: Some("49".into()),
+                ..FieldDefinition::new(FieldType::Int)
+            },
+        );
+        SchemaDefinition {
+            schema_id: "test.v1".into(),
+            version: 1,
+            description: None,
+            limits: None,
+            strict: false,
+            fields,
+        }
+    }
+
+    #[test]
+    fn test_lossless_roundtrip() {
+        let data = serde_json::json!({ "name": "Bistro", "rating": 4.5, "land": 43 });
+        let report = verify_roundtrip(&schema(), &data).unwrap();
+        assert!(report.is_lossless());
+        assert!(report.restored.is_empty());
+    }
+
+    #[test]
+    fn test_unknown_field_reported_as_dropped() {
+        let data = serde_json::json!({ "name": "Bistro", "notizen": "intern" });
+        let report = verify_roundtrip(&schema(), &data).unwrap();
+        assert_eq!(report.dropped, vec!["notizen"]);
+        assert!(!report.is_lossless());
+    }
+
+    #[test]
+    fn test_float_coercion_reported() {
+        // 0.1 + 0.2 style f64 values that have no exact f32 representation
+        let data = serde_json::json!({ "name": "Bistro", "rating": 4.123456789 });
+        let report = verify_roundtrip(&schema(), &data).unwrap();
+        assert_eq!(report.coerced.len(), 1);
+        assert_eq!(report.coerced[0].path, "rating");
+    }
+
+    #[test]
+    fn test_restored_default_reported() {
+        let data = serde_json::json!({ "name": "Bistro" });
+        let report = verify_roundtrip(&schema(), &data).unwrap();
+        assert!(report.is_lossless());
+        assert_eq!(report.restored, vec!["land"]);
+    }
+
+    #[test]
+    fn test_invalid_data_errors_before_diff() {
+        let data = serde_json::json!({ "rating": 1.0 });
+        assert!(verify_roundtrip(&schema(), &data).is_err());
+    }
+}
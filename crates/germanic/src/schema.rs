@@ -113,21 +113,27 @@ pub trait Validate {
 }
 
 // ============================================================================
-// SERIALIZATION (Placeholder for later)
+// SERIALIZATION
 // ============================================================================
 
 /// Trait for FlatBuffer serialization.
 ///
-/// **Not yet implemented** – coming in Phase 3 of macro development.
-///
-/// ## Planned Signature
+/// Automatically implemented by `#[derive(GermanicSchema)]` when the
+/// struct names its generated FlatBuffer type:
 ///
 /// ```rust,ignore
-/// pub trait GermanicSerialize {
-///     /// Serializes the schema into FlatBuffer bytes.
-///     fn serialize(&self, builder: &mut FlatBufferBuilder) -> WIPOffset<UnionWIPOffset>;
-/// }
+/// #[derive(GermanicSchema)]
+/// #[germanic(
+///     schema_id = "de.gesundheit.praxis.v1",
+///     flatbuffer = "crate::generated::praxis::de::gesundheit::Praxis"
+/// )]
+/// pub struct PraxisSchema { /* ... */ }
 /// ```
+///
+/// The macro generates the FlatBufferBuilder code (strings, vectors,
+/// nested tables, scalars) from the struct fields. Nested schema fields
+/// serialize through their own derived builder helper, so each struct
+/// only declares its own FlatBuffer type.
 pub trait GermanicSerialize {
     /// Serializes the schema into a byte vector.
     fn to_bytes(&self) -> Vec<u8>;
@@ -2,7 +2,13 @@
 //!
 //! Contains Rust representations of FlatBuffer schemas.
 
+pub mod krankenhaus;
 pub mod practice;
+pub mod stellenanzeige;
+pub mod verein;
 
 // Re-exports for convenient access
+pub use krankenhaus::KrankenhausSchema;
 pub use practice::{AdresseSchema, PraxisSchema};
+pub use stellenanzeige::StellenanzeigeSchema;
+pub use verein::VereinSchema;
@@ -0,0 +1,214 @@
+//! # Krankenhaus Schema
+//!
+//! Schema for hospitals — the second built-in the CLI knows by name.
+//!
+//! Like [`super::verein`] there is no hand-written FlatBuffer serializer:
+//! `germanic compile --schema krankenhaus` routes through the dynamic
+//! pipeline with the embedded copy of
+//! `schemas/definitions/de/de.gesundheit.krankenhaus.v1.schema.json`,
+//! exactly as the practice path does. The static struct gives Rust
+//! integrations typed field access and validation.
+
+use crate::GermanicSchema;
+use crate::schemas::practice::AdresseSchema;
+use serde::{Deserialize, Serialize};
+
+// ============================================================================
+// NOTAUFNAHME
+// ============================================================================
+
+/// Emergency department of a hospital.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, GermanicSchema)]
+#[germanic(schema_id = "de.gesundheit.notaufnahme.v1")]
+pub struct NotaufnahmeSchema {
+    /// Direct emergency phone number
+    #[germanic(required)]
+    pub telefon: String,
+
+    /// Open around the clock?
+    #[serde(default)]
+    pub rund_um_die_uhr: bool,
+
+    /// Helicopter landing pad available?
+    #[serde(default)]
+    pub hubschrauberlandeplatz: bool,
+}
+
+// ============================================================================
+// KRANKENHAUS
+// ============================================================================
+
+/// Main schema for a hospital.
+///
+/// ## Fields
+///
+/// | Field            | Type              | Required | Description              |
+/// |------------------|-------------------|----------|--------------------------|
+/// | name             | String            | ✅       | Hospital name            |
+/// | adresse          | AdresseSchema     | ✅       | Complete address         |
+/// | telefon          | String            | ✅       | Switchboard              |
+/// | notaufnahme      | NotaufnahmeSchema | ✅       | Emergency department     |
+/// | fachabteilungen  | `Vec<String>`     | ✅       | Medical departments      |
+/// | ...              | ...               | ...      | additional optional      |
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, GermanicSchema)]
+#[germanic(schema_id = "de.gesundheit.krankenhaus.v1")]
+pub struct KrankenhausSchema {
+    // ────────────────────────────────────────────────────────────────────────
+    // REQUIRED FIELDS
+    // ────────────────────────────────────────────────────────────────────────
+    /// Hospital name
+    #[germanic(required)]
+    pub name: String,
+
+    /// Complete address
+    pub adresse: AdresseSchema,
+
+    /// Switchboard phone number
+    #[germanic(required)]
+    pub telefon: String,
+
+    /// Emergency department
+    pub notaufnahme: NotaufnahmeSchema,
+
+    /// Medical departments ("Kardiologie", "Unfallchirurgie")
+    #[germanic(required)]
+    #[serde(default)]
+    pub fachabteilungen: Vec<String>,
+
+    // ────────────────────────────────────────────────────────────────────────
+    // OPTIONAL FIELDS
+    // ────────────────────────────────────────────────────────────────────────
+    /// Operating organization ("Städtisches Klinikum", church, private)
+    #[serde(default)]
+    pub traeger: Option<String>,
+
+    /// Number of beds
+    #[serde(default)]
+    pub bettenanzahl: Option<i64>,
+
+    /// Website URL
+    #[serde(default)]
+    pub website: Option<String>,
+
+    /// Separate emergency phone number
+    #[serde(default)]
+    pub notfall_telefon: Option<String>,
+
+    /// Visiting hours as free text
+    #[serde(default)]
+    pub besuchszeiten: Option<String>,
+
+    /// Number of parking spaces
+    #[serde(default)]
+    pub parkplaetze: Option<i64>,
+
+    /// Brief self-description
+    #[serde(default)]
+    pub kurzbeschreibung: Option<String>,
+
+    // ────────────────────────────────────────────────────────────────────────
+    // LISTS
+    // ────────────────────────────────────────────────────────────────────────
+    /// Floors with patient wards
+    #[serde(default)]
+    pub stockwerke: Vec<i64>,
+
+    // ────────────────────────────────────────────────────────────────────────
+    // BOOLEANS
+    // ────────────────────────────────────────────────────────────────────────
+    /// Barrier-free access?
+    #[serde(default)]
+    #[germanic(default = "false")]
+    pub barrierefreiheit: bool,
+}
+
+// ============================================================================
+// TESTS
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::{SchemaMetadata, Validate};
+
+    #[test]
+    fn test_krankenhaus_schema_id() {
+        let klinik = KrankenhausSchema::default();
+        assert_eq!(klinik.schema_id(), "de.gesundheit.krankenhaus.v1");
+    }
+
+    #[test]
+    fn test_krankenhaus_validation_missing() {
+        let klinik = KrankenhausSchema::default();
+        let result = klinik.validate();
+
+        assert!(result.is_err());
+        if let Err(crate::error::ValidationError::RequiredFieldsMissing(fields)) = result {
+            assert!(fields.contains(&"name".to_string()));
+            assert!(fields.contains(&"telefon".to_string()));
+            assert!(fields.contains(&"fachabteilungen".to_string()));
+            assert!(fields.contains(&"adresse.ort".to_string()));
+            assert!(fields.contains(&"notaufnahme.telefon".to_string()));
+        }
+    }
+
+    #[test]
+    fn test_krankenhaus_json_deserialization() {
+        let json = r#"{
+            "name": "Städtisches Klinikum",
+            "adresse": {
+                "strasse": "Klinikstraße",
+                "plz": "80331",
+                "ort": "München"
+            },
+            "telefon": "+49 89 1234-0",
+            "notaufnahme": {
+                "telefon": "+49 89 1234-112",
+                "rund_um_die_uhr": true
+            },
+            "fachabteilungen": ["Kardiologie", "Unfallchirurgie"],
+            "bettenanzahl": 450
+        }"#;
+
+        let klinik: KrankenhausSchema = serde_json::from_str(json).unwrap();
+        assert_eq!(klinik.name, "Städtisches Klinikum");
+        assert!(klinik.notaufnahme.rund_um_die_uhr);
+        assert!(!klinik.notaufnahme.hubschrauberlandeplatz);
+        assert_eq!(klinik.bettenanzahl, Some(450));
+        assert!(klinik.validate().is_ok());
+    }
+
+    #[test]
+    fn test_krankenhaus_matches_shipped_definition() {
+        // The static struct and the shipped .schema.json must not drift
+        let definition = include_str!(
+            "../../../../schemas/definitions/de/de.gesundheit.krankenhaus.v1.schema.json"
+        );
+        let (schema, warnings) =
+            crate::dynamic::parse_schema_auto(definition).expect("shipped definition invalid");
+        assert!(warnings.is_empty());
+        assert_eq!(schema.schema_id, "de.gesundheit.krankenhaus.v1");
+
+        let klinik = KrankenhausSchema {
+            name: "Städtisches Klinikum".to_string(),
+            adresse: AdresseSchema {
+                strasse: "Klinikstraße".to_string(),
+                hausnummer: None,
+                plz: "80331".to_string(),
+                ort: "München".to_string(),
+                land: "DE".to_string(),
+            },
+            telefon: "+49 89 1234-0".to_string(),
+            notaufnahme: NotaufnahmeSchema {
+                telefon: "+49 89 1234-112".to_string(),
+                rund_um_die_uhr: true,
+                hubschrauberlandeplatz: false,
+            },
+            fachabteilungen: vec!["Kardiologie".to_string()],
+            ..Default::default()
+        };
+        let data = serde_json::to_value(&klinik).unwrap();
+        crate::dynamic::validate::validate_against_schema(&schema, &data)
+            .expect("struct data must validate against the shipped definition");
+    }
+}
@@ -26,16 +26,8 @@
 //! ```
 
 use crate::GermanicSchema;
-use crate::schema::GermanicSerialize;
-use flatbuffers::FlatBufferBuilder;
 use serde::{Deserialize, Serialize};
 
-// Import of generated FlatBuffer types
-use crate::generated::praxis::de::gesundheit::{
-    Adresse as FbAdresse, AdresseArgs as FbAdresseArgs, Praxis as FbPraxis,
-    PraxisArgs as FbPraxisArgs,
-};
-
 // ============================================================================
 // ADRESSE
 // ============================================================================
@@ -52,7 +44,10 @@ use crate::generated::praxis::de::gesundheit::{
 /// | ort         | String         | ✅       | -       |
 /// | land        | String         | ❌       | "DE"    |
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, GermanicSchema)]
-#[germanic(schema_id = "de.gesundheit.adresse.v1")]
+#[germanic(
+    schema_id = "de.gesundheit.adresse.v1",
+    flatbuffer = "crate::generated::praxis::de::gesundheit::Adresse"
+)]
 pub struct AdresseSchema {
     /// Street name (without house number)
     #[germanic(required)]
@@ -62,8 +57,8 @@ pub struct AdresseSchema {
     #[serde(default)]
     pub hausnummer: Option<String>,
 
-    /// Postal code
-    #[germanic(required)]
+    /// Postal code (German PLZ: exactly five digits)
+    #[germanic(required, min_len = 5, max_len = 5, pattern = "[0-9]{5}")]
     pub plz: String,
 
     /// City name
@@ -80,39 +75,57 @@ fn default_land() -> String {
     "DE".to_string()
 }
 
-impl GermanicSerialize for AdresseSchema {
-    /// Serializes the address to FlatBuffer bytes.
-    ///
-    /// **Note:** AdresseSchema alone is not a valid root type.
-    /// This method is mainly used for tests.
-    /// Normally address is serialized as part of PraxisSchema.
-    fn to_bytes(&self) -> Vec<u8> {
-        let mut builder = FlatBufferBuilder::with_capacity(256);
-
-        // Create strings (leaves first)
-        let strasse = builder.create_string(&self.strasse);
-        let hausnummer = self.hausnummer.as_ref().map(|h| builder.create_string(h));
-        let plz = builder.create_string(&self.plz);
-        let ort = builder.create_string(&self.ort);
-        let land = builder.create_string(&self.land);
-
-        // Create address table
-        let adresse = FbAdresse::create(
-            &mut builder,
-            &FbAdresseArgs {
-                strasse: Some(strasse),
-                hausnummer,
-                plz: Some(plz),
-                ort: Some(ort),
-                land: Some(land),
-            },
-        );
+// ============================================================================
+// OEFFNUNGSZEITEN
+// ============================================================================
 
-        // Note: Address is not a root type in the FlatBuffer schema,
-        // so we use finish_minimal instead of finish
-        builder.finish_minimal(adresse);
-        builder.finished_data().to_vec()
-    }
+/// A single opening interval on one weekday.
+///
+/// A day with a lunch break gets two intervals:
+///
+/// ```json
+/// [
+///   { "tag": "Mo", "von": "09:00", "bis": "12:00" },
+///   { "tag": "Mo", "von": "14:00", "bis": "17:00" }
+/// ]
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, GermanicSchema)]
+#[germanic(
+    schema_id = "de.gesundheit.oeffnungsintervall.v1",
+    flatbuffer = "crate::generated::praxis::de::gesundheit::Oeffnungsintervall"
+)]
+pub struct OeffnungsintervallSchema {
+    /// Weekday abbreviation ("Mo", "Di", "Mi", "Do", "Fr", "Sa", "So")
+    #[germanic(required)]
+    pub tag: String,
+
+    /// Opening time in 24h format, e.g. "09:00"
+    #[germanic(required)]
+    pub von: String,
+
+    /// Closing time in 24h format, e.g. "17:00"
+    #[germanic(required)]
+    pub bis: String,
+}
+
+/// A closed period (vacation, training, renovation).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, GermanicSchema)]
+#[germanic(
+    schema_id = "de.gesundheit.schliesszeit.v1",
+    flatbuffer = "crate::generated::praxis::de::gesundheit::Schliesszeit"
+)]
+pub struct SchliesszeitSchema {
+    /// First closed day (ISO 8601 date, e.g. "2026-08-03")
+    #[germanic(required)]
+    pub von: String,
+
+    /// Last closed day (ISO 8601 date)
+    #[germanic(required)]
+    pub bis: String,
+
+    /// Reason shown to patients, e.g. "Urlaub" or "Fortbildung"
+    #[serde(default)]
+    pub grund: Option<String>,
 }
 
 // ============================================================================
@@ -132,7 +145,10 @@ impl GermanicSerialize for AdresseSchema {
 /// | telefon           | `Option<String>` | ❌       | Phone number                     |
 /// | ...               | ...            | ...      | additional optional fields       |
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, GermanicSchema)]
-#[germanic(schema_id = "de.gesundheit.praxis.v1")]
+#[germanic(
+    schema_id = "de.gesundheit.praxis.v1",
+    flatbuffer = "crate::generated::praxis::de::gesundheit::Praxis"
+)]
 pub struct PraxisSchema {
     // ────────────────────────────────────────────────────────────────────────
     // REQUIRED FIELDS
@@ -172,6 +188,9 @@ pub struct PraxisSchema {
     pub terminbuchung_url: Option<String>,
 
     /// Opening hours as free text
+    ///
+    /// Kept for backward compatibility — older praxis.json files only
+    /// carry this field. Prefer `oeffnungszeiten_struktur` for new data.
     #[serde(default)]
     pub oeffnungszeiten: Option<String>,
 
@@ -210,166 +229,59 @@ pub struct PraxisSchema {
     #[serde(default)]
     #[germanic(default = "false")]
     pub kassenpatienten: bool,
-}
 
-impl GermanicSerialize for PraxisSchema {
-    /// Serializes the practice schema to FlatBuffer bytes.
-    ///
-    /// ## Algorithm (Inside-Out)
-    ///
-    /// ```text
-    /// 1. Create strings             → Offsets
-    /// 2. Create string vectors      → Offsets
-    /// 3. Create address             → Offset (needs string offsets)
-    /// 4. Create practice            → Offset (needs all others)
-    /// 5. finish()                   → Bytes
-    /// ```
-    fn to_bytes(&self) -> Vec<u8> {
-        // Estimate capacity: ~100 bytes base + strings
-        let capacity = 256 + self.name.len() + self.bezeichnung.len();
-        let mut builder = FlatBufferBuilder::with_capacity(capacity);
-
-        // ════════════════════════════════════════════════════════════════════
-        // STEP 1: Create all strings (leaves first)
-        // ════════════════════════════════════════════════════════════════════
-
-        // Required strings
-        let name = builder.create_string(&self.name);
-        let bezeichnung = builder.create_string(&self.bezeichnung);
-
-        // Optional strings (only if present)
-        let praxisname = self.praxisname.as_ref().map(|s| builder.create_string(s));
-        let telefon = self.telefon.as_ref().map(|s| builder.create_string(s));
-        let email = self.email.as_ref().map(|s| builder.create_string(s));
-        let website = self.website.as_ref().map(|s| builder.create_string(s));
-        let terminbuchung_url = self
-            .terminbuchung_url
-            .as_ref()
-            .map(|s| builder.create_string(s));
-        let oeffnungszeiten = self
-            .oeffnungszeiten
-            .as_ref()
-            .map(|s| builder.create_string(s));
-        let kurzbeschreibung = self
-            .kurzbeschreibung
-            .as_ref()
-            .map(|s| builder.create_string(s));
-
-        // ════════════════════════════════════════════════════════════════════
-        // STEP 2: Create string vectors
-        // ════════════════════════════════════════════════════════════════════
-        //
-        // FlatBuffer expects: Vector<WIPOffset<&str>>
-        // We must first create all strings in the vector, then the vector
-
-        let schwerpunkte = if !self.schwerpunkte.is_empty() {
-            let offsets: Vec<_> = self
-                .schwerpunkte
-                .iter()
-                .map(|s| builder.create_string(s))
-                .collect();
-            Some(builder.create_vector(&offsets))
-        } else {
-            None
-        };
+    // ────────────────────────────────────────────────────────────────────────
+    // GEO COORDINATES
+    // ────────────────────────────────────────────────────────────────────────
+    // Appended after the original field set — dynamic vtable slots follow
+    // declaration order, so new fields must not shift existing ones.
+    /// Latitude in decimal degrees (WGS 84), range -90..90
+    #[germanic(min = -90.0, max = 90.0)]
+    #[serde(default)]
+    pub breitengrad: Option<f64>,
 
-        let therapieformen = if !self.therapieformen.is_empty() {
-            let offsets: Vec<_> = self
-                .therapieformen
-                .iter()
-                .map(|s| builder.create_string(s))
-                .collect();
-            Some(builder.create_vector(&offsets))
-        } else {
-            None
-        };
+    /// Longitude in decimal degrees (WGS 84), range -180..180
+    #[germanic(min = -180.0, max = 180.0)]
+    #[serde(default)]
+    pub laengengrad: Option<f64>,
 
-        let qualifikationen = if !self.qualifikationen.is_empty() {
-            let offsets: Vec<_> = self
-                .qualifikationen
-                .iter()
-                .map(|s| builder.create_string(s))
-                .collect();
-            Some(builder.create_vector(&offsets))
-        } else {
-            None
-        };
+    // ────────────────────────────────────────────────────────────────────────
+    // STRUCTURED OPENING HOURS
+    // ────────────────────────────────────────────────────────────────────────
+    /// Per-day opening intervals (machine-readable)
+    #[serde(default)]
+    pub oeffnungszeiten_struktur: Vec<OeffnungsintervallSchema>,
 
-        let sprachen = if !self.sprachen.is_empty() {
-            let offsets: Vec<_> = self
-                .sprachen
-                .iter()
-                .map(|s| builder.create_string(s))
-                .collect();
-            Some(builder.create_vector(&offsets))
-        } else {
-            None
-        };
+    /// Closed periods (vacation etc.)
+    #[serde(default)]
+    pub schliesszeiten: Vec<SchliesszeitSchema>,
 
-        // ════════════════════════════════════════════════════════════════════
-        // STEP 3: Create address (Nested Table)
-        // ════════════════════════════════════════════════════════════════════
-
-        let adresse = {
-            let strasse = builder.create_string(&self.adresse.strasse);
-            let hausnummer = self
-                .adresse
-                .hausnummer
-                .as_ref()
-                .map(|h| builder.create_string(h));
-            let plz = builder.create_string(&self.adresse.plz);
-            let ort = builder.create_string(&self.adresse.ort);
-            let land = builder.create_string(&self.adresse.land);
-
-            FbAdresse::create(
-                &mut builder,
-                &FbAdresseArgs {
-                    strasse: Some(strasse),
-                    hausnummer,
-                    plz: Some(plz),
-                    ort: Some(ort),
-                    land: Some(land),
-                },
-            )
-        };
+    // ────────────────────────────────────────────────────────────────────────
+    // INSURANCE & ACCESS
+    // ────────────────────────────────────────────────────────────────────────
+    /// Accepted public health insurers, e.g. ["AOK", "TK", "Barmer"]
+    #[serde(default)]
+    pub kassen: Vec<String>,
 
-        // ════════════════════════════════════════════════════════════════════
-        // STEP 4: Create practice (Root)
-        // ════════════════════════════════════════════════════════════════════
-
-        let praxis = FbPraxis::create(
-            &mut builder,
-            &FbPraxisArgs {
-                // Required
-                name: Some(name),
-                bezeichnung: Some(bezeichnung),
-                adresse: Some(adresse),
-                // Optional
-                praxisname,
-                telefon,
-                email,
-                website,
-                terminbuchung_url,
-                oeffnungszeiten,
-                kurzbeschreibung,
-                // Vektoren
-                schwerpunkte,
-                therapieformen,
-                qualifikationen,
-                sprachen,
-                // Booleans
-                privatpatienten: self.privatpatienten,
-                kassenpatienten: self.kassenpatienten,
-            },
-        );
+    /// Holds a Kassenzulassung (statutory insurance accreditation)?
+    #[serde(default)]
+    #[germanic(default = "false")]
+    pub kassenzulassung: bool,
+
+    /// Barrier-free access (wheelchair, elevator)?
+    #[serde(default)]
+    #[germanic(default = "false")]
+    pub barrierefreiheit: bool,
 
-        // ════════════════════════════════════════════════════════════════════
-        // STEP 5: Finalize
-        // ════════════════════════════════════════════════════════════════════
+    /// Number of parking spaces at the practice
+    #[germanic(min = 0.0)]
+    #[serde(default)]
+    pub parkplaetze: Option<i64>,
 
-        builder.finish(praxis, None);
-        builder.finished_data().to_vec()
-    }
+    /// Offers telemedicine / video consultations?
+    #[serde(default)]
+    #[germanic(default = "false")]
+    pub telemedizin: bool,
 }
 
 // ============================================================================
@@ -379,7 +291,8 @@ impl GermanicSerialize for PraxisSchema {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::schema::{SchemaMetadata, Validate};
+    use crate::generated::praxis::de::gesundheit::{Adresse as FbAdresse, Praxis as FbPraxis};
+    use crate::schema::{GermanicSerialize, SchemaMetadata, Validate};
 
     // ────────────────────────────────────────────────────────────────────────
     // EXISTING TESTS
@@ -603,6 +516,362 @@ mod tests {
         assert_eq!(fb.sprachen().unwrap().len(), 2);
     }
 
+    #[test]
+    fn test_practice_geo_roundtrip() {
+        let praxis = PraxisSchema {
+            name: "Test".to_string(),
+            bezeichnung: "Arzt".to_string(),
+            adresse: AdresseSchema {
+                strasse: "Teststr.".to_string(),
+                hausnummer: None,
+                plz: "80331".to_string(),
+                ort: "München".to_string(),
+                land: "DE".to_string(),
+            },
+            breitengrad: Some(48.137154),
+            laengengrad: Some(11.576124),
+            ..Default::default()
+        };
+
+        let bytes = praxis.to_bytes();
+        let fb = flatbuffers::root::<FbPraxis>(&bytes).unwrap();
+
+        assert_eq!(fb.breitengrad(), Some(48.137154));
+        assert_eq!(fb.laengengrad(), Some(11.576124));
+    }
+
+    #[test]
+    fn test_practice_geo_absent_by_default() {
+        let praxis = PraxisSchema {
+            name: "Test".to_string(),
+            bezeichnung: "Arzt".to_string(),
+            adresse: AdresseSchema {
+                strasse: "Teststr.".to_string(),
+                hausnummer: None,
+                plz: "80331".to_string(),
+                ort: "München".to_string(),
+                land: "DE".to_string(),
+            },
+            ..Default::default()
+        };
+
+        let bytes = praxis.to_bytes();
+        let fb = flatbuffers::root::<FbPraxis>(&bytes).unwrap();
+
+        // Optional scalars: no value written means no vtable slot
+        assert_eq!(fb.breitengrad(), None);
+        assert_eq!(fb.laengengrad(), None);
+    }
+
+    #[test]
+    fn test_opening_hours_roundtrip() {
+        let praxis = PraxisSchema {
+            name: "Test".to_string(),
+            bezeichnung: "Arzt".to_string(),
+            adresse: AdresseSchema {
+                strasse: "Teststr.".to_string(),
+                hausnummer: None,
+                plz: "80331".to_string(),
+                ort: "München".to_string(),
+                land: "DE".to_string(),
+            },
+            oeffnungszeiten_struktur: vec![
+                OeffnungsintervallSchema {
+                    tag: "Mo".to_string(),
+                    von: "09:00".to_string(),
+                    bis: "12:00".to_string(),
+                },
+                OeffnungsintervallSchema {
+                    tag: "Mo".to_string(),
+                    von: "14:00".to_string(),
+                    bis: "17:00".to_string(),
+                },
+            ],
+            schliesszeiten: vec![SchliesszeitSchema {
+                von: "2026-08-03".to_string(),
+                bis: "2026-08-21".to_string(),
+                grund: Some("Urlaub".to_string()),
+            }],
+            ..Default::default()
+        };
+
+        let bytes = praxis.to_bytes();
+        let fb = flatbuffers::root::<FbPraxis>(&bytes).unwrap();
+
+        let intervalle = fb.oeffnungszeiten_struktur().unwrap();
+        assert_eq!(intervalle.len(), 2);
+        assert_eq!(intervalle.get(0).tag(), "Mo");
+        assert_eq!(intervalle.get(0).von(), "09:00");
+        assert_eq!(intervalle.get(1).bis(), "17:00");
+
+        let schliesszeiten = fb.schliesszeiten().unwrap();
+        assert_eq!(schliesszeiten.len(), 1);
+        assert_eq!(schliesszeiten.get(0).von(), "2026-08-03");
+        assert_eq!(schliesszeiten.get(0).grund(), Some("Urlaub"));
+    }
+
+    #[test]
+    fn test_free_text_opening_hours_still_accepted() {
+        // Older praxis.json files only carry the free-text field
+        let json = r#"{
+            "name": "Dr. Anna Schmidt",
+            "bezeichnung": "Zahnärztin",
+            "adresse": { "strasse": "Hauptstraße", "plz": "80331", "ort": "München" },
+            "oeffnungszeiten": "Mo-Fr 9-17 Uhr"
+        }"#;
+
+        let praxis: PraxisSchema = serde_json::from_str(json).unwrap();
+        assert_eq!(praxis.oeffnungszeiten.as_deref(), Some("Mo-Fr 9-17 Uhr"));
+        assert!(praxis.oeffnungszeiten_struktur.is_empty());
+        assert!(praxis.schliesszeiten.is_empty());
+
+        let bytes = praxis.to_bytes();
+        let fb = flatbuffers::root::<FbPraxis>(&bytes).unwrap();
+        assert_eq!(fb.oeffnungszeiten(), Some("Mo-Fr 9-17 Uhr"));
+        assert!(fb.oeffnungszeiten_struktur().is_none());
+    }
+
+    #[test]
+    fn test_insurance_and_access_roundtrip() {
+        let praxis = PraxisSchema {
+            name: "Test".to_string(),
+            bezeichnung: "Arzt".to_string(),
+            adresse: AdresseSchema {
+                strasse: "Teststr.".to_string(),
+                hausnummer: None,
+                plz: "80331".to_string(),
+                ort: "München".to_string(),
+                land: "DE".to_string(),
+            },
+            kassen: vec!["AOK".to_string(), "TK".to_string()],
+            kassenzulassung: true,
+            barrierefreiheit: true,
+            parkplaetze: Some(6),
+            telemedizin: true,
+            ..Default::default()
+        };
+
+        let bytes = praxis.to_bytes();
+        let fb = flatbuffers::root::<FbPraxis>(&bytes).unwrap();
+
+        let kassen = fb.kassen().unwrap();
+        assert_eq!(kassen.len(), 2);
+        assert_eq!(kassen.get(0), "AOK");
+        assert!(fb.kassenzulassung());
+        assert!(fb.barrierefreiheit());
+        assert_eq!(fb.parkplaetze(), Some(6));
+        assert!(fb.telemedizin());
+    }
+
+    #[test]
+    fn test_insurance_and_access_defaults() {
+        let praxis = PraxisSchema {
+            name: "Test".to_string(),
+            bezeichnung: "Arzt".to_string(),
+            adresse: AdresseSchema {
+                strasse: "Teststr.".to_string(),
+                hausnummer: None,
+                plz: "80331".to_string(),
+                ort: "München".to_string(),
+                land: "DE".to_string(),
+            },
+            ..Default::default()
+        };
+
+        let bytes = praxis.to_bytes();
+        let fb = flatbuffers::root::<FbPraxis>(&bytes).unwrap();
+
+        assert!(fb.kassen().is_none());
+        assert!(!fb.kassenzulassung());
+        assert!(!fb.barrierefreiheit());
+        assert_eq!(fb.parkplaetze(), None);
+        assert!(!fb.telemedizin());
+    }
+
+    #[test]
+    fn test_praxis_definition_rejects_out_of_range_geo() {
+        // The dynamic pipeline (CLI path) enforces the coordinate ranges
+        let definition = include_str!("../../schemas/de.gesundheit.praxis.v1.schema.json");
+        let (schema, _warnings) =
+            crate::dynamic::parse_schema_auto(definition).expect("embedded definition invalid");
+
+        let data = serde_json::json!({
+            "name": "Dr. Müller",
+            "bezeichnung": "Arzt",
+            "adresse": { "strasse": "Hauptstraße", "plz": "80331", "ort": "München" },
+            "breitengrad": 123.0,
+            "laengengrad": 11.5
+        });
+
+        let err = crate::dynamic::validate::validate_against_schema(&schema, &data).unwrap_err();
+        assert!(err.to_string().contains("breitengrad"));
+    }
+
+    #[test]
+    fn test_schema_definition_matches_shipped() {
+        // One source of truth: the generated definition must agree with
+        // the shipped .schema.json on names, order, types and required flags
+        let generated = PraxisSchema::schema_definition();
+        let definition = include_str!("../../schemas/de.gesundheit.praxis.v1.schema.json");
+        let (shipped, _warnings) = crate::dynamic::parse_schema_auto(definition).unwrap();
+
+        assert_eq!(generated.schema_id, shipped.schema_id);
+        assert_eq!(
+            generated.fields.keys().collect::<Vec<_>>(),
+            shipped.fields.keys().collect::<Vec<_>>(),
+        );
+
+        for (name, field) in &generated.fields {
+            let shipped_field = &shipped.fields[name];
+            assert_eq!(field.field_type, shipped_field.field_type, "{name}");
+            assert_eq!(field.required, shipped_field.required, "{name}");
+            assert_eq!(field.default, shipped_field.default, "{name}");
+            assert_eq!(field.min, shipped_field.min, "{name}");
+            assert_eq!(field.max, shipped_field.max, "{name}");
+        }
+    }
+
+    #[test]
+    fn test_constraint_validation_geo_range() {
+        let praxis = PraxisSchema {
+            name: "Dr. Anna Schmidt".to_string(),
+            bezeichnung: "Zahnärztin".to_string(),
+            adresse: AdresseSchema {
+                strasse: "Leopoldstraße 12".to_string(),
+                plz: "80331".to_string(),
+                ort: "München".to_string(),
+                land: "DE".to_string(),
+                ..Default::default()
+            },
+            breitengrad: Some(123.0),
+            ..Default::default()
+        };
+
+        let error = praxis.validate().unwrap_err();
+        match error {
+            crate::error::ValidationError::ConstraintViolation { field, message } => {
+                assert_eq!(field, "breitengrad");
+                assert_eq!(message, "value 123 exceeds maximum 90");
+            }
+            other => panic!("expected constraint violation, got: {other}"),
+        }
+
+        // In range → no violation
+        let praxis = PraxisSchema {
+            breitengrad: Some(48.137154),
+            ..praxis
+        };
+        assert!(praxis.validate().is_ok());
+    }
+
+    #[test]
+    fn test_constraint_validation_plz() {
+        // "12" is non-empty, so the required check alone would let it pass
+        let adresse = AdresseSchema {
+            strasse: "Hauptstraße".to_string(),
+            plz: "12".to_string(),
+            ort: "München".to_string(),
+            land: "DE".to_string(),
+            ..Default::default()
+        };
+
+        let error = adresse.validate().unwrap_err();
+        match error {
+            crate::error::ValidationError::ConstraintViolation { field, message } => {
+                assert_eq!(field, "plz");
+                assert_eq!(message, "length 2 is below minimum length 5");
+            }
+            other => panic!("expected constraint violation, got: {other}"),
+        }
+
+        // Nested violations surface with the parent path
+        let praxis = PraxisSchema {
+            name: "Dr. Anna Schmidt".to_string(),
+            bezeichnung: "Zahnärztin".to_string(),
+            adresse,
+            ..Default::default()
+        };
+        let error = praxis.validate().unwrap_err();
+        match error {
+            crate::error::ValidationError::ConstraintViolation { field, .. } => {
+                assert_eq!(field, "adresse.plz");
+            }
+            other => panic!("expected constraint violation, got: {other}"),
+        }
+    }
+
+    #[test]
+    fn test_schema_definition_nested_fields() {
+        let definition = PraxisSchema::schema_definition();
+
+        let adresse = &definition.fields["adresse"];
+        assert!(adresse.required);
+        let nested = adresse.fields.as_ref().unwrap();
+        assert!(nested["strasse"].required);
+        assert_eq!(
+            nested["land"].default,
+            Some(serde_json::Value::String("DE".to_string()))
+        );
+
+        let intervalle = &definition.fields["oeffnungszeiten_struktur"];
+        assert_eq!(
+            intervalle.field_type,
+            crate::dynamic::schema_def::FieldType::TableArray
+        );
+        assert!(intervalle.fields.as_ref().unwrap()["tag"].required);
+    }
+
+    #[test]
+    fn test_json_schema_export() {
+        let schema = PraxisSchema::json_schema();
+
+        assert_eq!(
+            schema["$schema"],
+            "http://json-schema.org/draft-07/schema#"
+        );
+        assert_eq!(schema["$id"], "de.gesundheit.praxis.v1");
+        assert_eq!(schema["type"], "object");
+
+        // Required fields carry over from the derive attributes
+        let required = schema["required"].as_array().unwrap();
+        assert!(required.contains(&serde_json::json!("name")));
+        assert!(required.contains(&serde_json::json!("adresse")));
+
+        // Property types follow the JSON Schema vocabulary
+        let properties = schema["properties"].as_object().unwrap();
+        assert_eq!(properties["telefon"]["type"], "string");
+        assert_eq!(properties["breitengrad"]["type"], "number");
+        assert_eq!(properties["telemedizin"]["type"], "boolean");
+        assert_eq!(properties["kassen"]["items"]["type"], "string");
+
+        // Nested structs export as objects with their own required list
+        assert_eq!(properties["adresse"]["type"], "object");
+        assert!(
+            properties["adresse"]["required"]
+                .as_array()
+                .unwrap()
+                .contains(&serde_json::json!("strasse"))
+        );
+    }
+
+    #[test]
+    fn test_doc_comments_become_descriptions() {
+        let definition = PraxisSchema::schema_definition();
+
+        // `///` docs on fields carry over into the SchemaDefinition
+        assert_eq!(
+            definition.fields["breitengrad"].description.as_deref(),
+            Some("Latitude in decimal degrees (WGS 84), range -90..90")
+        );
+
+        // ... and from there into the JSON Schema export
+        let schema = PraxisSchema::json_schema();
+        assert_eq!(
+            schema["properties"]["breitengrad"]["description"],
+            "Latitude in decimal degrees (WGS 84), range -90..90"
+        );
+    }
+
     #[test]
     fn test_address_serialization() {
         let adresse = AdresseSchema {
@@ -0,0 +1,246 @@
+//! # Stellenanzeige Schema
+//!
+//! Schema for job postings (the recruiting use case).
+//!
+//! Like [`super::verein`] this is a static companion to a shipped
+//! definition — data compiles through the dynamic pipeline with
+//! `schemas/definitions/de/de.arbeit.stellenanzeige.v1.schema.json`,
+//! while the struct gives Rust integrations typed field access and
+//! validation.
+
+use crate::GermanicSchema;
+use serde::{Deserialize, Serialize};
+
+// ============================================================================
+// STANDORT
+// ============================================================================
+
+/// Work location of a job posting.
+///
+/// All fields are optional — remote-only postings have no street
+/// address, which is why this is not [`super::practice::AdresseSchema`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, GermanicSchema)]
+#[germanic(schema_id = "de.arbeit.standort.v1")]
+pub struct StandortSchema {
+    /// Street name (without house number)
+    #[serde(default)]
+    pub strasse: Option<String>,
+
+    /// House number
+    #[serde(default)]
+    pub hausnummer: Option<String>,
+
+    /// Postal code
+    #[serde(default)]
+    pub plz: Option<String>,
+
+    /// City name
+    #[serde(default)]
+    pub ort: Option<String>,
+
+    /// Country code (ISO 3166-1 alpha-2)
+    #[serde(default)]
+    pub land: Option<String>,
+}
+
+// ============================================================================
+// GEHALT
+// ============================================================================
+
+/// Salary range of a job posting.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, GermanicSchema)]
+#[germanic(schema_id = "de.arbeit.gehalt.v1")]
+pub struct GehaltSchema {
+    /// Lower bound in euros
+    #[serde(default)]
+    pub min_eur: Option<i64>,
+
+    /// Upper bound in euros
+    #[serde(default)]
+    pub max_eur: Option<i64>,
+
+    /// Currency code ("EUR")
+    #[serde(default)]
+    pub waehrung: Option<String>,
+
+    /// Payment interval ("Jahr", "Monat")
+    #[serde(default)]
+    pub intervall: Option<String>,
+}
+
+// ============================================================================
+// STELLENANZEIGE
+// ============================================================================
+
+/// Main schema for a job posting.
+///
+/// ## Fields
+///
+/// | Field          | Type             | Required | Description                   |
+/// |----------------|------------------|----------|-------------------------------|
+/// | titel          | String           | ✅       | Job title                     |
+/// | unternehmen    | String           | ✅       | Employer name                 |
+/// | standort       | StandortSchema   | ✅       | Work location                 |
+/// | vertragsart    | String           | ✅       | "Vollzeit", "Teilzeit", …     |
+/// | anforderungen  | `Vec<String>`    | ✅       | Hard requirements             |
+/// | kontakt_email  | String           | ✅       | Application contact           |
+/// | ...            | ...              | ...      | additional optional fields    |
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, GermanicSchema)]
+#[germanic(schema_id = "de.arbeit.stellenanzeige.v1")]
+pub struct StellenanzeigeSchema {
+    // ────────────────────────────────────────────────────────────────────────
+    // REQUIRED FIELDS
+    // ────────────────────────────────────────────────────────────────────────
+    /// Job title ("Bäckereifachverkäufer:in")
+    #[germanic(required)]
+    pub titel: String,
+
+    /// Employer name
+    #[germanic(required)]
+    pub unternehmen: String,
+
+    /// Work location
+    pub standort: StandortSchema,
+
+    /// Employment type ("Vollzeit", "Teilzeit", "Minijob", "Ausbildung")
+    #[germanic(required)]
+    pub vertragsart: String,
+
+    /// Hard requirements
+    #[germanic(required)]
+    #[serde(default)]
+    pub anforderungen: Vec<String>,
+
+    /// Application contact email
+    #[germanic(required)]
+    pub kontakt_email: String,
+
+    // ────────────────────────────────────────────────────────────────────────
+    // OPTIONAL FIELDS
+    // ────────────────────────────────────────────────────────────────────────
+    /// Share of remote work as free text ("2 Tage/Woche")
+    #[serde(default)]
+    pub remote_anteil: Option<String>,
+
+    /// Salary range
+    #[serde(default)]
+    pub gehalt: Option<GehaltSchema>,
+
+    /// Application URL
+    #[serde(default)]
+    pub bewerbung_url: Option<String>,
+
+    /// Publication date (ISO 8601)
+    #[serde(default)]
+    pub veroeffentlicht_am: Option<String>,
+
+    /// Last day applications are accepted (ISO 8601)
+    #[serde(default)]
+    pub gueltig_bis: Option<String>,
+
+    /// Brief description of the position
+    #[serde(default)]
+    pub kurzbeschreibung: Option<String>,
+
+    // ────────────────────────────────────────────────────────────────────────
+    // LISTS
+    // ────────────────────────────────────────────────────────────────────────
+    /// Nice-to-have qualifications
+    #[serde(default)]
+    pub nice_to_have: Vec<String>,
+
+    /// Day-to-day tasks
+    #[serde(default)]
+    pub aufgaben: Vec<String>,
+
+    /// Benefits ("Jobrad", "13. Gehalt")
+    #[serde(default)]
+    pub benefits: Vec<String>,
+
+    // ────────────────────────────────────────────────────────────────────────
+    // BOOLEANS
+    // ────────────────────────────────────────────────────────────────────────
+    /// Remote work possible?
+    #[serde(default)]
+    #[germanic(default = "false")]
+    pub remote_moeglich: bool,
+
+    /// Fixed-term contract?
+    #[serde(default)]
+    #[germanic(default = "false")]
+    pub befristet: bool,
+}
+
+// ============================================================================
+// TESTS
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::{SchemaMetadata, Validate};
+
+    #[test]
+    fn test_stellenanzeige_schema_id() {
+        let stelle = StellenanzeigeSchema::default();
+        assert_eq!(stelle.schema_id(), "de.arbeit.stellenanzeige.v1");
+    }
+
+    #[test]
+    fn test_stellenanzeige_validation_missing() {
+        let stelle = StellenanzeigeSchema::default();
+        let result = stelle.validate();
+
+        assert!(result.is_err());
+        if let Err(crate::error::ValidationError::RequiredFieldsMissing(fields)) = result {
+            assert!(fields.contains(&"titel".to_string()));
+            assert!(fields.contains(&"vertragsart".to_string()));
+            assert!(fields.contains(&"anforderungen".to_string()));
+            assert!(fields.contains(&"kontakt_email".to_string()));
+        }
+    }
+
+    #[test]
+    fn test_stellenanzeige_json_deserialization() {
+        let json = r#"{
+            "titel": "Bäckereifachverkäufer:in",
+            "unternehmen": "Bäckerei Sonnenschein",
+            "standort": { "plz": "80331", "ort": "München" },
+            "vertragsart": "Vollzeit",
+            "anforderungen": ["Freundliches Auftreten"],
+            "kontakt_email": "jobs@baeckerei-sonnenschein.de",
+            "gehalt": { "min_eur": 28000, "max_eur": 32000, "intervall": "Jahr" },
+            "gueltig_bis": "2026-12-31"
+        }"#;
+
+        let stelle: StellenanzeigeSchema = serde_json::from_str(json).unwrap();
+        assert_eq!(stelle.titel, "Bäckereifachverkäufer:in");
+        assert_eq!(stelle.gehalt.as_ref().unwrap().min_eur, Some(28000));
+        assert!(!stelle.remote_moeglich);
+        assert!(stelle.validate().is_ok());
+    }
+
+    #[test]
+    fn test_stellenanzeige_matches_shipped_definition() {
+        // The static struct and the shipped .schema.json must not drift
+        let definition = include_str!(
+            "../../../../schemas/definitions/de/de.arbeit.stellenanzeige.v1.schema.json"
+        );
+        let (schema, warnings) =
+            crate::dynamic::parse_schema_auto(definition).expect("shipped definition invalid");
+        assert!(warnings.is_empty());
+        assert_eq!(schema.schema_id, "de.arbeit.stellenanzeige.v1");
+
+        let stelle = StellenanzeigeSchema {
+            titel: "Bäckereifachverkäufer:in".to_string(),
+            unternehmen: "Bäckerei Sonnenschein".to_string(),
+            vertragsart: "Vollzeit".to_string(),
+            anforderungen: vec!["Freundliches Auftreten".to_string()],
+            kontakt_email: "jobs@baeckerei-sonnenschein.de".to_string(),
+            ..Default::default()
+        };
+        let data = serde_json::to_value(&stelle).unwrap();
+        crate::dynamic::validate::validate_against_schema(&schema, &data)
+            .expect("struct data must validate against the shipped definition");
+    }
+}
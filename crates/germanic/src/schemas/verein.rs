@@ -0,0 +1,212 @@
+//! # Verein Schema
+//!
+//! Schema for clubs, associations and non-profits (Vereine).
+//!
+//! Unlike [`super::practice`] there is no hand-written FlatBuffer
+//! serializer here — Verein data compiles through the dynamic pipeline
+//! with the shipped definition:
+//!
+//! ```text
+//!   verein.json
+//!       │
+//!       ▼
+//!   schemas/definitions/de/de.vereine.verein.v1.schema.json
+//!       │
+//!       ▼
+//!   compile_dynamic() → .grm
+//! ```
+//!
+//! The static struct exists for typed validation in Rust integrations
+//! (plugins, importers) that want compile-time field names.
+
+use crate::GermanicSchema;
+use crate::schemas::practice::AdresseSchema;
+use serde::{Deserialize, Serialize};
+
+// ============================================================================
+// VEREIN
+// ============================================================================
+
+/// Main schema for a club or association.
+///
+/// ## Fields
+///
+/// | Field                  | Type             | Required | Description                      |
+/// |------------------------|------------------|----------|----------------------------------|
+/// | name                   | String           | ✅       | Registered club name             |
+/// | zweck                  | String           | ✅       | Purpose per the statutes         |
+/// | adresse                | AdresseSchema    | ✅       | Club house / office address      |
+/// | gemeinnuetzig          | bool             | ❌       | Recognized as non-profit         |
+/// | ...                    | ...              | ...      | additional optional fields       |
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, GermanicSchema)]
+#[germanic(schema_id = "de.vereine.verein.v1")]
+pub struct VereinSchema {
+    // ────────────────────────────────────────────────────────────────────────
+    // REQUIRED FIELDS
+    // ────────────────────────────────────────────────────────────────────────
+    /// Registered club name ("Musikverein Harmonie e.V.")
+    #[germanic(required)]
+    pub name: String,
+
+    /// Purpose of the association per the statutes
+    #[germanic(required)]
+    pub zweck: String,
+
+    /// Club house or office address
+    pub adresse: AdresseSchema,
+
+    // ────────────────────────────────────────────────────────────────────────
+    // OPTIONAL FIELDS
+    // ────────────────────────────────────────────────────────────────────────
+    /// Contact person (chairperson, secretary)
+    #[serde(default)]
+    pub ansprechpartner: Option<String>,
+
+    /// Phone number
+    #[serde(default)]
+    pub telefon: Option<String>,
+
+    /// Email address
+    #[serde(default)]
+    pub email: Option<String>,
+
+    /// Website URL
+    #[serde(default)]
+    pub website: Option<String>,
+
+    /// Regular meeting times as free text ("jeden 1. Dienstag, 19 Uhr")
+    #[serde(default)]
+    pub treffen: Option<String>,
+
+    /// Annual membership fee in euros
+    #[serde(default)]
+    pub mitgliedsbeitrag_jahr: Option<f64>,
+
+    /// One-time admission fee in euros
+    #[serde(default)]
+    pub aufnahmegebuehr: Option<f64>,
+
+    /// Current number of members
+    #[serde(default)]
+    pub mitglieder: Option<i64>,
+
+    /// Founding year
+    #[serde(default)]
+    pub gruendungsjahr: Option<i64>,
+
+    /// Register entry ("VR 12345, Amtsgericht München")
+    #[serde(default)]
+    pub vereinsregister: Option<String>,
+
+    /// Brief self-description
+    #[serde(default)]
+    pub kurzbeschreibung: Option<String>,
+
+    // ────────────────────────────────────────────────────────────────────────
+    // LISTS
+    // ────────────────────────────────────────────────────────────────────────
+    /// Activities and offerings ("Jugendkapelle", "Blasorchester")
+    #[serde(default)]
+    pub angebote: Vec<String>,
+
+    // ────────────────────────────────────────────────────────────────────────
+    // BOOLEANS
+    // ────────────────────────────────────────────────────────────────────────
+    /// Recognized as non-profit (gemeinnützig, §52 AO)?
+    #[serde(default)]
+    #[germanic(default = "false")]
+    pub gemeinnuetzig: bool,
+
+    /// Open for new members?
+    #[serde(default)]
+    #[germanic(default = "true")]
+    pub aufnahme_offen: bool,
+}
+
+// ============================================================================
+// TESTS
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::{SchemaMetadata, Validate};
+
+    #[test]
+    fn test_verein_schema_id() {
+        let verein = VereinSchema::default();
+        assert_eq!(verein.schema_id(), "de.vereine.verein.v1");
+    }
+
+    #[test]
+    fn test_verein_defaults() {
+        let verein = VereinSchema::default();
+        assert!(!verein.gemeinnuetzig);
+        assert!(verein.aufnahme_offen);
+    }
+
+    #[test]
+    fn test_verein_validation_missing() {
+        let verein = VereinSchema::default();
+        let result = verein.validate();
+
+        assert!(result.is_err());
+        if let Err(crate::error::ValidationError::RequiredFieldsMissing(fields)) = result {
+            assert!(fields.contains(&"name".to_string()));
+            assert!(fields.contains(&"zweck".to_string()));
+            assert!(fields.contains(&"adresse.ort".to_string()));
+        }
+    }
+
+    #[test]
+    fn test_verein_json_deserialization() {
+        let json = r#"{
+            "name": "Musikverein Harmonie e.V.",
+            "zweck": "Förderung der Blasmusik",
+            "adresse": {
+                "strasse": "Hauptstraße",
+                "plz": "80331",
+                "ort": "München"
+            },
+            "treffen": "jeden 1. Dienstag, 19 Uhr",
+            "mitgliedsbeitrag_jahr": 48.0,
+            "gemeinnuetzig": true,
+            "angebote": ["Jugendkapelle", "Blasorchester"]
+        }"#;
+
+        let verein: VereinSchema = serde_json::from_str(json).unwrap();
+        assert_eq!(verein.name, "Musikverein Harmonie e.V.");
+        assert_eq!(verein.adresse.land, "DE"); // Default
+        assert!(verein.gemeinnuetzig);
+        assert_eq!(verein.angebote.len(), 2);
+        assert!(verein.validate().is_ok());
+    }
+
+    #[test]
+    fn test_verein_matches_shipped_definition() {
+        // The static struct and the shipped .schema.json must not drift
+        let definition = include_str!(
+            "../../../../schemas/definitions/de/de.vereine.verein.v1.schema.json"
+        );
+        let (schema, warnings) =
+            crate::dynamic::parse_schema_auto(definition).expect("shipped definition invalid");
+        assert!(warnings.is_empty());
+        assert_eq!(schema.schema_id, "de.vereine.verein.v1");
+
+        let verein = VereinSchema {
+            name: "Musikverein Harmonie e.V.".to_string(),
+            zweck: "Förderung der Blasmusik".to_string(),
+            adresse: AdresseSchema {
+                strasse: "Hauptstraße".to_string(),
+                hausnummer: None,
+                plz: "80331".to_string(),
+                ort: "München".to_string(),
+                land: "DE".to_string(),
+            },
+            ..Default::default()
+        };
+        let data = serde_json::to_value(&verein).unwrap();
+        crate::dynamic::validate::validate_against_schema(&schema, &data)
+            .expect("struct data must validate against the shipped definition");
+    }
+}
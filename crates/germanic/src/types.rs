@@ -12,10 +12,11 @@
 //! │   Offset │ Size  │ Content                                                  │
 //! │   ───────┼───────┼────────────────────────────────────────                  │
 //! │   0x00   │ 3     │ Magic: "GRM" (0x47 0x52 0x4D)                            │
-//! │   0x03   │ 1     │ Version (current: 0x01)                                  │
+//! │   0x03   │ 1     │ Version (0x01 plain, 0x02 with language tag)             │
 //! │   0x04   │ 2     │ Schema-ID length (little-endian u16)                     │
 //! │   0x06   │ n     │ Schema-ID (UTF-8, e.g. "de.gesundheit.praxis.v1")        │
-//! │   0x06+n │ 64    │ Ed25519 signature (optional, 0x00 if unsigned)           │
+//! │   ...    │ 1+m   │ Version 2 only: tag length (u8) + BCP-47 tag (UTF-8)     │
+//! │   ...    │ 64    │ Ed25519 signature (optional, 0x00 if unsigned)           │
 //! │   ...    │ ...   │ FlatBuffer Payload                                       │
 //! │                                                                             │
 //! │   EXAMPLE (praxis.grm):                                                     │
@@ -44,6 +45,16 @@ pub const GRM_MAGIC: [u8; 4] = [0x47, 0x52, 0x4D, 0x01]; // "GRM" + Version 1
 /// Current .grm format version.
 pub const GRM_VERSION: u8 = 0x01;
 
+/// Format version for headers that carry a BCP-47 language tag.
+///
+/// Version 2 inserts `[tag length u8][tag bytes]` between the schema ID
+/// and the signature. Files without a language tag stay at version 1,
+/// so existing readers keep working unchanged.
+pub const GRM_VERSION_LANG: u8 = 0x02;
+
+/// Maximum length of a BCP-47 language tag in bytes (length is stored as u8).
+pub const MAX_LANGUAGE_TAG_LENGTH: usize = u8::MAX as usize;
+
 /// Size of the Ed25519 signature in bytes.
 pub const SIGNATURE_SIZE: usize = 64;
 
@@ -72,6 +83,13 @@ pub struct GrmHeader {
     /// If present: 64 bytes
     /// If not: None (written as 64 null bytes)
     pub signature: Option<[u8; SIGNATURE_SIZE]>,
+
+    /// Optional BCP-47 language tag (e.g. "de-DE", "en").
+    ///
+    /// If present, the header is written as format version 2.
+    /// Lets agents pick the right variant when a site publishes
+    /// the same data in multiple languages.
+    pub language: Option<String>,
 }
 
 impl GrmHeader {
@@ -80,6 +98,7 @@ impl GrmHeader {
         Self {
             schema_id: schema_id.into(),
             signature: None,
+            language: None,
         }
     }
 
@@ -88,16 +107,31 @@ impl GrmHeader {
         Self {
             schema_id: schema_id.into(),
             signature: Some(signature),
+            language: None,
         }
     }
 
+    /// Sets the BCP-47 language tag (builder-style).
+    ///
+    /// ```rust,ignore
+    /// let header = GrmHeader::new("de.gesundheit.praxis.v1").with_language("de-DE");
+    /// ```
+    pub fn with_language(mut self, language: impl Into<String>) -> Self {
+        self.language = Some(language.into());
+        self
+    }
+
     /// Serializes the header to bytes.
     ///
     /// ## Format
     ///
     /// ```text
-    /// [Magic 4B][Schema-ID length 2B][Schema-ID nB][Signature 64B]
+    /// Version 1: [Magic 4B][Schema-ID length 2B][Schema-ID nB][Signature 64B]
+    /// Version 2: [Magic 4B][Schema-ID length 2B][Schema-ID nB][Tag length 1B][Tag mB][Signature 64B]
     /// ```
+    ///
+    /// Headers without a language tag are always written as version 1,
+    /// so they stay byte-identical to files produced before version 2 existed.
     pub fn to_bytes(&self) -> Result<Vec<u8>, HeaderParseError> {
         let schema_bytes = self.schema_id.as_bytes();
         if schema_bytes.len() > u16::MAX as usize {
@@ -108,12 +142,31 @@ impl GrmHeader {
         }
         let schema_len = schema_bytes.len() as u16;
 
-        // Capacity: 4 (Magic) + 2 (Length) + n (Schema) + 64 (Signature)
-        let capacity = 4 + 2 + schema_bytes.len() + SIGNATURE_SIZE;
+        // Validate language tag (if set)
+        if let Some(lang) = &self.language {
+            if lang.len() > MAX_LANGUAGE_TAG_LENGTH {
+                return Err(HeaderParseError::LanguageTagTooLong {
+                    len: lang.len(),
+                    max: MAX_LANGUAGE_TAG_LENGTH,
+                });
+            }
+            if lang.is_empty() || !lang.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'-') {
+                return Err(HeaderParseError::InvalidLanguageTag { tag: lang.clone() });
+            }
+        }
+
+        // Capacity: 4 (Magic) + 2 (Length) + n (Schema) + [1 + m (Language)] + 64 (Signature)
+        let lang_len = self.language.as_ref().map(|l| 1 + l.len()).unwrap_or(0);
+        let capacity = 4 + 2 + schema_bytes.len() + lang_len + SIGNATURE_SIZE;
         let mut bytes = Vec::with_capacity(capacity);
 
-        // 1. Magic bytes
-        bytes.extend_from_slice(&GRM_MAGIC);
+        // 1. Magic bytes (version byte depends on presence of language tag)
+        bytes.extend_from_slice(&GRM_MAGIC[0..3]);
+        bytes.push(if self.language.is_some() {
+            GRM_VERSION_LANG
+        } else {
+            GRM_VERSION
+        });
 
         // 2. Schema-ID length (little-endian u16)
         bytes.extend_from_slice(&schema_len.to_le_bytes());
@@ -121,7 +174,13 @@ impl GrmHeader {
         // 3. Schema-ID
         bytes.extend_from_slice(schema_bytes);
 
-        // 4. Signature (64 bytes, or zeros)
+        // 4. Language tag (version 2 only)
+        if let Some(lang) = &self.language {
+            bytes.push(lang.len() as u8);
+            bytes.extend_from_slice(lang.as_bytes());
+        }
+
+        // 5. Signature (64 bytes, or zeros)
         match &self.signature {
             Some(sig) => bytes.extend_from_slice(sig),
             None => bytes.extend_from_slice(&[0u8; SIGNATURE_SIZE]),
@@ -148,34 +207,63 @@ impl GrmHeader {
             });
         }
 
-        // 1. Check magic bytes
-        if data[0..4] != GRM_MAGIC {
+        // 1. Check magic bytes ("GRM" + known version)
+        if data[0..3] != GRM_MAGIC[0..3] {
             return Err(HeaderParseError::InvalidMagicBytes {
                 received: [data[0], data[1], data[2], data[3]],
             });
         }
+        let version = data[3];
+        if version != GRM_VERSION && version != GRM_VERSION_LANG {
+            return Err(HeaderParseError::UnsupportedVersion { version });
+        }
 
         // 2. Read schema-ID length
         let schema_len = u16::from_le_bytes([data[4], data[5]]) as usize;
 
         // 3. Check if enough data for schema-ID
-        let total_header_len = 4 + 2 + schema_len + SIGNATURE_SIZE;
-        if data.len() < total_header_len {
+        let schema_start = 6;
+        let schema_end = schema_start + schema_len;
+        if data.len() < schema_end + SIGNATURE_SIZE {
             return Err(HeaderParseError::InsufficientData {
-                expected: total_header_len,
+                expected: schema_end + SIGNATURE_SIZE,
                 received: data.len(),
             });
         }
 
         // 4. Parse schema-ID
-        let schema_start = 6;
-        let schema_end = schema_start + schema_len;
         let schema_id = std::str::from_utf8(&data[schema_start..schema_end])
             .map_err(|_| HeaderParseError::InvalidSchemaId)?
             .to_string();
 
-        // 5. Read signature
-        let sig_start = schema_end;
+        // 5. Language tag (version 2 only)
+        let (language, sig_start) = if version == GRM_VERSION_LANG {
+            if data.len() < schema_end + 1 + SIGNATURE_SIZE {
+                return Err(HeaderParseError::InsufficientData {
+                    expected: schema_end + 1 + SIGNATURE_SIZE,
+                    received: data.len(),
+                });
+            }
+            let lang_len = data[schema_end] as usize;
+            let lang_start = schema_end + 1;
+            let lang_end = lang_start + lang_len;
+            if data.len() < lang_end + SIGNATURE_SIZE {
+                return Err(HeaderParseError::InsufficientData {
+                    expected: lang_end + SIGNATURE_SIZE,
+                    received: data.len(),
+                });
+            }
+            let tag = std::str::from_utf8(&data[lang_start..lang_end])
+                .map_err(|_| HeaderParseError::InvalidLanguageTag {
+                    tag: format!("{:02X?}", &data[lang_start..lang_end]),
+                })?
+                .to_string();
+            (Some(tag), lang_end)
+        } else {
+            (None, schema_end)
+        };
+
+        // 6. Read signature
         let sig_end = sig_start + SIGNATURE_SIZE;
         let sig_bytes: [u8; SIGNATURE_SIZE] = data[sig_start..sig_end]
             .try_into()
@@ -191,14 +279,16 @@ impl GrmHeader {
         let header = GrmHeader {
             schema_id,
             signature,
+            language,
         };
 
-        Ok((header, total_header_len))
+        Ok((header, sig_end))
     }
 
     /// Calculates the header size in bytes.
     pub fn size(&self) -> usize {
-        4 + 2 + self.schema_id.len() + SIGNATURE_SIZE
+        let lang_len = self.language.as_ref().map(|l| 1 + l.len()).unwrap_or(0);
+        4 + 2 + self.schema_id.len() + lang_len + SIGNATURE_SIZE
     }
 }
 
@@ -233,6 +323,29 @@ pub enum HeaderParseError {
         /// Maximum allowed length in bytes.
         max: usize,
     },
+
+    /// The version byte is not a known .grm format version.
+    #[error("Unsupported .grm format version: 0x{version:02X}")]
+    UnsupportedVersion {
+        /// The version byte that was found.
+        version: u8,
+    },
+
+    /// The language tag exceeds the maximum length for the header format.
+    #[error("Language tag too long: {len} bytes (maximum: {max})")]
+    LanguageTagTooLong {
+        /// Actual length in bytes.
+        len: usize,
+        /// Maximum allowed length in bytes.
+        max: usize,
+    },
+
+    /// The language tag is empty or contains invalid characters.
+    #[error("Invalid language tag: {tag:?} (expected BCP-47, e.g. \"de-DE\")")]
+    InvalidLanguageTag {
+        /// The offending tag.
+        tag: String,
+    },
 }
 
 // ============================================================================
@@ -281,6 +394,46 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_header_with_language_roundtrip() {
+        let original = GrmHeader::new("de.gesundheit.praxis.v1").with_language("de-DE");
+        let bytes = original.to_bytes().unwrap();
+
+        // Version byte must be 2 for tagged headers
+        assert_eq!(bytes[3], GRM_VERSION_LANG);
+
+        let (parsed, length) = GrmHeader::from_bytes(&bytes).unwrap();
+        assert_eq!(parsed.schema_id, original.schema_id);
+        assert_eq!(parsed.language, Some("de-DE".to_string()));
+        assert_eq!(length, bytes.len());
+        assert_eq!(length, original.size());
+    }
+
+    #[test]
+    fn test_header_without_language_stays_version_1() {
+        let bytes = GrmHeader::new("test.v1").to_bytes().unwrap();
+        assert_eq!(bytes[3], GRM_VERSION);
+    }
+
+    #[test]
+    fn test_header_rejects_invalid_language_tag() {
+        let header = GrmHeader::new("test.v1").with_language("de DE!");
+        assert!(matches!(
+            header.to_bytes(),
+            Err(HeaderParseError::InvalidLanguageTag { .. })
+        ));
+    }
+
+    #[test]
+    fn test_header_rejects_unknown_version() {
+        let mut bytes = GrmHeader::new("test.v1").to_bytes().unwrap();
+        bytes[3] = 0x7F;
+        assert!(matches!(
+            GrmHeader::from_bytes(&bytes),
+            Err(HeaderParseError::UnsupportedVersion { version: 0x7F })
+        ));
+    }
+
     #[test]
     fn test_header_rejects_oversized_schema_id() {
         let huge_id = "x".repeat(u16::MAX as usize + 1);
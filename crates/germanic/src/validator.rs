@@ -68,8 +68,8 @@ pub fn validate_grm(data: &[u8]) -> GermanicResult<GrmValidation> {
         });
     }
 
-    // 2. Check magic bytes
-    if data[0..4] != GRM_MAGIC {
+    // 2. Check magic bytes ("GRM" prefix — version byte is checked by the header parser)
+    if data[0..3] != GRM_MAGIC[0..3] {
         return Ok(GrmValidation {
             valid: false,
             schema_id: None,
@@ -105,6 +105,16 @@ pub fn validate_grm(data: &[u8]) -> GermanicResult<GrmValidation> {
                 });
             }
 
+            // 5. Structural payload verification (generic offset/vtable
+            //    sanity checks — schema-agnostic, works for any .grm)
+            if let Err(reason) = verify_root_table(payload) {
+                return Ok(GrmValidation {
+                    valid: false,
+                    schema_id: Some(header.schema_id),
+                    error: Some(format!("Corrupted payload: {}", reason)),
+                });
+            }
+
             Ok(GrmValidation {
                 valid: true,
                 schema_id: Some(header.schema_id),
@@ -119,6 +129,63 @@ pub fn validate_grm(data: &[u8]) -> GermanicResult<GrmValidation> {
     }
 }
 
+/// Generic sanity checks on the root table of a FlatBuffer.
+///
+/// Mirrors the invariants the official verifier enforces for a single
+/// table: root offset in bounds, vtable reachable, sizes plausible,
+/// all field offsets inside the table's inline area.
+fn verify_root_table(payload: &[u8]) -> Result<(), String> {
+    let read_u16 = |pos: usize| -> Result<u16, String> {
+        payload
+            .get(pos..pos + 2)
+            .map(|b| u16::from_le_bytes([b[0], b[1]]))
+            .ok_or_else(|| "read out of bounds".to_string())
+    };
+
+    // Root: u32 offset to the root table
+    let root_offset = payload
+        .get(0..4)
+        .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]) as usize)
+        .ok_or("missing root offset")?;
+    if root_offset < 4 || root_offset + 4 > payload.len() {
+        return Err(format!("root offset {} out of bounds", root_offset));
+    }
+
+    // Table starts with i32 offset BACK to its vtable
+    let soffset = payload
+        .get(root_offset..root_offset + 4)
+        .map(|b| i32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+        .ok_or("missing vtable offset")?;
+    let vtable_pos = usize::try_from(root_offset as i64 - soffset as i64)
+        .map_err(|_| "vtable position out of range".to_string())?;
+
+    // Vtable: u16 vtable size, u16 table inline size, then u16 slots
+    let vtable_size = read_u16(vtable_pos)? as usize;
+    let inline_size = read_u16(vtable_pos + 2)? as usize;
+    if vtable_size < 4 || vtable_size % 2 != 0 {
+        return Err(format!("implausible vtable size {}", vtable_size));
+    }
+    if vtable_pos + vtable_size > payload.len() {
+        return Err("vtable exceeds buffer".to_string());
+    }
+    if inline_size < 4 || root_offset + inline_size > payload.len() {
+        return Err(format!("implausible table size {}", inline_size));
+    }
+
+    // Every present field offset must point inside the inline area
+    for slot in (4..vtable_size).step_by(2) {
+        let field_offset = read_u16(vtable_pos + slot)? as usize;
+        if field_offset != 0 && field_offset + 1 > inline_size {
+            return Err(format!(
+                "field offset {} outside table of size {}",
+                field_offset, inline_size
+            ));
+        }
+    }
+
+    Ok(())
+}
+
 /// Result of .grm validation.
 #[derive(Debug, Clone)]
 pub struct GrmValidation {
@@ -209,14 +276,59 @@ mod tests {
         assert!(result.error.unwrap().contains("Payload too short"));
     }
 
+    /// Builds a real minimal payload via the dynamic builder.
+    fn real_grm(schema_id: &str) -> Vec<u8> {
+        use crate::dynamic::schema_def::{FieldDefinition, FieldType, SchemaDefinition};
+        let mut fields = indexmap::IndexMap::new();
+        fields.insert(
+            "name".into(),
+            FieldDefinition {
+                required: true,
+                ..FieldDefinition::new(FieldType::String)
+            },
+        );
+        let schema = SchemaDefinition {
+            schema_id: schema_id.into(),
+            version: 1,
+            description: None,
+            limits: None,
+            strict: false,
+            fields,
+        };
+        let data = serde_json::json!({ "name": "Test" });
+        crate::dynamic::compile_dynamic_from_values(&schema, &data).unwrap()
+    }
+
     #[test]
     fn test_validate_grm_valid() {
+        let bytes = real_grm("test.v1");
+        let result = validate_grm(&bytes).unwrap();
+
+        assert!(result.valid, "error: {:?}", result.error);
+        assert_eq!(result.schema_id, Some("test.v1".to_string()));
+    }
+
+    #[test]
+    fn test_validate_grm_rejects_zeroed_payload() {
+        // Header fine, payload all zeros — previously reported "valid"
         let header = GrmHeader::new("test.v1");
         let mut bytes = header.to_bytes().unwrap();
-        bytes.extend_from_slice(&[0x00; 16]); // Enough for a minimal FlatBuffer
+        bytes.extend_from_slice(&[0x00; 16]);
         let result = validate_grm(&bytes).unwrap();
 
-        assert!(result.valid);
-        assert_eq!(result.schema_id, Some("test.v1".to_string()));
+        assert!(!result.valid);
+        assert!(result.error.unwrap().contains("Corrupted payload"));
+    }
+
+    #[test]
+    fn test_validate_grm_rejects_corrupted_root_offset() {
+        let mut bytes = real_grm("test.v1");
+        // Corrupt the root offset (first 4 payload bytes after the header)
+        let header_len = GrmHeader::from_bytes(&bytes).unwrap().1;
+        bytes[header_len..header_len + 4].copy_from_slice(&u32::MAX.to_le_bytes());
+        let result = validate_grm(&bytes).unwrap();
+
+        assert!(!result.valid);
+        assert!(result.error.unwrap().contains("Corrupted payload"));
     }
 }
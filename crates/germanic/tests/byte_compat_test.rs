@@ -42,46 +42,30 @@ fn praxis_schema_def() -> SchemaDefinition {
     addr_fields.insert(
         "strasse".into(),
         FieldDefinition {
-            field_type: FieldType::String,
             required: true,
-            default: None,
-            fields: None,
-        },
-    );
-    addr_fields.insert(
-        "hausnummer".into(),
-        FieldDefinition {
-            field_type: FieldType::String,
-            required: false,
-            default: None,
-            fields: None,
+            ..FieldDefinition::new(FieldType::String)
         },
     );
+    addr_fields.insert("hausnummer".into(), FieldDefinition::new(FieldType::String));
     addr_fields.insert(
         "plz".into(),
         FieldDefinition {
-            field_type: FieldType::String,
             required: true,
-            default: None,
-            fields: None,
+            ..FieldDefinition::new(FieldType::String)
         },
     );
     addr_fields.insert(
         "ort".into(),
         FieldDefinition {
-            field_type: FieldType::String,
             required: true,
-            default: None,
-            fields: None,
+            ..FieldDefinition::new(FieldType::String)
         },
     );
     addr_fields.insert(
         "land".into(),
         FieldDefinition {
-            field_type: FieldType::String,
-            required: false,
             default: Some("DE".into()),
-            fields: None,
+            ..FieldDefinition::new(FieldType::String)
         },
     );
 
@@ -90,151 +74,57 @@ fn praxis_schema_def() -> SchemaDefinition {
     fields.insert(
         "name".into(),
         FieldDefinition {
-            field_type: FieldType::String,
             required: true,
-            default: None,
-            fields: None,
+            ..FieldDefinition::new(FieldType::String)
         },
     );
     fields.insert(
         "bezeichnung".into(),
         FieldDefinition {
-            field_type: FieldType::String,
             required: true,
-            default: None,
-            fields: None,
-        },
-    );
-    fields.insert(
-        "praxisname".into(),
-        FieldDefinition {
-            field_type: FieldType::String,
-            required: false,
-            default: None,
-            fields: None,
+            ..FieldDefinition::new(FieldType::String)
         },
     );
+    fields.insert("praxisname".into(), FieldDefinition::new(FieldType::String));
     fields.insert(
         "adresse".into(),
         FieldDefinition {
-            field_type: FieldType::Table,
             required: true,
-            default: None,
             fields: Some(addr_fields),
+            ..FieldDefinition::new(FieldType::Table)
         },
     );
-    fields.insert(
-        "telefon".into(),
-        FieldDefinition {
-            field_type: FieldType::String,
-            required: false,
-            default: None,
-            fields: None,
-        },
-    );
-    fields.insert(
-        "email".into(),
-        FieldDefinition {
-            field_type: FieldType::String,
-            required: false,
-            default: None,
-            fields: None,
-        },
-    );
-    fields.insert(
-        "website".into(),
-        FieldDefinition {
-            field_type: FieldType::String,
-            required: false,
-            default: None,
-            fields: None,
-        },
-    );
-    fields.insert(
-        "schwerpunkte".into(),
-        FieldDefinition {
-            field_type: FieldType::StringArray,
-            required: false,
-            default: None,
-            fields: None,
-        },
-    );
-    fields.insert(
-        "therapieformen".into(),
-        FieldDefinition {
-            field_type: FieldType::StringArray,
-            required: false,
-            default: None,
-            fields: None,
-        },
-    );
-    fields.insert(
-        "qualifikationen".into(),
-        FieldDefinition {
-            field_type: FieldType::StringArray,
-            required: false,
-            default: None,
-            fields: None,
-        },
-    );
-    fields.insert(
-        "terminbuchung_url".into(),
-        FieldDefinition {
-            field_type: FieldType::String,
-            required: false,
-            default: None,
-            fields: None,
-        },
-    );
-    fields.insert(
-        "oeffnungszeiten".into(),
-        FieldDefinition {
-            field_type: FieldType::String,
-            required: false,
-            default: None,
-            fields: None,
-        },
-    );
+    fields.insert("telefon".into(), FieldDefinition::new(FieldType::String));
+    fields.insert("email".into(), FieldDefinition::new(FieldType::String));
+    fields.insert("website".into(), FieldDefinition::new(FieldType::String));
+    fields.insert("schwerpunkte".into(), FieldDefinition::new(FieldType::StringArray));
+    fields.insert("therapieformen".into(), FieldDefinition::new(FieldType::StringArray));
+    fields.insert("qualifikationen".into(), FieldDefinition::new(FieldType::StringArray));
+    fields.insert("terminbuchung_url".into(), FieldDefinition::new(FieldType::String));
+    fields.insert("oeffnungszeiten".into(), FieldDefinition::new(FieldType::String));
     fields.insert(
         "privatpatienten".into(),
         FieldDefinition {
-            field_type: FieldType::Bool,
-            required: false,
             default: Some("false".into()),
-            fields: None,
+            ..FieldDefinition::new(FieldType::Bool)
         },
     );
     fields.insert(
         "kassenpatienten".into(),
         FieldDefinition {
-            field_type: FieldType::Bool,
-            required: false,
             default: Some("false".into()),
-            fields: None,
-        },
-    );
-    fields.insert(
-        "sprachen".into(),
-        FieldDefinition {
-            field_type: FieldType::StringArray,
-            required: false,
-            default: None,
-            fields: None,
-        },
-    );
-    fields.insert(
-        "kurzbeschreibung".into(),
-        FieldDefinition {
-            field_type: FieldType::String,
-            required: false,
-            default: None,
-            fields: None,
+            ..FieldDefinition::new(FieldType::Bool)
         },
     );
+    fields.insert("sprachen".into(), FieldDefinition::new(FieldType::StringArray));
+    fields.insert("kurzbeschreibung".into(), FieldDefinition::new(FieldType::String));
 
     SchemaDefinition {
         schema_id: "de.gesundheit.praxis.v1".into(),
         version: 1,
+        description: None,
+        limits: None,
+        strict: false,
         fields,
     }
 }
@@ -328,3 +218,75 @@ fn test_dynamic_minimal_praxis() {
     assert_eq!(praxis.adresse().strasse(), "Teststr.");
     assert!(!praxis.privatpatienten()); // default false
 }
+
+/// Praxis .grm files compiled before the geo/opening-hours/insurance
+/// fields existed must keep decoding under the shipped definition.
+///
+/// Dynamic vtable slots follow field order, so schema additions may only
+/// be APPENDED — this test builds a payload with the original field set
+/// and reads it back through the current embedded schema.
+#[test]
+fn test_old_praxis_layout_decodes_with_shipped_definition() {
+    // The dynamic praxis definition exactly as shipped before the additions
+    let old_definition = r#"{
+        "schema_id": "de.gesundheit.praxis.v1",
+        "version": 1,
+        "fields": {
+            "name": { "type": "string", "required": true },
+            "bezeichnung": { "type": "string", "required": true },
+            "adresse": {
+                "type": "table",
+                "required": true,
+                "fields": {
+                    "strasse": { "type": "string", "required": true },
+                    "hausnummer": { "type": "string" },
+                    "plz": { "type": "string", "required": true },
+                    "ort": { "type": "string", "required": true },
+                    "land": { "type": "string", "default": "DE" }
+                }
+            },
+            "praxisname": { "type": "string" },
+            "telefon": { "type": "string" },
+            "email": { "type": "string" },
+            "website": { "type": "string" },
+            "terminbuchung_url": { "type": "string" },
+            "oeffnungszeiten": { "type": "string" },
+            "kurzbeschreibung": { "type": "string" },
+            "schwerpunkte": { "type": "[string]" },
+            "therapieformen": { "type": "[string]" },
+            "qualifikationen": { "type": "[string]" },
+            "sprachen": { "type": "[string]" },
+            "privatpatienten": { "type": "bool", "default": false },
+            "kassenpatienten": { "type": "bool", "default": false }
+        }
+    }"#;
+    let (old_schema, _warnings) = germanic::dynamic::parse_schema_auto(old_definition).unwrap();
+
+    let data = serde_json::json!({
+        "name": "Dr. Anna Müller",
+        "bezeichnung": "Fachärztin für Allgemeinmedizin",
+        "adresse": { "strasse": "Hauptstraße 42", "plz": "10115", "ort": "Berlin" },
+        "telefon": "+49 30 12345678",
+        "schwerpunkte": ["Kardiologie", "Diabetologie"],
+        "sprachen": ["Deutsch", "Englisch"],
+        "privatpatienten": true
+    });
+    let payload = build_flatbuffer(&old_schema, &data).expect("old-layout build failed");
+
+    // Decode with the CURRENT embedded definition (new fields appended)
+    let shipped = germanic::registry::find_definition("de.gesundheit.praxis.v1").unwrap();
+    let (new_schema, _warnings) = germanic::dynamic::parse_schema_auto(shipped).unwrap();
+    let decoded = germanic::reader::decode_payload(&new_schema, &payload).unwrap();
+
+    assert_eq!(decoded["name"], "Dr. Anna Müller");
+    assert_eq!(decoded["adresse"]["ort"], "Berlin");
+    assert_eq!(
+        decoded["schwerpunkte"],
+        serde_json::json!(["Kardiologie", "Diabetologie"])
+    );
+    assert_eq!(decoded["sprachen"], serde_json::json!(["Deutsch", "Englisch"]));
+    assert_eq!(decoded["privatpatienten"], serde_json::json!(true));
+    // Fields appended after the fact are simply absent in old payloads
+    assert!(decoded.get("breitengrad").is_none());
+    assert!(decoded.get("oeffnungszeiten_struktur").is_none());
+}
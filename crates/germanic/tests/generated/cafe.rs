@@ -0,0 +1,81 @@
+//! Generated by `germanic generate --lang rust`
+//! from schema `de.gastronomie.cafe.v1` — do not edit by hand.
+
+use germanic::GermanicSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, GermanicSchema)]
+#[germanic(schema_id = "de.gastronomie.cafe.adresse.v1")]
+pub struct AdresseSchema {
+    #[germanic(required)]
+    pub strasse: String,
+
+    #[serde(default)]
+    pub hausnummer: Option<String>,
+
+    #[germanic(required)]
+    pub plz: String,
+
+    #[germanic(required)]
+    pub ort: String,
+
+    #[serde(default = "default_land")]
+    #[germanic(default = "DE")]
+    pub land: String,
+}
+
+fn default_land() -> String {
+    "DE".to_string()
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, GermanicSchema)]
+#[germanic(schema_id = "de.gastronomie.cafe.v1")]
+pub struct CafeSchema {
+    #[germanic(required)]
+    pub name: String,
+
+    #[serde(default)]
+    pub inhaberin: Option<String>,
+
+    #[germanic(required)]
+    pub adresse: AdresseSchema,
+
+    #[germanic(required)]
+    pub telefon: String,
+
+    #[serde(default)]
+    pub email: Option<String>,
+
+    #[serde(default)]
+    pub website: Option<String>,
+
+    #[serde(default)]
+    pub kueche: Vec<String>,
+
+    #[serde(default)]
+    pub besonderheiten: Vec<String>,
+
+    #[serde(default)]
+    pub sitzplaetze_innen: i32,
+
+    #[serde(default)]
+    pub sitzplaetze_aussen: i32,
+
+    #[germanic(required)]
+    pub oeffnungszeiten: String,
+
+    #[serde(default)]
+    pub wlan: bool,
+
+    #[serde(default)]
+    pub barrierefreiheit: bool,
+
+    #[serde(default)]
+    pub kartenzahlung: bool,
+
+    #[serde(default)]
+    pub bewertung: f32,
+
+    #[serde(default)]
+    pub kurzbeschreibung: Option<String>,
+}
@@ -0,0 +1,76 @@
+//! # Generated-Code Compile Proof
+//!
+//! `germanic generate --lang rust` output must compile against the
+//! derive macro. The substring assertions in `codegen::rust` cannot
+//! catch an emitted attribute the macro rejects — nested schema_ids
+//! used to fail the `namespace.domain.name.vN` check exactly that way.
+//! Here the snapshot under `generated/cafe.rs` is compiled as a real
+//! module; a companion test pins it to the current generator output.
+//!
+//! Regenerate after codegen changes:
+//!
+//! ```text
+//! germanic generate schemas/definitions/de/de.gastronomie.cafe.v1.schema.json \
+//!     --lang rust --output crates/germanic/tests/generated/cafe.rs
+//! ```
+
+use germanic::schema::{SchemaMetadata, Validate};
+
+#[path = "generated/cafe.rs"]
+mod cafe;
+
+use cafe::{AdresseSchema, CafeSchema};
+
+#[test]
+fn test_snapshot_matches_current_generator() {
+    let definition = germanic::registry::find_definition("de.gastronomie.cafe.v1")
+        .expect("cafe schema is embedded in the registry");
+    let (schema, _warnings) = germanic::dynamic::parse_schema_auto(definition).unwrap();
+
+    assert_eq!(
+        germanic::codegen::rust::generate(&schema),
+        include_str!("generated/cafe.rs"),
+        "generated/cafe.rs is stale — regenerate it (see module docs)"
+    );
+}
+
+#[test]
+fn test_generated_struct_deserializes_and_validates() {
+    let json = r#"{
+        "name": "Café Morgenrot",
+        "inhaberin": "Clara Hoffmann",
+        "adresse": {
+            "strasse": "Marktplatz",
+            "hausnummer": "7",
+            "plz": "79098",
+            "ort": "Freiburg"
+        },
+        "telefon": "+49 761 123456",
+        "kueche": ["Frühstück", "Kuchen"],
+        "oeffnungszeiten": "Di-So 8-18 Uhr",
+        "wlan": true
+    }"#;
+
+    let cafe: CafeSchema = serde_json::from_str(json).unwrap();
+    cafe.validate().expect("fixture satisfies the schema");
+
+    assert_eq!(cafe.schema_id(), "de.gastronomie.cafe.v1");
+    assert_eq!(cafe.schema_version(), 1);
+    // serde default carried over from the definition
+    assert_eq!(cafe.adresse.land, "DE");
+}
+
+#[test]
+fn test_generated_nested_id_conforms_to_macro_format() {
+    // The mere existence of this impl proves the macro accepted the ID;
+    // the assertions pin the insert-before-version scheme.
+    let adresse = AdresseSchema::default();
+    assert_eq!(adresse.schema_id(), "de.gastronomie.cafe.adresse.v1");
+    assert_eq!(adresse.schema_version(), 1);
+}
+
+#[test]
+fn test_generated_validation_rejects_missing_required() {
+    let cafe = CafeSchema::default();
+    assert!(cafe.validate().is_err(), "empty café lacks required fields");
+}
@@ -105,6 +105,23 @@ fn test_schema_metadata() {
     assert_eq!(schema.schema_version(), 1);
 }
 
+#[test]
+fn test_schema_version_from_suffix() {
+    #[derive(GermanicSchema)]
+    #[germanic(schema_id = "test.versionierung.v3")]
+    pub struct VersionTestSchema {
+        pub name: Option<String>,
+    }
+
+    // The vN suffix drives schema_version(), not a hard-coded 1
+    let schema = VersionTestSchema::default();
+    assert!(schema.name.is_none());
+    assert_eq!(schema.schema_version(), 3);
+
+    // The dynamic definition carries the same version as the trait method
+    assert_eq!(VersionTestSchema::schema_definition().version, 3);
+}
+
 // ============================================================================
 // TEST 4: Combined validation and default
 // ============================================================================
@@ -241,3 +258,615 @@ fn test_nested_partial_error() {
         assert!(fields.contains(&"adresse.strasse".to_string()));
     }
 }
+
+// ============================================================================
+// TEST 6: Custom validator functions (validate_with)
+// ============================================================================
+
+/// LANR (lebenslange Arztnummer): exactly nine digits.
+fn validiere_lanr(lanr: &Option<String>) -> Result<(), String> {
+    match lanr {
+        None => Ok(()),
+        Some(value) if value.len() == 9 && value.chars().all(|c| c.is_ascii_digit()) => Ok(()),
+        Some(value) => Err(format!("\"{value}\" is not a valid LANR (nine digits)")),
+    }
+}
+
+#[derive(GermanicSchema)]
+#[germanic(schema_id = "test.arzt.v1")]
+pub struct ArztTestSchema {
+    #[germanic(required)]
+    pub name: String,
+
+    #[germanic(validate_with = "validiere_lanr")]
+    pub lanr: Option<String>,
+}
+
+#[test]
+fn test_validate_with_rejects_invalid_value() {
+    let schema = ArztTestSchema {
+        name: "Dr. Müller".to_string(),
+        lanr: Some("12AB".to_string()),
+    };
+
+    let result = schema.validate();
+    match result {
+        Err(germanic::error::ValidationError::ConstraintViolation { field, message }) => {
+            assert_eq!(field, "lanr");
+            assert!(message.contains("not a valid LANR"));
+        }
+        other => panic!("expected constraint violation, got: {other:?}"),
+    }
+}
+
+#[test]
+fn test_validate_with_accepts_valid_and_absent() {
+    let schema = ArztTestSchema {
+        name: "Dr. Müller".to_string(),
+        lanr: Some("123456789".to_string()),
+    };
+    assert!(schema.validate().is_ok());
+
+    let schema = ArztTestSchema {
+        name: "Dr. Müller".to_string(),
+        lanr: None,
+    };
+    assert!(schema.validate().is_ok());
+}
+
+#[test]
+fn test_validate_with_runs_after_required() {
+    // Missing required fields are reported first
+    let schema = ArztTestSchema {
+        name: "".to_string(),
+        lanr: Some("12AB".to_string()),
+    };
+
+    let result = schema.validate();
+    assert!(matches!(
+        result,
+        Err(germanic::error::ValidationError::RequiredFieldsMissing(_))
+    ));
+}
+
+// ============================================================================
+// TEST 7: Renamed fields (rename)
+// ============================================================================
+
+#[derive(GermanicSchema)]
+#[germanic(schema_id = "test.rename.v1")]
+pub struct RenameTestSchema {
+    #[germanic(required, rename = "street")]
+    pub strasse: String,
+
+    #[germanic(rename = "zip", min_len = 5)]
+    pub postleitzahl: Option<String>,
+}
+
+#[test]
+fn test_rename_in_error_paths() {
+    let schema = RenameTestSchema {
+        strasse: "".to_string(),
+        postleitzahl: None,
+    };
+
+    // Error paths use the schema name, not the Rust field name
+    if let Err(germanic::error::ValidationError::RequiredFieldsMissing(fields)) = schema.validate()
+    {
+        assert!(fields.contains(&"street".to_string()));
+        assert!(!fields.contains(&"strasse".to_string()));
+    } else {
+        panic!("expected missing required fields");
+    }
+
+    let schema = RenameTestSchema {
+        strasse: "Hauptstraße 1".to_string(),
+        postleitzahl: Some("12".to_string()),
+    };
+    match schema.validate() {
+        Err(germanic::error::ValidationError::ConstraintViolation { field, .. }) => {
+            assert_eq!(field, "zip");
+        }
+        other => panic!("expected constraint violation, got: {other:?}"),
+    }
+}
+
+#[test]
+fn test_rename_in_schema_definition() {
+    let definition = RenameTestSchema::schema_definition();
+
+    assert_eq!(
+        definition.fields.keys().collect::<Vec<_>>(),
+        vec!["street", "zip"],
+    );
+}
+
+// ============================================================================
+// TEST 8: Skipped fields (skip)
+// ============================================================================
+
+#[derive(GermanicSchema)]
+#[germanic(schema_id = "test.skip.v1")]
+pub struct SkipTestSchema {
+    #[germanic(required)]
+    pub name: String,
+
+    /// Internal cache — not part of the schema
+    #[germanic(skip)]
+    pub cache: std::collections::HashMap<String, String>,
+}
+
+#[test]
+fn test_skip_excluded_from_validation() {
+    // `cache` is a nested "Other" type, but skip must prevent the
+    // recursive validate() call and any required check
+    let schema = SkipTestSchema {
+        name: "Test".to_string(),
+        cache: std::collections::HashMap::new(),
+    };
+
+    assert!(schema.validate().is_ok());
+}
+
+#[test]
+fn test_skip_excluded_from_schema_definition() {
+    let definition = SkipTestSchema::schema_definition();
+
+    assert_eq!(definition.fields.keys().collect::<Vec<_>>(), vec!["name"]);
+}
+
+#[test]
+fn test_skip_default() {
+    let schema = SkipTestSchema::default();
+
+    assert!(schema.cache.is_empty());
+}
+
+// ============================================================================
+// TEST 9: Numeric fields
+// ============================================================================
+
+#[derive(GermanicSchema)]
+#[germanic(schema_id = "test.numeric.v1")]
+pub struct NumericTestSchema {
+    #[germanic(required)]
+    pub name: String,
+
+    #[germanic(default = "42")]
+    pub anzahl: i64,
+
+    #[germanic(default = "-7")]
+    pub differenz: i32,
+
+    #[germanic(default = "19.99")]
+    pub preis: f32,
+
+    #[germanic(default = "10", min = 0, max = 100)]
+    pub prozent: Option<u8>,
+
+    pub ohne_default: u32, // → 0
+}
+
+#[test]
+fn test_numeric_defaults() {
+    let schema = NumericTestSchema::default();
+
+    assert_eq!(schema.anzahl, 42);
+    assert_eq!(schema.differenz, -7);
+    assert_eq!(schema.preis, 19.99);
+    assert_eq!(schema.prozent, Some(10));
+    assert_eq!(schema.ohne_default, 0);
+}
+
+#[test]
+fn test_numeric_range_check() {
+    let schema = NumericTestSchema {
+        name: "Test".to_string(),
+        prozent: Some(250),
+        ..Default::default()
+    };
+
+    match schema.validate() {
+        Err(germanic::error::ValidationError::ConstraintViolation { field, message }) => {
+            assert_eq!(field, "prozent");
+            assert_eq!(message, "value 250 exceeds maximum 100");
+        }
+        other => panic!("expected constraint violation, got: {other:?}"),
+    }
+}
+
+#[test]
+fn test_numeric_schema_definition() {
+    use germanic::dynamic::schema_def::FieldType;
+
+    let definition = NumericTestSchema::schema_definition();
+
+    assert_eq!(definition.fields["anzahl"].field_type, FieldType::Int);
+    assert_eq!(definition.fields["preis"].field_type, FieldType::Float);
+    assert_eq!(
+        definition.fields["anzahl"].default,
+        Some(serde_json::json!(42))
+    );
+    assert_eq!(
+        definition.fields["prozent"].default,
+        Some(serde_json::json!(10))
+    );
+}
+
+// ============================================================================
+// TEST 10: Optional nested structs (Option<NestedStruct>)
+// ============================================================================
+
+#[derive(GermanicSchema)]
+#[germanic(schema_id = "test.filiale.v1")]
+pub struct FilialeTestSchema {
+    #[germanic(required)]
+    pub name: String,
+
+    pub zweigstelle: Option<AdresseTestSchema>, // Optional nested struct
+}
+
+#[test]
+fn test_optional_nested_absent_is_valid() {
+    let schema = FilialeTestSchema {
+        name: "Hauptsitz".to_string(),
+        zweigstelle: None,
+    };
+
+    assert!(schema.validate().is_ok());
+}
+
+#[test]
+fn test_optional_nested_validated_when_present() {
+    let schema = FilialeTestSchema {
+        name: "Hauptsitz".to_string(),
+        zweigstelle: Some(AdresseTestSchema {
+            strasse: "".to_string(), // ERROR
+            plz: "12345".to_string(),
+            ort: "Berlin".to_string(),
+            land: "DE".to_string(),
+        }),
+    };
+
+    let result = schema.validate();
+    assert!(result.is_err());
+
+    if let Err(germanic::error::ValidationError::RequiredFieldsMissing(fields)) = result {
+        assert_eq!(fields.len(), 1);
+        assert!(fields.contains(&"zweigstelle.strasse".to_string()));
+    }
+}
+
+#[test]
+fn test_optional_nested_valid_when_filled() {
+    let schema = FilialeTestSchema {
+        name: "Hauptsitz".to_string(),
+        zweigstelle: Some(AdresseTestSchema {
+            strasse: "Hauptstraße 1".to_string(),
+            plz: "12345".to_string(),
+            ort: "Berlin".to_string(),
+            land: "DE".to_string(),
+        }),
+    };
+
+    assert!(schema.validate().is_ok());
+}
+
+// ============================================================================
+// TEST 11: Nested struct vectors (Vec<NestedStruct>)
+// ============================================================================
+
+#[derive(GermanicSchema)]
+#[germanic(schema_id = "test.kette.v1")]
+pub struct KetteTestSchema {
+    #[germanic(required)]
+    pub name: String,
+
+    pub standorte: Vec<AdresseTestSchema>, // Nested struct vector
+}
+
+#[test]
+fn test_vec_nested_empty_is_valid() {
+    let schema = KetteTestSchema {
+        name: "Praxiskette".to_string(),
+        standorte: Vec::new(),
+    };
+
+    assert!(schema.validate().is_ok());
+}
+
+#[test]
+fn test_vec_nested_indexed_error_paths() {
+    fn adresse(plz: &str) -> AdresseTestSchema {
+        AdresseTestSchema {
+            strasse: "Hauptstraße 1".to_string(),
+            plz: plz.to_string(),
+            ort: "Berlin".to_string(),
+            land: "DE".to_string(),
+        }
+    }
+
+    let schema = KetteTestSchema {
+        name: "Praxiskette".to_string(),
+        // Third entry has an empty PLZ
+        standorte: vec![adresse("12345"), adresse("10115"), adresse("")],
+    };
+
+    let result = schema.validate();
+    assert!(result.is_err());
+
+    if let Err(germanic::error::ValidationError::RequiredFieldsMissing(fields)) = result {
+        assert_eq!(fields.len(), 1);
+        assert!(fields.contains(&"standorte[2].plz".to_string()));
+    }
+}
+
+// ============================================================================
+// TEST 12: Fieldless enums
+// ============================================================================
+
+#[derive(GermanicSchema, Debug, PartialEq)]
+pub enum KassenartTest {
+    #[germanic(default)]
+    Gesetzlich,
+    Privat,
+    #[germanic(rename = "selbstzahler")]
+    Selbstzahler,
+}
+
+#[derive(GermanicSchema)]
+#[germanic(schema_id = "test.abrechnung.v1")]
+pub struct AbrechnungTestSchema {
+    #[germanic(required)]
+    pub name: String,
+
+    #[germanic(enumeration, default = "Privat")]
+    pub kassenart: KassenartTest,
+
+    #[germanic(enumeration)]
+    pub bevorzugt: Option<KassenartTest>,
+}
+
+#[test]
+fn test_enum_string_and_ordinal() {
+    assert_eq!(
+        KassenartTest::variants(),
+        &["Gesetzlich", "Privat", "selbstzahler"]
+    );
+    assert_eq!(KassenartTest::Privat.as_str(), "Privat");
+    assert_eq!(KassenartTest::Selbstzahler.as_str(), "selbstzahler");
+    assert_eq!(KassenartTest::Gesetzlich.ordinal(), 0);
+    assert_eq!(KassenartTest::Privat.ordinal(), 1);
+}
+
+#[test]
+fn test_enum_from_name() {
+    assert_eq!(
+        KassenartTest::from_name("Privat"),
+        Some(KassenartTest::Privat)
+    );
+    assert_eq!(
+        KassenartTest::from_name("selbstzahler"),
+        Some(KassenartTest::Selbstzahler)
+    );
+    assert_eq!(KassenartTest::from_name("Unbekannt"), None);
+}
+
+#[test]
+fn test_enum_default_variant() {
+    assert_eq!(KassenartTest::default(), KassenartTest::Gesetzlich);
+}
+
+#[test]
+fn test_enum_fields_on_structs() {
+    let schema = AbrechnungTestSchema::default();
+
+    // Field-level default names a variant
+    assert_eq!(schema.kassenart, KassenartTest::Privat);
+    assert_eq!(schema.bevorzugt, None);
+
+    let schema = AbrechnungTestSchema {
+        name: "Praxis".to_string(),
+        ..Default::default()
+    };
+    assert!(schema.validate().is_ok());
+}
+
+#[test]
+fn test_enum_schema_definition() {
+    use germanic::dynamic::schema_def::FieldType;
+
+    let definition = AbrechnungTestSchema::schema_definition();
+
+    let kassenart = &definition.fields["kassenart"];
+    assert_eq!(kassenart.field_type, FieldType::Enum);
+    assert!(kassenart.required);
+    assert_eq!(
+        kassenart.values.as_deref(),
+        Some(&["Gesetzlich".to_string(), "Privat".to_string(), "selbstzahler".to_string()][..])
+    );
+    assert_eq!(kassenart.default, Some(serde_json::json!("Privat")));
+
+    assert!(!definition.fields["bevorzugt"].required);
+}
+
+// ============================================================================
+// TEST 13: min_items / max_items on collections
+// ============================================================================
+
+#[derive(GermanicSchema)]
+#[germanic(schema_id = "test.klinik.v1")]
+pub struct KlinikTestSchema {
+    #[germanic(required)]
+    pub name: String,
+
+    /// A hospital with fewer than three departments is suspicious
+    #[germanic(required, min_items = 3, max_items = 50)]
+    pub fachabteilungen: Vec<String>,
+}
+
+#[test]
+fn test_min_items_too_few() {
+    let schema = KlinikTestSchema {
+        name: "Klinikum Test".to_string(),
+        fachabteilungen: vec!["Kardiologie".to_string()],
+    };
+
+    match schema.validate() {
+        Err(germanic::error::ValidationError::ConstraintViolation { field, message }) => {
+            assert_eq!(field, "fachabteilungen");
+            assert_eq!(message, "array has 1 elements, minimum is 3");
+        }
+        other => panic!("Expected ConstraintViolation, got: {other:?}"),
+    }
+}
+
+#[test]
+fn test_min_items_within_range() {
+    let schema = KlinikTestSchema {
+        name: "Klinikum Test".to_string(),
+        fachabteilungen: vec![
+            "Kardiologie".to_string(),
+            "Unfallchirurgie".to_string(),
+            "Radiologie".to_string(),
+        ],
+    };
+
+    assert!(schema.validate().is_ok());
+}
+
+#[test]
+fn test_min_items_in_schema_definition() {
+    let definition = KlinikTestSchema::schema_definition();
+
+    // Element counts land in the length slots of the dynamic definition
+    let fachabteilungen = &definition.fields["fachabteilungen"];
+    assert_eq!(fachabteilungen.min_length, Some(3));
+    assert_eq!(fachabteilungen.max_length, Some(50));
+}
+
+// ============================================================================
+// TEST 14: Fluent builder
+// ============================================================================
+
+#[test]
+fn test_builder_valid() {
+    let schema = AdresseTestSchema::builder()
+        .strasse("Hauptstraße 1")
+        .plz("12345")
+        .ort("Berlin")
+        .build()
+        .expect("All required fields set");
+
+    assert_eq!(schema.strasse, "Hauptstraße 1");
+    // Unset fields keep their defaults
+    assert_eq!(schema.land, "DE");
+}
+
+#[test]
+fn test_builder_missing_required() {
+    let result = AdresseTestSchema::builder().strasse("Hauptstraße 1").build();
+
+    match result.err() {
+        Some(germanic::error::ValidationError::RequiredFieldsMissing(fields)) => {
+            assert!(fields.contains(&"plz".to_string()));
+            assert!(fields.contains(&"ort".to_string()));
+            assert!(!fields.contains(&"strasse".to_string()));
+        }
+        other => panic!("Expected RequiredFieldsMissing, got: {other:?}"),
+    }
+}
+
+#[test]
+fn test_builder_option_takes_inner_type() {
+    // Option<String> setters take the inner type, no Some() at call sites
+    let schema = ValidationTestSchema::builder()
+        .name("Dr. Müller")
+        .optional("Hinweis")
+        .build()
+        .expect("Valid schema");
+
+    assert_eq!(schema.optional.as_deref(), Some("Hinweis"));
+}
+
+#[test]
+fn test_builder_runs_constraint_checks() {
+    let result = KlinikTestSchema::builder()
+        .name("Klinikum Test")
+        .fachabteilungen(vec!["Kardiologie".to_string()])
+        .build();
+
+    match result.err() {
+        Some(germanic::error::ValidationError::ConstraintViolation { field, .. }) => {
+            assert_eq!(field, "fachabteilungen");
+        }
+        other => panic!("Expected ConstraintViolation, got: {other:?}"),
+    }
+}
+
+// ============================================================================
+// TEST 15: Flattened nested structs
+// ============================================================================
+
+#[derive(GermanicSchema)]
+#[germanic(schema_id = "test.kontakt.v1")]
+pub struct KontaktTestSchema {
+    #[germanic(required)]
+    pub telefon: String,
+
+    pub email: Option<String>,
+}
+
+#[derive(GermanicSchema)]
+#[germanic(schema_id = "test.visitenkarte.v1")]
+pub struct VisitenkarteTestSchema {
+    #[germanic(required)]
+    pub name: String,
+
+    #[germanic(flatten)]
+    pub kontakt: KontaktTestSchema,
+}
+
+#[test]
+fn test_flatten_schema_definition() {
+    let definition = VisitenkarteTestSchema::schema_definition();
+
+    // The nested fields sit at the top level, no "kontakt" key exists
+    assert!(definition.fields.contains_key("telefon"));
+    assert!(definition.fields.contains_key("email"));
+    assert!(!definition.fields.contains_key("kontakt"));
+    assert!(definition.fields["telefon"].required);
+}
+
+#[test]
+fn test_flatten_error_paths_stay_flat() {
+    let schema = VisitenkarteTestSchema {
+        name: "Dr. Müller".to_string(),
+        kontakt: KontaktTestSchema {
+            telefon: "".to_string(),
+            email: None,
+        },
+    };
+
+    let result = schema.validate();
+    if let Err(germanic::error::ValidationError::RequiredFieldsMissing(fields)) = result {
+        // "telefon", not "kontakt.telefon" — the schema is flat
+        assert!(fields.contains(&"telefon".to_string()));
+        assert!(!fields.contains(&"kontakt.telefon".to_string()));
+    } else {
+        panic!("Expected RequiredFieldsMissing");
+    }
+}
+
+#[test]
+fn test_flatten_valid() {
+    let schema = VisitenkarteTestSchema {
+        name: "Dr. Müller".to_string(),
+        kontakt: KontaktTestSchema {
+            telefon: "+49 89 123456".to_string(),
+            email: Some("praxis@example.de".to_string()),
+        },
+    };
+
+    assert!(schema.validate().is_ok());
+}